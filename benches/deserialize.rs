@@ -0,0 +1,115 @@
+//! Benchmarks of update deserialization.
+//!
+//! Run with `cargo bench --bench deserialize`. This uses a plain loop instead
+//! of a benchmarking framework, so numbers are only useful for comparison
+//! between runs on the same machine.
+
+use std::time::Instant;
+
+use teloxide_core::types::Update;
+
+const ITERATIONS: u32 = 100_000;
+
+const COMMAND: &str = r#"{
+    "update_id": 892252934,
+    "message": {
+        "message_id": 6557,
+        "from": {
+            "id": 218485655,
+            "is_bot": false,
+            "first_name": "Waffle",
+            "username": "WaffleLapkin",
+            "language_code": "en"
+        },
+        "chat": {
+            "id": 218485655,
+            "first_name": "Waffle",
+            "username": "WaffleLapkin",
+            "type": "private"
+        },
+        "date": 1569518342,
+        "text": "/start hello",
+        "entities": [{ "type": "bot_command", "offset": 0, "length": 6 }]
+    }
+}"#;
+
+const FORMATTED: &str = r#"{
+    "update_id": 892252935,
+    "message": {
+        "message_id": 6558,
+        "from": {
+            "id": 218485655,
+            "is_bot": false,
+            "first_name": "Waffle",
+            "username": "WaffleLapkin",
+            "language_code": "en"
+        },
+        "chat": {
+            "id": -1001308953350,
+            "title": "Chat",
+            "type": "supergroup"
+        },
+        "date": 1569518343,
+        "text": "bold italic link @user #tag",
+        "entities": [
+            { "type": "bold", "offset": 0, "length": 4 },
+            { "type": "italic", "offset": 5, "length": 6 },
+            { "type": "text_link", "offset": 12, "length": 4, "url": "https://example.com" },
+            { "type": "mention", "offset": 17, "length": 5 },
+            { "type": "hashtag", "offset": 23, "length": 4 }
+        ]
+    }
+}"#;
+
+const PHOTO: &str = r#"{
+    "update_id": 892252936,
+    "message": {
+        "message_id": 6559,
+        "from": {
+            "id": 218485655,
+            "is_bot": false,
+            "first_name": "Waffle",
+            "username": "WaffleLapkin",
+            "language_code": "en"
+        },
+        "chat": {
+            "id": 218485655,
+            "first_name": "Waffle",
+            "username": "WaffleLapkin",
+            "type": "private"
+        },
+        "date": 1569518344,
+        "photo": [
+            { "file_id": "a", "file_unique_id": "a", "width": 90, "height": 60, "file_size": 1000 },
+            { "file_id": "b", "file_unique_id": "b", "width": 320, "height": 213, "file_size": 10000 },
+            { "file_id": "c", "file_unique_id": "c", "width": 800, "height": 533, "file_size": 50000 }
+        ],
+        "caption": "caption"
+    }
+}"#;
+
+fn bench(name: &str, json: &str) {
+    // Make sure the input is valid, otherwise we'd measure error paths
+    serde_json::from_str::<Update>(json).unwrap();
+
+    // Summing ids keeps the results used
+    let mut ids = 0i64;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        ids = ids.wrapping_add(i64::from(serde_json::from_str::<Update>(json).unwrap().id));
+    }
+    let elapsed = start.elapsed();
+    assert_ne!(ids, 0);
+
+    println!(
+        "{:<12} {:>8} ns/iter",
+        name,
+        elapsed.as_nanos() / u128::from(ITERATIONS)
+    );
+}
+
+fn main() {
+    bench("command", COMMAND);
+    bench("formatted", FORMATTED);
+    bench("photo", PHOTO);
+}
@@ -0,0 +1,50 @@
+//! Benchmarks of building request URLs.
+//!
+//! Run with `cargo bench --bench url`. This uses a plain loop instead of a
+//! benchmarking framework, so numbers are only useful for comparison between
+//! runs on the same machine. `join` is the naive `Url::join`-based approach,
+//! for comparison.
+
+use std::time::Instant;
+
+use reqwest::Url;
+use teloxide_core::net::{file_url, method_url, TELEGRAM_API_URL};
+
+const ITERATIONS: u32 = 1_000_000;
+
+const TOKEN: &str = "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao";
+
+fn bench<F>(name: &str, f: F)
+where
+    F: Fn(Url) -> Url,
+{
+    let base = Url::parse(TELEGRAM_API_URL).unwrap();
+
+    // Summing lengths keeps the results used
+    let mut len = 0usize;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        len = len.wrapping_add(f(base.clone()).as_str().len());
+    }
+    let elapsed = start.elapsed();
+    assert_ne!(len, 0);
+
+    println!(
+        "{:<12} {:>8} ns/iter",
+        name,
+        elapsed.as_nanos() / u128::from(ITERATIONS)
+    );
+}
+
+fn main() {
+    bench("method", |base| method_url(base, TOKEN, "sendMessage"));
+    bench("method/join", |base| {
+        base.join(&format!("/bot{}/{}", TOKEN, "sendMessage"))
+            .unwrap()
+    });
+    bench("file", |base| file_url(base, TOKEN, "photos/file_0.jpg"));
+    bench("file/join", |base| {
+        base.join(&format!("file/bot{}/{}", TOKEN, "photos/file_0.jpg"))
+            .unwrap()
+    });
+}
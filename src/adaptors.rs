@@ -8,6 +8,14 @@
 //!
 //! [`Requester`]: crate::requests::Requester
 
+/// [`Audit`] bot adaptor which logs all sent requests with redacted
+/// payloads.
+///
+/// [`Audit`]: audit::Audit
+#[cfg(feature = "audit")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "audit")))]
+pub mod audit;
+
 /// [`AutoSend`] bot adaptor which allows sending a request without calling
 /// [`send`].
 ///
@@ -17,6 +25,16 @@
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "auto_send")))]
 pub mod auto_send;
 
+/// [`BotBuilder`] which assembles the commonly used adaptor stack.
+///
+/// [`BotBuilder`]: builder::BotBuilder
+#[cfg(all(feature = "cache_me", feature = "throttle"))]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(all(feature = "cache_me", feature = "throttle")))
+)]
+pub mod builder;
+
 /// [`CacheMe`] bot adaptor which caches [`GetMe`] requests.
 ///
 /// [`CacheMe`]: cache_me::CacheMe
@@ -25,6 +43,53 @@ pub mod auto_send;
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "cache_me")))]
 pub mod cache_me;
 
+/// [`ChatQueue`] bot adaptor which delivers messages to the same chat in
+/// order.
+///
+/// [`ChatQueue`]: chat_queue::ChatQueue
+#[cfg(feature = "chat_queue")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_queue")))]
+pub mod chat_queue;
+
+/// [`ConcurrencyLimit`] bot adaptor which limits the number of requests in
+/// flight.
+///
+/// [`ConcurrencyLimit`]: concurrency_limit::ConcurrencyLimit
+#[cfg(feature = "concurrency_limit")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "concurrency_limit"))
+)]
+pub mod concurrency_limit;
+
+/// [`ChatMigration`] bot adaptor which retries requests to migrated chats.
+///
+/// [`ChatMigration`]: chat_migration::ChatMigration
+#[cfg(feature = "chat_migration")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_migration")))]
+pub mod chat_migration;
+
+/// [`MediaCache`] bot adaptor which reuses `file_id`s of uploaded files.
+///
+/// [`MediaCache`]: media_cache::MediaCache
+#[cfg(feature = "media_cache")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "media_cache")))]
+pub mod media_cache;
+
+/// [`Outbox`] bot adaptor which persists requests before sending them.
+///
+/// [`Outbox`]: outbox::Outbox
+#[cfg(feature = "outbox")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "outbox")))]
+pub mod outbox;
+
+/// [`ErrorHook`] bot adaptor which calls a hook on every request error.
+///
+/// [`ErrorHook`]: error_hook::ErrorHook
+#[cfg(feature = "error_hook")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "error_hook")))]
+pub mod error_hook;
+
 /// [`Throttle`] bot adaptor which allows automatically throttle when hitting
 /// API limits.
 ///
@@ -33,16 +98,173 @@ pub mod cache_me;
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "throttle")))]
 pub mod throttle;
 
+/// [`ValidateRequests`] bot adaptor which rejects obviously invalid requests
+/// before sending them.
+///
+/// [`ValidateRequests`]: validate::ValidateRequests
+#[cfg(feature = "validate")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "validate")))]
+pub mod validate;
+
 mod parse_mode;
 
+#[cfg(feature = "audit")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "audit")))]
+pub use audit::Audit;
 #[cfg(feature = "auto_send")]
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "auto_send")))]
 pub use auto_send::AutoSend;
+#[cfg(all(feature = "cache_me", feature = "throttle"))]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(all(feature = "cache_me", feature = "throttle")))
+)]
+pub use builder::{BotBuilder, DefaultBot, DefaultParseModeBot};
 #[cfg(feature = "cache_me")]
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "cache_me")))]
 pub use cache_me::CacheMe;
+#[cfg(feature = "chat_migration")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_migration")))]
+pub use chat_migration::ChatMigration;
+#[cfg(feature = "chat_queue")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_queue")))]
+pub use chat_queue::ChatQueue;
+#[cfg(feature = "concurrency_limit")]
+#[cfg_attr(
+    all(docsrs, feature = "nightly"),
+    doc(cfg(feature = "concurrency_limit"))
+)]
+pub use concurrency_limit::ConcurrencyLimit;
+#[cfg(feature = "error_hook")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "error_hook")))]
+pub use error_hook::ErrorHook;
+#[cfg(feature = "media_cache")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "media_cache")))]
+pub use media_cache::MediaCache;
+#[cfg(feature = "outbox")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "outbox")))]
+pub use outbox::{Outbox, OutboxStore};
 #[cfg(feature = "throttle")]
 #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "throttle")))]
 pub use throttle::Throttle;
+#[cfg(feature = "validate")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "validate")))]
+pub use validate::ValidateRequests;
 
 pub use parse_mode::DefaultParseMode;
+
+/// Common interface of bot adaptors, which allows to inspect and partially
+/// unwrap stacks of adaptors generically.
+///
+/// Every adaptor also has inherent `inner` and `into_inner` methods, this
+/// trait is only needed in generic code. Configuration of adaptors is exposed
+/// by their own getters (e.g. [`DefaultParseMode::parse_mode`]), so that they
+/// can be rebuilt around the unwrapped bot.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::{Adaptor, DefaultParseMode},
+///     types::ParseMode,
+///     Bot,
+/// };
+///
+/// fn token<A>(bot: &A) -> &str
+/// where
+///     A: Adaptor<Inner = Bot>,
+/// {
+///     bot.inner().token()
+/// }
+///
+/// let bot = DefaultParseMode::new(Bot::new("TOKEN"), ParseMode::Html);
+/// assert_eq!(token(&bot), "TOKEN");
+///
+/// // Rebuild the adaptor with another parse mode
+/// let bot = DefaultParseMode::new(Adaptor::into_inner(bot), ParseMode::MarkdownV2);
+/// assert_eq!(bot.parse_mode(), ParseMode::MarkdownV2);
+/// ```
+pub trait Adaptor {
+    /// The wrapped bot.
+    type Inner;
+
+    /// Returns a reference to the wrapped bot.
+    fn inner(&self) -> &Self::Inner;
+
+    /// Unwraps the wrapped bot.
+    fn into_inner(self) -> Self::Inner;
+}
+
+macro_rules! impl_adaptor {
+    ($( $(#[$attr:meta])* $T:ident<B $(, $P:ident)*>; )*) => {
+        $(
+            $(#[$attr])*
+            impl<B $(, $P)*> Adaptor for $T<B $(, $P)*> {
+                type Inner = B;
+
+                fn inner(&self) -> &B {
+                    $T::inner(self)
+                }
+
+                fn into_inner(self) -> B {
+                    $T::into_inner(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_adaptor! {
+    #[cfg(feature = "audit")]
+    Audit<B>;
+    #[cfg(feature = "auto_send")]
+    AutoSend<B>;
+    #[cfg(feature = "cache_me")]
+    CacheMe<B>;
+    #[cfg(feature = "chat_migration")]
+    ChatMigration<B>;
+    #[cfg(feature = "chat_queue")]
+    ChatQueue<B>;
+    #[cfg(feature = "concurrency_limit")]
+    ConcurrencyLimit<B>;
+    #[cfg(feature = "error_hook")]
+    ErrorHook<B, H, DH>;
+    #[cfg(feature = "media_cache")]
+    MediaCache<B>;
+    #[cfg(feature = "outbox")]
+    Outbox<B>;
+    #[cfg(feature = "throttle")]
+    Throttle<B>;
+    #[cfg(feature = "validate")]
+    ValidateRequests<B>;
+    DefaultParseMode<B>;
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(all(
+        feature = "throttle",
+        feature = "cache_me",
+        feature = "chat_queue",
+        feature = "chat_migration",
+        feature = "media_cache"
+    ))]
+    fn requests_are_clone() {
+        use crate::{
+            adaptors::{throttle::Limits, ChatMigration, ChatQueue, MediaCache, Throttle},
+            requests::Requester,
+            types::InputFile,
+            Bot,
+        };
+
+        fn assert_clone<T: Clone>(_: &T) {}
+
+        let (bot, _worker) = Throttle::new(Bot::new("TOKEN"), Limits::default());
+        let bot = ChatMigration::new(MediaCache::new(ChatQueue::new(bot)));
+
+        assert_clone(&bot.send_message(0, "text"));
+        assert_clone(&bot.send_photo(0, InputFile::memory("photo.png", &b"data"[..])));
+        assert_clone(&crate::adaptors::CacheMe::new(Bot::new("TOKEN")).get_me());
+    }
+}
@@ -0,0 +1,396 @@
+use std::{borrow::Cow, fmt, sync::Arc};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    requests::{HasPayload, Payload, Request, Requester},
+    types::*,
+};
+
+/// Fields which are redacted by default, see [`Audit::redact`].
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "text",
+    "caption",
+    "message_text",
+    "description",
+    "question",
+    "options",
+    "phone_number",
+    "vcard",
+    "first_name",
+    "last_name",
+    "email",
+    "address",
+    "latitude",
+    "longitude",
+    "provider_token",
+    "url",
+];
+
+/// The string which replaces values of the redacted fields.
+pub const REDACTED: &str = "<redacted>";
+
+/// The `log` target used by [`Audit`] when no custom sink is set.
+pub const LOG_TARGET: &str = "teloxide_core::audit";
+
+/// A record about a sent request, see [`Audit`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditRecord {
+    /// Name of the method, e.g.: `SendMessage`.
+    pub method: &'static str,
+
+    /// Payload of the request with the redacted fields replaced by
+    /// [`REDACTED`].
+    pub payload: Value,
+}
+
+/// Audit log adaptor, see [`RequesterExt::audit`].
+///
+/// Logs a record ([`AuditRecord`]) for every sent request: name of the method
+/// and its payload with some fields redacted (by default fields listed in
+/// [`DEFAULT_REDACTED_FIELDS`]). Records are either logged to the
+/// [`LOG_TARGET`] with the `info` level or passed to a custom sink set with
+/// [`Audit::sink`].
+///
+/// Contents of files from memory ([`InputFile::Memory`]) are never recorded,
+/// only their names and lengths.
+///
+/// Note that a record is produced when the request is sent (i.e. on
+/// [`Request::send`] or [`Request::send_ref`]), not when it's created.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::audit::AuditRecord,
+///     requests::{Requester, RequesterExt},
+///     Bot,
+/// };
+///
+/// let bot = Bot::new("TOKEN")
+///     .audit()
+///     .redact("title")
+///     .sink(|record: &AuditRecord| eprintln!("{} {}", record.method, record.payload));
+/// # let _ = bot;
+/// ```
+///
+/// [`RequesterExt::audit`]: crate::requests::RequesterExt::audit
+#[derive(Clone)]
+pub struct Audit<B> {
+    bot: B,
+    config: Arc<Config>,
+}
+
+type Sink = Arc<dyn Fn(&AuditRecord) + Send + Sync>;
+
+#[derive(Clone)]
+struct Config {
+    redacted: Vec<Cow<'static, str>>,
+    sink: Option<Sink>,
+}
+
+impl<B> Audit<B> {
+    /// Creates new `Audit` which redacts [`DEFAULT_REDACTED_FIELDS`] and logs
+    /// to the [`LOG_TARGET`].
+    ///
+    /// Note: it's recommended to use [`RequesterExt::audit`] instead.
+    ///
+    /// [`RequesterExt::audit`]: crate::requests::RequesterExt::audit
+    pub fn new(bot: B) -> Self {
+        let redacted = DEFAULT_REDACTED_FIELDS
+            .iter()
+            .copied()
+            .map(Cow::Borrowed)
+            .collect();
+
+        Self {
+            bot,
+            config: Arc::new(Config {
+                redacted,
+                sink: None,
+            }),
+        }
+    }
+
+    /// Adds a field to the list of redacted fields.
+    ///
+    /// Fields are matched by name at any depth of the payload, e.g.
+    /// redacting `title` redacts both [`SendInvoice::title`] and titles of
+    /// all inline query results in [`AnswerInlineQuery`].
+    ///
+    /// [`SendInvoice::title`]: crate::payloads::SendInvoice::title
+    /// [`AnswerInlineQuery`]: crate::payloads::AnswerInlineQuery
+    pub fn redact<F>(mut self, field: F) -> Self
+    where
+        F: Into<Cow<'static, str>>,
+    {
+        Arc::make_mut(&mut self.config).redacted.push(field.into());
+        self
+    }
+
+    /// Replaces the list of redacted fields.
+    pub fn redacted_fields<I, F>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<Cow<'static, str>>,
+    {
+        Arc::make_mut(&mut self.config).redacted = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets a sink which receives all records instead of the [`LOG_TARGET`].
+    pub fn sink<F>(mut self, sink: F) -> Self
+    where
+        F: Fn(&AuditRecord) + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.config).sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Returns the list of redacted fields.
+    pub fn redacted(&self) -> impl Iterator<Item = &str> {
+        self.config.redacted.iter().map(|f| &**f)
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for Audit<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Audit")
+            .field("bot", &self.bot)
+            .field("redacted", &self.config.redacted)
+            .field("sink", &self.config.sink.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl Config {
+    fn record<P>(&self, payload: &P)
+    where
+        P: Payload + Serialize,
+    {
+        let mut payload = serde_json::to_value(payload).unwrap_or_else(|err| {
+            Value::String(format!("<failed to serialize the payload: {}>", err))
+        });
+        summarize_memory_files(&mut payload);
+        redact(&mut payload, &self.redacted);
+
+        let record = AuditRecord {
+            method: P::NAME,
+            payload,
+        };
+
+        match &self.sink {
+            Some(sink) => sink(&record),
+            None => log::info!(target: LOG_TARGET, "{} {}", record.method, record.payload),
+        }
+    }
+}
+
+/// Recursively replaces values of the `fields` in `value` with [`REDACTED`].
+fn redact(value: &mut Value, fields: &[Cow<'static, str>]) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if fields.iter().any(|f| f == key) {
+                    *value = Value::String(REDACTED.to_owned());
+                } else {
+                    redact(value, fields);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| redact(v, fields)),
+        _ => {}
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        AuditRequest {
+            inner: $this.inner().$m($($arg),*),
+            config: Arc::clone(&$this.config),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        AuditRequest<B::$T>
+    };
+}
+
+impl<B> Requester for Audit<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, copy_message, send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, answer_shipping_query,
+        answer_pre_checkout_query, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Audit<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Audit`], produces an [`AuditRecord`] when sent.
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone)]
+pub struct AuditRequest<R> {
+    inner: R,
+    config: Arc<Config>,
+}
+
+impl<R> HasPayload for AuditRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for AuditRequest<R>
+where
+    R: crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R> Request for AuditRequest<R>
+where
+    R: Request,
+    R::Payload: Serialize,
+{
+    type Err = R::Err;
+    type Send = R::Send;
+    type SendRef = R::SendRef;
+
+    fn send(self) -> Self::Send {
+        self.config.record(self.inner.payload_ref());
+        self.inner.send()
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        self.config.record(self.inner.payload_ref());
+        self.inner.send_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::payloads::{SendContact, SendContactSetters, SendDocument, SendDocumentSetters};
+
+    #[test]
+    fn redacts_fields() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let audit = Audit::new(()).redact("title").sink({
+            let records = Arc::clone(&records);
+            move |r: &AuditRecord| records.lock().unwrap().push(r.clone())
+        });
+
+        let payload = SendContact::new(1, "+0000000000", "First").vcard("BEGIN:VCARD");
+        audit.config.record(&payload);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].method, "SendContact");
+        assert_eq!(records[0].payload["chat_id"], 1);
+        assert_eq!(records[0].payload["phone_number"], REDACTED);
+        assert_eq!(records[0].payload["first_name"], REDACTED);
+        assert_eq!(records[0].payload["vcard"], REDACTED);
+    }
+
+    #[test]
+    fn memory_files_are_not_recorded() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let audit = Audit::new(()).sink({
+            let records = Arc::clone(&records);
+            move |r: &AuditRecord| records.lock().unwrap().push(r.clone())
+        });
+
+        let payload =
+            SendDocument::new(1, InputFile::memory("a.txt", &b"secret"[..])).caption("secret");
+        audit.config.record(&payload);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records[0].payload["document"], "<file `a.txt`, 6 bytes>");
+        assert_eq!(records[0].payload["caption"], REDACTED);
+    }
+
+    #[test]
+    fn redacts_nested_fields() {
+        let mut value = serde_json::json!({
+            "results": [{ "title": "a", "id": "0" }, { "title": "b", "id": "1" }]
+        });
+        redact(&mut value, &[Cow::Borrowed("title")]);
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "results": [{ "title": REDACTED, "id": "0" }, { "title": REDACTED, "id": "1" }]
+            })
+        );
+    }
+}
@@ -89,17 +89,26 @@ where
         stop_message_live_location, stop_message_live_location_inline, send_venue,
         send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
         get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
         promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
         revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
         leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
         set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
         set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
         edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
         edit_message_media, edit_message_media_inline, edit_message_reply_markup,
         edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
         get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
         add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
         set_sticker_set_thumb, send_invoice, answer_shipping_query,
         answer_pre_checkout_query, set_passport_data_errors, send_game,
@@ -142,6 +151,24 @@ enum Inner<R: Request> {
     Done,
 }
 
+/// Clones an unsent request.
+///
+/// ## Panics
+///
+/// If the request was already polled.
+impl<R> Clone for AutoRequest<R>
+where
+    R: Request + Clone,
+{
+    fn clone(&self) -> Self {
+        match &self.0 {
+            Inner::Request(req) => Self::new(req.clone()),
+            Inner::Future(_) => already_polled(),
+            Inner::Done => done_unreachable(),
+        }
+    }
+}
+
 impl<R> Request for AutoRequest<R>
 where
     R: Request,
@@ -187,6 +214,28 @@ impl<R: Request> HasPayload for AutoRequest<R> {
     }
 }
 
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for AutoRequest<R>
+where
+    R: Request + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        match &self.0 {
+            Inner::Request(req) => req.to_curl(),
+            Inner::Future(_) => already_polled(),
+            Inner::Done => done_unreachable(),
+        }
+    }
+
+    fn to_http(&self) -> String {
+        match &self.0 {
+            Inner::Request(req) => req.to_http(),
+            Inner::Future(_) => already_polled(),
+            Inner::Done => done_unreachable(),
+        }
+    }
+}
+
 impl<R: Request> Future for AutoRequest<R> {
     type Output = Result<Output<R>, R::Err>;
 
@@ -243,3 +292,22 @@ fn done_unreachable() -> ! {
 fn already_polled() -> ! {
     panic!("AutoRequest was already polled once")
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        requests::{HasPayload, Requester, RequesterExt},
+        Bot,
+    };
+
+    #[test]
+    fn clone() {
+        let bot = Bot::new("TOKEN").auto_send();
+        let request = bot.send_message(0, "hi");
+        let mut clone = request.clone();
+        clone.payload_mut().text = "bye".to_owned();
+
+        assert_eq!(request.payload_ref().text, "hi");
+        assert_eq!(clone.payload_ref().text, "bye");
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    adaptors::{
+        throttle::{Adaptive, Limits},
+        CacheMe, DefaultParseMode, Throttle,
+    },
+    types::ParseMode,
+    Bot,
+};
+
+/// The adaptor stack assembled by [`BotBuilder::build`].
+pub type DefaultBot<B = Bot> = CacheMe<Throttle<B>>;
+
+/// The adaptor stack assembled by [`BotBuilder::build_with_parse_mode`].
+pub type DefaultParseModeBot<B = Bot> = DefaultParseMode<DefaultBot<B>>;
+
+/// Builder of the commonly used adaptor stack.
+///
+/// The order in which adaptors are applied matters, e.g. adaptors inside of
+/// [`Throttle`] can cause it to miscalculate the usage of limits. This builder
+/// always applies them in the recommended order, from the innermost to the
+/// outermost:
+///
+/// 1. [`Throttle`], which needs to see every request which is actually sent
+///    to Telegram;
+/// 2. [`CacheMe`], so that cached [`GetMe`] requests don't even reach the
+///    throttling;
+/// 3. [`DefaultParseMode`] (optional, see [`build_with_parse_mode`]), which
+///    only alters payloads.
+///
+/// [`DefaultBot`] and [`DefaultParseModeBot`] name the resulting types, e.g.
+/// for fields of structs which store the bot.
+///
+/// ## Examples
+///
+/// ```no_run (throttle fails to spawn task without tokio runtime)
+/// use teloxide_core::{
+///     adaptors::{throttle::Limits, BotBuilder, DefaultParseModeBot},
+///     types::ParseMode,
+///     Bot,
+/// };
+///
+/// let bot: DefaultParseModeBot = BotBuilder::new(Bot::new("TOKEN"))
+///     .limits(Limits::default())
+///     .build_with_parse_mode(ParseMode::Html);
+/// ```
+///
+/// [`GetMe`]: crate::payloads::GetMe
+/// [`build_with_parse_mode`]: BotBuilder::build_with_parse_mode
+#[derive(Clone, Debug)]
+#[must_use = "`BotBuilder` does nothing until `build` is called"]
+pub struct BotBuilder<B = Bot> {
+    bot: B,
+    limits: Limits,
+    adaptive: Option<Adaptive>,
+}
+
+impl<B> BotBuilder<B> {
+    /// Creates a builder wrapping the `bot`, with the default [`Limits`] and
+    /// without adaptive throttling.
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            limits: Limits::default(),
+            adaptive: None,
+        }
+    }
+
+    /// Sets limits of the [`Throttle`].
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Enables adaptive throttling, see [`Throttle::adaptive`].
+    pub fn adaptive(mut self, adaptive: Adaptive) -> Self {
+        self.adaptive = Some(adaptive);
+        self
+    }
+
+    /// Assembles the adaptors, spawning the worker of the [`Throttle`] with
+    /// `tokio::spawn`.
+    pub fn build(self) -> DefaultBot<B>
+    where
+        B: 'static,
+    {
+        let mut throttle = Throttle::new_spawn(self.bot, self.limits);
+        if let Some(adaptive) = self.adaptive {
+            throttle = throttle.adaptive(adaptive);
+        }
+
+        CacheMe::new(throttle)
+    }
+
+    /// Assembles the adaptors like [`build`] and additionally sets the
+    /// default parse mode.
+    ///
+    /// [`build`]: BotBuilder::build
+    pub fn build_with_parse_mode(self, parse_mode: ParseMode) -> DefaultParseModeBot<B>
+    where
+        B: 'static,
+    {
+        DefaultParseMode::new(self.build(), parse_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order() {
+        let rt = crate::test_utils::rt();
+        let _guard = rt.enter();
+
+        let bot = BotBuilder::new(Bot::new("TOKEN"))
+            .adaptive(Adaptive::default())
+            .build_with_parse_mode(ParseMode::Html);
+
+        assert_eq!(bot.parse_mode(), ParseMode::Html);
+        let throttle: &Throttle<Bot> = bot.inner().inner();
+        let limits = throttle.limits();
+        assert_eq!(limits, Limits::default());
+        assert_eq!(throttle.inner().token(), "TOKEN");
+
+        // Unwrap the parse mode and rebuild the rest of the stack
+        let bot: DefaultBot = CacheMe::new(Throttle::new_spawn(
+            bot.into_inner().into_inner().into_inner(),
+            limits,
+        ));
+        assert_eq!(bot.inner().inner().token(), "TOKEN");
+    }
+}
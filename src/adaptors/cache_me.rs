@@ -1,4 +1,9 @@
-use std::{pin::Pin, sync::Arc};
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use futures::{
     future,
@@ -9,7 +14,7 @@ use futures::{
 use once_cell::sync::OnceCell;
 
 use crate::{
-    payloads::GetMe,
+    payloads::{GetChatMember, GetMe},
     requests::{HasPayload, Request, Requester},
     types::{ChatId, Me, *},
 };
@@ -18,10 +23,14 @@ use crate::{
 ///
 /// Bot's user is hardly ever changed, so sometimes it's reasonable to cache
 /// response from `get_me` method.
+///
+/// Additionally responses of `get_chat_member` can be cached for a limited
+/// time, see [`CacheMe::cache_chat_members`].
 #[derive(Clone)]
 pub struct CacheMe<B> {
     bot: B,
     me: Arc<OnceCell<Me>>,
+    members: Option<Arc<MemberCache>>,
 }
 
 impl<B> CacheMe<B> {
@@ -34,6 +43,56 @@ impl<B> CacheMe<B> {
         Self {
             bot,
             me: Arc::new(OnceCell::new()),
+            members: None,
+        }
+    }
+
+    /// Enables caching of `get_chat_member` responses for `ttl`.
+    ///
+    /// Permission checks (e.g. [`RequesterExt::is_admin`]) are usually the
+    /// most frequent requests of moderation bots, while the membership
+    /// rarely changes. Note that changes in the chat are not noticed until
+    /// the cached response becomes stale, use
+    /// [`CacheMe::invalidate_chat_member`] (e.g. on [`ChatMember`] updates)
+    /// to drop a cached response earlier.
+    ///
+    /// The cache is shared between clones of `self`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::{requests::RequesterExt, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN")
+    ///     .cache_me()
+    ///     .cache_chat_members(Duration::from_secs(60));
+    /// # let _ = bot;
+    /// ```
+    ///
+    /// [`RequesterExt::is_admin`]: crate::requests::RequesterExt::is_admin
+    /// [`ChatMember`]: crate::types::UpdateKind::ChatMember
+    pub fn cache_chat_members(mut self, ttl: Duration) -> Self {
+        self.members = Some(Arc::new(MemberCache::new(ttl)));
+        self
+    }
+
+    /// Drops the cached `get_chat_member` response for the `user_id` in the
+    /// `chat_id`, if any.
+    pub fn invalidate_chat_member<C>(&self, chat_id: C, user_id: i64)
+    where
+        C: Into<ChatId>,
+    {
+        if let Some(members) = &self.members {
+            members.remove(&(chat_id.into(), user_id));
+        }
+    }
+
+    /// Drops all cached `get_chat_member` responses.
+    pub fn clear_chat_members(&self) {
+        if let Some(members) = &self.members {
+            members.state.lock().unwrap().entries.clear();
         }
     }
 
@@ -47,6 +106,32 @@ impl<B> CacheMe<B> {
         self.bot
     }
 
+    /// Returns cached response from `get_me`, if it was already fetched.
+    ///
+    /// See also: [`CacheMe::ensure_me`].
+    pub fn me(&self) -> Option<&Me> {
+        self.me.get()
+    }
+
+    /// Returns cached response from `get_me`, fetching it if it wasn't
+    /// fetched yet.
+    ///
+    /// After this method succeeds, [`CacheMe::me`] always returns `Some(_)`
+    /// (until the cache is [cleared]).
+    ///
+    /// [cleared]: CacheMe::clear
+    pub async fn ensure_me(&self) -> Result<&Me, B::Err>
+    where
+        B: Requester,
+    {
+        if let Some(me) = self.me.get() {
+            return Ok(me);
+        }
+
+        let me = self.bot.get_me().send().await?;
+        Ok(self.me.get_or_init(|| me))
+    }
+
     /// Clear cache.
     ///
     /// Returns cached response from `get_me`, if it was cached.
@@ -88,6 +173,18 @@ where
         }
     }
 
+    type GetChatMember = CachedChatMemberRequest<B::GetChatMember>;
+
+    fn get_chat_member<C>(&self, chat_id: C, user_id: i64) -> Self::GetChatMember
+    where
+        C: Into<ChatId>,
+    {
+        CachedChatMemberRequest {
+            request: self.bot.get_chat_member(chat_id, user_id),
+            cache: self.members.clone(),
+        }
+    }
+
     requester_forward! {
         log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
         forward_message, copy_message, send_message, send_photo, send_audio, send_document,
@@ -96,17 +193,26 @@ where
         stop_message_live_location, stop_message_live_location_inline, send_venue,
         send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
         get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
         promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
         revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
-        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,
         set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
         set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
         edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
         edit_message_media, edit_message_media_inline, edit_message_reply_markup,
         edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
         get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
         add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
         set_sticker_set_thumb, send_invoice, answer_shipping_query,
         answer_pre_checkout_query, set_passport_data_errors, send_game,
@@ -121,8 +227,10 @@ download_forward! {
     { this => this.inner() }
 }
 
+#[derive(Clone)]
 pub struct CachedMeRequest<R: Request<Payload = GetMe>>(Inner<R>, GetMe);
 
+#[derive(Clone)]
 enum Inner<R: Request<Payload = GetMe>> {
     Ready(Me),
     Pending(R, Arc<OnceCell<Me>>),
@@ -211,3 +319,214 @@ impl<F: Future<Output = Result<T, E>>, T: Clone, E> Future for Init<F, T> {
         }
     }
 }
+
+/// Cached `get_chat_member` responses.
+struct MemberCache {
+    ttl: Duration,
+    state: Mutex<MemberCacheState>,
+}
+
+struct MemberCacheState {
+    entries: HashMap<MemberKey, (Instant, ChatMember)>,
+    /// Stale entries are removed at most once per `ttl`.
+    next_prune: Instant,
+}
+
+type MemberKey = (ChatId, i64);
+
+impl MemberCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: Mutex::new(MemberCacheState {
+                entries: HashMap::new(),
+                next_prune: Instant::now() + ttl,
+            }),
+        }
+    }
+
+    fn get(&self, key: &MemberKey) -> Option<ChatMember> {
+        let state = self.state.lock().unwrap();
+        match state.entries.get(key) {
+            Some((cached_at, member)) if cached_at.elapsed() < self.ttl => Some(member.clone()),
+            _ => None,
+        }
+    }
+
+    fn insert(&self, key: MemberKey, member: ChatMember) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        if now >= state.next_prune {
+            let ttl = self.ttl;
+            state
+                .entries
+                .retain(|_, (cached_at, _)| now.duration_since(*cached_at) < ttl);
+            state.next_prune = now + ttl;
+        }
+
+        state.entries.insert(key, (now, member));
+    }
+
+    fn remove(&self, key: &MemberKey) {
+        self.state.lock().unwrap().entries.remove(key);
+    }
+}
+
+#[derive(Clone)]
+pub struct CachedChatMemberRequest<R> {
+    request: R,
+    cache: Option<Arc<MemberCache>>,
+}
+
+impl<R> CachedChatMemberRequest<R>
+where
+    R: Request<Payload = GetChatMember>,
+{
+    /// Returns the key of the request and the cached response, if it's
+    /// fresh.
+    fn lookup(&self) -> (MemberKey, Option<ChatMember>) {
+        let payload = self.request.payload_ref();
+        let key = (payload.chat_id.clone(), payload.user_id);
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(&key));
+
+        (key, cached)
+    }
+}
+
+impl<R> HasPayload for CachedChatMemberRequest<R>
+where
+    R: Request<Payload = GetChatMember>,
+{
+    type Payload = GetChatMember;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for CachedChatMemberRequest<R>
+where
+    R: crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.request.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.request.to_http()
+    }
+}
+
+impl<R> Request for CachedChatMemberRequest<R>
+where
+    R: Request<Payload = GetChatMember>,
+{
+    type Err = R::Err;
+    type Send = CachedChatMemberSend<R::Send, R::Err>;
+    type SendRef = CachedChatMemberSend<R::SendRef, R::Err>;
+
+    fn send(self) -> Self::Send {
+        let (key, cached) = self.lookup();
+        let fut = match cached {
+            Some(member) => future::Either::Left(ok(member)),
+            None => future::Either::Right(StoreMember {
+                fut: self.request.send(),
+                cache: self.cache,
+                key: Some(key),
+            }),
+        };
+        CachedChatMemberSend(fut)
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let (key, cached) = self.lookup();
+        let fut = match cached {
+            Some(member) => future::Either::Left(ok(member)),
+            None => future::Either::Right(StoreMember {
+                fut: self.request.send_ref(),
+                cache: self.cache.clone(),
+                key: Some(key),
+            }),
+        };
+        CachedChatMemberSend(fut)
+    }
+}
+
+#[pin_project::pin_project]
+pub struct CachedChatMemberSend<F, E>(
+    #[pin] future::Either<Ready<Result<ChatMember, E>>, StoreMember<F>>,
+);
+
+impl<F, E> Future for CachedChatMemberSend<F, E>
+where
+    F: Future<Output = Result<ChatMember, E>>,
+{
+    type Output = Result<ChatMember, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().0.poll(cx)
+    }
+}
+
+/// Stores the response of `get_chat_member` in the cache.
+#[pin_project::pin_project]
+pub struct StoreMember<F> {
+    #[pin]
+    fut: F,
+    cache: Option<Arc<MemberCache>>,
+    key: Option<MemberKey>,
+}
+
+impl<F, E> Future for StoreMember<F>
+where
+    F: Future<Output = Result<ChatMember, E>>,
+{
+    type Output = Result<ChatMember, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = futures::ready!(this.fut.poll(cx));
+
+        if let (Ok(member), Some(cache), Some(key)) = (&res, &*this.cache, this.key.take()) {
+            cache.insert(key, member.clone());
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::{payloads::GetChatMemberSetters, requests::JsonRequest, Bot};
+
+    #[test]
+    fn chat_members() {
+        let member: ChatMember = serde_json::from_str(
+            r#"{"user":{"id":2,"is_bot":false,"first_name":"u"},"status":"member"}"#,
+        )
+        .unwrap();
+
+        let bot = CacheMe::new(Bot::new("TOKEN")).cache_chat_members(Duration::from_secs(60));
+        let cache = bot.members.clone().unwrap();
+        cache.insert((ChatId::Id(1), 2), member.clone());
+
+        let request: CachedChatMemberRequest<JsonRequest<GetChatMember>> =
+            bot.get_chat_member(1, 2);
+        assert_eq!(request.send_ref().now_or_never().unwrap().unwrap(), member);
+
+        // Payloads can be changed after the request is created
+        assert!(request.user_id(3).lookup().1.is_none());
+
+        bot.invalidate_chat_member(1, 2);
+        assert!(cache.get(&(ChatId::Id(1), 2)).is_none());
+    }
+}
@@ -0,0 +1,836 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use crate::{
+    errors::AsResponseParameters,
+    requests::{HasPayload, Output, Request, Requester},
+    types::*,
+};
+
+/// Chat migration adaptor, see [`RequesterExt::chat_migration`].
+///
+/// When a group is upgraded to a supergroup, requests to the old chat id fail
+/// with [`RequestError::MigrateToChatId`]. This adaptor remembers the new id
+/// of the chat, retries the failed request with it and (via
+/// [`ChatMigration::on_migration`]) notifies you about the migration, so that
+/// you can update your database. All subsequent requests to the old id are
+/// sent to the new one straight away.
+///
+/// Only methods which have a `chat_id` parameter are affected, other methods
+/// are forwarded as is. The `from_chat_id` of [`ForwardMessage`] and
+/// [`CopyMessage`] is also replaced with the new id of a known migration.
+///
+/// Message ids start anew in the supergroup, so requests which refer to a
+/// message of the old chat (e.g. [`DeleteMessage`], [`EditMessageText`],
+/// sends with a `reply_to_message_id` or [`ForwardMessage`] from the migrated
+/// chat) are never retried: the migration is remembered (and reported), but
+/// the error is returned. Forwards and copies of messages from other chats are
+/// retried.
+///
+/// Note that requests can be retried only when they are sent with
+/// [`Request::send`], since [`Request::send_ref`] can't change the request.
+/// Requests sent with [`Request::send_ref`] still return the error, but the
+/// migration is remembered (and reported).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{requests::RequesterExt, Bot};
+///
+/// let bot = Bot::new("TOKEN")
+///     .chat_migration()
+///     .on_migration(|from: i64, to: i64| println!("chat {} was migrated to {}", from, to));
+/// # let _ = bot;
+/// ```
+///
+/// [`RequesterExt::chat_migration`]: crate::requests::RequesterExt::chat_migration
+/// [`RequestError::MigrateToChatId`]: crate::RequestError::MigrateToChatId
+/// [`ForwardMessage`]: crate::payloads::ForwardMessage
+/// [`CopyMessage`]: crate::payloads::CopyMessage
+/// [`DeleteMessage`]: crate::payloads::DeleteMessage
+/// [`EditMessageText`]: crate::payloads::EditMessageText
+#[derive(Clone)]
+pub struct ChatMigration<B> {
+    bot: B,
+    state: Arc<State>,
+}
+
+type Callback = Arc<dyn Fn(i64, i64) + Send + Sync>;
+
+#[derive(Default)]
+struct State {
+    /// Old chat id -> new chat id.
+    migrated: Mutex<HashMap<i64, i64>>,
+    callback: Mutex<Option<Callback>>,
+}
+
+impl<B> ChatMigration<B> {
+    /// Creates new `ChatMigration`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::chat_migration`] instead.
+    ///
+    /// [`RequesterExt::chat_migration`]: crate::requests::RequesterExt::chat_migration
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            state: Arc::default(),
+        }
+    }
+
+    /// Sets a callback which is called with the old and the new ids of a chat
+    /// when a migration is detected.
+    ///
+    /// The callback is called once per migration (unless it's detected by
+    /// several concurrent requests). The callback is shared with clones of
+    /// this adaptor, previously set callback is replaced.
+    pub fn on_migration<F>(self, f: F) -> Self
+    where
+        F: Fn(i64, i64) + Send + Sync + 'static,
+    {
+        *self.state.callback.lock().unwrap() = Some(Arc::new(f));
+        self
+    }
+
+    /// Returns the new id of the chat with the `old` id, if it was migrated.
+    pub fn migrated(&self, old: i64) -> Option<i64> {
+        self.state.get(old)
+    }
+
+    /// Adds a known migration, e.g. to restore migrations from your database.
+    pub fn add_migration(&self, from: i64, to: i64) {
+        self.state.migrated.lock().unwrap().insert(from, to);
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+impl<B: fmt::Debug> fmt::Debug for ChatMigration<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChatMigration")
+            .field("bot", &self.bot)
+            .field("migrated", &*self.state.migrated.lock().unwrap())
+            .finish()
+    }
+}
+
+impl State {
+    fn get(&self, old: i64) -> Option<i64> {
+        self.migrated.lock().unwrap().get(&old).copied()
+    }
+
+    /// Replaces the `chat_id` with the new id, if the chat was migrated.
+    fn redirect(&self, chat_id: &mut dyn ChatIdField) {
+        if let Some(new) = chat_id.id().and_then(|id| self.get(id)) {
+            chat_id.set_id(new);
+        }
+    }
+
+    /// Remembers a migration and calls the callback if it's new.
+    fn record(&self, from: i64, to: i64) {
+        let prev = self.migrated.lock().unwrap().insert(from, to);
+        if prev != Some(to) {
+            // The lock isn't held while the callback is running
+            let callback = self.callback.lock().unwrap().clone();
+            if let Some(callback) = callback {
+                callback(from, to);
+            }
+        }
+    }
+
+    /// Records a migration if `res` is an error caused by it, returning the
+    /// new chat id.
+    fn check<T, E>(&self, res: &Result<T, E>, old: Option<i64>) -> Option<i64>
+    where
+        E: AsResponseParameters,
+    {
+        let (old, new) = (old?, res.as_ref().err()?.migrate_to_chat_id()?);
+        self.record(old, new);
+        Some(new)
+    }
+}
+
+/// A `chat_id` parameter, which is a [`ChatId`] for most methods and a numeric
+/// id for [`SetGameScore`].
+///
+/// [`SetGameScore`]: crate::payloads::SetGameScore
+trait ChatIdField {
+    fn id(&self) -> Option<i64>;
+
+    fn set_id(&mut self, id: i64);
+}
+
+impl ChatIdField for ChatId {
+    fn id(&self) -> Option<i64> {
+        match self {
+            ChatId::Id(id) => Some(*id),
+            ChatId::ChannelUsername(_) => None,
+        }
+    }
+
+    fn set_id(&mut self, id: i64) {
+        *self = ChatId::Id(id);
+    }
+}
+
+impl ChatIdField for i64 {
+    fn id(&self) -> Option<i64> {
+        Some(*self)
+    }
+
+    fn set_id(&mut self, id: i64) {
+        *self = id;
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {{
+        let mut inner = $this.inner().$m($($arg),*);
+        $this.state.redirect(&mut inner.payload_mut().chat_id);
+        redirect_from!($m $this.state, inner.payload_mut());
+
+        ChatMigrationRequest {
+            inner,
+            chat_id: |p| &mut p.chat_id,
+            chat_id_ref: |p| &p.chat_id,
+            retryable: retryable!($m),
+            state: Arc::clone(&$this.state),
+        }
+    }};
+}
+
+/// Returns whether a request can be retried with the new chat id, i.e. whether
+/// it doesn't refer to a message of the old chat.
+macro_rules! retryable {
+    // `message_id` refers to `from_chat_id`, so these can be retried unless
+    // it's the migrated chat
+    (forward_message) => {
+        |p| p.from_chat_id != p.chat_id
+    };
+    (copy_message) => {
+        |p| p.from_chat_id != p.chat_id && p.reply_to_message_id.is_none()
+    };
+    (edit_message_live_location) => {
+        |_| false
+    };
+    (stop_message_live_location) => {
+        |_| false
+    };
+    (pin_chat_message) => {
+        |_| false
+    };
+    (edit_message_text) => {
+        |_| false
+    };
+    (edit_message_caption) => {
+        |_| false
+    };
+    (edit_message_media) => {
+        |_| false
+    };
+    (edit_message_reply_markup) => {
+        |_| false
+    };
+    (stop_poll) => {
+        |_| false
+    };
+    (delete_message) => {
+        |_| false
+    };
+    (set_message_reaction) => {
+        |_| false
+    };
+    (set_game_score) => {
+        |_| false
+    };
+    (unpin_chat_message) => {
+        |p| p.message_id.is_none()
+    };
+    (send_message) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_photo) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_audio) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_document) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_video) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_animation) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_voice) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_video_note) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_media_group) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_location) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_venue) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_contact) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_poll) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_dice) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_sticker) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    (send_invoice) => {
+        |p| p.reply_to_message_id.is_none()
+    };
+    ($m:ident) => {
+        |_| true
+    };
+}
+
+/// Redirects `from_chat_id` of methods which have it.
+macro_rules! redirect_from {
+    (forward_message $state:expr, $payload:expr) => {
+        $state.redirect(&mut $payload.from_chat_id)
+    };
+    (copy_message $state:expr, $payload:expr) => {
+        $state.redirect(&mut $payload.from_chat_id)
+    };
+    ($m:ident $state:expr, $payload:expr) => {};
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ChatMigrationRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for ChatMigration<B>
+where
+    B: Requester,
+    B::Err: AsResponseParameters,
+    B::ForwardMessage: Send,
+    B::CopyMessage: Send,
+    B::SendMessage: Send,
+    B::SendPhoto: Send,
+    B::SendAudio: Send,
+    B::SendDocument: Send,
+    B::SendVideo: Send,
+    B::SendAnimation: Send,
+    B::SendVoice: Send,
+    B::SendVideoNote: Send,
+    B::SendMediaGroup: Send,
+    B::SendLocation: Send,
+    B::EditMessageLiveLocation: Send,
+    B::StopMessageLiveLocation: Send,
+    B::SendVenue: Send,
+    B::SendContact: Send,
+    B::SendPoll: Send,
+    B::SendDice: Send,
+    B::SendChatAction: Send,
+    B::KickChatMember: Send,
+    B::UnbanChatMember: Send,
+    B::RestrictChatMember: Send,
+    B::BanChatSenderChat: Send,
+    B::UnbanChatSenderChat: Send,
+    B::PromoteChatMember: Send,
+    B::SetChatAdministratorCustomTitle: Send,
+    B::SetChatPermissions: Send,
+    B::ExportChatInviteLink: Send,
+    B::CreateChatInviteLink: Send,
+    B::EditChatInviteLink: Send,
+    B::CreateChatSubscriptionInviteLink: Send,
+    B::EditChatSubscriptionInviteLink: Send,
+    B::RevokeChatInviteLink: Send,
+    B::ApproveChatJoinRequest: Send,
+    B::DeclineChatJoinRequest: Send,
+    B::CreateForumTopic: Send,
+    B::EditForumTopic: Send,
+    B::EditGeneralForumTopic: Send,
+    B::CloseGeneralForumTopic: Send,
+    B::ReopenGeneralForumTopic: Send,
+    B::HideGeneralForumTopic: Send,
+    B::UnhideGeneralForumTopic: Send,
+    B::UnpinAllGeneralForumTopicMessages: Send,
+    B::SetChatPhoto: Send,
+    B::DeleteChatPhoto: Send,
+    B::SetChatTitle: Send,
+    B::SetChatDescription: Send,
+    B::PinChatMessage: Send,
+    B::UnpinChatMessage: Send,
+    B::UnpinAllChatMessages: Send,
+    B::LeaveChat: Send,
+    B::GetChat: Send,
+    B::GetChatAdministrators: Send,
+    B::GetChatMembersCount: Send,
+    B::GetChatMember: Send,
+    B::SetChatStickerSet: Send,
+    B::DeleteChatStickerSet: Send,
+    B::EditMessageText: Send,
+    B::EditMessageCaption: Send,
+    B::EditMessageMedia: Send,
+    B::EditMessageReplyMarkup: Send,
+    B::StopPoll: Send,
+    B::DeleteMessage: Send,
+    B::SetMessageReaction: Send,
+    B::SendSticker: Send,
+    B::SendInvoice: Send,
+    B::SetGameScore: Send,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        forward_message, copy_message, send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+        edit_message_live_location, stop_message_live_location, send_venue, send_contact,
+        send_poll, send_dice, send_chat_action, kick_chat_member, unban_chat_member,
+        restrict_chat_member, ban_chat_sender_chat, unban_chat_sender_chat, promote_chat_member,
+        set_chat_administrator_custom_title, set_chat_permissions, export_chat_invite_link,
+        create_chat_invite_link, edit_chat_invite_link, create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link, revoke_chat_invite_link, set_chat_photo,
+        approve_chat_join_request, decline_chat_join_request,
+        delete_chat_photo, set_chat_title, set_chat_description, pin_chat_message,
+        unpin_chat_message, unpin_all_chat_messages, leave_chat, get_chat,
+        create_forum_topic, edit_forum_topic,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        get_chat_administrators, get_chat_members_count, get_chat_member, set_chat_sticker_set,
+        delete_chat_sticker_set, edit_message_text, edit_message_caption, edit_message_media,
+        edit_message_reply_markup, stop_poll, delete_message, set_message_reaction,
+        send_sticker, send_invoice, set_game_score => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        edit_message_live_location_inline, stop_message_live_location_inline,
+        get_user_profile_photos, get_file, answer_callback_query, set_my_commands,
+        get_my_commands, answer_inline_query, edit_message_text_inline,
+        edit_message_caption_inline, edit_message_media_inline,
+        edit_message_reply_markup_inline, get_sticker_set, upload_sticker_file,
+        get_custom_emoji_stickers, get_forum_topic_icon_stickers,
+        create_new_sticker_set, add_sticker_to_set, set_sticker_position_in_set,
+        delete_sticker_from_set, set_sticker_set_thumb, answer_shipping_query,
+        answer_pre_checkout_query, set_passport_data_errors, send_game,
+        set_game_score_inline, get_game_high_scores, get_updates_fault_tolerant => fid, ftyid
+    }
+}
+
+download_forward! {
+    'w
+    B
+    ChatMigration<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`ChatMigration`], see its documentation for more.
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone)]
+pub struct ChatMigrationRequest<R: HasPayload> {
+    inner: R,
+    chat_id: fn(&mut R::Payload) -> &mut dyn ChatIdField,
+    chat_id_ref: fn(&R::Payload) -> &dyn ChatIdField,
+    retryable: fn(&R::Payload) -> bool,
+    state: Arc<State>,
+}
+
+impl<R: HasPayload> ChatMigrationRequest<R> {
+    fn old_id(&self) -> Option<i64> {
+        (self.chat_id_ref)(self.inner.payload_ref()).id()
+    }
+}
+
+impl<R: HasPayload> HasPayload for ChatMigrationRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for ChatMigrationRequest<R>
+where
+    R: HasPayload + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R> Request for ChatMigrationRequest<R>
+where
+    R: Request + Send,
+    R::Err: AsResponseParameters,
+{
+    type Err = R::Err;
+    type Send = ChatMigrationSend<R>;
+    type SendRef = ChatMigrationSendRef<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        ChatMigrationSend {
+            old: self.old_id(),
+            // The request is kept to be retried
+            first: Some(self.inner.send_ref()),
+            retry: None,
+            request: Some(self),
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        ChatMigrationSendRef {
+            fut: self.inner.send_ref(),
+            state: Arc::clone(&self.state),
+            old: self.old_id(),
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct ChatMigrationSend<R: Request> {
+    request: Option<ChatMigrationRequest<R>>,
+    old: Option<i64>,
+    #[pin]
+    first: Option<R::SendRef>,
+    #[pin]
+    retry: Option<R::Send>,
+}
+
+impl<R> Future for ChatMigrationSend<R>
+where
+    R: Request,
+    R::Err: AsResponseParameters,
+{
+    type Output = Result<Output<R>, R::Err>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            let res = futures::ready!(first.poll(cx));
+            this.first.set(None);
+
+            let mut request = this
+                .request
+                .take()
+                .expect("ChatMigrationSend polled after completion");
+
+            match request.state.check(&res, *this.old) {
+                Some(new) if (request.retryable)(request.inner.payload_ref()) => {
+                    (request.chat_id)(request.inner.payload_mut()).set_id(new);
+                    this.retry.set(Some(request.inner.send()));
+                }
+                _ => return Poll::Ready(res),
+            }
+        }
+
+        match this.retry.as_pin_mut() {
+            Some(retry) => retry.poll(cx),
+            None => panic!("ChatMigrationSend polled after completion"),
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct ChatMigrationSendRef<F> {
+    #[pin]
+    fut: F,
+    state: Arc<State>,
+    old: Option<i64>,
+}
+
+impl<F, T, E> Future for ChatMigrationSendRef<F>
+where
+    F: Future<Output = Result<T, E>>,
+    E: AsResponseParameters,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let res = futures::ready!(this.fut.poll(cx));
+        this.state.check(&res, *this.old);
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::future::ready;
+
+    use super::*;
+    use crate::{
+        payloads::{DeleteMessage, ForwardMessage, SendMessage},
+        requests::Payload,
+        test_utils::MockRequest,
+        RequestError,
+    };
+
+    #[test]
+    fn records_migrations() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let bot = ChatMigration::new(()).on_migration({
+            let calls = Arc::clone(&calls);
+            move |from, to| {
+                assert_eq!((from, to), (-1, -1001));
+                calls.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let res: Result<(), _> = Err(RequestError::MigrateToChatId(-1001));
+        assert_eq!(bot.state.check(&res, Some(-1)), Some(-1001));
+        assert_eq!(bot.state.check(&res, Some(-1)), Some(-1001));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        let res: Result<(), RequestError> = Ok(());
+        assert_eq!(bot.state.check(&res, Some(-2)), None);
+
+        let mut chat_id = ChatId::Id(-1);
+        bot.state.redirect(&mut chat_id);
+        assert_eq!(chat_id, ChatId::Id(-1001));
+        assert_eq!(bot.migrated(-1), Some(-1001));
+    }
+
+    #[test]
+    fn callback_is_shared() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let bot = ChatMigration::new(());
+        let clone = bot.clone();
+        let _bot = bot.on_migration({
+            let calls = Arc::clone(&calls);
+            move |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        clone.state.record(-1, -1001);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    /// Creates a request which fails with a migration when sent to the chat
+    /// `-1`, recording the chat ids it was sent to.
+    fn migrating_request<P, T>(
+        state: &Arc<State>,
+        payload: P,
+        ok: T,
+        retryable: fn(&P) -> bool,
+        sent: &Arc<Mutex<Vec<ChatId>>>,
+    ) -> ChatMigrationRequest<MockRequest<P>>
+    where
+        P: Payload<Output = T> + HasChatId + Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static,
+    {
+        let sent = Arc::clone(sent);
+        ChatMigrationRequest {
+            inner: MockRequest::new(payload, move |payload: &P| {
+                sent.lock().unwrap().push(payload.chat_id().clone());
+                ready(match payload.chat_id() {
+                    ChatId::Id(-1) => Err(RequestError::MigrateToChatId(-1001)),
+                    _ => Ok(ok.clone()),
+                })
+            }),
+            chat_id: |p| p.chat_id_mut(),
+            chat_id_ref: |p| p.chat_id(),
+            retryable,
+            state: Arc::clone(state),
+        }
+    }
+
+    trait HasChatId {
+        fn chat_id(&self) -> &ChatId;
+
+        fn chat_id_mut(&mut self) -> &mut ChatId;
+    }
+
+    impl HasChatId for SendMessage {
+        fn chat_id(&self) -> &ChatId {
+            &self.chat_id
+        }
+
+        fn chat_id_mut(&mut self) -> &mut ChatId {
+            &mut self.chat_id
+        }
+    }
+
+    impl HasChatId for DeleteMessage {
+        fn chat_id(&self) -> &ChatId {
+            &self.chat_id
+        }
+
+        fn chat_id_mut(&mut self) -> &mut ChatId {
+            &mut self.chat_id
+        }
+    }
+
+    impl HasChatId for ForwardMessage {
+        fn chat_id(&self) -> &ChatId {
+            &self.chat_id
+        }
+
+        fn chat_id_mut(&mut self) -> &mut ChatId {
+            &mut self.chat_id
+        }
+    }
+
+    fn message() -> Message {
+        serde_json::from_str(
+            r#"{"message_id":1,"date":0,"chat":{"id":-1001,"type":"supergroup","title":"t"},"text":"hi"}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn failed_requests_are_retried() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let request = |state: &Arc<State>| {
+            migrating_request(
+                state,
+                SendMessage::new(-1, "hi"),
+                message(),
+                retryable!(send_message),
+                &sent,
+            )
+        };
+
+        let bot = ChatMigration::new(());
+        futures::executor::block_on(request(&bot.state).send()).unwrap();
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1), ChatId::Id(-1001)]);
+        assert_eq!(bot.migrated(-1), Some(-1001));
+
+        // `send_ref` can't retry, but the migration is remembered
+        let bot = ChatMigration::new(());
+        sent.lock().unwrap().clear();
+        let res = futures::executor::block_on(request(&bot.state).send_ref());
+        assert!(matches!(res, Err(RequestError::MigrateToChatId(-1001))));
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1)]);
+        assert_eq!(bot.migrated(-1), Some(-1001));
+
+        // Replies refer to a message of the old chat
+        let bot = ChatMigration::new(());
+        sent.lock().unwrap().clear();
+        let mut request = request(&bot.state);
+        request.payload_mut().reply_to_message_id = Some(1);
+        let res = futures::executor::block_on(request.send());
+        assert!(matches!(res, Err(RequestError::MigrateToChatId(-1001))));
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1)]);
+        assert_eq!(bot.migrated(-1), Some(-1001));
+    }
+
+    #[test]
+    fn message_requests_are_not_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let bot = ChatMigration::new(()).on_migration({
+            let calls = Arc::clone(&calls);
+            move |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let request = migrating_request(
+            &bot.state,
+            DeleteMessage::new(-1, 0),
+            True,
+            retryable!(delete_message),
+            &sent,
+        );
+
+        let res = futures::executor::block_on(request.send());
+        assert!(matches!(res, Err(RequestError::MigrateToChatId(-1001))));
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1)]);
+        assert_eq!(bot.migrated(-1), Some(-1001));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn forwards_are_retried_unless_from_migrated_chat() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let request = |state: &Arc<State>, from_chat_id: i64| {
+            migrating_request(
+                state,
+                ForwardMessage::new(-1, from_chat_id, 1),
+                message(),
+                retryable!(forward_message),
+                &sent,
+            )
+        };
+
+        let bot = ChatMigration::new(());
+        futures::executor::block_on(request(&bot.state, -2).send()).unwrap();
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1), ChatId::Id(-1001)]);
+
+        // The message id refers to the old chat
+        let bot = ChatMigration::new(());
+        sent.lock().unwrap().clear();
+        let res = futures::executor::block_on(request(&bot.state, -1).send());
+        assert!(matches!(res, Err(RequestError::MigrateToChatId(-1001))));
+        assert_eq!(*sent.lock().unwrap(), [ChatId::Id(-1)]);
+        assert_eq!(bot.migrated(-1), Some(-1001));
+    }
+
+    #[test]
+    fn redirects_from_chat_id() {
+        let bot = ChatMigration::new(crate::Bot::new("TOKEN"));
+        bot.add_migration(-1, -1001);
+
+        let request = bot.forward_message(-1, -1, 0);
+        assert_eq!(request.payload_ref().chat_id, ChatId::Id(-1001));
+        assert_eq!(request.payload_ref().from_chat_id, ChatId::Id(-1001));
+
+        let request = bot.copy_message(-2, -1, 0);
+        assert_eq!(request.payload_ref().chat_id, ChatId::Id(-2));
+        assert_eq!(request.payload_ref().from_chat_id, ChatId::Id(-1001));
+
+        // Games take a numeric chat id
+        let request = bot.set_game_score(0, 1, -1, 0);
+        assert_eq!(request.payload_ref().chat_id, -1001);
+    }
+}
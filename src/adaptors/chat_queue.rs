@@ -0,0 +1,347 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::FutureExt;
+use never::Never;
+use tokio::sync::oneshot::{self, Receiver, Sender};
+
+use crate::{
+    requests::{HasPayload, Request, Requester},
+    types::*,
+};
+
+/// Per-chat send queue adaptor.
+///
+/// Telegram may reorder messages which are sent concurrently, e.g. when you
+/// send 2 messages with `join!(a.send(), b.send())` the message `b` can appear
+/// in the chat before `a`. This adaptor ensures that messages sent to the same
+/// chat are delivered strictly in the order in which they were sent (i.e. in
+/// the order of [`Request::send`]/[`Request::send_ref`] calls), the next
+/// request to a chat is only started after the previous one has completed.
+/// Requests to different chats still proceed in parallel.
+///
+/// Only methods which send messages (same as for [`Throttle`]) are queued,
+/// other methods are forwarded as is.
+///
+/// ## Note about usernames
+///
+/// Just as in [`Throttle`], there is no way to tell if `ChatId::Id(x)`
+/// corresponds to the same chat as `ChatId::ChannelUsername(u)`, so requests
+/// to the same chat using different kinds of ids are not ordered.
+///
+/// [`Throttle`]: crate::adaptors::Throttle
+#[derive(Clone, Debug)]
+pub struct ChatQueue<B> {
+    bot: B,
+    queue: Queue,
+}
+
+impl<B> ChatQueue<B> {
+    /// Creates new `ChatQueue`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::chat_queue`] instead.
+    ///
+    /// [`RequesterExt::chat_queue`]: crate::requests::RequesterExt::chat_queue
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            queue: Queue::default(),
+        }
+    }
+
+    /// Returns the number of chats which currently have requests in flight.
+    pub fn active_chats(&self) -> usize {
+        self.queue.state.lock().unwrap().slots.len()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ChatQueueRequest {
+            request: $this.inner().$m($($arg),*),
+            chat_id: |p| &p.chat_id,
+            queue: $this.queue.clone(),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ChatQueueRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B: Requester> Requester for ChatQueue<B> {
+    type Err = B::Err;
+
+    requester_forward! {
+        send_message, forward_message, copy_message, send_photo, send_audio,
+        send_document, send_video, send_animation, send_voice, send_video_note,
+        send_media_group, send_location, send_venue, send_contact, send_poll,
+        send_dice, send_sticker, send_invoice => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline,
+        send_chat_action, get_user_profile_photos, get_file, kick_chat_member,
+        unban_chat_member, restrict_chat_member, promote_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
+        set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,
+        get_chat_member, set_chat_sticker_set, delete_chat_sticker_set,
+        answer_callback_query, set_my_commands, get_my_commands, answer_inline_query,
+        edit_message_text, edit_message_text_inline, edit_message_caption,
+        edit_message_caption_inline, edit_message_media, edit_message_media_inline,
+        edit_message_reply_markup, edit_message_reply_markup_inline, stop_poll,
+        delete_message, get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        set_message_reaction,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, answer_shipping_query, answer_pre_checkout_query,
+        set_passport_data_errors, send_game, set_game_score, set_game_score_inline,
+        get_game_high_scores, get_updates_fault_tolerant => fid, ftyid
+    }
+}
+
+download_forward! {
+    'w
+    B
+    ChatQueue<B>
+    { this => this.inner() }
+}
+
+/// Queues of all chats.
+#[derive(Clone, Debug, Default)]
+struct Queue {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    next_seq: u64,
+    /// The last request sent to each chat.
+    slots: HashMap<ChatId, Slot>,
+}
+
+/// The last request sent to a chat.
+#[derive(Debug)]
+struct Slot {
+    seq: u64,
+    /// Resolves when the request completes (or is dropped).
+    done: Receiver<Never>,
+}
+
+impl Queue {
+    /// Enqueues a request to the `chat_id`, returning a future which resolves
+    /// when it's the request's turn and a guard which must be dropped when the
+    /// request completes.
+    fn enqueue(&self, chat_id: &ChatId) -> (Turn, Done) {
+        let (tx, rx) = oneshot::channel();
+
+        let mut state = self.state.lock().unwrap();
+        let seq = state.next_seq;
+        state.next_seq += 1;
+
+        let prev = state
+            .slots
+            .insert(chat_id.clone(), Slot { seq, done: rx })
+            .map(|slot| slot.done);
+
+        let done = Done {
+            _tx: tx,
+            seq,
+            chat_id: chat_id.clone(),
+            queue: self.clone(),
+        };
+
+        (Turn(prev), done)
+    }
+}
+
+/// Resolves when all previous requests to the chat have completed.
+struct Turn(Option<Receiver<Never>>);
+
+impl Future for Turn {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(prev) = &mut self.0 {
+            // The sender is never used, so this can only resolve with an error
+            // when the previous request completes.
+            let _ = futures::ready!(prev.poll_unpin(cx));
+            self.0 = None;
+        }
+
+        Poll::Ready(())
+    }
+}
+
+/// Guard which notifies the next request to the chat when dropped.
+struct Done {
+    _tx: Sender<Never>,
+    seq: u64,
+    chat_id: ChatId,
+    queue: Queue,
+}
+
+impl Drop for Done {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock().unwrap();
+
+        // If this was the last request to the chat, nobody waits for it, so
+        // the slot can be freed.
+        if matches!(state.slots.get(&self.chat_id), Some(slot) if slot.seq == self.seq) {
+            state.slots.remove(&self.chat_id);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ChatQueueRequest<R: HasPayload> {
+    request: R,
+    chat_id: fn(&R::Payload) -> &ChatId,
+    queue: Queue,
+}
+
+impl<R: HasPayload> HasPayload for ChatQueueRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for ChatQueueRequest<R>
+where
+    R: HasPayload + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.request.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.request.to_http()
+    }
+}
+
+impl<R: Request> Request for ChatQueueRequest<R> {
+    type Err = R::Err;
+    type Send = ChatQueueSend<R::Send>;
+    type SendRef = ChatQueueSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let (turn, done) = self.queue.enqueue((self.chat_id)(self.payload_ref()));
+
+        // `Request` documentation explicitly notes that `send{,_ref}` should
+        // **not** do any kind of work, so it's ok to call it before our turn.
+        ChatQueueSend {
+            turn,
+            fut: self.request.send(),
+            done: Some(done),
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let (turn, done) = self.queue.enqueue((self.chat_id)(self.payload_ref()));
+
+        ChatQueueSend {
+            turn,
+            fut: self.request.send_ref(),
+            done: Some(done),
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct ChatQueueSend<F> {
+    turn: Turn,
+    #[pin]
+    fut: F,
+    done: Option<Done>,
+}
+
+impl<F: Future> Future for ChatQueueSend<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        futures::ready!(Pin::new(this.turn).poll(cx));
+        let res = futures::ready!(this.fut.poll(cx));
+
+        // Let the next request go.
+        this.done.take();
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[test]
+    fn in_order() {
+        let queue = Queue::default();
+        let chat = ChatId::Id(1);
+
+        let (mut first, first_done) = queue.enqueue(&chat);
+        let (mut second, second_done) = queue.enqueue(&chat);
+        let (mut other, _other_done) = queue.enqueue(&ChatId::Id(2));
+
+        assert!((&mut first).now_or_never().is_some());
+        assert!((&mut other).now_or_never().is_some());
+        assert!((&mut second).now_or_never().is_none());
+
+        drop(first_done);
+        assert!((&mut second).now_or_never().is_some());
+
+        drop(second_done);
+        assert_eq!(queue.state.lock().unwrap().slots.len(), 1);
+    }
+}
@@ -0,0 +1,497 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::FutureExt;
+use tokio::sync::oneshot::{self, Receiver, Sender};
+
+use crate::{
+    requests::{HasPayload, Request, Requester},
+    types::*,
+};
+
+/// Adaptor which limits the number of requests in flight.
+///
+/// Unlike [`Throttle`], which respects the rate limits of Telegram, this caps
+/// the number of requests which are sent simultaneously, e.g. to not exhaust
+/// sockets (or memory) of a small server when a lot of requests are sent at
+/// once during a broadcast.
+///
+/// Requests above the limit wait until other requests complete. Waiting
+/// requests are let through in a round-robin fashion between chats (only
+/// methods which send messages, same as for [`ChatQueue`], are attributed to
+/// chats, all other requests share a single queue), so a burst of requests to
+/// one chat doesn't delay requests to other chats. Requests to the same chat
+/// are let through in the order in which they were sent.
+///
+/// Note that file downloads are not limited.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{requests::RequesterExt, Bot};
+///
+/// // At most 16 requests are sent at the same time
+/// let bot = Bot::new("TOKEN").concurrency_limit(16);
+/// # let _ = bot;
+/// ```
+///
+/// [`Throttle`]: crate::adaptors::Throttle
+/// [`ChatQueue`]: crate::adaptors::ChatQueue
+#[derive(Clone, Debug)]
+pub struct ConcurrencyLimit<B> {
+    bot: B,
+    limiter: Limiter,
+}
+
+impl<B> ConcurrencyLimit<B> {
+    /// Creates new `ConcurrencyLimit` which allows at most `limit` requests
+    /// in flight.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::concurrency_limit`]
+    /// instead.
+    ///
+    /// ## Panics
+    ///
+    /// If `limit` is `0`.
+    ///
+    /// [`RequesterExt::concurrency_limit`]: crate::requests::RequesterExt::concurrency_limit
+    pub fn new(bot: B, limit: usize) -> Self {
+        assert!(limit > 0, "concurrency limit must be positive");
+
+        Self {
+            bot,
+            limiter: Limiter::new(limit),
+        }
+    }
+
+    /// Returns the maximal number of requests in flight.
+    pub fn limit(&self) -> usize {
+        self.limiter.state.lock().unwrap().limit
+    }
+
+    /// Returns the number of requests which are currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.limiter.state.lock().unwrap().in_flight
+    }
+
+    /// Returns the number of requests which wait for other requests to
+    /// complete.
+    pub fn waiting(&self) -> usize {
+        let state = self.limiter.state.lock().unwrap();
+        state
+            .waiters
+            .values()
+            .flatten()
+            .filter(|tx| !tx.is_closed())
+            .count()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ConcurrencyLimitRequest {
+            request: $this.inner().$m($($arg),*),
+            chat_id: |p| Some(&p.chat_id),
+            limiter: $this.limiter.clone(),
+        }
+    };
+}
+
+macro_rules! fnc {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ConcurrencyLimitRequest {
+            request: $this.inner().$m($($arg),*),
+            chat_id: |_| None,
+            limiter: $this.limiter.clone(),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ConcurrencyLimitRequest<B::$T>
+    };
+}
+
+impl<B: Requester> Requester for ConcurrencyLimit<B> {
+    type Err = B::Err;
+
+    requester_forward! {
+        send_message, forward_message, copy_message, send_photo, send_audio,
+        send_document, send_video, send_animation, send_voice, send_video_note,
+        send_media_group, send_location, send_venue, send_contact, send_poll,
+        send_dice, send_sticker, send_invoice => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline,
+        send_chat_action, get_user_profile_photos, get_file, kick_chat_member,
+        unban_chat_member, restrict_chat_member, promote_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
+        set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count,
+        get_chat_member, set_chat_sticker_set, delete_chat_sticker_set,
+        answer_callback_query, set_my_commands, get_my_commands, answer_inline_query,
+        edit_message_text, edit_message_text_inline, edit_message_caption,
+        edit_message_caption_inline, edit_message_media, edit_message_media_inline,
+        edit_message_reply_markup, edit_message_reply_markup_inline, stop_poll,
+        delete_message, get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        set_message_reaction,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, answer_shipping_query, answer_pre_checkout_query,
+        set_passport_data_errors, send_game, set_game_score, set_game_score_inline,
+        get_game_high_scores, get_updates_fault_tolerant => fnc, fty
+    }
+}
+
+download_forward! {
+    'w
+    B
+    ConcurrencyLimit<B>
+    { this => this.inner() }
+}
+
+/// Permits of all requests.
+#[derive(Clone, Debug)]
+struct Limiter {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    limit: usize,
+    in_flight: usize,
+    /// Chats with waiting requests in the order in which they are let
+    /// through, `None` stands for requests which are not sent to a chat.
+    ring: VecDeque<Option<ChatId>>,
+    /// Waiting requests of every chat in the `ring`.
+    waiters: HashMap<Option<ChatId>, VecDeque<Sender<()>>>,
+}
+
+impl Limiter {
+    fn new(limit: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State {
+                limit,
+                in_flight: 0,
+                ring: VecDeque::new(),
+                waiters: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Returns a future which resolves to a permit to send a request to the
+    /// `chat_id`.
+    fn acquire(&self, chat_id: Option<&ChatId>) -> Acquire {
+        let mut state = self.state.lock().unwrap();
+
+        if state.in_flight < state.limit && state.ring.is_empty() {
+            state.in_flight += 1;
+            return Acquire {
+                rx: None,
+                permit: Some(Permit {
+                    limiter: self.clone(),
+                }),
+                limiter: self.clone(),
+            };
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let key = chat_id.cloned();
+        let queue = state.waiters.entry(key.clone()).or_default();
+        queue.push_back(tx);
+        if queue.len() == 1 {
+            state.ring.push_back(key);
+        }
+
+        Acquire {
+            rx: Some(rx),
+            permit: None,
+            limiter: self.clone(),
+        }
+    }
+
+    /// Passes a permit to the next waiting request or frees it if there are
+    /// none.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+
+        while let Some(key) = state.ring.pop_front() {
+            let queue = state
+                .waiters
+                .get_mut(&key)
+                .expect("chats in the ring have waiters");
+            let tx = queue.pop_front().expect("queues in the ring aren't empty");
+            if queue.is_empty() {
+                state.waiters.remove(&key);
+            } else {
+                state.ring.push_back(key);
+            }
+
+            // Fails if the request was dropped while waiting
+            if tx.send(()).is_ok() {
+                return;
+            }
+        }
+
+        state.in_flight -= 1;
+    }
+}
+
+/// Resolves to a [`Permit`] when the request can be sent.
+struct Acquire {
+    /// Resolves when the permit is passed to the request, `None` if it was
+    /// acquired immediately.
+    rx: Option<Receiver<()>>,
+    /// The permit, if it was acquired immediately.
+    permit: Option<Permit>,
+    limiter: Limiter,
+}
+
+impl Future for Acquire {
+    type Output = Permit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(rx) = &mut self.rx {
+            // The sender can't be dropped without sending, since all waiters
+            // are kept until they are let through
+            let _ = futures::ready!(rx.poll_unpin(cx));
+            self.rx = None;
+
+            return Poll::Ready(Permit {
+                limiter: self.limiter.clone(),
+            });
+        }
+
+        Poll::Ready(
+            self.permit
+                .take()
+                .expect("`Acquire` polled after completion"),
+        )
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        // The permit could be passed to this request after it was last polled
+        if let Some(mut rx) = self.rx.take() {
+            if rx.try_recv().is_ok() {
+                self.limiter.release();
+            }
+        }
+    }
+}
+
+/// Guard which releases the permit when dropped.
+struct Permit {
+    limiter: Limiter,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
+
+#[derive(Clone)]
+pub struct ConcurrencyLimitRequest<R: HasPayload> {
+    request: R,
+    chat_id: fn(&R::Payload) -> Option<&ChatId>,
+    limiter: Limiter,
+}
+
+impl<R: HasPayload> HasPayload for ConcurrencyLimitRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.request.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.request.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for ConcurrencyLimitRequest<R>
+where
+    R: HasPayload + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.request.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.request.to_http()
+    }
+}
+
+impl<R: Request> Request for ConcurrencyLimitRequest<R> {
+    type Err = R::Err;
+    type Send = ConcurrencyLimitSend<R::Send>;
+    type SendRef = ConcurrencyLimitSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        ConcurrencyLimitSend {
+            chat_id: (self.chat_id)(self.payload_ref()).cloned(),
+            limiter: self.limiter,
+            acquire: None,
+            permit: None,
+            fut: self.request.send(),
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        ConcurrencyLimitSend {
+            chat_id: (self.chat_id)(self.payload_ref()).cloned(),
+            limiter: self.limiter.clone(),
+            acquire: None,
+            permit: None,
+            fut: self.request.send_ref(),
+        }
+    }
+}
+
+/// Future returned by [`ConcurrencyLimitRequest`].
+///
+/// The permit is only acquired when the future is first polled, so requests
+/// which are created but not yet awaited don't take the place of others.
+#[pin_project::pin_project]
+pub struct ConcurrencyLimitSend<F> {
+    chat_id: Option<ChatId>,
+    limiter: Limiter,
+    acquire: Option<Acquire>,
+    permit: Option<Permit>,
+    #[pin]
+    fut: F,
+}
+
+impl<F: Future> Future for ConcurrencyLimitSend<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if this.permit.is_none() {
+            let acquire = match this.acquire {
+                Some(acquire) => acquire,
+                None => this
+                    .acquire
+                    .insert(this.limiter.acquire(this.chat_id.as_ref())),
+            };
+            *this.permit = Some(futures::ready!(Pin::new(acquire).poll(cx)));
+            *this.acquire = None;
+        }
+        let res = futures::ready!(this.fut.poll(cx));
+
+        // Let the next request go.
+        this.permit.take();
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_robin() {
+        let limiter = Limiter::new(1);
+        let (a, b) = (ChatId::Id(1), ChatId::Id(2));
+
+        let running = limiter.acquire(Some(&a)).now_or_never().unwrap();
+        let mut a1 = limiter.acquire(Some(&a));
+        let mut a2 = limiter.acquire(Some(&a));
+        let mut b1 = limiter.acquire(Some(&b));
+        let mut other = limiter.acquire(None);
+        assert!((&mut a1).now_or_never().is_none());
+
+        drop(running);
+        let permit = (&mut a1).now_or_never().unwrap();
+        assert!((&mut b1).now_or_never().is_none());
+
+        // The second request to `a` waits for `b` and the rest
+        drop(permit);
+        assert!((&mut a2).now_or_never().is_none());
+        let permit = (&mut b1).now_or_never().unwrap();
+
+        drop(permit);
+        let permit = (&mut other).now_or_never().unwrap();
+        drop(permit);
+        drop((&mut a2).now_or_never().unwrap());
+
+        assert_eq!(limiter.state.lock().unwrap().in_flight, 0);
+    }
+
+    #[test]
+    fn unpolled_requests_dont_hold_permits() {
+        use futures::future::ready;
+
+        use crate::{payloads::DeleteMessage, test_utils::MockRequest};
+
+        let limiter = Limiter::new(1);
+        let request = ConcurrencyLimitRequest {
+            request: MockRequest::new(DeleteMessage::new(1, 1), |_| ready(Ok(True))),
+            chat_id: |_| None,
+            limiter: limiter.clone(),
+        };
+
+        let created = request.send_ref();
+        assert_eq!(limiter.state.lock().unwrap().in_flight, 0);
+
+        // Isn't blocked by the request which wasn't polled yet
+        assert!(request.send_ref().now_or_never().unwrap().is_ok());
+        assert!(created.now_or_never().unwrap().is_ok());
+
+        assert_eq!(limiter.state.lock().unwrap().in_flight, 0);
+    }
+
+    #[test]
+    fn dropped_waiters() {
+        let limiter = Limiter::new(1);
+        let running = limiter.acquire(None).now_or_never().unwrap();
+
+        // Dropped before it got the permit
+        drop(limiter.acquire(None));
+        // Got the permit, but was dropped before being polled
+        let passed = limiter.acquire(None);
+        let mut last = limiter.acquire(None);
+
+        drop(running);
+        drop(passed);
+        drop((&mut last).now_or_never().unwrap());
+
+        let state = limiter.state.lock().unwrap();
+        assert_eq!(state.in_flight, 0);
+        assert!(state.ring.is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+use std::{
+    fmt,
+    future::Future,
+    io,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use serde::Serialize;
+use tokio::io::AsyncWrite;
+
+use crate::{
+    net::Download,
+    requests::{HasPayload, Payload, Request, Requester},
+    types::*,
+};
+
+/// The maximum length of [`ErrorContext::payload`] in bytes (not counting
+/// the trailing `…` of truncated payloads).
+pub const PAYLOAD_SUMMARY_LEN: usize = 1024;
+
+/// Information about a failed request, passed to the hook of [`ErrorHook`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ErrorContext {
+    /// Name of the method, e.g.: `SendMessage`, or `DownloadFile` for
+    /// downloads.
+    pub method: &'static str,
+
+    /// Summary of the payload of the request: its JSON, truncated to
+    /// [`PAYLOAD_SUMMARY_LEN`] bytes. Truncated payloads end with `…`.
+    /// Contents of files from memory ([`InputFile::Memory`]) are replaced
+    /// with their names and sizes.
+    ///
+    /// For downloads this is `{"path":"<path of the file>"}`.
+    ///
+    /// [`InputFile::Memory`]: crate::types::InputFile::Memory
+    pub payload: String,
+}
+
+/// A hook of [`ErrorHook`], implemented for all functions
+/// `Fn(&ErrorContext, E) -> E`.
+pub trait Hook<E> {
+    /// Called with the error of a failed request, returns the error which is
+    /// returned from the request instead.
+    fn on_error(&self, cx: &ErrorContext, err: E) -> E;
+}
+
+impl<F, E> Hook<E> for F
+where
+    F: Fn(&ErrorContext, E) -> E,
+{
+    fn on_error(&self, cx: &ErrorContext, err: E) -> E {
+        self(cx, err)
+    }
+}
+
+/// A hook which returns errors unchanged, the default [download hook].
+///
+/// [download hook]: ErrorHook::download_hook
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoHook;
+
+impl<E> Hook<E> for NoHook {
+    fn on_error(&self, _: &ErrorContext, err: E) -> E {
+        err
+    }
+}
+
+/// Serializes the `value` to JSON with contents of in-memory files replaced by
+/// their sizes, truncated to [`PAYLOAD_SUMMARY_LEN`] bytes.
+fn summary<T: Serialize + ?Sized>(value: &T) -> String {
+    struct Bounded(Vec<u8>);
+
+    impl io::Write for Bounded {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let room = PAYLOAD_SUMMARY_LEN - self.0.len();
+            if room == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "the summary is full",
+                ));
+            }
+
+            let len = std::cmp::min(room, buf.len());
+            self.0.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut value = match serde_json::to_value(value) {
+        Ok(value) => value,
+        Err(err) => return format!("<failed to serialize the payload: {}>", err),
+    };
+    summarize_memory_files(&mut value);
+
+    let mut json = Bounded(Vec::new());
+    match serde_json::to_writer(&mut json, &value) {
+        Ok(()) => String::from_utf8(json.0).unwrap_or_default(),
+        Err(err) if err.is_io() => {
+            // The summary is truncated, possibly within a character
+            let mut json = json.0;
+            let valid = std::str::from_utf8(&json).map_or_else(|err| err.valid_up_to(), str::len);
+            json.truncate(valid);
+
+            let mut json = String::from_utf8(json).unwrap_or_default();
+            json.push('…');
+            json
+        }
+        Err(err) => format!("<failed to serialize the payload: {}>", err),
+    }
+}
+
+/// Error hook adaptor, see [`RequesterExt::error_hook`].
+///
+/// Calls the hook on every error returned by a request, together with an
+/// [`ErrorContext`] which describes the failed request. The error returned
+/// by the hook is then returned from the request instead of the original
+/// one, so the hook can be used both to report errors in a single place (e.g.
+/// to an alerting system) and to transform them.
+///
+/// Note that a [summary] of the payload is built when the request is sent,
+/// since the payload is not available after the request fails. It's bounded
+/// and doesn't include contents of in-memory files, so this is cheap, but
+/// it's still done for requests which succeed. The [`ErrorContext`] itself is
+/// only created for failed requests.
+///
+/// Errors of [downloads] are passed to a separate hook, since they have a
+/// different type, see [`ErrorHook::download_hook`]. Errors of download
+/// streams are not hooked.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::error_hook::ErrorContext,
+///     requests::{Requester, RequesterExt},
+///     Bot, RequestError,
+/// };
+///
+/// let bot = Bot::new("TOKEN").error_hook(|cx: &ErrorContext, err: RequestError| {
+///     eprintln!("{} failed: {} (payload: {})", cx.method, err, cx.payload);
+///     err
+/// });
+/// # let _ = bot;
+/// ```
+///
+/// [`RequesterExt::error_hook`]: crate::requests::RequesterExt::error_hook
+/// [summary]: ErrorContext::payload
+/// [downloads]: crate::net::Download::download_file
+pub struct ErrorHook<B, H, DH = NoHook> {
+    bot: B,
+    hook: Arc<H>,
+    download_hook: Arc<DH>,
+}
+
+impl<B, H> ErrorHook<B, H> {
+    /// Creates new `ErrorHook`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::error_hook`] instead.
+    ///
+    /// [`RequesterExt::error_hook`]: crate::requests::RequesterExt::error_hook
+    pub fn new(bot: B, hook: H) -> Self {
+        Self {
+            bot,
+            hook: Arc::new(hook),
+            download_hook: Arc::new(NoHook),
+        }
+    }
+}
+
+impl<B, H, DH> ErrorHook<B, H, DH> {
+    /// Sets the hook which is called on every error of [`download_file`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{
+    ///     adaptors::error_hook::ErrorContext,
+    ///     requests::RequesterExt,
+    ///     Bot, DownloadError, RequestError,
+    /// };
+    ///
+    /// let bot = Bot::new("TOKEN")
+    ///     .error_hook(|_: &ErrorContext, err: RequestError| err)
+    ///     .download_hook(|cx: &ErrorContext, err: DownloadError| {
+    ///         eprintln!("download failed: {} ({})", err, cx.payload);
+    ///         err
+    ///     });
+    /// # let _ = bot;
+    /// ```
+    ///
+    /// [`download_file`]: crate::net::Download::download_file
+    pub fn download_hook<N>(self, hook: N) -> ErrorHook<B, H, N> {
+        ErrorHook {
+            bot: self.bot,
+            hook: self.hook,
+            download_hook: Arc::new(hook),
+        }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+impl<B: Clone, H, DH> Clone for ErrorHook<B, H, DH> {
+    fn clone(&self) -> Self {
+        Self {
+            bot: self.bot.clone(),
+            hook: Arc::clone(&self.hook),
+            download_hook: Arc::clone(&self.download_hook),
+        }
+    }
+}
+
+impl<B: fmt::Debug, H, DH> fmt::Debug for ErrorHook<B, H, DH> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorHook")
+            .field("bot", &self.bot)
+            .field("hook", &"..")
+            .finish()
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ErrorHookRequest {
+            inner: $this.inner().$m($($arg),*),
+            hook: Arc::clone(&$this.hook),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ErrorHookRequest<B::$T, H>
+    };
+}
+
+impl<B, H, DH> Requester for ErrorHook<B, H, DH>
+where
+    B: Requester,
+    H: Hook<B::Err> + Send + Sync + 'static,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, copy_message, send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count, get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, answer_shipping_query,
+        answer_pre_checkout_query, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => f, fty
+    }
+}
+
+impl<'w, B, H, DH> Download<'w> for ErrorHook<B, H, DH>
+where
+    B: Download<'w>,
+    DH: Hook<B::Err> + Send + Sync + 'static,
+{
+    type Err = B::Err;
+
+    type Fut = ErrorHookSend<B::Fut, DH>;
+
+    fn download_file(
+        &self,
+        path: &str,
+        destination: &'w mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Self::Fut {
+        #[derive(Serialize)]
+        struct DownloadPayload<'a> {
+            path: &'a str,
+        }
+
+        ErrorHookSend {
+            fut: self.inner().download_file(path, destination),
+            method: "DownloadFile",
+            payload: summary(&DownloadPayload { path }),
+            hook: Arc::clone(&self.download_hook),
+        }
+    }
+
+    type StreamErr = B::StreamErr;
+
+    type Stream = B::Stream;
+
+    fn download_file_stream(&self, path: &str) -> Self::Stream {
+        self.inner().download_file_stream(path)
+    }
+}
+
+/// Request returned by [`ErrorHook`], calls the hook if it fails.
+#[must_use = "Requests do nothing unless sent"]
+pub struct ErrorHookRequest<R, H> {
+    inner: R,
+    hook: Arc<H>,
+}
+
+impl<R: Clone, H> Clone for ErrorHookRequest<R, H> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            hook: Arc::clone(&self.hook),
+        }
+    }
+}
+
+impl<R, H> HasPayload for ErrorHookRequest<R, H>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R, H> crate::requests::ToCurl for ErrorHookRequest<R, H>
+where
+    R: crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R, H> Request for ErrorHookRequest<R, H>
+where
+    R: Request,
+    R::Payload: Serialize,
+    H: Hook<R::Err> + Send + Sync + 'static,
+{
+    type Err = R::Err;
+    type Send = ErrorHookSend<R::Send, H>;
+    type SendRef = ErrorHookSend<R::SendRef, H>;
+
+    fn send(self) -> Self::Send {
+        ErrorHookSend {
+            payload: summary(self.inner.payload_ref()),
+            method: <R::Payload as Payload>::NAME,
+            fut: self.inner.send(),
+            hook: self.hook,
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        ErrorHookSend {
+            payload: summary(self.inner.payload_ref()),
+            method: <R::Payload as Payload>::NAME,
+            fut: self.inner.send_ref(),
+            hook: Arc::clone(&self.hook),
+        }
+    }
+}
+
+/// Future returned by [`ErrorHookRequest`] and by downloads of [`ErrorHook`].
+#[pin_project::pin_project]
+pub struct ErrorHookSend<Fut, H> {
+    #[pin]
+    fut: Fut,
+    method: &'static str,
+    payload: String,
+    hook: Arc<H>,
+}
+
+impl<Fut, H, T, E> Future for ErrorHookSend<Fut, H>
+where
+    Fut: Future<Output = Result<T, E>>,
+    H: Hook<E>,
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let res = futures::ready!(this.fut.poll(cx));
+        let hook = &**this.hook;
+        let (method, payload) = (*this.method, this.payload);
+
+        Poll::Ready(res.map_err(|err| {
+            let cx = ErrorContext {
+                method,
+                payload: std::mem::take(payload),
+            };
+            hook.on_error(&cx, err)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::{future, FutureExt};
+
+    use super::*;
+    use crate::{
+        payloads::{DeleteMessage, SendDocument, SendMessage},
+        requests::RequesterExt,
+        test_utils::{rt, MockRequest},
+        Bot, DownloadError, RequestError,
+    };
+
+    #[test]
+    fn calls_hook_on_error() {
+        let contexts = Arc::new(Mutex::new(Vec::new()));
+        let hook = Arc::new({
+            let contexts = Arc::clone(&contexts);
+            move |cx: &ErrorContext, _: RequestError| {
+                contexts.lock().unwrap().push(cx.clone());
+                RequestError::RetryAfter(1)
+            }
+        });
+
+        let fail = ErrorHookRequest {
+            inner: MockRequest::new(SendMessage::new(1, "hi"), |_| {
+                future::ready(Err(RequestError::RetryAfter(10)))
+            }),
+            hook: Arc::clone(&hook),
+        };
+        let res = fail.send_ref().now_or_never().unwrap();
+        assert!(matches!(res, Err(RequestError::RetryAfter(1))));
+        let res = fail.send().now_or_never().unwrap();
+        assert!(matches!(res, Err(RequestError::RetryAfter(1))));
+
+        let ok = ErrorHookRequest {
+            inner: MockRequest::new(DeleteMessage::new(1, 2), |_| future::ready(Ok(True))),
+            hook,
+        };
+        assert!(ok.send_ref().now_or_never().unwrap().is_ok());
+        assert!(ok.send().now_or_never().unwrap().is_ok());
+
+        let contexts = contexts.lock().unwrap();
+        assert_eq!(contexts.len(), 2);
+        for cx in contexts.iter() {
+            assert_eq!(cx.method, "SendMessage");
+            assert_eq!(cx.payload, r#"{"chat_id":1,"text":"hi"}"#);
+        }
+    }
+
+    #[test]
+    fn summary_is_bounded() {
+        let file = InputFile::memory("file.bin", vec![0; 1024 * 1024]);
+        let payload = summary(&SendDocument::new(1, file));
+        assert_eq!(
+            payload,
+            r#"{"chat_id":1,"document":"<file `file.bin`, 1048576 bytes>"}"#
+        );
+
+        let payload = summary(&SendMessage::new(1, "a".repeat(2 * PAYLOAD_SUMMARY_LEN)));
+        assert!(payload.starts_with(r#"{"chat_id":1,"text":"aaa"#));
+        assert!(payload.ends_with('…'));
+        assert_eq!(payload.len(), PAYLOAD_SUMMARY_LEN + '…'.len_utf8());
+
+        // Not cut within a character
+        let payload = summary(&SendMessage::new(1, "я".repeat(PAYLOAD_SUMMARY_LEN)));
+        assert!(payload.len() <= PAYLOAD_SUMMARY_LEN + '…'.len_utf8());
+        assert!(payload.ends_with("яя…"));
+    }
+
+    #[test]
+    fn calls_download_hook() {
+        // Nothing listens on the port once the listener is dropped
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let bot = Bot::new("TOKEN")
+            .set_api_url(url.parse().unwrap())
+            .error_hook(|_: &ErrorContext, err: RequestError| err)
+            .download_hook({
+                let paths = Arc::clone(&paths);
+                move |cx: &ErrorContext, err: DownloadError| {
+                    paths.lock().unwrap().push((cx.method, cx.payload.clone()));
+                    err
+                }
+            });
+
+        let res = rt().block_on(async {
+            let mut sink = tokio::io::sink();
+            bot.download_file("photos/file_1.jpg", &mut sink).await
+        });
+        assert!(matches!(res, Err(DownloadError::NetworkError(_))));
+        assert_eq!(
+            *paths.lock().unwrap(),
+            [("DownloadFile", r#"{"path":"photos/file_1.jpg"}"#.to_owned())]
+        );
+    }
+}
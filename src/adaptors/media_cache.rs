@@ -0,0 +1,846 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use tokio::io::AsyncReadExt;
+
+use crate::{
+    errors::AsApiError,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+    ApiError, RequestError,
+};
+
+/// SHA-256 digest of a file, see [`MediaCache`].
+pub type Digest = [u8; 32];
+
+/// The number of files cached by default, see [`MediaCache::capacity`].
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// Kind of a cached file, i.e. the method it was sent with.
+///
+/// Files are cached per kind, since e.g. a `file_id` of a document can't be
+/// used to send the same file as a photo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaKind {
+    /// Sent with `send_photo`.
+    Photo,
+    /// Sent with `send_audio`.
+    Audio,
+    /// Sent with `send_document`.
+    Document,
+    /// Sent with `send_video`.
+    Video,
+    /// Sent with `send_animation`.
+    Animation,
+    /// Sent with `send_voice`.
+    Voice,
+    /// Sent with `send_video_note`.
+    VideoNote,
+    /// Sent with `send_sticker`.
+    Sticker,
+}
+
+impl MediaKind {
+    /// Returns the kind of files sent by the method with the `name` (e.g.
+    /// `SendPhoto`).
+    fn of(name: &str) -> Option<Self> {
+        match name {
+            "SendPhoto" => Some(Self::Photo),
+            "SendAudio" => Some(Self::Audio),
+            "SendDocument" => Some(Self::Document),
+            "SendVideo" => Some(Self::Video),
+            "SendAnimation" => Some(Self::Animation),
+            "SendVoice" => Some(Self::Voice),
+            "SendVideoNote" => Some(Self::VideoNote),
+            "SendSticker" => Some(Self::Sticker),
+            _ => None,
+        }
+    }
+}
+
+/// Key of a cached file: its kind and the digest of its content.
+pub type CacheKey = (MediaKind, Digest);
+
+/// Media cache adaptor, see [`RequesterExt::media_cache`].
+///
+/// Remembers `file_id`s of uploaded files (by the kind of media and the
+/// SHA-256 digest of the content) and replaces subsequent uploads of the same
+/// content with the `file_id`, so that the file is uploaded only once. This is
+/// useful for bots which send the same assets over and over.
+///
+/// Only [`InputFile::File`] and [`InputFile::Memory`] files (possibly
+/// [with overridden metadata]) sent with
+/// `send_photo`, `send_audio`, `send_document`, `send_video`,
+/// `send_animation`, `send_voice`, `send_video_note` and `send_sticker` are
+/// cached. Files are read (to compute the digest) when the request is sent.
+/// The overridden file name and MIME type are a part of the digest, since
+/// Telegram keeps them along with the file.
+///
+/// [with overridden metadata]: InputFile::WithMetadata
+///
+/// If Telegram rejects a cached `file_id` (e.g. because it has expired), the
+/// entry is removed from the cache and the file is uploaded again. Note that
+/// such errors are only detected in [`RequestError`]s.
+///
+/// Note that uploads can be replaced only when requests are sent with
+/// [`Request::send`], since [`Request::send_ref`] can't change the request.
+/// Files sent with [`Request::send_ref`] are still remembered.
+///
+/// The cache is in-memory and holds up to [`DEFAULT_CAPACITY`] files by
+/// default (the least recently used files are evicted first), use
+/// [`MediaCache::entries`] and [`MediaCache::insert`] to persist it.
+///
+/// [`RequesterExt::media_cache`]: crate::requests::RequesterExt::media_cache
+#[derive(Clone, Debug)]
+pub struct MediaCache<B> {
+    bot: B,
+    cache: Cache,
+}
+
+type Cache = Arc<Mutex<Entries>>;
+
+#[derive(Debug)]
+struct Entries {
+    capacity: usize,
+    /// Incremented on every use of the cache, to find the least recently used
+    /// entries.
+    clock: u64,
+    map: HashMap<CacheKey, Entry>,
+    /// Keys of the entries by the time they were used.
+    order: BTreeMap<u64, CacheKey>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    file_id: String,
+    used: u64,
+}
+
+impl Entries {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            map: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<String> {
+        self.clock += 1;
+        let clock = self.clock;
+
+        let entry = self.map.get_mut(key)?;
+        self.order.remove(&entry.used);
+        self.order.insert(clock, *key);
+        entry.used = clock;
+        Some(entry.file_id.clone())
+    }
+
+    fn insert(&mut self, key: CacheKey, file_id: String) {
+        self.clock += 1;
+        let used = self.clock;
+        if let Some(old) = self.map.insert(key, Entry { file_id, used }) {
+            self.order.remove(&old.used);
+        }
+        self.order.insert(used, key);
+        self.evict();
+    }
+
+    fn remove(&mut self, key: &CacheKey) -> Option<String> {
+        let entry = self.map.remove(key)?;
+        self.order.remove(&entry.used);
+        Some(entry.file_id)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+        self.order.clear();
+    }
+
+    /// Removes the least recently used entries until there are at most
+    /// `capacity` of them.
+    fn evict(&mut self) {
+        while self.map.len() > self.capacity {
+            let oldest = match self.order.keys().next() {
+                Some(used) => *used,
+                None => break,
+            };
+
+            if let Some(key) = self.order.remove(&oldest) {
+                self.map.remove(&key);
+            }
+        }
+    }
+}
+
+impl<B> MediaCache<B> {
+    /// Creates new `MediaCache` with an empty cache.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::media_cache`] instead.
+    ///
+    /// [`RequesterExt::media_cache`]: crate::requests::RequesterExt::media_cache
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            cache: Arc::new(Mutex::new(Entries::new(DEFAULT_CAPACITY))),
+        }
+    }
+
+    /// Sets the maximum number of cached files, [`DEFAULT_CAPACITY`] by
+    /// default.
+    ///
+    /// When the cache is full, the least recently used files are evicted.
+    pub fn capacity(self, capacity: usize) -> Self {
+        {
+            let mut cache = self.cache.lock().unwrap();
+            cache.capacity = capacity;
+            cache.evict();
+        }
+        self
+    }
+
+    /// Returns the `file_id` of a file of the `kind` with the `digest`, if it
+    /// was uploaded.
+    pub fn get(&self, kind: MediaKind, digest: &Digest) -> Option<String> {
+        self.cache.lock().unwrap().get(&(kind, *digest))
+    }
+
+    /// Adds a `file_id` of a file of the `kind` with the `digest` to the
+    /// cache, e.g. to restore a persisted cache.
+    pub fn insert(&self, kind: MediaKind, digest: Digest, file_id: String) {
+        self.cache.lock().unwrap().insert((kind, digest), file_id);
+    }
+
+    /// Removes a file from the cache, returning its `file_id`.
+    pub fn remove(&self, kind: MediaKind, digest: &Digest) -> Option<String> {
+        self.cache.lock().unwrap().remove(&(kind, *digest))
+    }
+
+    /// Returns all cached files.
+    pub fn entries(&self) -> Vec<(CacheKey, String)> {
+        let cache = self.cache.lock().unwrap();
+        cache
+            .map
+            .iter()
+            .map(|(key, entry)| (*key, entry.file_id.clone()))
+            .collect()
+    }
+
+    /// Returns the number of cached files.
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap().map.len()
+    }
+
+    /// Returns `true` if no files are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all files from the cache.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+/// Computes the SHA-256 digest of `data`, as used by [`MediaCache`] for files
+/// without overridden metadata.
+pub fn digest(data: &[u8]) -> Digest {
+    Sha256::digest(data).into()
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($c:ident : $C:ty, $file:ident : $F:ty)) => {
+        MediaCacheRequest {
+            inner: $this.inner().$m($c, $file),
+            file: |p| &mut p.$file,
+            file_ref: |p| &p.$file,
+            cache: Arc::clone(&$this.cache),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MediaCacheRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for MediaCache<B>
+where
+    B: Requester,
+    B::Err: 'static,
+    B::SendPhoto: Send,
+    B::SendAudio: Send,
+    B::SendDocument: Send,
+    B::SendVideo: Send,
+    B::SendAnimation: Send,
+    B::SendVoice: Send,
+    B::SendVideoNote: Send,
+    B::SendSticker: Send,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        send_photo, send_audio, send_document, send_video, send_animation, send_voice,
+        send_video_note, send_sticker => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, copy_message, send_message, send_media_group, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
+        get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, get_chat, get_chat_administrators, get_chat_members_count, get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
+        set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message,
+        set_message_reaction,
+        get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, answer_shipping_query,
+        answer_pre_checkout_query, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline, get_game_high_scores,
+        get_updates_fault_tolerant => fid, ftyid
+    }
+}
+
+download_forward! {
+    'w
+    B
+    MediaCache<B>
+    { this => this.inner() }
+}
+
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone)]
+pub struct MediaCacheRequest<R: HasPayload> {
+    inner: R,
+    file: fn(&mut R::Payload) -> &mut InputFile,
+    file_ref: fn(&R::Payload) -> &InputFile,
+    cache: Cache,
+}
+
+impl<R: HasPayload> HasPayload for MediaCacheRequest<R> {
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for MediaCacheRequest<R>
+where
+    R: HasPayload + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R> Request for MediaCacheRequest<R>
+where
+    R: Request + Send,
+    R::Payload: Payload<Output = Message>,
+    R::Err: 'static,
+{
+    type Err = R::Err;
+    type Send = MediaCacheSend<R>;
+    type SendRef = MediaCacheSendRef<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let digesting = Digesting::new((self.file_ref)(self.inner.payload_ref()));
+
+        MediaCacheSend {
+            request: Some(self.inner),
+            file: self.file,
+            digesting,
+            key: None,
+            attempt: Attempt::Digesting,
+            cache: self.cache,
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        let digesting = Digesting::new((self.file_ref)(self.inner.payload_ref()));
+
+        MediaCacheSendRef {
+            digesting,
+            key: None,
+            kind: MediaKind::of(<R::Payload as Payload>::NAME),
+            fut: self.inner.send_ref(),
+            res: None,
+            cache: Arc::clone(&self.cache),
+        }
+    }
+}
+
+/// A future which computes the digest of a file.
+enum Digesting {
+    Ready(Option<Digest>),
+    Reading(BoxFuture<'static, Option<Digest>>),
+}
+
+impl Digesting {
+    fn new(file: &InputFile) -> Self {
+        match file {
+            InputFile::Memory { data, .. } => Self::Ready(Some(digest(data))),
+            InputFile::File(path) => {
+                let path = path.clone();
+                Self::Reading(
+                    async move {
+                        // If the file can't be read, the upload will fail anyway
+                        digest_file(&path).await.ok()
+                    }
+                    .boxed(),
+                )
+            }
+            // Telegram keeps the name and the MIME type of the upload, so they
+            // are a part of the key
+            InputFile::WithMetadata(meta) => {
+                let file_name = meta.file_name.clone();
+                let mime_type = meta.mime_type.as_ref().map(ToString::to_string);
+                let with_metadata = move |digest: Option<Digest>| {
+                    digest.map(|d| with_metadata(d, file_name.as_deref(), mime_type.as_deref()))
+                };
+
+                match Self::new(&meta.file) {
+                    Self::Ready(digest) => Self::Ready(with_metadata(digest)),
+                    Self::Reading(fut) => Self::Reading(fut.map(with_metadata).boxed()),
+                }
+            }
+            _ => Self::Ready(None),
+        }
+    }
+
+    fn poll(&mut self, cx: &mut Context<'_>) -> Poll<Option<Digest>> {
+        match self {
+            Self::Ready(digest) => Poll::Ready(*digest),
+            Self::Reading(fut) => {
+                let digest = futures::ready!(fut.poll_unpin(cx));
+                *self = Self::Ready(digest);
+                Poll::Ready(digest)
+            }
+        }
+    }
+}
+
+/// Combines the `digest` of the content with the overridden metadata of the
+/// file, see [`InputFile::WithMetadata`].
+fn with_metadata(digest: Digest, file_name: Option<&str>, mime_type: Option<&str>) -> Digest {
+    let mut sha = Sha256::new();
+    sha.update(digest);
+    for field in [file_name, mime_type].iter() {
+        // Length prefixes keep e.g. (`ab`, `c`) and (`a`, `bc`) apart
+        match field {
+            Some(value) => {
+                sha.update([1]);
+                sha.update((value.len() as u64).to_be_bytes());
+                sha.update(value.as_bytes());
+            }
+            None => sha.update([0]),
+        }
+    }
+    sha.finalize().into()
+}
+
+/// Computes the digest of the file at `path`, reading it by chunks.
+async fn digest_file(path: &std::path::Path) -> std::io::Result<Digest> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut buf = vec![0; 64 * 1024];
+    let mut sha = Sha256::new();
+
+    loop {
+        match file.read(&mut buf).await? {
+            0 => return Ok(sha.finalize().into()),
+            n => sha.update(&buf[..n]),
+        }
+    }
+}
+
+fn remember(cache: &Cache, key: Option<CacheKey>, message: &Message) {
+    if let (Some(key), Some(file_id)) = (key, sent_file_id(message)) {
+        cache.lock().unwrap().insert(key, file_id.to_owned());
+    }
+}
+
+/// Returns `true` if the error is caused by a wrong (e.g. expired) file id.
+fn is_wrong_file_id(err: &dyn Any) -> bool {
+    err.downcast_ref::<RequestError>()
+        .and_then(RequestError::api_error)
+        .map(ApiError::is_wrong_file_id)
+        == Some(true)
+}
+
+/// Returns `file_id` of the file sent in the `message`.
+fn sent_file_id(message: &Message) -> Option<&str> {
+    let id = message
+        .photo()
+        .and_then(|sizes| sizes.last())
+        .map(|size| &size.file_id)
+        .or_else(|| message.animation().map(|a| &a.file_id))
+        .or_else(|| message.audio().map(|a| &a.file_id))
+        .or_else(|| message.document().map(|d| &d.file_id))
+        .or_else(|| message.video().map(|v| &v.file_id))
+        .or_else(|| message.voice().map(|v| &v.file_id))
+        .or_else(|| message.video_note().map(|v| &v.file_id))
+        .or_else(|| message.sticker().map(|s| &s.file_id))?;
+
+    Some(id)
+}
+
+#[pin_project::pin_project]
+pub struct MediaCacheSend<R: Request> {
+    request: Option<R>,
+    file: fn(&mut R::Payload) -> &mut InputFile,
+    digesting: Digesting,
+    key: Option<CacheKey>,
+    #[pin]
+    attempt: Attempt<R>,
+    cache: Cache,
+}
+
+#[pin_project::pin_project(project = AttemptProj)]
+enum Attempt<R: Request> {
+    /// The digest of the file is being computed.
+    Digesting,
+    /// The request is sent with the cached `file_id` instead of the
+    /// `original` file.
+    Cached {
+        #[pin]
+        fut: R::SendRef,
+        original: Option<InputFile>,
+    },
+    /// The file is uploaded.
+    Upload {
+        #[pin]
+        fut: R::Send,
+    },
+}
+
+impl<R> Future for MediaCacheSend<R>
+where
+    R: Request,
+    R::Payload: Payload<Output = Message>,
+    R::Err: 'static,
+{
+    type Output = Result<Output<R>, R::Err>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this.attempt.as_mut().project() {
+                AttemptProj::Digesting => {
+                    let digest = futures::ready!(this.digesting.poll(cx));
+                    let kind = MediaKind::of(<R::Payload as Payload>::NAME);
+                    *this.key = kind.zip(digest);
+
+                    let cache = &this.cache;
+                    let cached = this.key.and_then(|key| cache.lock().unwrap().get(&key));
+                    let request = this
+                        .request
+                        .as_mut()
+                        .expect("MediaCacheSend polled after completion");
+
+                    match cached {
+                        Some(file_id) => {
+                            let file = (this.file)(request.payload_mut());
+                            let original = std::mem::replace(file, InputFile::FileId(file_id));
+                            let fut = request.send_ref();
+                            this.attempt.set(Attempt::Cached {
+                                fut,
+                                original: Some(original),
+                            });
+                        }
+                        None => {
+                            let fut = this.request.take().expect("checked above").send();
+                            this.attempt.set(Attempt::Upload { fut });
+                        }
+                    }
+                }
+                AttemptProj::Cached { fut, original } => {
+                    let res = futures::ready!(fut.poll(cx));
+
+                    match res {
+                        // The cached file id doesn't work anymore, upload the file again
+                        Err(err) if is_wrong_file_id(&err) => {
+                            if let Some(key) = this.key {
+                                this.cache.lock().unwrap().remove(key);
+                            }
+
+                            let mut request = this
+                                .request
+                                .take()
+                                .expect("MediaCacheSend polled after completion");
+                            if let Some(original) = original.take() {
+                                *(this.file)(request.payload_mut()) = original;
+                            }
+                            this.attempt.set(Attempt::Upload {
+                                fut: request.send(),
+                            });
+                        }
+                        res => {
+                            this.request.take();
+                            return Poll::Ready(res);
+                        }
+                    }
+                }
+                AttemptProj::Upload { fut } => {
+                    let res = futures::ready!(fut.poll(cx));
+                    if let Ok(message) = &res {
+                        remember(this.cache, *this.key, message);
+                    }
+
+                    return Poll::Ready(res);
+                }
+            }
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct MediaCacheSendRef<F: Future> {
+    digesting: Digesting,
+    key: Option<Option<CacheKey>>,
+    kind: Option<MediaKind>,
+    #[pin]
+    fut: F,
+    res: Option<F::Output>,
+    cache: Cache,
+}
+
+impl<F, E> Future for MediaCacheSendRef<F>
+where
+    F: Future<Output = Result<Message, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if this.res.is_none() {
+            *this.res = Some(futures::ready!(this.fut.poll(cx)));
+        }
+
+        if this.key.is_none() {
+            let digest = futures::ready!(this.digesting.poll(cx));
+            *this.key = Some(this.kind.zip(digest));
+        }
+
+        let res = this
+            .res
+            .take()
+            .expect("MediaCacheSendRef polled after completion");
+        if let Ok(message) = &res {
+            remember(this.cache, this.key.flatten(), message);
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future::ready;
+
+    use super::*;
+    use crate::{
+        payloads::SendPhoto,
+        test_utils::{rt, MockRequest},
+    };
+
+    fn hex(digest: &Digest) -> String {
+        digest.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn sha256() {
+        assert_eq!(
+            hex(&digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn digests_files() {
+        let digesting = Digesting::new(&InputFile::memory("a.png", &b"image"[..]));
+        let digest = match digesting {
+            Digesting::Ready(Some(digest)) => digest,
+            _ => panic!("memory files are digested immediately"),
+        };
+        assert_eq!(digest, super::digest(b"image"));
+
+        assert!(matches!(
+            Digesting::new(&InputFile::file_id("id")),
+            Digesting::Ready(None)
+        ));
+    }
+
+    #[test]
+    fn metadata_is_digested() {
+        let digest = |file: InputFile| match Digesting::new(&file) {
+            Digesting::Ready(digest) => digest.unwrap(),
+            _ => panic!("memory files are digested immediately"),
+        };
+        let file = || InputFile::memory("a.png", &b"image"[..]);
+
+        let plain = digest(file());
+        let renamed = digest(file().file_name("b.png"));
+        let other_name = digest(file().file_name("c.png"));
+        let mime = digest(file().mime_type(mime::IMAGE_PNG));
+        assert_ne!(plain, renamed);
+        assert_ne!(renamed, other_name);
+        assert_ne!(plain, mime);
+        assert_eq!(renamed, digest(file().file_name("b.png")));
+    }
+
+    #[test]
+    fn least_recently_used_files_are_evicted() {
+        let cache = MediaCache::new(()).capacity(2);
+        let (a, b, c) = (digest(b"a"), digest(b"b"), digest(b"c"));
+
+        cache.insert(MediaKind::Photo, a, "a".to_owned());
+        cache.insert(MediaKind::Photo, b, "b".to_owned());
+        assert_eq!(cache.get(MediaKind::Photo, &a).as_deref(), Some("a"));
+        cache.insert(MediaKind::Photo, c, "c".to_owned());
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(MediaKind::Photo, &b), None);
+        assert!(cache.get(MediaKind::Photo, &a).is_some());
+        assert!(cache.get(MediaKind::Photo, &c).is_some());
+
+        // Files are cached per kind
+        assert_eq!(cache.get(MediaKind::Document, &a), None);
+
+        // Replaced and removed entries don't count as used
+        cache.insert(MediaKind::Photo, a, "a2".to_owned());
+        assert_eq!(cache.remove(MediaKind::Photo, &c).as_deref(), Some("c"));
+        cache.insert(MediaKind::Photo, b, "b".to_owned());
+        cache.insert(MediaKind::Photo, c, "c".to_owned());
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(MediaKind::Photo, &a), None);
+        assert_eq!(cache.cache.lock().unwrap().order.len(), 2);
+    }
+
+    /// Returns a request which records sent files and fails if it's sent
+    /// with the `stale` file id.
+    fn mock(file: InputFile, sent: &Arc<Mutex<Vec<InputFile>>>) -> MockRequest<SendPhoto> {
+        let sent = Arc::clone(sent);
+        MockRequest::new(SendPhoto::new(1, file), move |payload| {
+            sent.lock().unwrap().push(payload.photo.clone());
+
+            if payload.photo == InputFile::file_id("stale") {
+                return ready(Err(RequestError::ApiError {
+                    kind: ApiError::WrongFileIdOrUrl,
+                    status_code: reqwest::StatusCode::BAD_REQUEST,
+                }));
+            }
+
+            let message = serde_json::from_str(
+                r#"{
+                  "message_id": 1,
+                  "date": 0,
+                  "chat": {"id": 1, "type": "private", "first_name": "A"},
+                  "photo": [{"file_id": "fresh", "file_unique_id": "u", "width": 1, "height": 1}]
+                }"#,
+            );
+            ready(Ok(message.unwrap()))
+        })
+    }
+
+    #[test]
+    fn stale_file_ids_are_reuploaded() {
+        let cache = MediaCache::new(());
+        let file = InputFile::memory("a.png", &b"image"[..]);
+        cache.insert(MediaKind::Photo, digest(b"image"), "stale".to_owned());
+
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let request = MediaCacheRequest {
+            inner: mock(file.clone(), &sent),
+            file: |p| &mut p.photo,
+            file_ref: |p| &p.photo,
+            cache: Arc::clone(&cache.cache),
+        };
+
+        let message = futures::executor::block_on(request.send()).unwrap();
+        assert_eq!(sent_file_id(&message), Some("fresh"));
+        assert_eq!(*sent.lock().unwrap(), [InputFile::file_id("stale"), file]);
+        assert_eq!(
+            cache.get(MediaKind::Photo, &digest(b"image")).as_deref(),
+            Some("fresh")
+        );
+
+        let entries = cache.cache.lock().unwrap();
+        assert_eq!(entries.order.len(), entries.map.len());
+    }
+
+    #[test]
+    fn files_are_digested_by_chunks() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide-core-media-cache-{}", std::process::id()));
+        let data = vec![7; 200 * 1024];
+        std::fs::write(&path, &data).unwrap();
+
+        let res = rt().block_on(digest_file(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(res.unwrap(), digest(&data));
+    }
+}
@@ -0,0 +1,787 @@
+use std::{
+    collections::BTreeMap,
+    fs::{File, OpenOptions},
+    future::Future,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::task::JoinHandle;
+
+use crate::{
+    requests::{HasPayload, Payload, Request, Requester},
+    types::*,
+};
+
+/// A request persisted in an [`OutboxStore`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Identifier of the entry, unique inside of the store.
+    pub id: u64,
+
+    /// Name of the method, e.g.: `SendMessage`.
+    pub method: String,
+
+    /// Serialized payload of the request.
+    pub payload: Value,
+}
+
+/// Storage of the requests which are about to be sent, see [`Outbox`].
+///
+/// Methods of the store may block, [`Outbox`] calls them on a thread where
+/// blocking is acceptable (see [`tokio::task::spawn_blocking`]).
+pub trait OutboxStore: Send + Sync {
+    /// Persists a request, returning the identifier of the new entry.
+    fn push(&self, method: &str, payload: Value) -> io::Result<u64>;
+
+    /// Marks the entry as completed, i.e. removes it from the store.
+    fn complete(&self, id: u64) -> io::Result<()>;
+
+    /// Returns all entries which weren't completed, in the order in which they
+    /// were pushed.
+    fn pending(&self) -> io::Result<Vec<OutboxEntry>>;
+}
+
+/// An [`OutboxStore`] which keeps entries in memory.
+///
+/// This store doesn't survive restarts, it's mainly useful for tests and to
+/// inspect requests which are in flight.
+#[derive(Debug, Default)]
+pub struct MemoryOutbox {
+    inner: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    next_id: u64,
+    entries: BTreeMap<u64, OutboxEntry>,
+}
+
+impl Entries {
+    fn push(&mut self, method: &str, payload: Value) -> OutboxEntry {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let entry = OutboxEntry {
+            id,
+            method: method.to_owned(),
+            payload,
+        };
+        self.entries.insert(id, entry.clone());
+        entry
+    }
+}
+
+impl MemoryOutbox {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl OutboxStore for MemoryOutbox {
+    fn push(&self, method: &str, payload: Value) -> io::Result<u64> {
+        Ok(self.inner.lock().unwrap().push(method, payload).id)
+    }
+
+    fn complete(&self, id: u64) -> io::Result<()> {
+        self.inner.lock().unwrap().entries.remove(&id);
+        Ok(())
+    }
+
+    fn pending(&self) -> io::Result<Vec<OutboxEntry>> {
+        Ok(self
+            .inner
+            .lock()
+            .unwrap()
+            .entries
+            .values()
+            .cloned()
+            .collect())
+    }
+}
+
+/// An [`OutboxStore`] which keeps entries in a file.
+///
+/// The file is an append-only log of JSON lines, every operation is written
+/// and synced to the disk (with [`File::sync_data`]) before returning, so
+/// entries survive crashes and restarts.
+///
+/// Completed entries are removed from the file automatically once there are
+/// enough of them, see [`FileOutbox::compact`].
+#[derive(Debug)]
+pub struct FileOutbox {
+    path: PathBuf,
+    inner: Mutex<FileInner>,
+}
+
+/// Number of stale records in the file after which it's compacted.
+const COMPACT_THRESHOLD: usize = 1024;
+
+#[derive(Debug)]
+struct FileInner {
+    file: File,
+    entries: Entries,
+    /// Number of records in the file which don't describe pending entries.
+    stale: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Record {
+    Push(OutboxEntry),
+    Complete(u64),
+}
+
+impl FileOutbox {
+    /// Opens the store at `path`, creating the file if it doesn't exist.
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let (entries, records, torn) = Self::read(&path)?;
+        let created = !path.exists();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if created {
+            sync_dir(&path)?;
+        }
+        if let Some(len) = torn {
+            // Otherwise the next record would be appended to the torn one
+            file.set_len(len)?;
+            file.sync_data()?;
+        }
+
+        let stale = records - entries.entries.len();
+        Ok(Self {
+            path,
+            inner: Mutex::new(FileInner {
+                file,
+                entries,
+                stale,
+            }),
+        })
+    }
+
+    /// Returns the path to the file of this store.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Rewrites the file, removing completed entries.
+    ///
+    /// This is done automatically when entries are completed, so it's only
+    /// needed to shrink the file right away.
+    pub fn compact(&self) -> io::Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        self.compact_inner(&mut inner)
+    }
+
+    fn compact_inner(&self, inner: &mut FileInner) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        {
+            let mut file = File::create(&tmp)?;
+            for entry in inner.entries.entries.values() {
+                write_record(&mut file, &Record::Push(entry.clone()))?;
+            }
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp, &self.path)?;
+        sync_dir(&self.path)?;
+
+        inner.file = OpenOptions::new().append(true).open(&self.path)?;
+        inner.stale = 0;
+        Ok(())
+    }
+
+    /// Reads the entries and the number of records from the file.
+    ///
+    /// The last record may be only partly written if the process crashed
+    /// while writing it. Such a record is skipped and the length of the file
+    /// without it is returned, so that it can be truncated.
+    fn read(path: &Path) -> io::Result<(Entries, usize, Option<u64>)> {
+        let mut entries = Entries::default();
+        let mut records = 0;
+
+        let data = match std::fs::read(path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok((entries, 0, None)),
+            Err(err) => return Err(err),
+        };
+
+        let mut start = 0;
+        while start < data.len() {
+            let (line, end) = match data[start..].iter().position(|&b| b == b'\n') {
+                Some(n) => (&data[start..start + n], start + n + 1),
+                None => (&data[start..], data.len()),
+            };
+            if line.iter().all(u8::is_ascii_whitespace) {
+                start = end;
+                continue;
+            }
+
+            let last = data[end..].iter().all(u8::is_ascii_whitespace);
+            let terminated = data[..end].last() == Some(&b'\n');
+            let record = match serde_json::from_slice::<Record>(line) {
+                // An unterminated record is torn even if it happens to parse
+                Ok(record) if terminated => Some(record),
+                Ok(_) => None,
+                Err(_) if last => None,
+                Err(err) => return Err(err.into()),
+            };
+            let record = match record {
+                Some(record) => record,
+                None => {
+                    log::warn!(
+                        "skipping a partly written record at the end of the outbox file {}",
+                        path.display()
+                    );
+                    return Ok((entries, records, Some(start as u64)));
+                }
+            };
+
+            records += 1;
+            match record {
+                Record::Push(entry) => {
+                    entries.next_id = entries.next_id.max(entry.id + 1);
+                    entries.entries.insert(entry.id, entry);
+                }
+                Record::Complete(id) => {
+                    entries.entries.remove(&id);
+                }
+            }
+
+            start = end;
+        }
+
+        Ok((entries, records, None))
+    }
+}
+
+fn write_record(file: &mut File, record: &Record) -> io::Result<()> {
+    let mut line = serde_json::to_vec(record)?;
+    line.push(b'\n');
+    file.write_all(&line)?;
+    file.sync_data()
+}
+
+/// Syncs the directory containing the file at `path`, so that creation or
+/// renaming of the file survives a crash.
+fn sync_dir(path: &Path) -> io::Result<()> {
+    // Directories can't be opened as files on other platforms
+    if cfg!(unix) {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+impl OutboxStore for FileOutbox {
+    fn push(&self, method: &str, payload: Value) -> io::Result<u64> {
+        let mut inner = self.inner.lock().unwrap();
+
+        let entry = inner.entries.push(method, payload);
+        let id = entry.id;
+        if let Err(err) = write_record(&mut inner.file, &Record::Push(entry)) {
+            inner.entries.entries.remove(&id);
+            return Err(err);
+        }
+
+        Ok(id)
+    }
+
+    fn complete(&self, id: u64) -> io::Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner.entries.entries.remove(&id).is_some() {
+            write_record(&mut inner.file, &Record::Complete(id))?;
+
+            // The push and the completion records
+            inner.stale += 2;
+            if inner.stale >= COMPACT_THRESHOLD && inner.stale > inner.entries.entries.len() {
+                self.compact_inner(&mut inner)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pending(&self) -> io::Result<Vec<OutboxEntry>> {
+        let inner = self.inner.lock().unwrap();
+        Ok(inner.entries.entries.values().cloned().collect())
+    }
+}
+
+/// Outbox adaptor, see [`RequesterExt::outbox`].
+///
+/// Persists every request which changes state (e.g. sends a message) in an
+/// [`OutboxStore`] before sending it and marks it as completed after it was
+/// successfully sent. This way requests which were queued (e.g. in
+/// [`Throttle`]) but not sent because of a crash or restart can be found with
+/// [`Outbox::pending`] and resent.
+///
+/// Requests which only get information (e.g. [`GetMe`] or [`GetUpdates`]),
+/// [`SendChatAction`], answers to queries (which expire long before a
+/// restart), [`LogOut`] and [`Close`] are not persisted.
+///
+/// Contents of files from memory ([`InputFile::Memory`]) are not persisted,
+/// they are replaced with their names and lengths, so such files need to be
+/// attached again to resend a request.
+///
+/// Note that requests which failed are *not* marked as completed, so it's up
+/// to you to decide whether to resend them.
+///
+/// This adaptor should be used outside of adaptors which queue requests, i.e.
+/// `Outbox<Throttle<Bot>>`.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::outbox::MemoryOutbox,
+///     requests::{Requester, RequesterExt},
+///     Bot,
+/// };
+///
+/// # fn main() -> std::io::Result<()> {
+/// let bot = Bot::new("TOKEN").outbox(MemoryOutbox::new());
+///
+/// // Requests which weren't sent before the restart.
+/// for entry in bot.pending()? {
+///     println!("{} {}", entry.method, entry.payload);
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`RequesterExt::outbox`]: crate::requests::RequesterExt::outbox
+/// [`Throttle`]: crate::adaptors::Throttle
+/// [`GetMe`]: crate::payloads::GetMe
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`SendChatAction`]: crate::payloads::SendChatAction
+/// [`LogOut`]: crate::payloads::LogOut
+/// [`Close`]: crate::payloads::Close
+#[derive(Clone)]
+pub struct Outbox<B> {
+    bot: B,
+    store: Arc<dyn OutboxStore>,
+}
+
+impl<B> Outbox<B> {
+    /// Creates new `Outbox`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::outbox`] instead.
+    ///
+    /// [`RequesterExt::outbox`]: crate::requests::RequesterExt::outbox
+    pub fn new<S>(bot: B, store: S) -> Self
+    where
+        S: OutboxStore + 'static,
+    {
+        Self {
+            bot,
+            store: Arc::new(store),
+        }
+    }
+
+    /// Returns the store used by this adaptor.
+    pub fn store(&self) -> &dyn OutboxStore {
+        &*self.store
+    }
+
+    /// Returns the requests which weren't sent successfully.
+    pub fn pending(&self) -> io::Result<Vec<OutboxEntry>> {
+        self.store.pending()
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        OutboxRequest {
+            inner: $this.inner().$m($($arg),*),
+            store: Arc::clone(&$this.store),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        OutboxRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for Outbox<B>
+where
+    B: Requester,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        set_webhook, delete_webhook,
+        forward_message, copy_message, send_message, send_photo, send_audio, send_document,
+        send_video, send_animation, send_voice, send_video_note, send_media_group, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue,
+        send_contact, send_poll, send_dice, kick_chat_member, unban_chat_member,
+        restrict_chat_member, ban_chat_sender_chat, unban_chat_sender_chat,
+        promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
+        export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
+        revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
+        leave_chat, set_chat_sticker_set, delete_chat_sticker_set,
+        set_my_commands, edit_message_text,
+        edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
+        edit_message_media, edit_message_media_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
+        upload_sticker_file, create_new_sticker_set,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, set_passport_data_errors, send_game,
+        set_game_score, set_game_score_inline => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, get_webhook_info, send_chat_action,
+        get_user_profile_photos, get_file, get_forum_topic_icon_stickers,
+        get_chat, get_chat_administrators, get_chat_members_count, get_chat_member,
+        answer_callback_query, get_my_commands, answer_inline_query,
+        get_sticker_set, get_custom_emoji_stickers, answer_shipping_query,
+        answer_pre_checkout_query, get_game_high_scores,
+        get_updates_fault_tolerant => fid, ftyid
+    }
+}
+
+download_forward! {
+    'w
+    B
+    Outbox<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`Outbox`].
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone)]
+pub struct OutboxRequest<R> {
+    inner: R,
+    store: Arc<dyn OutboxStore>,
+}
+
+impl<R> OutboxRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Serialize,
+{
+    /// Serializes the payload, to persist it when the request is polled.
+    fn entry(&self) -> Option<(&'static str, Value)> {
+        match serde_json::to_value(self.inner.payload_ref()) {
+            Ok(mut payload) => {
+                summarize_memory_files(&mut payload);
+                Some((<R::Payload as Payload>::NAME, payload))
+            }
+            Err(err) => {
+                // Failing to persist a request should not prevent it from being sent.
+                log::error!("Couldn't persist a request in the outbox: {}", err);
+                None
+            }
+        }
+    }
+}
+
+impl<R> HasPayload for OutboxRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for OutboxRequest<R>
+where
+    R: crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R> Request for OutboxRequest<R>
+where
+    R: Request,
+    R::Payload: Serialize,
+{
+    type Err = R::Err;
+    type Send = OutboxSend<R::Send>;
+    type SendRef = OutboxSend<R::SendRef>;
+
+    fn send(self) -> Self::Send {
+        let persist = Persist::Start(self.entry());
+        OutboxSend {
+            fut: self.inner.send(),
+            store: self.store,
+            persist,
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        OutboxSend {
+            persist: Persist::Start(self.entry()),
+            fut: self.inner.send_ref(),
+            store: Arc::clone(&self.store),
+        }
+    }
+}
+
+#[pin_project::pin_project]
+pub struct OutboxSend<F> {
+    #[pin]
+    fut: F,
+    store: Arc<dyn OutboxStore>,
+    persist: Persist,
+}
+
+/// State of persisting of a request in the store.
+enum Persist {
+    /// The request is not yet persisted.
+    Start(Option<(&'static str, Value)>),
+    /// The request is being persisted.
+    Pushing(JoinHandle<io::Result<u64>>),
+    /// The request was persisted with the id (or failed to be).
+    Done(Option<u64>),
+}
+
+impl<F, T, E> Future for OutboxSend<F>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        // The store may block, so it's used on the blocking thread pool
+        loop {
+            match this.persist {
+                Persist::Start(entry) => match entry.take() {
+                    Some((method, payload)) => {
+                        let store = Arc::clone(this.store);
+                        let push = tokio::task::spawn_blocking(move || store.push(method, payload));
+                        *this.persist = Persist::Pushing(push);
+                    }
+                    None => *this.persist = Persist::Done(None),
+                },
+                Persist::Pushing(push) => {
+                    let id = match futures::ready!(Pin::new(push).poll(cx)) {
+                        Ok(Ok(id)) => Some(id),
+                        Ok(Err(err)) => {
+                            // Failing to persist a request should not prevent it from being sent.
+                            log::error!("Couldn't persist a request in the outbox: {}", err);
+                            None
+                        }
+                        Err(err) => {
+                            log::error!("Couldn't persist a request in the outbox: {}", err);
+                            None
+                        }
+                    };
+                    *this.persist = Persist::Done(id);
+                }
+                Persist::Done(_) => break,
+            }
+        }
+
+        let res = futures::ready!(this.fut.poll(cx));
+
+        if let (Ok(_), Persist::Done(Some(id))) = (&res, &*this.persist) {
+            let (store, id) = (Arc::clone(this.store), *id);
+            tokio::task::spawn_blocking(move || {
+                if let Err(err) = store.complete(id) {
+                    log::error!(
+                        "Couldn't mark a request in the outbox as completed: {}",
+                        err
+                    );
+                }
+            });
+            *this.persist = Persist::Done(None);
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory() {
+        let store = MemoryOutbox::new();
+        let a = store.push("SendMessage", Value::from(1)).unwrap();
+        let b = store.push("SendPhoto", Value::from(2)).unwrap();
+        store.complete(a).unwrap();
+
+        let pending = store.pending().unwrap();
+        assert_eq!(
+            pending,
+            vec![OutboxEntry {
+                id: b,
+                method: "SendPhoto".to_owned(),
+                payload: Value::from(2),
+            }]
+        );
+    }
+
+    #[test]
+    fn file() {
+        let path = std::env::temp_dir().join(format!("teloxide-outbox-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let store = FileOutbox::open(&path).unwrap();
+            let a = store.push("SendMessage", Value::from(1)).unwrap();
+            store.push("SendPhoto", Value::from(2)).unwrap();
+            store.complete(a).unwrap();
+        }
+
+        let store = FileOutbox::open(&path).unwrap();
+        let pending = store.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].method, "SendPhoto");
+
+        // New ids don't clash with the ids of the persisted entries.
+        let c = store.push("SendVideo", Value::from(3)).unwrap();
+        assert!(c > pending[0].id);
+
+        store.compact().unwrap();
+        drop(store);
+        let store = FileOutbox::open(&path).unwrap();
+        assert_eq!(store.pending().unwrap().len(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn torn_records_are_skipped() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide-outbox-torn-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let store = FileOutbox::open(&path).unwrap();
+            store.push("SendMessage", Value::from(1)).unwrap();
+        }
+
+        // A crash while writing the next record
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"push":{"id":1,"method":"Send"#)
+            .unwrap();
+        drop(file);
+
+        let store = FileOutbox::open(&path).unwrap();
+        assert_eq!(store.pending().unwrap().len(), 1);
+        store.push("SendPhoto", Value::from(2)).unwrap();
+        drop(store);
+
+        let store = FileOutbox::open(&path).unwrap();
+        assert_eq!(store.pending().unwrap().len(), 2);
+        drop(store);
+
+        // Corruption before the last record is still an error
+        let mut data = b"garbage\n".to_vec();
+        data.extend(std::fs::read(&path).unwrap());
+        std::fs::write(&path, data).unwrap();
+        assert!(FileOutbox::open(&path).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_is_compacted() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide-outbox-compact-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let store = FileOutbox::open(&path).unwrap();
+        store.push("SendMessage", Value::from(0)).unwrap();
+        for i in 0..COMPACT_THRESHOLD {
+            let id = store.push("SendMessage", Value::from(i)).unwrap();
+            store.complete(id).unwrap();
+        }
+
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert!(lines < COMPACT_THRESHOLD, "{} lines", lines);
+        drop(store);
+        assert_eq!(FileOutbox::open(&path).unwrap().pending().unwrap().len(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn persists_state_changing_requests() {
+        use crate::{requests::RequesterExt, Bot};
+
+        // Nothing listens on this port, so all requests fail and stay in the outbox
+        let url = reqwest::Url::parse("http://127.0.0.1:9/").unwrap();
+        let bot = Bot::new("TOKEN")
+            .set_api_url(url)
+            .outbox(MemoryOutbox::new());
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let document = InputFile::memory("a.txt", &b"secret"[..]);
+            assert!(bot.send_document(1, document).send().await.is_err());
+            assert!(bot.get_me().send().await.is_err());
+            assert!(bot.get_updates().send().await.is_err());
+        });
+
+        let pending = bot.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].method, "SendDocument");
+        assert_eq!(pending[0].payload["document"], "<file `a.txt`, 6 bytes>");
+    }
+}
@@ -6,6 +6,11 @@ use crate::{
 
 /// Default parse mode adaptor, see
 /// [`RequesterExt::parse_mode`](crate::requests::RequesterExt::parse_mode).
+///
+/// The default can be suppressed for a single request with
+/// [`ParseModeSetters::no_parse_mode`].
+///
+/// [`ParseModeSetters::no_parse_mode`]: crate::payloads::ParseModeSetters::no_parse_mode
 #[derive(Clone)]
 pub struct DefaultParseMode<B> {
     bot: B,
@@ -25,6 +30,11 @@ impl<B> DefaultParseMode<B> {
         }
     }
 
+    /// Returns the default parse mode.
+    pub fn parse_mode(&self) -> ParseMode {
+        self.mode
+    }
+
     /// Allows to access the inner bot.
     pub fn inner(&self) -> &B {
         &self.bot
@@ -101,16 +111,25 @@ impl<B: Requester> Requester for DefaultParseMode<B> {
         stop_message_live_location, stop_message_live_location_inline, send_venue,
         send_contact, send_dice, send_chat_action, get_user_profile_photos,
         get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
         promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
         revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
         leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
         set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
         set_my_commands, get_my_commands, answer_inline_query,
         edit_message_media, edit_message_media_inline, edit_message_reply_markup,
         edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+        set_message_reaction,
         get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
         add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
         set_sticker_set_thumb, send_invoice, answer_shipping_query,
         answer_pre_checkout_query, set_passport_data_errors, send_game,
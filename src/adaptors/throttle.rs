@@ -1,8 +1,10 @@
 use std::{
+    any::Any,
     collections::{hash_map::Entry, HashMap, VecDeque},
     future::Future,
     hash::{Hash, Hasher},
     pin::Pin,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -19,8 +21,10 @@ use vecrem::VecExt;
 
 use crate::{
     adaptors::throttle::chan_send::{ChanSend, MpscSend},
+    errors::AsResponseParameters,
     requests::{HasPayload, Output, Request, Requester},
     types::*,
+    RequestError,
 };
 
 // Throttling is quite complicated. This comment describes the algorithm of the
@@ -83,12 +87,14 @@ const DELAY: Duration = Duration::from_millis(250);
 /// particular bot if it has a lot of users (but they may or may not do that).
 ///
 /// [@BotSupport]: https://t.me/botsupport
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Limits {
     /// Allowed messages in one chat per second.
     pub messages_per_sec_chat: u32,
 
-    /// Allowed messages in one chat per minute.
+    /// Allowed messages in one group (or channel) per minute.
+    ///
+    /// This limit is not applied to private chats, see [`ChatClass`].
     pub messages_per_min_chat: u32,
 
     /// Allowed messages per second.
@@ -108,6 +114,75 @@ impl Default for Limits {
     }
 }
 
+/// Class of a chat, used to choose which [`Limits`] apply to it.
+///
+/// By default it's guessed from the chat id (see [`ChatClass::guess`]), a
+/// custom classifier can be set with [`Throttle::classifier`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ChatClass {
+    /// A private chat, only [`Limits::messages_per_sec_chat`] is applied.
+    Private,
+
+    /// A group, a supergroup or a channel, both
+    /// [`Limits::messages_per_sec_chat`] and [`Limits::messages_per_min_chat`]
+    /// are applied.
+    Group,
+}
+
+impl ChatClass {
+    /// Guesses the class of a chat by its id.
+    ///
+    /// Positive ids belong to users (i.e. private chats), while negative ids
+    /// and usernames belong to groups and channels.
+    pub fn guess(chat_id: &ChatId) -> Self {
+        match chat_id {
+            ChatId::Id(id) if *id > 0 => Self::Private,
+            _ => Self::Group,
+        }
+    }
+}
+
+/// Configuration of the adaptive throttling, see [`Throttle::adaptive`].
+///
+/// When a request fails with `RetryAfter` error, the effective limits (both
+/// overall and for the chat the request was sent to) are multiplied by
+/// [`backoff`] (but not below [`min_factor`]) and no more requests are sent to
+/// the chat until the time specified in the error passes. Then, after every
+/// [`cooldown`] without errors the limits are increased by [`recovery`] until
+/// they are restored to the original [`Limits`].
+///
+/// [`backoff`]: Adaptive::backoff
+/// [`min_factor`]: Adaptive::min_factor
+/// [`cooldown`]: Adaptive::cooldown
+/// [`recovery`]: Adaptive::recovery
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Adaptive {
+    /// Factor by which the limits are multiplied after a `RetryAfter` error.
+    pub backoff: f64,
+
+    /// The minimal factor of the limits.
+    pub min_factor: f64,
+
+    /// Time without `RetryAfter` errors after which the limits are
+    /// increased.
+    pub cooldown: Duration,
+
+    /// Amount by which the factor of the limits is increased after every
+    /// [`cooldown`](Adaptive::cooldown).
+    pub recovery: f64,
+}
+
+impl Default for Adaptive {
+    fn default() -> Self {
+        Self {
+            backoff: 0.5,
+            min_factor: 0.1,
+            cooldown: Duration::from_secs(30),
+            recovery: 0.1,
+        }
+    }
+}
+
 /// Automatic request limits respecting mechanism.
 ///
 /// Telegram has strict [limits], which, if exceeded will sooner or later cause
@@ -146,11 +221,221 @@ impl Default for Limits {
 ///
 /// As such, we encourage not to use `ChatId::ChannelUsername(u)` with this bot
 /// wrapper.
+///
+/// ## Observability
+///
+/// Current state of the throttling can be inspected with [`Throttle::stats`],
+/// additionally [`Throttle::on_delay`] allows to be notified about requests
+/// which were delayed for too long.
+///
+/// ## Adaptive throttling
+///
+/// By default the limits are static, [`Throttle::adaptive`] allows to
+/// additionally lower them when Telegram responds with `RetryAfter` errors,
+/// see [`Adaptive`] for more.
+///
+/// `RetryAfter` errors (used both by the [statistics] and by the adaptive
+/// throttling) are only detected in errors of type [`RequestError`]. If the
+/// inner bot returns a different error type, use [`Throttle::retry_after`] to
+/// enable their detection.
+///
+/// [statistics]: Throttle::stats
+///
+/// ## Chat classes
+///
+/// Telegram limits differ for private chats and groups, so every chat is
+/// assigned a [`ChatClass`]. By default it's guessed by the chat id, a custom
+/// classifier can be set with [`Throttle::classifier`].
 #[derive(Clone)]
 pub struct Throttle<B> {
     bot: B,
+    limits: Limits,
     // `RequestLock` allows to unlock requests (allowing them to be sent).
-    queue: mpsc::Sender<(ChatIdHash, RequestLock)>,
+    queue: mpsc::Sender<(ChatIdHash, ChatClass, RequestLock)>,
+    shared: Arc<Mutex<Shared>>,
+    on_delay: Option<Arc<DelayCallback>>,
+    classifier: Option<Arc<Classifier>>,
+    retry_after: RetryAfter,
+}
+
+type Classifier = dyn Fn(&ChatId) -> ChatClass + Send + Sync;
+
+/// Returns the number of seconds to wait from an error, if it's a `RetryAfter`
+/// error, see [`Throttle::retry_after`].
+type RetryAfter = fn(&dyn Any) -> Option<i32>;
+
+fn retry_after<E>(err: &dyn Any) -> Option<i32>
+where
+    E: AsResponseParameters + 'static,
+{
+    err.downcast_ref::<E>().and_then(E::retry_after)
+}
+
+fn classify(classifier: &Option<Arc<Classifier>>, chat_id: &ChatId) -> ChatClass {
+    match classifier {
+        Some(classifier) => classifier(chat_id),
+        None => ChatClass::guess(chat_id),
+    }
+}
+
+/// Statistics of a [`Throttle`], see [`Throttle::stats`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThrottleStats {
+    /// Number of requests waiting to be sent.
+    pub queued: usize,
+
+    /// Number of requests sent in the last second.
+    pub sent_last_sec: u32,
+
+    /// Number of requests sent in the last minute.
+    pub sent_last_min: u32,
+
+    /// Number of `RetryAfter` errors received in the last minute.
+    pub retry_after_last_min: u32,
+
+    /// State of the chats to which requests were sent in the last minute.
+    pub chats: HashMap<ChatIdHash, ChatStats>,
+}
+
+impl ThrottleStats {
+    /// Returns the state of the chat, if any requests were sent to it in the
+    /// last minute.
+    pub fn chat(&self, chat_id: &ChatId) -> Option<&ChatStats> {
+        self.chats.get(&chat_id.into())
+    }
+}
+
+/// State of a chat in a [`Throttle`], see [`ThrottleStats`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChatStats {
+    /// Number of requests sent to the chat in the last second.
+    pub sent_last_sec: u32,
+
+    /// Number of requests sent to the chat in the last minute.
+    pub sent_last_min: u32,
+
+    /// Number of requests which can be sent to the chat right now without
+    /// exceeding [`Limits::messages_per_sec_chat`] (lowered while the chat is
+    /// penalized by [adaptive throttling]).
+    ///
+    /// [adaptive throttling]: Throttle::adaptive
+    pub available_sec: u32,
+
+    /// Number of requests which can be sent to the chat right now without
+    /// exceeding [`Limits::messages_per_min_chat`] (lowered while the chat is
+    /// penalized by [adaptive throttling]). This is `u32::MAX` for private
+    /// chats, which have no per-minute limit, see [`ChatClass`].
+    ///
+    /// [adaptive throttling]: Throttle::adaptive
+    pub available_min: u32,
+}
+
+/// State shared between the worker, requests and [`Throttle::stats`].
+#[derive(Default)]
+struct Shared {
+    stats: ThrottleStats,
+    /// Times of `RetryAfter` errors received in the last minute.
+    retry_after: VecDeque<Instant>,
+    adaptive: Option<Adaptive>,
+    /// `RetryAfter` errors which weren't yet processed by the worker, only
+    /// collected if adaptive throttling is enabled.
+    feedback: Vec<(ChatIdHash, Duration)>,
+}
+
+/// State of the adaptive throttling.
+#[derive(Default)]
+struct Penalties {
+    overall: Option<Penalty>,
+    chats: HashMap<ChatIdHash, Penalty>,
+}
+
+#[derive(Copy, Clone)]
+struct Penalty {
+    factor: f64,
+    changed: Instant,
+    blocked_until: Instant,
+}
+
+impl Penalties {
+    fn update(&mut self, adaptive: &Adaptive, now: Instant, feedback: Vec<(ChatIdHash, Duration)>) {
+        for (chat, retry_after) in feedback {
+            self.overall.get_or_insert_with(|| Penalty::new(now)).hit(
+                adaptive,
+                now,
+                Duration::from_secs(0),
+            );
+            self.chats
+                .entry(chat)
+                .or_insert_with(|| Penalty::new(now))
+                .hit(adaptive, now, retry_after);
+        }
+
+        if let Some(p) = &mut self.overall {
+            if p.recover(adaptive, now) {
+                self.overall = None;
+            }
+        }
+        self.chats.retain(|_, p| !p.recover(adaptive, now));
+    }
+}
+
+impl Penalty {
+    fn new(now: Instant) -> Self {
+        Self {
+            factor: 1.0,
+            changed: now,
+            blocked_until: now,
+        }
+    }
+
+    fn hit(&mut self, adaptive: &Adaptive, now: Instant, retry_after: Duration) {
+        self.factor = (self.factor * adaptive.backoff).max(adaptive.min_factor);
+        self.changed = now;
+        self.blocked_until = self.blocked_until.max(now + retry_after);
+    }
+
+    /// Returns `true` if the penalty is fully recovered.
+    fn recover(&mut self, adaptive: &Adaptive, now: Instant) -> bool {
+        if now.duration_since(self.changed) >= adaptive.cooldown {
+            self.factor = (self.factor + adaptive.recovery).min(1.0);
+            self.changed = now;
+        }
+
+        self.factor >= 1.0 && self.blocked_until <= now
+    }
+
+    fn apply(&self, limit: u32) -> u32 {
+        ((f64::from(limit) * self.factor) as u32).max(1)
+    }
+
+    fn is_blocked(&self, now: Instant) -> bool {
+        self.blocked_until > now
+    }
+}
+
+/// Returns the per-second and per-minute limits of a chat of the `class`, or
+/// `None` if Telegram asked us not to send requests to the chat for a while.
+fn chat_limits(
+    limits: &Limits,
+    class: ChatClass,
+    penalty: Option<&Penalty>,
+    now: Instant,
+) -> Option<(u32, u32)> {
+    let (per_sec, per_min) = match class {
+        ChatClass::Private => (limits.messages_per_sec_chat, u32::MAX),
+        ChatClass::Group => (limits.messages_per_sec_chat, limits.messages_per_min_chat),
+    };
+
+    match penalty {
+        Some(penalty) if penalty.is_blocked(now) => None,
+        Some(penalty) => Some((penalty.apply(per_sec), penalty.apply(per_min))),
+        None => Some((per_sec, per_min)),
+    }
+}
+
+struct DelayCallback {
+    threshold: Duration,
+    f: Box<dyn Fn(ChatIdHash, Duration) + Send + Sync>,
 }
 
 type RequestsSent = u32;
@@ -162,17 +447,24 @@ type RequestsSent = u32;
 struct RequestsSentToChats {
     per_min: HashMap<ChatIdHash, RequestsSent>,
     per_sec: HashMap<ChatIdHash, RequestsSent>,
+    /// Classes of the chats in `per_min`.
+    classes: HashMap<ChatIdHash, ChatClass>,
 }
 
-async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)>) {
+async fn worker(
+    limits: Limits,
+    mut rx: mpsc::Receiver<(ChatIdHash, ChatClass, RequestLock)>,
+    shared: Arc<Mutex<Shared>>,
+) {
     // FIXME(waffle): Make an research about data structures for this queue.
     //                Currently this is O(n) removing (n = number of elements
     //                stayed), amortized O(1) push (vec+vecrem).
-    let mut queue: Vec<(ChatIdHash, RequestLock)> =
+    let mut queue: Vec<(ChatIdHash, ChatClass, RequestLock)> =
         Vec::with_capacity(limits.messages_per_sec_overall as usize);
 
     let mut history: VecDeque<(ChatIdHash, Instant)> = VecDeque::new();
     let mut requests_sent = RequestsSentToChats::default();
+    let mut penalties = Penalties::default();
 
     let mut rx_is_closed = false;
 
@@ -232,44 +524,75 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
                 if let Entry::Occupied(entry) = entry {
                     if *entry.get() == 0 {
                         entry.remove_entry();
+                        requests_sent.classes.remove(&chat);
                     }
                 }
             }
         }
 
+        let (adaptive, feedback) = {
+            let mut shared = shared.lock().unwrap();
+            (shared.adaptive, std::mem::take(&mut shared.feedback))
+        };
+        match &adaptive {
+            Some(adaptive) => penalties.update(adaptive, now, feedback),
+            None => penalties = Penalties::default(),
+        }
+
         // as truncates which is ok since in case of truncation it would always be >=
         // limits.overall_s
         let used = history
             .iter()
             .take_while(|(_, time)| time > &sec_back)
             .count() as u32;
-        let mut allowed = limits.messages_per_sec_overall.saturating_sub(used);
+        let overall_limit = match &penalties.overall {
+            Some(penalty) => penalty.apply(limits.messages_per_sec_overall),
+            None => limits.messages_per_sec_overall,
+        };
+        let mut allowed = overall_limit.saturating_sub(used);
+
+        for (chat, _) in history.iter().take_while(|(_, time)| time > &sec_back) {
+            *requests_sent.per_sec.entry(*chat).or_insert(0) += 1;
+        }
 
         if allowed == 0 {
+            update_stats(
+                &shared,
+                &limits,
+                &penalties,
+                queue.len(),
+                &history,
+                &requests_sent,
+            );
             requests_sent.per_sec.clear();
             tokio::time::sleep(DELAY).await;
             continue;
         }
 
-        for (chat, _) in history.iter().take_while(|(_, time)| time > &sec_back) {
-            *requests_sent.per_sec.entry(*chat).or_insert(0) += 1;
-        }
-
         let mut queue_removing = queue.removing();
 
         while let Some(entry) = queue_removing.next() {
-            let chat = &entry.value().0;
-            let requests_sent_count = requests_sent.per_sec.get(chat).copied().unwrap_or(0);
-            let limits_not_exceeded = requests_sent_count < limits.messages_per_sec_chat
-                && requests_sent_count < limits.messages_per_min_chat;
+            let (chat, class, _) = entry.value();
+            let (chat, class) = (*chat, *class);
+
+            let (per_sec_chat, per_min_chat) =
+                match chat_limits(&limits, class, penalties.chats.get(&chat), now) {
+                    Some(limits) => limits,
+                    None => continue,
+                };
+
+            let sent_last_sec = requests_sent.per_sec.get(&chat).copied().unwrap_or(0);
+            let sent_last_min = requests_sent.per_min.get(&chat).copied().unwrap_or(0);
+            let limits_not_exceeded = sent_last_sec < per_sec_chat && sent_last_min < per_min_chat;
 
             if limits_not_exceeded {
-                *requests_sent.per_sec.entry(*chat).or_insert(0) += 1;
-                *requests_sent.per_min.entry(*chat).or_insert(0) += 1;
-                history.push_back((*chat, Instant::now()));
+                *requests_sent.per_sec.entry(chat).or_insert(0) += 1;
+                *requests_sent.per_min.entry(chat).or_insert(0) += 1;
+                requests_sent.classes.insert(chat, class);
+                history.push_back((chat, Instant::now()));
 
                 // Close the channel and unlock the associated request.
-                let (_, lock) = entry.remove();
+                let (_, _, lock) = entry.remove();
                 lock.unlock();
 
                 // We have "sent" one request, so now we can send one less.
@@ -279,6 +602,16 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
                 }
             }
         }
+        drop(queue_removing);
+
+        update_stats(
+            &shared,
+            &limits,
+            &penalties,
+            queue.len(),
+            &history,
+            &requests_sent,
+        );
 
         // It's easier to just recompute last second stats, instead of keeping
         // track of it alongside with minute stats, so we just throw this away.
@@ -287,6 +620,48 @@ async fn worker(limits: Limits, mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)
     }
 }
 
+fn update_stats(
+    shared: &Mutex<Shared>,
+    limits: &Limits,
+    penalties: &Penalties,
+    queued: usize,
+    history: &VecDeque<(ChatIdHash, Instant)>,
+    requests_sent: &RequestsSentToChats,
+) {
+    let now = Instant::now();
+    let chats = requests_sent
+        .per_min
+        .iter()
+        .map(|(chat, &sent_last_min)| {
+            let sent_last_sec = requests_sent.per_sec.get(chat).copied().unwrap_or(0);
+            let class = requests_sent
+                .classes
+                .get(chat)
+                .copied()
+                .unwrap_or(ChatClass::Group);
+            let (per_sec, per_min) =
+                chat_limits(limits, class, penalties.chats.get(chat), now).unwrap_or((0, 0));
+            let stats = ChatStats {
+                sent_last_sec,
+                sent_last_min,
+                available_sec: per_sec.saturating_sub(sent_last_sec),
+                available_min: per_min.saturating_sub(sent_last_min),
+            };
+
+            (*chat, stats)
+        })
+        .collect();
+
+    let mut shared = shared.lock().unwrap();
+    shared.stats = ThrottleStats {
+        queued,
+        sent_last_sec: requests_sent.per_sec.values().sum(),
+        sent_last_min: history.len() as u32,
+        retry_after_last_min: 0,
+        chats,
+    };
+}
+
 async fn read_from_rx<T>(rx: &mut mpsc::Receiver<T>, queue: &mut Vec<T>, rx_is_closed: &mut bool) {
     if queue.is_empty() {
         match rx.recv().await {
@@ -314,9 +689,18 @@ impl<B> Throttle<B> {
     /// polled/spawned/awaited.
     pub fn new(bot: B, limits: Limits) -> (Self, impl Future<Output = ()>) {
         let (tx, rx) = mpsc::channel(limits.messages_per_sec_overall as usize);
-
-        let worker = worker(limits, rx);
-        let this = Self { bot, queue: tx };
+        let shared = Arc::new(Mutex::new(Shared::default()));
+
+        let worker = worker(limits, rx, Arc::clone(&shared));
+        let this = Self {
+            bot,
+            limits,
+            queue: tx,
+            shared,
+            on_delay: None,
+            classifier: None,
+            retry_after: retry_after::<RequestError>,
+        };
 
         (this, worker)
     }
@@ -345,6 +729,113 @@ impl<B> Throttle<B> {
         this
     }
 
+    /// Sets a callback which is called when a request was delayed by the
+    /// throttling for more than `threshold`.
+    ///
+    /// The callback receives the chat to which the request is sent and the
+    /// time for which it was delayed.
+    pub fn on_delay<F>(mut self, threshold: Duration, f: F) -> Self
+    where
+        F: Fn(ChatIdHash, Duration) + Send + Sync + 'static,
+    {
+        self.on_delay = Some(Arc::new(DelayCallback {
+            threshold,
+            f: Box::new(f),
+        }));
+        self
+    }
+
+    /// Sets a classifier which is used to determine which limits apply to a
+    /// chat, see [`ChatClass`].
+    ///
+    /// By default [`ChatClass::guess`] is used. The classifier is called every
+    /// time a request is sent, so it should be cheap (e.g. look up a cache).
+    pub fn classifier<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&ChatId) -> ChatClass + Send + Sync + 'static,
+    {
+        self.classifier = Some(Arc::new(f));
+        self
+    }
+
+    /// Enables detection of `RetryAfter` errors in errors of the inner bot.
+    ///
+    /// By default `RetryAfter` errors are only detected in [`RequestError`]s
+    /// (which is the error type of [`Bot`](crate::Bot) and of the adaptors
+    /// provided by this crate). This method is only needed for bots with a
+    /// custom error type.
+    ///
+    /// Note: this doesn't affect `Throttle`s created by [`Throttle::share`]
+    /// before the call.
+    pub fn retry_after(mut self) -> Self
+    where
+        B: Requester,
+        B::Err: AsResponseParameters + 'static,
+    {
+        self.retry_after = retry_after::<B::Err>;
+        self
+    }
+
+    /// Enables adaptive throttling, see [`Adaptive`] for more.
+    ///
+    /// Note: this affects all clones of this `Throttle`.
+    pub fn adaptive(self, adaptive: Adaptive) -> Self {
+        self.shared.lock().unwrap().adaptive = Some(adaptive);
+        self
+    }
+
+    /// Returns current statistics of the throttling.
+    ///
+    /// Note: the statistics are updated by the worker, so they may be a bit
+    /// (up to a quarter of a second) outdated.
+    pub fn stats(&self) -> ThrottleStats {
+        let mut shared = self.shared.lock().unwrap();
+
+        let min_back = Instant::now() - MINUTE;
+        while matches!(shared.retry_after.front(), Some(time) if *time < min_back) {
+            shared.retry_after.pop_front();
+        }
+
+        ThrottleStats {
+            retry_after_last_min: shared.retry_after.len() as u32,
+            ..shared.stats.clone()
+        }
+    }
+
+    /// Creates a new `Throttle` wrapping `bot` which shares the worker (and
+    /// the settings) with `self`.
+    ///
+    /// Since the worker is shared, the limits apply to all the requests sent
+    /// through both `Throttle`s together. Note that Telegram limits every bot
+    /// separately, so this is mostly useful to wrap clones of one bot in
+    /// different adaptors.
+    pub fn share<B2>(&self, bot: B2) -> Throttle<B2> {
+        Throttle {
+            bot,
+            limits: self.limits,
+            queue: self.queue.clone(),
+            shared: Arc::clone(&self.shared),
+            on_delay: self.on_delay.clone(),
+            classifier: self.classifier.clone(),
+            retry_after: self.retry_after,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn shares_worker_with<B2>(&self, other: &Throttle<B2>) -> bool {
+        Arc::ptr_eq(&self.shared, &other.shared)
+    }
+
+    /// Returns the limits passed on creation.
+    ///
+    /// Note: with [adaptive throttling] the effective limits may be
+    /// temporarily lower after `RetryAfter` errors.
+    ///
+    /// [adaptive throttling]: Throttle::adaptive
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
     /// Allows to access inner bot
     pub fn inner(&self) -> &B {
         &self.bot
@@ -360,8 +851,12 @@ macro_rules! f {
     ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
         ThrottlingRequest {
             request: $this.inner().$m($($arg),*),
-            chat_id: |p| (&p.payload_ref().chat_id).into(),
+            chat_id: |p| &p.payload_ref().chat_id,
             worker: $this.queue.clone(),
+            shared: Arc::clone(&$this.shared),
+            on_delay: $this.on_delay.clone(),
+            classifier: $this.classifier.clone(),
+            retry_after: $this.retry_after,
         }
     };
 }
@@ -386,6 +881,7 @@ macro_rules! ftyid {
 
 impl<B: Requester> Requester for Throttle<B>
 where
+    B::Err: 'static,
     B::SendMessage: Send,
     B::ForwardMessage: Send,
     B::CopyMessage: Send,
@@ -420,10 +916,17 @@ where
         stop_message_live_location, stop_message_live_location_inline,
         send_chat_action, get_user_profile_photos, get_file, kick_chat_member,
         unban_chat_member, restrict_chat_member, promote_chat_member,
+        ban_chat_sender_chat, unban_chat_sender_chat,
         set_chat_administrator_custom_title, set_chat_permissions,
         export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+        create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
         revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+        approve_chat_join_request, decline_chat_join_request,
         set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages,
         leave_chat, get_chat, get_chat_administrators, get_chat_members_count,
         get_chat_member, set_chat_sticker_set, delete_chat_sticker_set,
         answer_callback_query, set_my_commands, get_my_commands, answer_inline_query,
@@ -431,6 +934,8 @@ where
         edit_message_caption_inline, edit_message_media, edit_message_media_inline,
         edit_message_reply_markup, edit_message_reply_markup_inline, stop_poll,
         delete_message, get_sticker_set, upload_sticker_file, create_new_sticker_set,
+        get_custom_emoji_stickers,
+        set_message_reaction,
         add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
         set_sticker_set_thumb, answer_shipping_query, answer_pre_checkout_query,
         set_passport_data_errors, send_game, set_game_score, set_game_score_inline,
@@ -450,8 +955,10 @@ download_forward! {
 /// It is used instead of `ChatId` to make copying cheap even in case of
 /// usernames. (It is just a hashed username.)
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-enum ChatIdHash {
+pub enum ChatIdHash {
+    /// Unique identifier of a chat, same as [`ChatId::Id`].
     Id(i64),
+    /// Hash of a username, see [`ChatId::ChannelUsername`].
     ChannelUsernameHash(u64),
 }
 
@@ -469,10 +976,15 @@ impl From<&ChatId> for ChatIdHash {
     }
 }
 
+#[derive(Clone)]
 pub struct ThrottlingRequest<R: HasPayload> {
     request: R,
-    chat_id: fn(&R::Payload) -> ChatIdHash,
-    worker: mpsc::Sender<(ChatIdHash, RequestLock)>,
+    chat_id: fn(&R::Payload) -> &ChatId,
+    worker: mpsc::Sender<(ChatIdHash, ChatClass, RequestLock)>,
+    shared: Arc<Mutex<Shared>>,
+    on_delay: Option<Arc<DelayCallback>>,
+    classifier: Option<Arc<Classifier>>,
+    retry_after: RetryAfter,
 }
 
 impl<R: HasPayload> HasPayload for ThrottlingRequest<R> {
@@ -487,9 +999,24 @@ impl<R: HasPayload> HasPayload for ThrottlingRequest<R> {
     }
 }
 
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for ThrottlingRequest<R>
+where
+    R: HasPayload + crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.request.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.request.to_http()
+    }
+}
+
 impl<R> Request for ThrottlingRequest<R>
 where
     R: Request + Send,
+    R::Err: 'static,
 {
     type Err = R::Err;
     type Send = ThrottlingSend<R>;
@@ -499,21 +1026,39 @@ where
         let (tx, rx) = channel();
 
         let chat_id = (self.chat_id)(self.payload_ref());
-        let send = self.worker.send1((chat_id, tx));
+        let class = classify(&self.classifier, chat_id);
+        let chat_id = chat_id.into();
+        let send = self.worker.send1((chat_id, class, tx));
+        let meta = SendMeta {
+            chat_id,
+            registered: Instant::now(),
+            shared: self.shared,
+            on_delay: self.on_delay,
+            retry_after: self.retry_after,
+        };
 
         let inner = ThrottlingSendInner::Registering {
             request: self.request,
             send,
             wait: rx,
         };
-        ThrottlingSend(inner)
+        ThrottlingSend { inner, meta }
     }
 
     fn send_ref(&self) -> Self::SendRef {
         let (tx, rx) = channel();
 
         let chat_id = (self.chat_id)(self.payload_ref());
-        let send = self.worker.clone().send1((chat_id, tx));
+        let class = classify(&self.classifier, chat_id);
+        let chat_id = chat_id.into();
+        let send = self.worker.clone().send1((chat_id, class, tx));
+        let meta = SendMeta {
+            chat_id,
+            registered: Instant::now(),
+            shared: Arc::clone(&self.shared),
+            on_delay: self.on_delay.clone(),
+            retry_after: self.retry_after,
+        };
 
         // As we can't move self.0 (request) out, as we do in `send` we are
         // forced to call `send_ref()`. This may have overhead and/or lead to
@@ -528,19 +1073,62 @@ where
             send,
             wait: rx,
         };
-        ThrottlingSendRef(inner)
+        ThrottlingSendRef { inner, meta }
+    }
+}
+
+/// Information about a sent request, used to update [`Throttle::stats`] and to
+/// call [`Throttle::on_delay`] callback.
+struct SendMeta {
+    chat_id: ChatIdHash,
+    registered: Instant,
+    shared: Arc<Mutex<Shared>>,
+    on_delay: Option<Arc<DelayCallback>>,
+    retry_after: RetryAfter,
+}
+
+impl SendMeta {
+    /// Called when the worker has unlocked the request.
+    fn unlocked(&self) {
+        if let Some(on_delay) = &self.on_delay {
+            let delay = self.registered.elapsed();
+            if delay > on_delay.threshold {
+                (on_delay.f)(self.chat_id, delay);
+            }
+        }
+    }
+
+    /// Called when the request has completed.
+    fn completed<T, E>(&self, res: &Result<T, E>)
+    where
+        E: 'static,
+    {
+        let retry_after = self.retry_after;
+        if let Some(retry_after) = res.as_ref().err().and_then(|err| retry_after(err)) {
+            let mut shared = self.shared.lock().unwrap();
+            shared.retry_after.push_back(Instant::now());
+
+            if shared.adaptive.is_some() {
+                let retry_after = Duration::from_secs(retry_after.max(0) as u64);
+                shared.feedback.push((self.chat_id, retry_after));
+            }
+        }
     }
 }
 
 #[pin_project::pin_project]
-pub struct ThrottlingSend<R: Request>(#[pin] ThrottlingSendInner<R>);
+pub struct ThrottlingSend<R: Request> {
+    #[pin]
+    inner: ThrottlingSendInner<R>,
+    meta: SendMeta,
+}
 
 #[pin_project::pin_project(project = SendProj, project_replace = SendRepl)]
 enum ThrottlingSendInner<R: Request> {
     Registering {
         request: R,
         #[pin]
-        send: ChanSend<(ChatIdHash, RequestLock)>,
+        send: ChanSend<(ChatIdHash, ChatClass, RequestLock)>,
         wait: RequestWaiter,
     },
     Pending {
@@ -555,11 +1143,16 @@ enum ThrottlingSendInner<R: Request> {
     Done,
 }
 
-impl<R: Request> Future for ThrottlingSend<R> {
+impl<R: Request> Future for ThrottlingSend<R>
+where
+    R::Err: 'static,
+{
     type Output = Result<Output<R>, R::Err>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.as_mut().project().0;
+        let proj = self.as_mut().project();
+        let meta = proj.meta;
+        let mut this = proj.inner;
 
         match this.as_mut().project() {
             SendProj::Registering {
@@ -598,6 +1191,8 @@ impl<R: Request> Future for ThrottlingSend<R> {
                 // always be `Err(_)` (because `Ok(Never)` is uninhibited)
                 // and that's what we want.
                 Poll::Ready(_) => {
+                    meta.unlocked();
+
                     if let SendRepl::Pending { request, wait: _ } =
                         this.as_mut().project_replace(ThrottlingSendInner::Done)
                     {
@@ -611,6 +1206,7 @@ impl<R: Request> Future for ThrottlingSend<R> {
             },
             SendProj::Sent { fut } => {
                 let res = futures::ready!(fut.poll(cx));
+                meta.completed(&res);
                 this.set(ThrottlingSendInner::Done);
                 Poll::Ready(res)
             }
@@ -620,14 +1216,18 @@ impl<R: Request> Future for ThrottlingSend<R> {
 }
 
 #[pin_project::pin_project]
-pub struct ThrottlingSendRef<R: Request>(#[pin] ThrottlingSendRefInner<R>);
+pub struct ThrottlingSendRef<R: Request> {
+    #[pin]
+    inner: ThrottlingSendRefInner<R>,
+    meta: SendMeta,
+}
 
 #[pin_project::pin_project(project = SendRefProj, project_replace = SendRefRepl)]
 enum ThrottlingSendRefInner<R: Request> {
     Registering {
         request: R::SendRef,
         #[pin]
-        send: ChanSend<(ChatIdHash, RequestLock)>,
+        send: ChanSend<(ChatIdHash, ChatClass, RequestLock)>,
         wait: RequestWaiter,
     },
     Pending {
@@ -642,11 +1242,16 @@ enum ThrottlingSendRefInner<R: Request> {
     Done,
 }
 
-impl<R: Request> Future for ThrottlingSendRef<R> {
+impl<R: Request> Future for ThrottlingSendRef<R>
+where
+    R::Err: 'static,
+{
     type Output = Result<Output<R>, R::Err>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.as_mut().project().0;
+        let proj = self.as_mut().project();
+        let meta = proj.meta;
+        let mut this = proj.inner;
 
         match this.as_mut().project() {
             SendRefProj::Registering {
@@ -685,6 +1290,8 @@ impl<R: Request> Future for ThrottlingSendRef<R> {
                 // always be `Err(_)` (because `Ok(Never)` is uninhibited)
                 // and that's what we want.
                 Poll::Ready(_) => {
+                    meta.unlocked();
+
                     if let SendRefRepl::Pending { request, wait: _ } =
                         this.as_mut().project_replace(ThrottlingSendRefInner::Done)
                     {
@@ -697,6 +1304,7 @@ impl<R: Request> Future for ThrottlingSendRef<R> {
             },
             SendRefProj::Sent { fut } => {
                 let res = futures::ready!(fut.poll(cx));
+                meta.completed(&res);
                 this.set(ThrottlingSendRefInner::Done);
                 Poll::Ready(res)
             }
@@ -782,3 +1390,128 @@ mod chan_send {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_retry_after() {
+        let err = RequestError::RetryAfter(5);
+        assert_eq!(retry_after::<RequestError>(&err), Some(5));
+
+        // Errors of other types are ignored
+        let err = "".parse::<i32>().unwrap_err();
+        assert_eq!(retry_after::<RequestError>(&err), None);
+    }
+
+    #[test]
+    fn stats() {
+        let limits = Limits::default();
+        let shared = Mutex::new(Shared::default());
+
+        let chat = ChatIdHash::Id(1);
+        let now = Instant::now();
+        let history = VecDeque::from(vec![(chat, now), (ChatIdHash::Id(2), now)]);
+        let mut requests_sent = RequestsSentToChats::default();
+        requests_sent.per_min.insert(chat, 1);
+        requests_sent.per_min.insert(ChatIdHash::Id(2), 1);
+        requests_sent.per_sec.insert(chat, 1);
+        requests_sent.classes.insert(chat, ChatClass::Group);
+        requests_sent
+            .classes
+            .insert(ChatIdHash::Id(2), ChatClass::Private);
+
+        update_stats(
+            &shared,
+            &limits,
+            &Penalties::default(),
+            3,
+            &history,
+            &requests_sent,
+        );
+
+        let stats = shared.lock().unwrap().stats.clone();
+        assert_eq!(stats.queued, 3);
+        assert_eq!(stats.sent_last_sec, 1);
+        assert_eq!(stats.sent_last_min, 2);
+        assert_eq!(
+            stats.chat(&ChatId::Id(1)),
+            Some(&ChatStats {
+                sent_last_sec: 1,
+                sent_last_min: 1,
+                available_sec: 0,
+                available_min: 19,
+            })
+        );
+        assert_eq!(stats.chats[&ChatIdHash::Id(2)].available_sec, 1);
+        // Private chats have no per-minute limit
+        assert_eq!(stats.chats[&ChatIdHash::Id(2)].available_min, u32::MAX - 1);
+
+        // Penalties lower the available budget or block the chat
+        let mut penalties = Penalties::default();
+        penalties.chats.insert(
+            chat,
+            Penalty {
+                factor: 0.5,
+                changed: now,
+                blocked_until: now,
+            },
+        );
+        penalties.chats.insert(
+            ChatIdHash::Id(2),
+            Penalty {
+                factor: 1.0,
+                changed: now,
+                blocked_until: now + MINUTE,
+            },
+        );
+        update_stats(&shared, &limits, &penalties, 3, &history, &requests_sent);
+
+        let stats = shared.lock().unwrap().stats.clone();
+        assert_eq!(stats.chats[&chat].available_min, 9);
+        assert_eq!(stats.chats[&ChatIdHash::Id(2)].available_sec, 0);
+        assert_eq!(stats.chats[&ChatIdHash::Id(2)].available_min, 0);
+    }
+
+    #[test]
+    fn guess_class() {
+        assert_eq!(ChatClass::guess(&ChatId::Id(123)), ChatClass::Private);
+        assert_eq!(ChatClass::guess(&ChatId::Id(-123)), ChatClass::Group);
+        assert_eq!(
+            ChatClass::guess(&ChatId::Id(-1001234567890)),
+            ChatClass::Group
+        );
+        assert_eq!(
+            ChatClass::guess(&ChatId::ChannelUsername("@channel".to_owned())),
+            ChatClass::Group
+        );
+    }
+
+    #[test]
+    fn penalties() {
+        let adaptive = Adaptive::default();
+        let mut penalties = Penalties::default();
+        let chat = ChatIdHash::Id(1);
+        let now = Instant::now();
+
+        penalties.update(&adaptive, now, vec![(chat, Duration::from_secs(5))]);
+        let penalty = penalties.chats[&chat];
+        assert!(penalty.is_blocked(now));
+        assert!(!penalty.is_blocked(now + Duration::from_secs(5)));
+        assert_eq!(penalty.apply(20), 10);
+        assert_eq!(penalties.overall.unwrap().apply(30), 15);
+
+        penalties.update(&adaptive, now, vec![(chat, Duration::from_secs(0))]);
+        assert_eq!(penalties.chats[&chat].apply(20), 5);
+        assert_eq!(penalties.chats[&chat].apply(1), 1);
+
+        let mut time = now;
+        for _ in 0..8 {
+            time += adaptive.cooldown;
+            penalties.update(&adaptive, time, Vec::new());
+        }
+        assert!(penalties.chats.is_empty());
+        assert!(penalties.overall.is_none());
+    }
+}
@@ -0,0 +1,604 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    errors::ValidationError,
+    payloads::{
+        AnswerCallbackQuery, CopyMessage, EditMessageCaption, EditMessageCaptionInline,
+        EditMessageReplyMarkup, EditMessageReplyMarkupInline, EditMessageText,
+        EditMessageTextInline, SendAnimation, SendAudio, SendDocument, SendMessage, SendPhoto,
+        SendVideo, SendVoice,
+    },
+    requests::{HasPayload, Request, Requester},
+    types::*,
+    utils::text::{visible_len, MAX_CAPTION_LEN, MAX_MESSAGE_TEXT_LEN},
+};
+
+/// The maximum length of the text of a callback query answer.
+pub const MAX_CALLBACK_ANSWER_LENGTH: usize = 200;
+
+/// The maximum size of `callback_data` of a button, in bytes.
+pub const MAX_CALLBACK_DATA_SIZE: usize = 64;
+
+/// The maximum number of buttons in an inline keyboard.
+///
+/// This limit is not documented, but Telegram rejects bigger keyboards.
+pub const MAX_INLINE_KEYBOARD_BUTTONS: usize = 100;
+
+/// Request validation adaptor, see [`RequesterExt::validate`].
+///
+/// This adaptor checks constraints which can be checked locally before
+/// sending a request and returns a [`ValidationError`] (converted into the
+/// error of the inner bot, e.g. [`RequestError::Validation`]) instead of an
+/// opaque "Bad Request" from Telegram. The following is checked:
+///
+/// - texts of messages are not empty and not longer than
+///   [`MAX_MESSAGE_TEXT_LEN`];
+/// - captions are not longer than [`MAX_CAPTION_LEN`];
+/// - texts of callback query answers are not longer than
+///   [`MAX_CALLBACK_ANSWER_LENGTH`];
+/// - `parse_mode` is not used together with entities and entities fit into
+///   the text;
+/// - inline keyboards have at most [`MAX_INLINE_KEYBOARD_BUTTONS`] buttons
+///   and their `callback_data` is 1-[`MAX_CALLBACK_DATA_SIZE`] bytes long.
+///
+/// Lengths of texts with a `parse_mode` are checked after parsing of the
+/// markup, see [`visible_len`].
+///
+/// Payloads can also be checked without the adaptor, see [`Validate`].
+///
+/// ## Examples
+///
+/// ```
+/// use futures::FutureExt;
+/// use teloxide_core::{
+///     requests::{Request, Requester, RequesterExt},
+///     Bot, RequestError, ValidationError,
+/// };
+///
+/// let bot = Bot::new("TOKEN").validate();
+///
+/// // Fails without sending the request
+/// let res = bot.send_message(0, "").send().now_or_never().unwrap();
+/// assert!(matches!(res, Err(RequestError::Validation(ValidationError::EmptyText))));
+/// ```
+///
+/// [`RequesterExt::validate`]: crate::requests::RequesterExt::validate
+/// [`RequestError::Validation`]: crate::RequestError::Validation
+/// [`MAX_MESSAGE_TEXT_LEN`]: crate::utils::text::MAX_MESSAGE_TEXT_LEN
+/// [`MAX_CAPTION_LEN`]: crate::utils::text::MAX_CAPTION_LEN
+/// [`visible_len`]: crate::utils::text::visible_len
+#[derive(Clone, Debug)]
+pub struct ValidateRequests<B> {
+    bot: B,
+}
+
+impl<B> ValidateRequests<B> {
+    /// Creates new `ValidateRequests`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::validate`] instead.
+    ///
+    /// [`RequesterExt::validate`]: crate::requests::RequesterExt::validate
+    pub fn new(bot: B) -> Self {
+        Self { bot }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+}
+
+/// Payloads which can be checked locally, see [`ValidateRequests`].
+pub trait Validate {
+    /// Returns an error if Telegram would certainly reject the payload.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// Checks a text which can be formatted with either `parse_mode` or
+/// `entities`, returning its length after parsing.
+fn check_formatted(
+    text: &str,
+    parse_mode: Option<ParseMode>,
+    entities: Option<&[MessageEntity]>,
+    limit: usize,
+    too_long: fn(usize, usize) -> ValidationError,
+) -> Result<usize, ValidationError> {
+    let entities = entities.unwrap_or(&[]);
+    if parse_mode.is_some() && !entities.is_empty() {
+        return Err(ValidationError::ParseModeWithEntities);
+    }
+
+    let length = visible_len(text, parse_mode);
+    if length > limit {
+        return Err(too_long(length, limit));
+    }
+
+    for entity in entities {
+        // An overflowing end is out of bounds too
+        match entity.offset.checked_add(entity.length) {
+            Some(end) if end <= length => {}
+            _ => {
+                return Err(ValidationError::EntityOutOfBounds {
+                    offset: entity.offset,
+                    length: entity.length,
+                })
+            }
+        }
+    }
+
+    Ok(length)
+}
+
+fn check_text(
+    text: &str,
+    parse_mode: Option<ParseMode>,
+    entities: &Option<Vec<MessageEntity>>,
+) -> Result<(), ValidationError> {
+    let length = check_formatted(
+        text,
+        parse_mode,
+        entities.as_deref(),
+        MAX_MESSAGE_TEXT_LEN,
+        |length, limit| ValidationError::TextTooLong { length, limit },
+    )?;
+
+    // Markup alone (e.g. `<b></b>`) is empty after parsing too
+    if length == 0 {
+        return Err(ValidationError::EmptyText);
+    }
+
+    Ok(())
+}
+
+fn check_caption(
+    caption: &Option<String>,
+    parse_mode: Option<ParseMode>,
+    entities: &Option<Vec<MessageEntity>>,
+) -> Result<(), ValidationError> {
+    check_formatted(
+        caption.as_deref().unwrap_or(""),
+        parse_mode,
+        entities.as_deref(),
+        MAX_CAPTION_LEN,
+        |length, limit| ValidationError::CaptionTooLong { length, limit },
+    )
+    .map(drop)
+}
+
+fn check_inline_keyboard(markup: &InlineKeyboardMarkup) -> Result<(), ValidationError> {
+    let count = markup.inline_keyboard.iter().map(Vec::len).sum();
+    if count > MAX_INLINE_KEYBOARD_BUTTONS {
+        return Err(ValidationError::TooManyButtons {
+            count,
+            limit: MAX_INLINE_KEYBOARD_BUTTONS,
+        });
+    }
+
+    for button in markup.inline_keyboard.iter().flatten() {
+        if let InlineKeyboardButtonKind::CallbackData(data) = &button.kind {
+            if data.is_empty() || data.len() > MAX_CALLBACK_DATA_SIZE {
+                return Err(ValidationError::InvalidCallbackData(data.len()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_reply_markup(markup: &Option<ReplyMarkup>) -> Result<(), ValidationError> {
+    match markup {
+        Some(ReplyMarkup::InlineKeyboard(markup)) => check_inline_keyboard(markup),
+        _ => Ok(()),
+    }
+}
+
+impl Validate for SendMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_text(&self.text, self.parse_mode, &self.entities)?;
+        check_reply_markup(&self.reply_markup)
+    }
+}
+
+macro_rules! validate_caption {
+    ($($Payload:ident),*) => {
+        $(
+            impl Validate for $Payload {
+                fn validate(&self) -> Result<(), ValidationError> {
+                    check_caption(&self.caption, self.parse_mode, &self.caption_entities)?;
+                    check_reply_markup(&self.reply_markup)
+                }
+            }
+        )*
+    };
+}
+
+validate_caption!(
+    SendPhoto,
+    SendAudio,
+    SendDocument,
+    SendVideo,
+    SendAnimation,
+    SendVoice,
+    CopyMessage
+);
+
+impl Validate for EditMessageText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_text(&self.text, self.parse_mode, &self.entities)?;
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for EditMessageTextInline {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_text(&self.text, self.parse_mode, &self.entities)?;
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for EditMessageCaption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_caption(&self.caption, self.parse_mode, &self.caption_entities)?;
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for EditMessageCaptionInline {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_caption(&self.caption, self.parse_mode, &self.caption_entities)?;
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for EditMessageReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for EditMessageReplyMarkupInline {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup.iter().try_for_each(check_inline_keyboard)
+    }
+}
+
+impl Validate for AnswerCallbackQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self.text.as_deref().map(|text| visible_len(text, None)) {
+            Some(length) if length > MAX_CALLBACK_ANSWER_LENGTH => {
+                Err(ValidationError::TextTooLong {
+                    length,
+                    limit: MAX_CALLBACK_ANSWER_LENGTH,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        ValidateRequest {
+            inner: $this.inner().$m($($arg),*),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        ValidateRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for ValidateRequests<B>
+where
+    B: Requester,
+    B::Err: From<ValidationError>,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        send_message, send_photo, send_audio, send_document, send_video, send_animation,
+        send_voice, copy_message, edit_message_text, edit_message_text_inline,
+        edit_message_caption, edit_message_caption_inline, edit_message_reply_markup,
+        edit_message_reply_markup_inline, answer_callback_query => f, fty
+    }
+
+    requester_forward! {
+        get_me, log_out, close, get_updates, set_webhook, delete_webhook, get_webhook_info,
+        forward_message, send_video_note, send_media_group, send_location,
+        edit_message_live_location, edit_message_live_location_inline,
+        stop_message_live_location, stop_message_live_location_inline, send_venue, send_contact,
+        send_poll, send_dice, send_chat_action, get_user_profile_photos, get_file,
+        kick_chat_member, unban_chat_member, restrict_chat_member, ban_chat_sender_chat,
+        unban_chat_sender_chat, promote_chat_member, set_chat_administrator_custom_title,
+        set_chat_permissions, export_chat_invite_link, create_chat_invite_link,
+        edit_chat_invite_link, create_chat_subscription_invite_link,
+        edit_chat_subscription_invite_link, revoke_chat_invite_link, set_chat_photo,
+        delete_chat_photo, set_chat_title, approve_chat_join_request, decline_chat_join_request,
+        set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+        create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+        edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+        hide_general_forum_topic, unhide_general_forum_topic,
+        unpin_all_general_forum_topic_messages, leave_chat,
+        get_chat, get_chat_administrators, get_chat_members_count, get_chat_member,
+        set_chat_sticker_set, delete_chat_sticker_set, set_my_commands, get_my_commands,
+        answer_inline_query, edit_message_media, edit_message_media_inline, stop_poll,
+        delete_message, send_sticker, set_message_reaction, get_sticker_set,
+        upload_sticker_file, create_new_sticker_set, get_custom_emoji_stickers,
+        add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
+        set_sticker_set_thumb, send_invoice, answer_shipping_query, answer_pre_checkout_query,
+        set_passport_data_errors, send_game, set_game_score, set_game_score_inline,
+        get_game_high_scores, get_updates_fault_tolerant => fid, ftyid
+    }
+}
+
+download_forward! {
+    'w
+    B
+    ValidateRequests<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`ValidateRequests`].
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone)]
+pub struct ValidateRequest<R> {
+    inner: R,
+}
+
+impl<R> HasPayload for ValidateRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+#[cfg(feature = "to_curl")]
+impl<R> crate::requests::ToCurl for ValidateRequest<R>
+where
+    R: crate::requests::ToCurl,
+{
+    fn to_curl(&self) -> String {
+        self.inner.to_curl()
+    }
+
+    fn to_http(&self) -> String {
+        self.inner.to_http()
+    }
+}
+
+impl<R> Request for ValidateRequest<R>
+where
+    R: Request,
+    R::Payload: Validate,
+    R::Err: From<ValidationError>,
+{
+    type Err = R::Err;
+    type Send = ValidateSend<R::Send, R::Err>;
+    type SendRef = ValidateSend<R::SendRef, R::Err>;
+
+    fn send(self) -> Self::Send {
+        match self.inner.payload_ref().validate() {
+            Ok(()) => ValidateSend::Valid(self.inner.send()),
+            Err(err) => ValidateSend::Invalid(Some(err.into())),
+        }
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        match self.inner.payload_ref().validate() {
+            Ok(()) => ValidateSend::Valid(self.inner.send_ref()),
+            Err(err) => ValidateSend::Invalid(Some(err.into())),
+        }
+    }
+}
+
+#[pin_project::pin_project(project = ValidateSendProj)]
+pub enum ValidateSend<F, E> {
+    Valid(#[pin] F),
+    Invalid(Option<E>),
+}
+
+impl<F, T, E> Future for ValidateSend<F, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            ValidateSendProj::Valid(fut) => fut.poll(cx),
+            ValidateSendProj::Invalid(err) => Poll::Ready(Err(err
+                .take()
+                .expect("`ValidateSend` polled after completion"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::{payloads::setters::*, requests::RequesterExt, Bot, RequestError};
+
+    #[test]
+    fn text() {
+        let bot = Bot::new("TOKEN");
+        let bold = MessageEntity::new(MessageEntityKind::Bold, 0, 2);
+
+        assert_eq!(bot.send_message(0, "hi").payload_ref().validate(), Ok(()));
+        assert_eq!(
+            bot.send_message(0, "").payload_ref().validate(),
+            Err(ValidationError::EmptyText)
+        );
+        assert_eq!(
+            bot.send_message(0, "🦀".repeat(2049))
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::TextTooLong {
+                length: 4098,
+                limit: MAX_MESSAGE_TEXT_LEN
+            })
+        );
+        assert_eq!(
+            bot.send_message(0, "🦀")
+                .entities(vec![bold.clone()])
+                .payload_ref()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            bot.send_message(0, "h")
+                .entities(vec![bold.clone()])
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::EntityOutOfBounds {
+                offset: 0,
+                length: 2
+            })
+        );
+        assert_eq!(
+            bot.send_message(0, "hi")
+                .parse_mode(ParseMode::Html)
+                .entities(vec![bold])
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::ParseModeWithEntities)
+        );
+        assert_eq!(
+            bot.send_message(0, "hi")
+                .entities(vec![MessageEntity::new(
+                    MessageEntityKind::Bold,
+                    1,
+                    usize::MAX
+                )])
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::EntityOutOfBounds {
+                offset: 1,
+                length: usize::MAX
+            })
+        );
+
+        // Lengths of formatted texts are checked after parsing
+        assert_eq!(
+            bot.send_message(0, "<b>a</b>".repeat(4096))
+                .parse_mode(ParseMode::Html)
+                .payload_ref()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            bot.send_message(0, "a\\.".repeat(2049))
+                .parse_mode(ParseMode::MarkdownV2)
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::TextTooLong {
+                length: 4098,
+                limit: MAX_MESSAGE_TEXT_LEN
+            })
+        );
+        assert_eq!(
+            bot.send_message(0, "<b></b>")
+                .parse_mode(ParseMode::Html)
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::EmptyText)
+        );
+    }
+
+    #[test]
+    fn caption_and_markup() {
+        let bot = Bot::new("TOKEN");
+        let photo = || bot.send_photo(0, InputFile::file_id("id"));
+
+        assert_eq!(photo().payload_ref().validate(), Ok(()));
+        assert_eq!(
+            photo().caption("a".repeat(1025)).payload_ref().validate(),
+            Err(ValidationError::CaptionTooLong {
+                length: 1025,
+                limit: MAX_CAPTION_LEN
+            })
+        );
+
+        let button = |data: &str| InlineKeyboardButton::callback("b".to_owned(), data.to_owned());
+        let keyboard =
+            |rows: usize, data: &str| InlineKeyboardMarkup::new(vec![vec![button(data); 10]; rows]);
+
+        assert_eq!(
+            photo()
+                .reply_markup(keyboard(10, "x"))
+                .payload_ref()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            photo()
+                .reply_markup(keyboard(11, "x"))
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::TooManyButtons {
+                count: 110,
+                limit: MAX_INLINE_KEYBOARD_BUTTONS
+            })
+        );
+        assert_eq!(
+            bot.edit_message_reply_markup(0, 1)
+                .reply_markup(keyboard(1, &"x".repeat(65)))
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::InvalidCallbackData(65))
+        );
+        assert_eq!(
+            bot.answer_callback_query("id")
+                .text("a".repeat(201))
+                .payload_ref()
+                .validate(),
+            Err(ValidationError::TextTooLong {
+                length: 201,
+                limit: MAX_CALLBACK_ANSWER_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn adaptor() {
+        let bot = Bot::new("TOKEN").validate();
+
+        let res = bot.send_message(0, "").send_ref().now_or_never().unwrap();
+        assert!(matches!(
+            res,
+            Err(RequestError::Validation(ValidationError::EmptyText))
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+use std::fmt;
+
+/// The newest version of the [Telegram Bot API] which is fully implemented by
+/// this library.
+///
+/// Parts of newer versions are implemented as well, see [`BotApiFeature`].
+///
+/// [Telegram Bot API]: https://core.telegram.org/bots/api
+pub const API_VERSION: &str = "5.2";
+
+/// Returns the version of the Telegram Bot API fully implemented by this
+/// library, same as [`API_VERSION`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{telegram_api_version, ApiVersion, BotApiFeature};
+///
+/// assert!(telegram_api_version() >= ApiVersion::new(5, 0));
+///
+/// // E.g. the version of a self-hosted Bot API server
+/// let server = ApiVersion::new(5, 4);
+/// if server.supports(BotApiFeature::ChatJoinRequests) {
+///     // use `approve_chat_join_request`
+/// }
+/// ```
+pub const fn telegram_api_version() -> ApiVersion {
+    // Parsed from `API_VERSION`, so that the version is only written once
+    let bytes = API_VERSION.as_bytes();
+    let mut major = 0;
+    let mut minor = 0;
+    let mut in_minor = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'.' => in_minor = true,
+            digit if in_minor => minor = minor * 10 + (digit - b'0'),
+            digit => major = major * 10 + (digit - b'0'),
+        }
+        i += 1;
+    }
+
+    ApiVersion::new(major, minor)
+}
+
+/// Version of the Telegram Bot API, e.g. `7.9`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ApiVersion {
+    /// Major version, e.g. `7` in `7.9`.
+    pub major: u8,
+
+    /// Minor version, e.g. `9` in `7.9`.
+    pub minor: u8,
+}
+
+impl ApiVersion {
+    /// Creates a version from its components.
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Returns `true` if the `feature` is available in this version and is
+    /// [implemented] by this library.
+    ///
+    /// [implemented]: BotApiFeature::is_implemented
+    pub fn supports(self, feature: BotApiFeature) -> bool {
+        feature.is_implemented() && self >= feature.supported_since()
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A feature of the Telegram Bot API, see [`ApiVersion::supports`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BotApiFeature {
+    /// Chat invite links created by bots, `createChatInviteLink` and friends.
+    InviteLinks,
+
+    /// Chat join requests, `approveChatJoinRequest` and
+    /// `declineChatJoinRequest`.
+    ChatJoinRequests,
+
+    /// Banning of channels in chats, `banChatSenderChat` and
+    /// `unbanChatSenderChat`.
+    SenderChatBans,
+
+    /// Video chats (previously called voice chats).
+    VideoChats,
+
+    /// Custom emoji, `getCustomEmojiStickers`.
+    CustomEmoji,
+
+    /// Forum topics and messages sent to them, `createForumTopic` and
+    /// friends.
+    ForumTopics,
+
+    /// Management of the General topic of forums, `editGeneralForumTopic` and
+    /// friends.
+    GeneralForumTopic,
+
+    /// Stories in messages.
+    Stories,
+
+    /// Message reactions, `setMessageReaction` and reaction updates.
+    Reactions,
+
+    /// Accent colors of chats.
+    AccentColors,
+
+    /// Telegram Business, e.g. business intro and opening hours of chats.
+    Business,
+
+    /// [`ChatFullInfo`] returned by `getChat` and birthdates of users.
+    ///
+    /// [`ChatFullInfo`]: crate::types::ChatFullInfo
+    ChatFullInfo,
+
+    /// Subscription invite links, `createChatSubscriptionInviteLink` and
+    /// `editChatSubscriptionInviteLink`.
+    SubscriptionInviteLinks,
+}
+
+impl BotApiFeature {
+    /// The version of the Bot API which introduced the feature.
+    pub fn supported_since(self) -> ApiVersion {
+        use BotApiFeature::*;
+
+        let (major, minor) = match self {
+            InviteLinks => (5, 1),
+            ChatJoinRequests => (5, 4),
+            SenderChatBans => (5, 5),
+            VideoChats => (6, 0),
+            CustomEmoji => (6, 2),
+            ForumTopics => (6, 3),
+            GeneralForumTopic => (6, 4),
+            Stories => (6, 8),
+            Reactions | AccentColors => (7, 0),
+            Business => (7, 2),
+            ChatFullInfo => (7, 3),
+            SubscriptionInviteLinks => (7, 9),
+        };
+
+        ApiVersion::new(major, minor)
+    }
+
+    /// Returns `true` if all methods, parameters and types of the feature are
+    /// implemented by this library.
+    ///
+    /// Features up to [`API_VERSION`] are always implemented, some of the newer
+    /// ones are implemented as well.
+    pub fn is_implemented(self) -> bool {
+        use BotApiFeature::*;
+
+        match self {
+            InviteLinks
+            | ChatJoinRequests
+            | SenderChatBans
+            | CustomEmoji
+            | GeneralForumTopic
+            | Stories
+            | Reactions
+            | AccentColors
+            | ChatFullInfo
+            | SubscriptionInviteLinks => true,
+            // `can_manage_video_chats` of `promoteChatMember`, forum topic methods
+            // (e.g. `closeForumTopic`) and sending messages on behalf of business
+            // accounts are missing
+            VideoChats | ForumTopics | Business => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version() {
+        assert_eq!(telegram_api_version().to_string(), API_VERSION);
+
+        let version = ApiVersion::new(6, 3);
+        assert!(version.supports(BotApiFeature::CustomEmoji));
+        assert!(!version.supports(BotApiFeature::GeneralForumTopic));
+        assert!(ApiVersion::new(10, 0).supports(BotApiFeature::GeneralForumTopic));
+    }
+
+    #[test]
+    fn not_implemented() {
+        assert!(!ApiVersion::new(10, 0).supports(BotApiFeature::Business));
+        assert!(!ApiVersion::new(10, 0).supports(BotApiFeature::ForumTopics));
+    }
+}
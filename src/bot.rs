@@ -1,18 +1,24 @@
-use std::{future::Future, sync::Arc};
+use std::{future::Future, iter, sync::Arc};
 
 use reqwest::Client;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    bot::api_url::ApiUrl,
+    bot::{api_url::ApiUrl, token::Token},
+    errors::InvalidToken,
     net,
-    requests::{MultipartPayload, Payload, ResponseResult},
-    serde_multipart,
+    requests::{MultipartPayload, Payload, ResponseResult, ResponseWithMeta},
+    serde_multipart::{self, FormTemplate},
+    RequestError,
 };
 
 mod api;
 mod api_url;
 mod download;
+mod pool;
+mod token;
+
+pub use pool::BotPool;
 
 const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
 
@@ -55,9 +61,13 @@ const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
 /// [Telegram Bot API]: https://core.telegram.org/bots/api
 #[derive(Debug, Clone)]
 pub struct Bot {
-    token: Arc<str>,
+    token: Token,
     api_url: ApiUrl,
     client: Client,
+    traffic_hook: Option<net::TrafficHook>,
+    upload_retries: u32,
+    upload_buffer_size: Option<u64>,
+    gzip_threshold: Option<usize>,
 }
 
 /// Constructors
@@ -79,6 +89,88 @@ impl Bot {
         Self::with_client(token, client)
     }
 
+    /// Creates a new `Bot` with the specified token and the default
+    /// [http-client](reqwest::Client), checking that the token is well-formed.
+    ///
+    /// Unlike [`Bot::new`], which accepts any string and so bad tokens are only
+    /// discovered on the first request, this returns an error if the token
+    /// doesn't look like `<bot id>:<secret>` (see [`Bot::validate_token`]).
+    /// Note that a well-formed token can still be rejected by Telegram.
+    ///
+    /// # Panics
+    ///
+    /// If it cannot create [`reqwest::Client`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{Bot, InvalidToken};
+    ///
+    /// let bot = Bot::from_token("123456:AAHxVZrqJp2r_eXivF8kN0xyoHc0bp-Y_nc").unwrap();
+    /// assert_eq!(bot.id(), Some(123456));
+    ///
+    /// assert_eq!(Bot::from_token("TOKEN").unwrap_err(), InvalidToken::NoSeparator);
+    /// ```
+    pub fn from_token<S>(token: S) -> Result<Self, InvalidToken>
+    where
+        S: Into<String>,
+    {
+        let token = token.into();
+        Self::validate_token(&token)?;
+        Ok(Self::new(token))
+    }
+
+    /// Checks that `token` is a well-formed bot token, i.e. looks like
+    /// `<bot id>:<secret>`, and returns the bot id.
+    ///
+    /// This function is `const`, so it can be used to check tokens known at
+    /// compile time.
+    pub const fn validate_token(token: &str) -> Result<i64, InvalidToken> {
+        let bytes = token.as_bytes();
+
+        let mut sep = 0;
+        while sep < bytes.len() && bytes[sep] != b':' {
+            sep += 1;
+        }
+        if sep == bytes.len() {
+            return Err(InvalidToken::NoSeparator);
+        }
+        if sep == 0 {
+            return Err(InvalidToken::InvalidId);
+        }
+
+        let mut id: i64 = 0;
+        let mut i = 0;
+        while i < sep {
+            let b = bytes[i];
+            if !b.is_ascii_digit() {
+                return Err(InvalidToken::InvalidId);
+            }
+            id = match id.checked_mul(10) {
+                Some(id) => match id.checked_add((b - b'0') as i64) {
+                    Some(id) => id,
+                    None => return Err(InvalidToken::InvalidId),
+                },
+                None => return Err(InvalidToken::InvalidId),
+            };
+            i += 1;
+        }
+
+        i = sep + 1;
+        if i == bytes.len() {
+            return Err(InvalidToken::InvalidSecret);
+        }
+        while i < bytes.len() {
+            let b = bytes[i];
+            if !(b.is_ascii_alphanumeric() || b == b'_' || b == b'-') {
+                return Err(InvalidToken::InvalidSecret);
+            }
+            i += 1;
+        }
+
+        Ok(id)
+    }
+
     /// Creates a new `Bot` with the specified token and your
     /// [`reqwest::Client`].
     ///
@@ -94,12 +186,41 @@ impl Bot {
         S: Into<String>,
     {
         Self {
-            token: Into::<Arc<str>>::into(Into::<String>::into(token)),
+            token: Token::new(token.into()),
             api_url: ApiUrl::Default,
             client,
+            traffic_hook: None,
+            upload_retries: 0,
+            upload_buffer_size: None,
+            gzip_threshold: None,
         }
     }
 
+    /// Creates a new `Bot` with the specified token and the default
+    /// [http-client](reqwest::Client) settings, which resolves the Telegram
+    /// API server using the `dns` resolver.
+    ///
+    /// See [`PinnedDns`] for more.
+    ///
+    /// # Panics
+    ///
+    /// If it cannot create [`reqwest::Client`].
+    ///
+    /// [`PinnedDns`]: crate::net::PinnedDns
+    #[cfg(feature = "pinned_dns")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "pinned_dns")))]
+    pub fn with_pinned_dns<S>(token: S, dns: net::PinnedDns) -> Self
+    where
+        S: Into<String>,
+    {
+        let client = net::default_reqwest_settings()
+            .dns_resolver(Arc::new(dns))
+            .build()
+            .expect("Client creation failed");
+
+        Self::with_client(token, client)
+    }
+
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` & `TELOXIDE_PROXY`
     /// environmental variables (a bot's token & a proxy) and the default
     /// [`reqwest::Client`].
@@ -131,7 +252,7 @@ impl Bot {
     /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
     /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
     pub fn from_env_with_client(client: Client) -> Self {
-        Self::with_client(&get_env(TELOXIDE_TOKEN), client)
+        Self::with_client(get_env(TELOXIDE_TOKEN), client)
     }
 
     /// Sets a custom API URL.
@@ -174,7 +295,151 @@ impl Bot {
     /// assert_ne!(bot2.api_url().as_str(), "https://example.com/");
     /// ```
     pub fn set_api_url(mut self, url: reqwest::Url) -> Self {
-        self.api_url = ApiUrl::Custom(Arc::new(url));
+        self.api_url = ApiUrl::custom(url);
+        self
+    }
+
+    /// Sets fallback API URLs, which are used when the [primary one] is
+    /// unreachable.
+    ///
+    /// When a connection to the primary URL can't be established, the request
+    /// is sent to the first fallback, then to the second one and so on. Other
+    /// errors (including timeouts, when the request may have been received by
+    /// the server) are returned as is, so a request is never sent twice.
+    ///
+    /// This is useful for high-availability setups with a self-hosted [bot API
+    /// server][tbas], e.g. a local server with the cloud API as the fallback.
+    /// Note however that files are always downloaded from the primary
+    /// server, since [`File::file_path`] is server-specific.
+    ///
+    /// Previously set fallbacks are replaced.
+    ///
+    /// [primary one]: Bot::set_api_url
+    /// [tbas]: https://github.com/tdlib/telegram-bot-api
+    /// [`File::file_path`]: crate::types::File::file_path
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::Bot;
+    ///
+    /// let local = reqwest::Url::parse("http://localhost:8081/").unwrap();
+    /// let cloud = reqwest::Url::parse("https://api.telegram.org/").unwrap();
+    ///
+    /// let bot = Bot::new("TOKEN")
+    ///     .set_api_url(local.clone())
+    ///     .set_fallback_api_urls(vec![cloud.clone()]);
+    ///
+    /// assert_eq!(bot.api_url(), local);
+    /// assert_eq!(bot.api_urls(), [local, cloud]);
+    /// ```
+    pub fn set_fallback_api_urls<I>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator<Item = reqwest::Url>,
+    {
+        let urls: Vec<_> = std::iter::once(self.api_url.get()).chain(urls).collect();
+        self.api_url = ApiUrl::Custom(Arc::from(urls));
+        self
+    }
+
+    /// Sets the number of times requests which upload files are retried if
+    /// they fail because of a network error, e.g. when the connection is lost
+    /// in the middle of an upload. By default such requests are not retried.
+    ///
+    /// The first retry is delayed by a second, the delay doubles with every
+    /// further retry up to 30 seconds.
+    ///
+    /// Files are reopened for every attempt ([`InputFile::File`] is opened
+    /// again, [`InputFile::Reopenable`] is reopened and the data of
+    /// [`InputFile::Memory`] is reused), so a partially sent file doesn't
+    /// break the retry. Small files can be read into memory before the first
+    /// attempt instead, see [`set_upload_buffer_size`].
+    ///
+    /// Note that Telegram may have already processed the request when the
+    /// error occurred (e.g. if the connection was lost while receiving the
+    /// response), in which case retrying it may e.g. send a message twice.
+    ///
+    /// [`InputFile::File`]: crate::types::InputFile::File
+    /// [`InputFile::Reopenable`]: crate::types::InputFile::Reopenable
+    /// [`InputFile::Memory`]: crate::types::InputFile::Memory
+    /// [`set_upload_buffer_size`]: Bot::set_upload_buffer_size
+    pub fn set_upload_retries(mut self, retries: u32) -> Self {
+        self.upload_retries = retries;
+        self
+    }
+
+    /// Sets the maximum size of files on disk (in bytes) which are read into
+    /// memory before uploading them with a request which may be sent more
+    /// than once (i.e. if [upload retries] or [fallback API URLs] are set).
+    ///
+    /// Every attempt then uploads the same data, even if the file is changed
+    /// or removed meanwhile. Bigger files are reopened for every attempt. By
+    /// default all files are reopened.
+    ///
+    /// [upload retries]: Bot::set_upload_retries
+    /// [fallback API URLs]: Bot::set_fallback_api_urls
+    pub fn set_upload_buffer_size(mut self, max_size: u64) -> Self {
+        self.upload_buffer_size = Some(max_size);
+        self
+    }
+
+    /// Compresses bodies of JSON requests which are at least `min_size` bytes
+    /// long with gzip (and sends them with `Content-Encoding: gzip`), useful
+    /// for big requests, e.g. [`AnswerInlineQuery`] with lots of results.
+    ///
+    /// **Note**: the official Telegram Bot API server doesn't accept
+    /// compressed requests, use this only with [self-hosted servers] (or
+    /// proxies in front of them) which do. Requests to [`TELEGRAM_API_URL`]
+    /// and to [fallback API URLs] are never compressed.
+    ///
+    /// [`AnswerInlineQuery`]: crate::payloads::AnswerInlineQuery
+    /// [`TELEGRAM_API_URL`]: crate::net::TELEGRAM_API_URL
+    /// [fallback API URLs]: Bot::set_fallback_api_urls
+    /// [self-hosted servers]: https://github.com/tdlib/telegram-bot-api
+    pub fn set_request_compression(mut self, min_size: usize) -> Self {
+        self.gzip_threshold = Some(min_size);
+        self
+    }
+}
+
+/// Hooks
+impl Bot {
+    /// Sets a callback which is called with sizes of every request and its
+    /// response, e.g. to track bandwidth usage.
+    ///
+    /// The callback is called once a response is received, requests which
+    /// failed without a response (e.g. because of a network error) are not
+    /// reported. Downloads of files (see [`Download`]) are reported as
+    /// `DownloadFile` once the whole file is received. Previously set
+    /// callback is replaced.
+    ///
+    /// [`Download`]: crate::net::Download
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicU64, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use teloxide_core::{net::TrafficRecord, Bot};
+    ///
+    /// let sent = Arc::new(AtomicU64::new(0));
+    /// let bot = Bot::new("TOKEN").on_traffic({
+    ///     let sent = Arc::clone(&sent);
+    ///     move |record: &TrafficRecord| {
+    ///         let size = record.request_size.unwrap_or(0);
+    ///         sent.fetch_add(size, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// # let _ = bot;
+    /// ```
+    pub fn on_traffic<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&net::TrafficRecord) + Send + Sync + 'static,
+    {
+        self.traffic_hook = Some(net::TrafficHook::new(hook));
         self
     }
 }
@@ -186,6 +451,13 @@ impl Bot {
         &self.token
     }
 
+    /// Returns id of the bot, extracted from the token.
+    ///
+    /// Returns `None` if the token is malformed (see [`Bot::validate_token`]).
+    pub fn id(&self) -> Option<i64> {
+        Self::validate_token(&self.token).ok()
+    }
+
     /// Returns currently used http-client.
     pub fn client(&self) -> &Client {
         &self.client
@@ -195,6 +467,12 @@ impl Bot {
     pub fn api_url(&self) -> reqwest::Url {
         self.api_url.get()
     }
+
+    /// Returns the primary API url followed by the fallback ones, see
+    /// [`Bot::set_fallback_api_urls`].
+    pub fn api_urls(&self) -> &[reqwest::Url] {
+        self.api_url.all()
+    }
 }
 
 impl Bot {
@@ -202,40 +480,117 @@ impl Bot {
         &self,
         payload: &P,
     ) -> impl Future<Output = ResponseResult<P::Output>> + 'static
+    where
+        P: Payload + Serialize,
+        P::Output: DeserializeOwned,
+    {
+        let fut = self.execute_json_with_meta(payload);
+        async move { fut.await.into_result() }
+    }
+
+    pub(crate) fn execute_json_with_meta<P>(
+        &self,
+        payload: &P,
+    ) -> impl Future<Output = ResponseWithMeta<P::Output>> + 'static
     where
         P: Payload + Serialize,
         P::Output: DeserializeOwned,
     {
         let client = self.client.clone();
-        let token = Arc::clone(&self.token);
+        let token = self.token.clone();
         let api_url = self.api_url.clone();
+        let hook = self.traffic_hook.clone();
 
         let params = serde_json::to_vec(payload)
             // this `expect` should be ok since we don't write request those may trigger error here
             .expect("serialization of request to be infallible");
+        net::deprecation::check_request(P::NAME, Some(&params));
+
+        let gzip_threshold = self.gzip_threshold;
 
-        // async move to capture client&token&api_url&params
-        async move { net::request_json(&client, token.as_ref(), api_url.get(), P::NAME, params).await }
+        // async move to capture client&token&api_url&hook&params
+        async move {
+            net::request_json_failover(
+                &client,
+                &token,
+                api_url.all(),
+                P::NAME,
+                params,
+                gzip_threshold,
+                hook.as_ref(),
+            )
+            .await
+        }
     }
 
     pub(crate) fn execute_multipart<P>(
         &self,
         payload: &P,
     ) -> impl Future<Output = ResponseResult<P::Output>>
+    where
+        P: MultipartPayload + Serialize,
+        P::Output: DeserializeOwned,
+    {
+        let fut = self.execute_multipart_with_meta(payload);
+        async move { fut.await.into_result() }
+    }
+
+    pub(crate) fn execute_multipart_with_meta<P>(
+        &self,
+        payload: &P,
+    ) -> impl Future<Output = ResponseWithMeta<P::Output>>
     where
         P: MultipartPayload + Serialize,
         P::Output: DeserializeOwned,
     {
         let client = self.client.clone();
-        let token = Arc::clone(&self.token);
+        let token = self.token.clone();
         let api_url = self.api_url.clone();
+        let hook = self.traffic_hook.clone();
+        let retries = self.upload_retries;
+        let buffer_size = self.upload_buffer_size;
+        net::deprecation::check_request(P::NAME, None);
 
-        let params = serde_multipart::to_form(payload);
+        // Forms can't be reused, so a new one is built from the template for
+        // every attempt
+        let template = serde_multipart::to_template(payload);
+        let attempts = api_url.all().len() + retries as usize;
 
-        // async move to capture client&token&api_url&params
+        // async move to capture client&token&api_url&hook&template
         async move {
-            let params = params.await?;
-            net::request_multipart(&client, token.as_ref(), api_url.get(), P::NAME, params).await
+            let mut template = match template {
+                Ok(template) => template,
+                Err(err) => return ResponseWithMeta::error(err.into()),
+            };
+            if let (Some(max_size), true) = (buffer_size, attempts > 1) {
+                if let Err(err) = template.buffer_files(max_size).await {
+                    return ResponseWithMeta::error(RequestError::Io(err));
+                }
+            }
+
+            // The template is only cloned if there may be another attempt
+            let mut template = Some(template);
+            let mut left = attempts;
+            let forms = iter::from_fn(move || {
+                left = left.checked_sub(1)?;
+                if left == 0 {
+                    template.take()
+                } else {
+                    template.clone()
+                }
+            })
+            .map(FormTemplate::into_form);
+
+            net::request_multipart_failover(
+                &client,
+                &token,
+                api_url.all(),
+                P::NAME,
+                forms,
+                retries,
+                hook.as_ref(),
+            )
+            .await
         }
     }
 }
@@ -243,3 +598,152 @@ impl Bot {
 fn get_env(env: &'static str) -> String {
     std::env::var(env).unwrap_or_else(|_| panic!("Cannot get the {} env variable", env))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::StreamExt;
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+    use crate::{
+        net::Download,
+        requests::{Request, Requester},
+        test_utils::{read_body, read_request, respond},
+        types::InputFile,
+    };
+
+    #[test]
+    fn on_traffic() {
+        let path = std::env::temp_dir().join(format!(
+            "teloxide-core-on-traffic-{}.jpg",
+            std::process::id()
+        ));
+        std::fs::write(&path, [0; 1000]).unwrap();
+
+        let rt = crate::test_utils::rt();
+        let records = rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let body = r#"{"ok":true,"result":true}"#;
+            let server = tokio::spawn(async move {
+                let mut sizes = Vec::new();
+                for _ in 0..4 {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    sizes.push(read_request(&mut conn).await as u64);
+                    respond(&mut conn, body).await;
+                }
+                sizes
+            });
+
+            let records = Arc::new(Mutex::new(Vec::new()));
+            let bot = Bot::new("TOKEN")
+                .set_api_url(api_url.parse().unwrap())
+                .on_traffic({
+                    let records = Arc::clone(&records);
+                    move |record: &net::TrafficRecord| records.lock().unwrap().push(record.clone())
+                });
+
+            bot.log_out().send().await.unwrap();
+            bot.set_chat_photo(0, InputFile::file(&path))
+                .send()
+                .await
+                .unwrap();
+
+            let mut file = Vec::new();
+            bot.download_file("a.jpg", &mut file).await.unwrap();
+            let chunks: Vec<_> = bot.download_file_stream("a.jpg").collect().await;
+            assert_eq!(file.len(), body.len());
+            assert_eq!(chunks.len(), 1);
+
+            let sizes = server.await.unwrap();
+            let records = records.lock().unwrap().clone();
+            assert_eq!(records[0].request_size, Some(sizes[0]));
+            assert_eq!(records[1].request_size, Some(sizes[1]));
+            assert!(sizes[1] > 1000);
+            assert_eq!(records[2].request_size, Some(sizes[2]));
+            records
+        });
+        std::fs::remove_file(&path).unwrap();
+
+        let methods: Vec<_> = records
+            .iter()
+            .map(|r| (r.method, r.response_size))
+            .collect();
+        assert_eq!(
+            methods,
+            [
+                ("LogOut", 25),
+                ("SetChatPhoto", 25),
+                ("DownloadFile", 25),
+                ("DownloadFile", 25)
+            ]
+        );
+    }
+
+    #[test]
+    fn upload_buffer_size() {
+        let path = std::env::temp_dir().join(format!(
+            "teloxide-core-upload-buffer-{}.jpg",
+            std::process::id()
+        ));
+        std::fs::write(&path, [7; 1000]).unwrap();
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            tokio::time::pause();
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn({
+                let path = path.clone();
+                async move {
+                    // Drop the first connection in the middle of the request and
+                    // remove the file before the retry
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    conn.read_exact(&mut [0; 16]).await.unwrap();
+                    std::fs::remove_file(&path).unwrap();
+                    drop(conn);
+
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    let body = read_body(&mut conn).await;
+                    respond(&mut conn, r#"{"ok":true,"result":true}"#).await;
+                    body
+                }
+            });
+
+            let bot = Bot::new("TOKEN")
+                .set_api_url(api_url.parse().unwrap())
+                .set_upload_retries(1)
+                .set_upload_buffer_size(1000);
+            bot.set_chat_photo(0, InputFile::file(&path))
+                .send()
+                .await
+                .unwrap();
+
+            let body = server.await.unwrap();
+            assert!(body.windows(1000).any(|w| w == [7; 1000]));
+        });
+    }
+
+    #[test]
+    fn validate_token() {
+        const ID: Result<i64, InvalidToken> = Bot::validate_token("42:abc-DEF_0");
+        assert_eq!(ID, Ok(42));
+
+        assert_eq!(Bot::validate_token("42"), Err(InvalidToken::NoSeparator));
+        assert_eq!(Bot::validate_token(":abc"), Err(InvalidToken::InvalidId));
+        assert_eq!(Bot::validate_token("4a:abc"), Err(InvalidToken::InvalidId));
+        assert_eq!(
+            Bot::validate_token("99999999999999999999:abc"),
+            Err(InvalidToken::InvalidId)
+        );
+        assert_eq!(Bot::validate_token("42:"), Err(InvalidToken::InvalidSecret));
+        assert_eq!(
+            Bot::validate_token("42:a b"),
+            Err(InvalidToken::InvalidSecret)
+        );
+    }
+}
@@ -3,8 +3,8 @@ use crate::{
     prelude::Requester,
     requests::{JsonRequest, MultipartRequest},
     types::{
-        BotCommand, ChatId, ChatPermissions, InlineQueryResult, InputFile, InputMedia,
-        InputSticker, LabeledPrice,
+        BotCommand, ChatId, ChatPermissions, Currency, InlineQueryResult, InputFile, InputMedia,
+        InputSticker, LabeledPrice, ThreadId,
     },
     Bot,
 };
@@ -350,6 +350,34 @@ impl Requester for Bot {
         )
     }
 
+    type BanChatSenderChat = JsonRequest<payloads::BanChatSenderChat>;
+
+    fn ban_chat_sender_chat<C>(&self, chat_id: C, sender_chat_id: i64) -> Self::BanChatSenderChat
+    where
+        C: Into<ChatId>,
+    {
+        Self::BanChatSenderChat::new(
+            self.clone(),
+            payloads::BanChatSenderChat::new(chat_id, sender_chat_id),
+        )
+    }
+
+    type UnbanChatSenderChat = JsonRequest<payloads::UnbanChatSenderChat>;
+
+    fn unban_chat_sender_chat<C>(
+        &self,
+        chat_id: C,
+        sender_chat_id: i64,
+    ) -> Self::UnbanChatSenderChat
+    where
+        C: Into<ChatId>,
+    {
+        Self::UnbanChatSenderChat::new(
+            self.clone(),
+            payloads::UnbanChatSenderChat::new(chat_id, sender_chat_id),
+        )
+    }
+
     type RestrictChatMember = JsonRequest<payloads::RestrictChatMember>;
 
     fn restrict_chat_member<C>(
@@ -444,6 +472,44 @@ impl Requester for Bot {
         )
     }
 
+    type CreateChatSubscriptionInviteLink = JsonRequest<payloads::CreateChatSubscriptionInviteLink>;
+
+    fn create_chat_subscription_invite_link<C>(
+        &self,
+        chat_id: C,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> Self::CreateChatSubscriptionInviteLink
+    where
+        C: Into<ChatId>,
+    {
+        Self::CreateChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::CreateChatSubscriptionInviteLink::new(
+                chat_id,
+                subscription_period,
+                subscription_price,
+            ),
+        )
+    }
+
+    type EditChatSubscriptionInviteLink = JsonRequest<payloads::EditChatSubscriptionInviteLink>;
+
+    fn edit_chat_subscription_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::EditChatSubscriptionInviteLink
+    where
+        C: Into<ChatId>,
+        I: Into<String>,
+    {
+        Self::EditChatSubscriptionInviteLink::new(
+            self.clone(),
+            payloads::EditChatSubscriptionInviteLink::new(chat_id, invite_link),
+        )
+    }
+
     type RevokeChatInviteLink = JsonRequest<payloads::RevokeChatInviteLink>;
 
     fn revoke_chat_invite_link<C, I>(
@@ -461,6 +527,30 @@ impl Requester for Bot {
         )
     }
 
+    type ApproveChatJoinRequest = JsonRequest<payloads::ApproveChatJoinRequest>;
+
+    fn approve_chat_join_request<C>(&self, chat_id: C, user_id: i64) -> Self::ApproveChatJoinRequest
+    where
+        C: Into<ChatId>,
+    {
+        Self::ApproveChatJoinRequest::new(
+            self.clone(),
+            payloads::ApproveChatJoinRequest::new(chat_id, user_id),
+        )
+    }
+
+    type DeclineChatJoinRequest = JsonRequest<payloads::DeclineChatJoinRequest>;
+
+    fn decline_chat_join_request<C>(&self, chat_id: C, user_id: i64) -> Self::DeclineChatJoinRequest
+    where
+        C: Into<ChatId>,
+    {
+        Self::DeclineChatJoinRequest::new(
+            self.clone(),
+            payloads::DeclineChatJoinRequest::new(chat_id, user_id),
+        )
+    }
+
     type SetChatPhoto = MultipartRequest<payloads::SetChatPhoto>;
 
     fn set_chat_photo<C>(&self, chat_id: C, photo: InputFile) -> Self::SetChatPhoto
@@ -768,6 +858,18 @@ impl Requester for Bot {
         )
     }
 
+    type SetMessageReaction = JsonRequest<payloads::SetMessageReaction>;
+
+    fn set_message_reaction<C>(&self, chat_id: C, message_id: i32) -> Self::SetMessageReaction
+    where
+        C: Into<ChatId>,
+    {
+        Self::SetMessageReaction::new(
+            self.clone(),
+            payloads::SetMessageReaction::new(chat_id, message_id),
+        )
+    }
+
     type SendSticker = MultipartRequest<payloads::SendSticker>;
 
     fn send_sticker<C>(&self, chat_id: C, sticker: InputFile) -> Self::SendSticker
@@ -786,6 +888,18 @@ impl Requester for Bot {
         Self::GetStickerSet::new(self.clone(), payloads::GetStickerSet::new(name))
     }
 
+    type GetCustomEmojiStickers = JsonRequest<payloads::GetCustomEmojiStickers>;
+
+    fn get_custom_emoji_stickers<C>(&self, custom_emoji_ids: C) -> Self::GetCustomEmojiStickers
+    where
+        C: IntoIterator<Item = String>,
+    {
+        Self::GetCustomEmojiStickers::new(
+            self.clone(),
+            payloads::GetCustomEmojiStickers::new(custom_emoji_ids),
+        )
+    }
+
     type UploadStickerFile = MultipartRequest<payloads::UploadStickerFile>;
 
     fn upload_sticker_file(&self, user_id: i64, png_sticker: InputFile) -> Self::UploadStickerFile where
@@ -891,7 +1005,7 @@ impl Requester for Bot {
         D: Into<String>,
         Pa: Into<String>,
         P: Into<String>,
-        C: Into<String>,
+        C: Into<Currency>,
         Pri: IntoIterator<Item = LabeledPrice>,
     {
         Self::SendInvoice::new(
@@ -902,7 +1016,7 @@ impl Requester for Bot {
                 description,
                 payload,
                 provider_token,
-                currency,
+                currency.into(),
                 prices,
             ),
         )
@@ -966,8 +1080,8 @@ impl Requester for Bot {
         &self,
         user_id: i64,
         score: u64,
-        chat_id: u32,
-        message_id: i64,
+        chat_id: i64,
+        message_id: i32,
     ) -> Self::SetGameScore {
         Self::SetGameScore::new(
             self.clone(),
@@ -1038,6 +1152,115 @@ impl Requester for Bot {
         Self::UnpinAllChatMessages::new(self.clone(), payloads::UnpinAllChatMessages::new(chat_id))
     }
 
+    type CreateForumTopic = JsonRequest<payloads::CreateForumTopic>;
+
+    fn create_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::CreateForumTopic
+    where
+        C: Into<ChatId>,
+        N: Into<String>,
+    {
+        Self::CreateForumTopic::new(self.clone(), payloads::CreateForumTopic::new(chat_id, name))
+    }
+
+    type EditForumTopic = JsonRequest<payloads::EditForumTopic>;
+
+    fn edit_forum_topic<C, T>(&self, chat_id: C, message_thread_id: T) -> Self::EditForumTopic
+    where
+        C: Into<ChatId>,
+        T: Into<ThreadId>,
+    {
+        Self::EditForumTopic::new(
+            self.clone(),
+            payloads::EditForumTopic::new(chat_id, message_thread_id),
+        )
+    }
+
+    type GetForumTopicIconStickers = JsonRequest<payloads::GetForumTopicIconStickers>;
+
+    fn get_forum_topic_icon_stickers(&self) -> Self::GetForumTopicIconStickers {
+        Self::GetForumTopicIconStickers::new(
+            self.clone(),
+            payloads::GetForumTopicIconStickers::new(),
+        )
+    }
+
+    type EditGeneralForumTopic = JsonRequest<payloads::EditGeneralForumTopic>;
+
+    fn edit_general_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::EditGeneralForumTopic
+    where
+        C: Into<ChatId>,
+        N: Into<String>,
+    {
+        Self::EditGeneralForumTopic::new(
+            self.clone(),
+            payloads::EditGeneralForumTopic::new(chat_id, name),
+        )
+    }
+
+    type CloseGeneralForumTopic = JsonRequest<payloads::CloseGeneralForumTopic>;
+
+    fn close_general_forum_topic<C>(&self, chat_id: C) -> Self::CloseGeneralForumTopic
+    where
+        C: Into<ChatId>,
+    {
+        Self::CloseGeneralForumTopic::new(
+            self.clone(),
+            payloads::CloseGeneralForumTopic::new(chat_id),
+        )
+    }
+
+    type ReopenGeneralForumTopic = JsonRequest<payloads::ReopenGeneralForumTopic>;
+
+    fn reopen_general_forum_topic<C>(&self, chat_id: C) -> Self::ReopenGeneralForumTopic
+    where
+        C: Into<ChatId>,
+    {
+        Self::ReopenGeneralForumTopic::new(
+            self.clone(),
+            payloads::ReopenGeneralForumTopic::new(chat_id),
+        )
+    }
+
+    type HideGeneralForumTopic = JsonRequest<payloads::HideGeneralForumTopic>;
+
+    fn hide_general_forum_topic<C>(&self, chat_id: C) -> Self::HideGeneralForumTopic
+    where
+        C: Into<ChatId>,
+    {
+        Self::HideGeneralForumTopic::new(
+            self.clone(),
+            payloads::HideGeneralForumTopic::new(chat_id),
+        )
+    }
+
+    type UnhideGeneralForumTopic = JsonRequest<payloads::UnhideGeneralForumTopic>;
+
+    fn unhide_general_forum_topic<C>(&self, chat_id: C) -> Self::UnhideGeneralForumTopic
+    where
+        C: Into<ChatId>,
+    {
+        Self::UnhideGeneralForumTopic::new(
+            self.clone(),
+            payloads::UnhideGeneralForumTopic::new(chat_id),
+        )
+    }
+
+    type UnpinAllGeneralForumTopicMessages =
+        JsonRequest<payloads::UnpinAllGeneralForumTopicMessages>;
+
+    fn unpin_all_general_forum_topic_messages<C>(
+        &self,
+        chat_id: C,
+    ) -> Self::UnpinAllGeneralForumTopicMessages
+    where
+        C: Into<ChatId>,
+    {
+        Self::UnpinAllGeneralForumTopicMessages::new(
+            self.clone(),
+            payloads::UnpinAllGeneralForumTopicMessages::new(chat_id),
+        )
+    }
+
     type GetUpdatesFaultTolerant = JsonRequest<payloads::GetUpdatesFaultTolerant>;
 
     fn get_updates_fault_tolerant(&self) -> Self::GetUpdatesFaultTolerant {
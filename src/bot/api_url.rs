@@ -1,18 +1,33 @@
 use std::sync::Arc;
 
+use once_cell::sync::Lazy;
+
+static DEFAULT: Lazy<reqwest::Url> = Lazy::new(|| {
+    reqwest::Url::parse(crate::net::TELEGRAM_API_URL).expect("failed to parse default url")
+});
+
 #[derive(Debug, Clone)]
 pub(crate) enum ApiUrl {
     Default,
-    Custom(Arc<reqwest::Url>),
+    /// The primary URL followed by fallbacks, never empty.
+    Custom(Arc<[reqwest::Url]>),
 }
 
 impl ApiUrl {
+    pub(crate) fn custom(url: reqwest::Url) -> Self {
+        ApiUrl::Custom(Arc::from(vec![url]))
+    }
+
+    /// Returns the primary URL.
     pub(crate) fn get(&self) -> reqwest::Url {
+        self.all()[0].clone()
+    }
+
+    /// Returns the primary URL followed by fallbacks.
+    pub(crate) fn all(&self) -> &[reqwest::Url] {
         match self {
-            // FIXME(waffle): parse once
-            ApiUrl::Default => reqwest::Url::parse(crate::net::TELEGRAM_API_URL)
-                .expect("failed to parse default url"),
-            ApiUrl::Custom(url) => (&**url).clone(),
+            ApiUrl::Default => std::slice::from_ref(&*DEFAULT),
+            ApiUrl::Custom(urls) => urls,
         }
     }
 }
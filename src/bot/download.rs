@@ -1,13 +1,25 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
 use bytes::Bytes;
-use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
-use tokio::io::AsyncWrite;
+use futures::{
+    future::{self, BoxFuture},
+    stream::{self, BoxStream},
+    FutureExt, StreamExt,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::{
     bot::Bot,
-    net::{self, Download},
+    net::{self, Download, TrafficHook, TrafficRecord},
     DownloadError,
 };
 
+/// The method name of downloads in [`TrafficRecord`]s.
+const DOWNLOAD_FILE: &str = "DownloadFile";
+
 impl<'w> Download<'w> for Bot {
     type Err = DownloadError;
 
@@ -20,13 +32,33 @@ impl<'w> Download<'w> for Bot {
         path: &str,
         destination: &'w mut (dyn AsyncWrite + Unpin + Send),
     ) -> Self::Fut {
-        net::download_file(
-            &self.client,
-            self.api_url.get(),
-            &self.token,
-            path,
-            destination,
-        )
+        let hook = match &self.traffic_hook {
+            Some(hook) => hook.clone(),
+            None => {
+                return net::download_file(
+                    &self.client,
+                    self.api_url.get(),
+                    &self.token,
+                    path,
+                    destination,
+                )
+                .boxed()
+            }
+        };
+
+        let mut stream =
+            net::download_file_stream(&self.client, self.api_url.get(), &self.token, path).boxed();
+        async move {
+            let mut size = 0;
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk?;
+                size += chunk.len() as u64;
+                destination.write_all(&chunk).await?;
+            }
+
+            report(&hook, size);
+            Ok(())
+        }
         .boxed()
     }
 
@@ -35,6 +67,38 @@ impl<'w> Download<'w> for Bot {
     type Stream = BoxStream<'static, Result<Bytes, Self::StreamErr>>;
 
     fn download_file_stream(&self, path: &str) -> Self::Stream {
-        net::download_file_stream(&self.client, self.api_url.get(), &self.token, path).boxed()
+        let download =
+            net::download_file_stream(&self.client, self.api_url.get(), &self.token, path);
+        let hook = match &self.traffic_hook {
+            Some(hook) => hook.clone(),
+            None => return download.boxed(),
+        };
+
+        // Reported when the stream ends
+        let size = Arc::new(AtomicU64::new(0));
+        let end = {
+            let size = Arc::clone(&size);
+            stream::once(future::lazy(move |_| {
+                report(&hook, size.load(Ordering::Relaxed));
+            }))
+            .filter_map(|()| future::ready(None))
+        };
+
+        download
+            .inspect(move |chunk| {
+                if let Ok(chunk) = chunk {
+                    size.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+                }
+            })
+            .chain(end)
+            .boxed()
     }
 }
+
+fn report(hook: &TrafficHook, size: u64) {
+    hook.report(&TrafficRecord {
+        method: DOWNLOAD_FILE,
+        request_size: Some(0),
+        response_size: size,
+    });
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+use reqwest::Client;
+
+use crate::{
+    bot::{token::Token, Bot},
+    net,
+};
+
+#[cfg(feature = "throttle")]
+use crate::adaptors::throttle::{Limits, Throttle};
+
+/// A set of bots which share one [`reqwest::Client`].
+///
+/// This is useful for multi-tenant bot platforms which run many bots in one
+/// process: all bots share the same connection pool, bots can be added and
+/// removed at runtime and looked up by their token.
+///
+/// `B` is the type of the bots in the pool, by default it's [`Bot`], but it
+/// can be a bot wrapped in adaptors, e.g. [`BotPool::throttled`] creates a
+/// pool of [`Throttle<Bot>`]s.
+///
+/// Cloning the pool is cheap, all clones refer to the same set of bots.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::BotPool;
+///
+/// let pool = BotPool::new();
+/// let bot = pool.add("123:first");
+/// pool.add("456:second");
+///
+/// assert_eq!(pool.len(), 2);
+/// assert_eq!(pool.get("123:first").unwrap().token(), bot.token());
+///
+/// pool.remove("456:second");
+/// assert!(pool.get("456:second").is_none());
+/// ```
+///
+/// [`Throttle<Bot>`]: crate::adaptors::Throttle
+#[derive(Clone)]
+pub struct BotPool<B = Bot> {
+    client: Client,
+    wrap: Arc<dyn Fn(Bot) -> B + Send + Sync>,
+    // Tokens are zeroed when they are removed and are never shown in `Debug`
+    bots: Arc<RwLock<HashMap<Token, B>>>,
+}
+
+impl BotPool {
+    /// Creates an empty pool with the default [http-client](reqwest::Client).
+    ///
+    /// # Panics
+    ///
+    /// If it cannot create [`reqwest::Client`].
+    pub fn new() -> Self {
+        let client = net::default_reqwest_settings()
+            .build()
+            .expect("Client creation failed");
+
+        Self::with_client(client)
+    }
+
+    /// Creates an empty pool with your [`reqwest::Client`].
+    pub fn with_client(client: Client) -> Self {
+        Self::with_wrapper(client, |bot| bot)
+    }
+}
+
+impl Default for BotPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "throttle")]
+impl BotPool<Throttle<Bot>> {
+    /// Creates an empty pool of throttled bots.
+    ///
+    /// Telegram limits every bot separately, so every bot gets its own
+    /// throttling worker (spawned with `tokio::spawn`) with the `limits`.
+    pub fn throttled(client: Client, limits: Limits) -> Self {
+        Self::with_wrapper(client, move |bot| Throttle::new_spawn(bot, limits))
+    }
+}
+
+impl<B> BotPool<B>
+where
+    B: Clone,
+{
+    /// Creates an empty pool with your [`reqwest::Client`] which wraps every
+    /// added bot with `wrap` (e.g. in adaptors).
+    pub fn with_wrapper<F>(client: Client, wrap: F) -> Self
+    where
+        F: Fn(Bot) -> B + Send + Sync + 'static,
+    {
+        Self {
+            client,
+            wrap: Arc::new(wrap),
+            bots: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Adds a bot with the `token` to the pool and returns it.
+    ///
+    /// If a bot with this token is already in the pool, it is returned
+    /// instead.
+    pub fn add<S>(&self, token: S) -> B
+    where
+        S: Into<String>,
+    {
+        let token = token.into();
+        let mut bots = self.bots.write().unwrap();
+
+        if let Some(bot) = bots.get(token.as_str()) {
+            return bot.clone();
+        }
+
+        let bot = Bot::with_client(token, self.client.clone());
+        let token = bot.token.clone();
+        let bot = (self.wrap)(bot);
+        bots.insert(token, bot.clone());
+        bot
+    }
+
+    /// Returns the bot with the `token`, if it's in the pool.
+    pub fn get(&self, token: &str) -> Option<B> {
+        self.bots.read().unwrap().get(token).cloned()
+    }
+
+    /// Removes the bot with the `token` from the pool, returning it.
+    pub fn remove(&self, token: &str) -> Option<B> {
+        self.bots.write().unwrap().remove(token)
+    }
+
+    /// Returns all bots in the pool.
+    pub fn bots(&self) -> Vec<B> {
+        self.bots.read().unwrap().values().cloned().collect()
+    }
+
+    /// Returns the number of bots in the pool.
+    pub fn len(&self) -> usize {
+        self.bots.read().unwrap().len()
+    }
+
+    /// Returns `true` if there are no bots in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the http-client shared by all bots in the pool.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+}
+
+impl<B> fmt::Debug for BotPool<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't show the tokens
+        f.debug_struct("BotPool")
+            .field("client", &self.client)
+            .field("len", &self.bots.read().unwrap().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_get_remove() {
+        let pool = BotPool::with_client(Client::new());
+
+        let bot = pool.add("1:a");
+        assert_eq!(bot.token(), "1:a");
+        assert_eq!(pool.add("1:a").token(), "1:a");
+        assert_eq!(pool.len(), 1);
+
+        pool.add("2:b");
+        assert_eq!(pool.bots().len(), 2);
+        assert_eq!(pool.get("2:b").and_then(|bot| bot.id()), Some(2));
+
+        assert!(pool.remove("1:a").is_some());
+        assert!(pool.get("1:a").is_none());
+        assert!(!pool.is_empty());
+        assert!(!format!("{:?}", pool).contains("2:b"));
+    }
+
+    #[cfg(feature = "throttle")]
+    #[test]
+    fn throttled_bots_have_own_workers() {
+        let rt = crate::test_utils::rt();
+        let _guard = rt.enter();
+
+        let pool = BotPool::throttled(Client::new(), Limits::default());
+        let first = pool.add("1:a");
+        let second = pool.add("2:b");
+        assert_eq!(first.inner().token(), "1:a");
+        assert_eq!(second.inner().token(), "2:b");
+
+        assert!(!first.shares_worker_with(&second));
+        assert!(first.shares_worker_with(&pool.add("1:a")));
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    sync::Arc,
+};
+
+use zeroize::Zeroize;
+
+/// Bot token which is never shown in the [`Debug`] output.
+///
+/// The token is also zeroed (with [`zeroize`]) when the last clone is dropped.
+/// Note that copies of the token (e.g. in request URLs) are not zeroed.
+#[derive(Clone)]
+pub(crate) struct Token(Arc<Secret>);
+
+struct Secret(String);
+
+impl Token {
+    pub(crate) fn new(token: String) -> Self {
+        Self(Arc::new(Secret(token)))
+    }
+}
+
+impl Deref for Token {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &(self.0).0
+    }
+}
+
+impl Borrow<str> for Token {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+// Consistent with `Borrow<str>`
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for Token {}
+
+impl Hash for Token {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Token(<redacted>)")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_is_redacted() {
+        let token = Token::new("123:secret".to_owned());
+        assert_eq!(&*token, "123:secret");
+        assert!(!format!("{:?}", token).contains("secret"));
+    }
+}
@@ -1,10 +1,12 @@
-use std::io;
+use std::{fmt, io, sync::Arc};
 
 use derive_more::From;
 use reqwest::StatusCode;
 use serde::Deserialize;
 use thiserror::Error;
 
+use crate::types::ResponseParameters;
+
 /// An error caused by downloading a file.
 #[derive(Debug, Error, From)]
 pub enum DownloadError {
@@ -15,6 +17,272 @@ pub enum DownloadError {
     /// An I/O error while writing a file to destination.
     #[error("An I/O error: {0}")]
     Io(#[source] std::io::Error),
+
+    /// The file has no [`file_path`], so it can't be downloaded.
+    ///
+    /// [`file_path`]: crate::types::File::file_path
+    #[from(ignore)]
+    #[error("The file has no path")]
+    NoFilePath,
+}
+
+/// An error caused by a malformed bot token, see [`Bot::from_token`].
+///
+/// [`Bot::from_token`]: crate::Bot::from_token
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidToken {
+    /// The token has no `:` separating the bot id from the secret.
+    #[error("The token has no `:` separator")]
+    NoSeparator,
+
+    /// The bot id (the part before `:`) is empty, contains non-digits or
+    /// doesn't fit into `i64`.
+    #[error("The token has an invalid bot id")]
+    InvalidId,
+
+    /// The secret (the part after `:`) is empty or contains characters other
+    /// than `A-Z`, `a-z`, `0-9`, `_` and `-`.
+    #[error("The token has an invalid secret")]
+    InvalidSecret,
+}
+
+/// An error caused by a malformed username, see [`ChatId::channel_username`].
+///
+/// [`ChatId::channel_username`]: crate::types::ChatId::channel_username
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidUsername {
+    /// The username is shorter than 5 or longer than 32 characters.
+    #[error("The username must be 5-32 characters long, got {0}")]
+    InvalidLength(usize),
+
+    /// The username contains characters other than `A-Z`, `a-z`, `0-9` and
+    /// `_`.
+    #[error("The username contains characters other than `A-Z`, `a-z`, `0-9` and `_`")]
+    InvalidCharacter,
+
+    /// The username doesn't start with a letter.
+    #[error("The username must start with a letter")]
+    InvalidStart,
+
+    /// The username ends with `_`.
+    #[error("The username can't end with `_`")]
+    TrailingUnderscore,
+}
+
+/// An error caused by deserializing a [`ReopenableFile`] which can't be
+/// restored.
+///
+/// A reopenable file is serialized by the id of its callback, so it can only
+/// be deserialized by the process which serialized it and only while a clone
+/// of the file is alive. E.g. requests with such files can't be restored
+/// from an `OutboxStore` after a restart.
+///
+/// [`ReopenableFile`]: crate::types::ReopenableFile
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReopenableFileError {
+    /// The file was serialized by another process.
+    #[error("The reopenable file was serialized by another process")]
+    OtherProcess,
+
+    /// All clones of the file with this id were dropped, so its callback is
+    /// gone.
+    #[error("The reopenable file with id {0} is no longer alive")]
+    Dropped(u64),
+}
+
+/// An error caused by a link which doesn't point to a chat, see
+/// [`ChatId::from_url`].
+///
+/// [`ChatId::from_url`]: crate::types::ChatId::from_url
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidChatUrl {
+    /// The link is not a `t.me` (or `tg://resolve`) link to a chat.
+    #[error("The link is not a link to a Telegram chat")]
+    NotChatLink,
+
+    /// The link is an invite link, which doesn't identify a chat.
+    #[error("Invite links can't be used to identify chats")]
+    InviteLink,
+
+    /// The link to a private chat (`t.me/c/...`) has an invalid chat id.
+    #[error("The link has an invalid chat id")]
+    InvalidId,
+
+    /// The link has an invalid username.
+    #[error("The link has an invalid username: {0}")]
+    InvalidUsername(#[source] InvalidUsername),
+}
+
+impl From<InvalidUsername> for InvalidChatUrl {
+    fn from(err: InvalidUsername) -> Self {
+        Self::InvalidUsername(err)
+    }
+}
+
+/// An error caused by coordinates which are out of range, see
+/// [`Coordinates`].
+///
+/// [`Coordinates`]: crate::types::Coordinates
+#[derive(Debug, Error, Clone, Copy, PartialEq)]
+pub enum InvalidCoordinates {
+    /// The latitude is not in `-90.0..=90.0` (or is `NaN`).
+    #[error("The latitude {0} is not in -90..=90")]
+    Latitude(f64),
+
+    /// The longitude is not in `-180.0..=180.0` (or is `NaN`).
+    #[error("The longitude {0} is not in -180..=180")]
+    Longitude(f64),
+}
+
+/// An error caused by a request which is known to be rejected by Telegram,
+/// see [`ValidateRequests`].
+///
+/// Lengths are measured in UTF-16 code units, like offsets of entities.
+///
+/// [`ValidateRequests`]: crate::adaptors::ValidateRequests
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+pub enum ValidationError {
+    /// The text of a message is empty.
+    #[error("The text is empty")]
+    EmptyText,
+
+    /// The text of a message (or of a callback query answer) is too long.
+    #[error("The text is {length} characters long, at most {limit} are allowed")]
+    TextTooLong {
+        /// The length of the text.
+        length: usize,
+
+        /// The maximum allowed length.
+        limit: usize,
+    },
+
+    /// The caption of a media message is too long.
+    #[error("The caption is {length} characters long, at most {limit} are allowed")]
+    CaptionTooLong {
+        /// The length of the caption.
+        length: usize,
+
+        /// The maximum allowed length.
+        limit: usize,
+    },
+
+    /// Both `parse_mode` and entities are specified.
+    #[error("`parse_mode` can't be used together with entities")]
+    ParseModeWithEntities,
+
+    /// An entity doesn't fit into the text.
+    #[error("The entity at {offset} of length {length} is out of bounds of the text")]
+    EntityOutOfBounds {
+        /// The offset of the entity.
+        offset: usize,
+
+        /// The length of the entity.
+        length: usize,
+    },
+
+    /// An inline keyboard has too many buttons.
+    #[error("The keyboard has {count} buttons, at most {limit} are allowed")]
+    TooManyButtons {
+        /// The number of buttons.
+        count: usize,
+
+        /// The maximum allowed number of buttons.
+        limit: usize,
+    },
+
+    /// A `callback_data` of a button is empty or longer than 64 bytes.
+    #[error("The callback data is {0} bytes long, 1-64 bytes are allowed")]
+    InvalidCallbackData(usize),
+}
+
+/// An error returned when a file doesn't satisfy requirements of Telegram for
+/// thumbnails, see [`Thumbnail`].
+///
+/// [`Thumbnail`]: crate::utils::thumbnail::Thumbnail
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+pub enum ThumbnailError {
+    /// The file is not a JPEG.
+    #[error("The thumbnail is not a JPEG")]
+    NotJpeg,
+
+    /// The JPEG is truncated or malformed, so its dimensions are unknown.
+    #[error("The thumbnail is a malformed JPEG")]
+    Malformed,
+
+    /// The file is too large.
+    #[error("The thumbnail is {size} bytes large, at most {limit} are allowed")]
+    TooLarge {
+        /// The size of the file in bytes.
+        size: usize,
+
+        /// The maximum allowed size.
+        limit: usize,
+    },
+
+    /// The width or the height of the image is too large.
+    #[error("The thumbnail is {width}x{height}, at most {limit}x{limit} is allowed")]
+    DimensionsTooLarge {
+        /// The width of the image.
+        width: u32,
+
+        /// The height of the image.
+        height: u32,
+
+        /// The maximum allowed width and height.
+        limit: u32,
+    },
+}
+
+/// An error caused by failing to resolve a host name, see `net::PinnedDns`
+/// (the `pinned_dns` feature).
+#[derive(Debug, Clone)]
+pub struct ResolveError {
+    host: String,
+    source: Option<Arc<io::Error>>,
+}
+
+impl ResolveError {
+    #[cfg(feature = "pinned_dns")]
+    pub(crate) fn new(host: &str, source: io::Error) -> Self {
+        Self {
+            host: host.to_owned(),
+            source: Some(Arc::new(source)),
+        }
+    }
+
+    #[cfg(feature = "pinned_dns")]
+    pub(crate) fn empty(host: &str) -> Self {
+        Self {
+            host: host.to_owned(),
+            source: None,
+        }
+    }
+
+    /// Returns the host which couldn't be resolved.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns the underlying I/O error, `None` if the host was resolved to
+    /// no addresses.
+    pub fn io_error(&self) -> Option<&io::Error> {
+        self.source.as_deref()
+    }
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(err) => write!(f, "Failed to resolve `{}`: {}", self.host, err),
+            None => write!(f, "Failed to resolve `{}`: no addresses", self.host),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|err| err as _)
+    }
 }
 
 /// An error caused by sending a request to Telegram.
@@ -44,6 +312,11 @@ pub enum RequestError {
     #[error("A network error: {0}")]
     NetworkError(#[source] reqwest::Error),
 
+    /// Failed to resolve the address of the Telegram API server, see
+    /// `net::PinnedDns` (the `pinned_dns` feature).
+    #[error("A DNS error: {0}")]
+    DnsError(#[source] ResolveError),
+
     /// Error while parsing a response from Telegram.
     ///
     /// If you've received this error, please, [open an issue] with the
@@ -56,6 +329,87 @@ pub enum RequestError {
     /// Occurs when trying to send a file to Telegram.
     #[error("An I/O error: {0}")]
     Io(#[source] io::Error),
+
+    /// A request was rejected before sending it, see [`ValidateRequests`].
+    ///
+    /// [`ValidateRequests`]: crate::adaptors::ValidateRequests
+    #[error("An invalid request: {0}")]
+    Validation(#[source] ValidationError),
+}
+
+impl From<ValidationError> for RequestError {
+    fn from(err: ValidationError) -> Self {
+        Self::Validation(err)
+    }
+}
+
+impl From<DownloadError> for RequestError {
+    fn from(err: DownloadError) -> Self {
+        match err {
+            DownloadError::NetworkError(err) => Self::NetworkError(err),
+            DownloadError::Io(err) => Self::Io(err),
+            DownloadError::NoFilePath => Self::Io(io::Error::new(io::ErrorKind::NotFound, err)),
+        }
+    }
+}
+
+/// Errors which may contain [`ResponseParameters`].
+///
+/// This trait is used by bot adaptors (e.g. [`Throttle`]) to react to
+/// errors without knowing the exact error type.
+///
+/// [`Throttle`]: crate::adaptors::Throttle
+pub trait AsResponseParameters {
+    /// Returns the response parameters of the error, if any.
+    fn response_parameters(&self) -> Option<ResponseParameters>;
+
+    /// Returns the number of seconds left to wait before the request can be
+    /// repeated, if the error is caused by exceeding flood control.
+    fn retry_after(&self) -> Option<i32> {
+        match self.response_parameters()? {
+            ResponseParameters::RetryAfter(secs) => Some(secs),
+            ResponseParameters::MigrateToChatId(_) => None,
+        }
+    }
+
+    /// Returns the new identifier of the chat, if the error is caused by the
+    /// group being migrated to a supergroup.
+    fn migrate_to_chat_id(&self) -> Option<i64> {
+        match self.response_parameters()? {
+            ResponseParameters::MigrateToChatId(id) => Some(id),
+            ResponseParameters::RetryAfter(_) => None,
+        }
+    }
+}
+
+impl AsResponseParameters for RequestError {
+    fn response_parameters(&self) -> Option<ResponseParameters> {
+        match *self {
+            Self::RetryAfter(secs) => Some(ResponseParameters::RetryAfter(secs)),
+            Self::MigrateToChatId(id) => Some(ResponseParameters::MigrateToChatId(id)),
+            _ => None,
+        }
+    }
+}
+
+/// Errors which may be [`ApiError`]s.
+///
+/// This trait is used (e.g. by [`Polling`]) to react to API errors without
+/// knowing the exact error type.
+///
+/// [`Polling`]: crate::requests::Polling
+pub trait AsApiError {
+    /// Returns the kind of the API error, if the error is one.
+    fn api_error(&self) -> Option<&ApiError>;
+}
+
+impl AsApiError for RequestError {
+    fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::ApiError { kind, .. } => Some(kind),
+            _ => None,
+        }
+    }
 }
 
 /// A kind of an API error.
@@ -341,6 +695,18 @@ pub enum ApiError {
     #[serde(rename = "Bad Request: wrong file id")]
     WrongFileId,
 
+    /// Occurs when bot tries to send a file by a wrong (e.g. expired) file id
+    /// or by an URL which can't be downloaded.
+    ///
+    /// May happen in methods:
+    /// 1. [`SendPhoto`]
+    /// 2. [`SendDocument`]
+    ///
+    /// [`SendPhoto`]: crate::payloads::SendPhoto
+    /// [`SendDocument`]: crate::payloads::SendDocument
+    #[serde(rename = "Bad Request: wrong file identifier/HTTP URL specified")]
+    WrongFileIdOrUrl,
+
     /// Occurs when bot tries to do some with group which was deactivated.
     #[serde(rename = "Bad Request: group is deactivated")]
     GroupDeactivated,
@@ -475,7 +841,11 @@ pub enum ApiError {
     /// 1. [`GetUpdates`]
     ///
     /// [`GetUpdates`]: crate::payloads::GetUpdates
-    #[serde(rename = "can't use getUpdates method while webhook is active")]
+    #[serde(
+        rename = "can't use getUpdates method while webhook is active",
+        alias = "Conflict: can't use getUpdates method while webhook is active; use \
+                 deleteWebhook to delete the webhook first"
+    )]
     CantGetUpdates,
 
     /// Occurs when bot tries to do some in group where bot was kicked.
@@ -563,3 +933,38 @@ pub enum ApiError {
     /// [open an issue]: https://github.com/teloxide/teloxide/issues/new
     Unknown(String),
 }
+
+impl ApiError {
+    /// Returns `true` if [`GetUpdates`] failed because updates are received
+    /// by someone else: another `getUpdates` request (i.e. another instance
+    /// of the bot is running) or a webhook.
+    ///
+    /// [`GetUpdates`]: crate::payloads::GetUpdates
+    pub fn is_get_updates_conflict(&self) -> bool {
+        matches!(
+            self,
+            Self::TerminatedByOtherGetUpdates | Self::CantGetUpdates
+        )
+    }
+
+    /// Returns `true` if the bot token is invalid (e.g. the bot was deleted
+    /// or the token was revoked), so no request of the bot can succeed.
+    pub fn is_invalid_token(&self) -> bool {
+        match self {
+            Self::Unknown(description) => {
+                description == "Unauthorized" || description == "Not Found"
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the request failed because a file id is wrong, e.g.
+    /// it has expired or belongs to another bot.
+    pub fn is_wrong_file_id(&self) -> bool {
+        match self {
+            Self::WrongFileId | Self::WrongFileIdOrUrl | Self::FileIdInvalid => true,
+            Self::Unknown(description) => description.contains("wrong remote file identifier"),
+            _ => false,
+        }
+    }
+}
@@ -1,8 +1,8 @@
 //! Core part of the [`teloxide`] library.
 //!
 //! This library provides tools for making requests to the [Telegram Bot API]
-//! (Currently, version `5.1` is supported) with ease. The library is fully
-//! asynchronouns and built using [`tokio`].
+//! (Currently, version `5.2` is fully supported, see [`API_VERSION`]) with ease.
+//! The library is fully asynchronouns and built using [`tokio`].
 //!
 //!```toml
 //! teloxide_core = "0.2"
@@ -45,11 +45,44 @@
 //!
 //! - `native-tls` = use [`native-tls`] tls implementation (**enabled by
 //!   default**)
-//! - `rustls` — use [`rustls`] tls implementation
+//! - `rustls` — use [`rustls`] tls implementation. To not link OpenSSL (e.g.
+//!   for static `musl` builds) disable default features:
+//!   `teloxide-core = { version = "...", default-features = false, features =
+//!   ["rustls"] }`. Without any tls feature the crate still compiles, but
+//!   can't connect to `https` urls (including the default Telegram Bot API
+//!   url)
 //! - `auto_send` — enables [`AutoSend`] bot adaptor
 //! - `throttle` — enables [`Throttle`] bot adaptor
 //! - `cache_me` — enables [`CacheMe`] bot adaptor
-//! - `full` — enables all features except `nigthly`
+//! - `audit` — enables [`Audit`] bot adaptor
+//! - `chat_queue` — enables [`ChatQueue`] bot adaptor
+//! - `outbox` — enables [`Outbox`] bot adaptor
+//! - `media_cache` — enables [`MediaCache`] bot adaptor
+//! - `chat_migration` — enables [`ChatMigration`] bot adaptor
+//! - `concurrency_limit` — enables [`ConcurrencyLimit`] bot adaptor
+//! - `error_hook` — enables [`ErrorHook`] bot adaptor
+//! - `validate` — enables [`ValidateRequests`] bot adaptor
+//! - `pinned_dns` — enables [`Bot::with_pinned_dns`], a DNS resolver which
+//!   pins addresses of Telegram API servers
+//! - `audio_metadata` — enables [`utils::audio_metadata`] for reading the
+//!   duration, the performer, the title and the cover art of audio files
+//! - `video_metadata` — enables [`utils::video_metadata`] for reading the
+//!   dimensions and the duration of `.mp4` videos
+//! - `scheduler` — enables the [`scheduler`] module for sending requests at a
+//!   given time
+//! - `to_curl` — enables [`ToCurl`], rendering of requests as cURL commands
+//!   and raw HTTP for debugging
+//! - `tower` — enables [`RequestService`], a [`tower::Service`] which sends
+//!   requests
+//! - `geo_types` — enables conversions between [`Coordinates`] and
+//!   [`geo_types::Point`]
+//! - `partial_api` — parses messages with content which is not supported by
+//!   this library yet (e.g. introduced in a newer Bot API version) into
+//!   [`MessageKind::Unknown`] with raw JSON, instead of failing
+//! - `tracing` — emits [deprecation warnings] with [`tracing`] instead of
+//!   [`log`]
+//! - `full` — enables all features except `nigthly`, `partial_api` and
+//!   `tracing`
 //! - `nightly` — enables nigthly-only features, currently:
 //!   - Removes some future boxing using `#![feature(type_alias_impl_trait)]`
 //!   - Used to built docs (`#![feature(doc_cfg, doc_spotlight)]`)
@@ -57,6 +90,24 @@
 //! [`AutoSend`]: adaptors::AutoSend
 //! [`Throttle`]: adaptors::Throttle
 //! [`CacheMe`]: adaptors::CacheMe
+//! [`Audit`]: adaptors::Audit
+//! [`ChatQueue`]: adaptors::ChatQueue
+//! [`Outbox`]: adaptors::Outbox
+//! [`MediaCache`]: adaptors::MediaCache
+//! [`ChatMigration`]: adaptors::ChatMigration
+//! [`ConcurrencyLimit`]: adaptors::ConcurrencyLimit
+//! [`ErrorHook`]: adaptors::ErrorHook
+//! [`ValidateRequests`]: adaptors::ValidateRequests
+//! [`Bot::with_pinned_dns`]: Bot::with_pinned_dns
+//! [`MessageKind::Unknown`]: types::MessageKind
+//! [deprecation warnings]: net::deprecation_warnings
+//! [`tracing`]: https://docs.rs/tracing
+//! [`log`]: https://docs.rs/log
+//! [`RequestService`]: requests::RequestService
+//! [`tower::Service`]: https://docs.rs/tower-service
+//! [`Coordinates`]: types::Coordinates
+//! [`geo_types::Point`]: https://docs.rs/geo-types
+//! [`ToCurl`]: requests::ToCurl
 //! [`native-tls`]: https://docs.rs/native-tls
 //! [`rustls`]: https://docs.rs/rustls
 
@@ -82,8 +133,13 @@
 mod local_macros;
 
 pub use self::{
-    bot::Bot,
-    errors::{ApiError, DownloadError, RequestError},
+    api_version::{telegram_api_version, ApiVersion, BotApiFeature, API_VERSION},
+    bot::{Bot, BotPool},
+    errors::{
+        ApiError, AsApiError, AsResponseParameters, DownloadError, InvalidChatUrl,
+        InvalidCoordinates, InvalidToken, InvalidUsername, ReopenableFileError, RequestError,
+        ResolveError, ThumbnailError, ValidationError,
+    },
 };
 
 pub mod adaptors;
@@ -91,11 +147,19 @@ pub mod net;
 pub mod payloads;
 pub mod prelude;
 pub mod requests;
+#[cfg(feature = "scheduler")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "scheduler")))]
+pub mod scheduler;
 pub mod types;
+pub mod utils;
 
 // reexported
+mod api_version;
 mod bot;
 mod errors;
 
 // implementation details
 mod serde_multipart;
+
+#[cfg(test)]
+mod test_utils;
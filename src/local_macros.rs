@@ -164,6 +164,16 @@ macro_rules! impl_payload {
                     $(,)?
                 }
             )?
+
+            $(
+                deprecated {
+                    $(
+                        #[deprecated $($deprecated_meta:tt)*]
+                        $old_setters:ident => $new_setters:ident : $DepFTy:ty
+                    ),*
+                    $(,)?
+                }
+            )?
         }
     ) => {
         #[serde_with_macros::skip_serializing_none]
@@ -171,6 +181,8 @@ macro_rules! impl_payload {
         $(
             #[ $($method_meta)* ]
         )*
+        // Payloads can be deserialized, e.g. to restore a `SerializedRequest`
+        #[derive(serde::Deserialize)]
         $vi struct $Method {
             $(
                 $(
@@ -236,6 +248,25 @@ macro_rules! impl_payload {
                         impl_payload! { @setter_opt $Method $opt_fields : $OptFTy $([$opt_conv])? }
                     )*
                 )?
+                $(
+                    $(
+                        calculated_doc! {
+                            #[doc = concat!(
+                                "Deprecated alias of [`",
+                                stringify!($new_setters),
+                                "`](",
+                                stringify!($Setters),
+                                "::",
+                                stringify!($new_setters),
+                                ")."
+                            )]
+                            #[deprecated $($deprecated_meta)*]
+                            fn $old_setters(self, value: $DepFTy) -> Self {
+                                self.$new_setters(value)
+                            }
+                        }
+                    )*
+                )?
             }
         }
 
@@ -699,6 +730,22 @@ macro_rules! requester_forward {
             $body!(unban_chat_member this (chat_id: C, user_id: i64))
         }
     };
+    (@method ban_chat_sender_chat $body:ident $ty:ident) => {
+        type BanChatSenderChat = $ty![BanChatSenderChat];
+
+        fn ban_chat_sender_chat<C>(&self, chat_id: C, sender_chat_id: i64) -> Self::BanChatSenderChat where C: Into<ChatId> {
+            let this = self;
+            $body!(ban_chat_sender_chat this (chat_id: C, sender_chat_id: i64))
+        }
+    };
+    (@method unban_chat_sender_chat $body:ident $ty:ident) => {
+        type UnbanChatSenderChat = $ty![UnbanChatSenderChat];
+
+        fn unban_chat_sender_chat<C>(&self, chat_id: C, sender_chat_id: i64) -> Self::UnbanChatSenderChat where C: Into<ChatId> {
+            let this = self;
+            $body!(unban_chat_sender_chat this (chat_id: C, sender_chat_id: i64))
+        }
+    };
     (@method restrict_chat_member $body:ident $ty:ident) => {
         type RestrictChatMember = $ty![RestrictChatMember];
 
@@ -757,6 +804,23 @@ macro_rules! requester_forward {
             $body!(edit_chat_invite_link this (chat_id: C, invite_link: I))
         }
     };
+    (@method create_chat_subscription_invite_link $body:ident $ty:ident) => {
+        type CreateChatSubscriptionInviteLink = $ty![CreateChatSubscriptionInviteLink];
+
+        fn create_chat_subscription_invite_link<C>(&self, chat_id: C, subscription_period: u32, subscription_price: u32) -> Self::CreateChatSubscriptionInviteLink where C: Into<ChatId> {
+            let this = self;
+            $body!(create_chat_subscription_invite_link this (chat_id: C, subscription_period: u32, subscription_price: u32))
+        }
+    };
+    (@method edit_chat_subscription_invite_link $body:ident $ty:ident) => {
+        type EditChatSubscriptionInviteLink = $ty![EditChatSubscriptionInviteLink];
+
+        fn edit_chat_subscription_invite_link<C, I>(&self, chat_id: C, invite_link: I) -> Self::EditChatSubscriptionInviteLink where C: Into<ChatId>,
+        I: Into<String> {
+            let this = self;
+            $body!(edit_chat_subscription_invite_link this (chat_id: C, invite_link: I))
+        }
+    };
     (@method revoke_chat_invite_link $body:ident $ty:ident) => {
         type RevokeChatInviteLink = $ty![RevokeChatInviteLink];
 
@@ -766,6 +830,22 @@ macro_rules! requester_forward {
             $body!(revoke_chat_invite_link this (chat_id: C, invite_link: I))
         }
     };
+    (@method approve_chat_join_request $body:ident $ty:ident) => {
+        type ApproveChatJoinRequest = $ty![ApproveChatJoinRequest];
+
+        fn approve_chat_join_request<C>(&self, chat_id: C, user_id: i64) -> Self::ApproveChatJoinRequest where C: Into<ChatId> {
+            let this = self;
+            $body!(approve_chat_join_request this (chat_id: C, user_id: i64))
+        }
+    };
+    (@method decline_chat_join_request $body:ident $ty:ident) => {
+        type DeclineChatJoinRequest = $ty![DeclineChatJoinRequest];
+
+        fn decline_chat_join_request<C>(&self, chat_id: C, user_id: i64) -> Self::DeclineChatJoinRequest where C: Into<ChatId> {
+            let this = self;
+            $body!(decline_chat_join_request this (chat_id: C, user_id: i64))
+        }
+    };
     (@method set_chat_photo $body:ident $ty:ident) => {
         type SetChatPhoto = $ty![SetChatPhoto];
 
@@ -823,6 +903,81 @@ macro_rules! requester_forward {
             $body!(unpin_all_chat_messages this (chat_id: C))
         }
     };
+    (@method create_forum_topic $body:ident $ty:ident) => {
+        type CreateForumTopic = $ty![CreateForumTopic];
+
+        fn create_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::CreateForumTopic where C: Into<ChatId>,
+        N: Into<String> {
+            let this = self;
+            $body!(create_forum_topic this (chat_id: C, name: N))
+        }
+    };
+    (@method edit_forum_topic $body:ident $ty:ident) => {
+        type EditForumTopic = $ty![EditForumTopic];
+
+        fn edit_forum_topic<C, T>(&self, chat_id: C, message_thread_id: T) -> Self::EditForumTopic where C: Into<ChatId>,
+        T: Into<ThreadId> {
+            let this = self;
+            $body!(edit_forum_topic this (chat_id: C, message_thread_id: T))
+        }
+    };
+    (@method get_forum_topic_icon_stickers $body:ident $ty:ident) => {
+        type GetForumTopicIconStickers = $ty![GetForumTopicIconStickers];
+
+        fn get_forum_topic_icon_stickers(&self) -> Self::GetForumTopicIconStickers {
+            let this = self;
+            $body!(get_forum_topic_icon_stickers this ())
+        }
+    };
+    (@method edit_general_forum_topic $body:ident $ty:ident) => {
+        type EditGeneralForumTopic = $ty![EditGeneralForumTopic];
+
+        fn edit_general_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::EditGeneralForumTopic where C: Into<ChatId>,
+        N: Into<String> {
+            let this = self;
+            $body!(edit_general_forum_topic this (chat_id: C, name: N))
+        }
+    };
+    (@method close_general_forum_topic $body:ident $ty:ident) => {
+        type CloseGeneralForumTopic = $ty![CloseGeneralForumTopic];
+
+        fn close_general_forum_topic<C>(&self, chat_id: C) -> Self::CloseGeneralForumTopic where C: Into<ChatId> {
+            let this = self;
+            $body!(close_general_forum_topic this (chat_id: C))
+        }
+    };
+    (@method reopen_general_forum_topic $body:ident $ty:ident) => {
+        type ReopenGeneralForumTopic = $ty![ReopenGeneralForumTopic];
+
+        fn reopen_general_forum_topic<C>(&self, chat_id: C) -> Self::ReopenGeneralForumTopic where C: Into<ChatId> {
+            let this = self;
+            $body!(reopen_general_forum_topic this (chat_id: C))
+        }
+    };
+    (@method hide_general_forum_topic $body:ident $ty:ident) => {
+        type HideGeneralForumTopic = $ty![HideGeneralForumTopic];
+
+        fn hide_general_forum_topic<C>(&self, chat_id: C) -> Self::HideGeneralForumTopic where C: Into<ChatId> {
+            let this = self;
+            $body!(hide_general_forum_topic this (chat_id: C))
+        }
+    };
+    (@method unhide_general_forum_topic $body:ident $ty:ident) => {
+        type UnhideGeneralForumTopic = $ty![UnhideGeneralForumTopic];
+
+        fn unhide_general_forum_topic<C>(&self, chat_id: C) -> Self::UnhideGeneralForumTopic where C: Into<ChatId> {
+            let this = self;
+            $body!(unhide_general_forum_topic this (chat_id: C))
+        }
+    };
+    (@method unpin_all_general_forum_topic_messages $body:ident $ty:ident) => {
+        type UnpinAllGeneralForumTopicMessages = $ty![UnpinAllGeneralForumTopicMessages];
+
+        fn unpin_all_general_forum_topic_messages<C>(&self, chat_id: C) -> Self::UnpinAllGeneralForumTopicMessages where C: Into<ChatId> {
+            let this = self;
+            $body!(unpin_all_general_forum_topic_messages this (chat_id: C))
+        }
+    };
     (@method leave_chat $body:ident $ty:ident) => {
         type LeaveChat = $ty![LeaveChat];
 
@@ -995,6 +1150,14 @@ macro_rules! requester_forward {
             $body!(delete_message this (chat_id: C, message_id: i32))
         }
     };
+    (@method set_message_reaction $body:ident $ty:ident) => {
+        type SetMessageReaction = $ty![SetMessageReaction];
+
+        fn set_message_reaction<C>(&self, chat_id: C, message_id: i32) -> Self::SetMessageReaction where C: Into<ChatId> {
+            let this = self;
+            $body!(set_message_reaction this (chat_id: C, message_id: i32))
+        }
+    };
     (@method send_sticker $body:ident $ty:ident) => {
         type SendSticker = $ty![SendSticker];
 
@@ -1011,6 +1174,14 @@ macro_rules! requester_forward {
             $body!(get_sticker_set this (name: N))
         }
     };
+    (@method get_custom_emoji_stickers $body:ident $ty:ident) => {
+        type GetCustomEmojiStickers = $ty![GetCustomEmojiStickers];
+
+        fn get_custom_emoji_stickers<C>(&self, custom_emoji_ids: C) -> Self::GetCustomEmojiStickers where C: IntoIterator<Item = String> {
+            let this = self;
+            $body!(get_custom_emoji_stickers this (custom_emoji_ids: C))
+        }
+    };
     (@method upload_sticker_file $body:ident $ty:ident) => {
         type UploadStickerFile = $ty![UploadStickerFile];
 
@@ -1070,7 +1241,7 @@ macro_rules! requester_forward {
         D: Into<String>,
         Pa: Into<String>,
         P: Into<String>,
-        C: Into<String>,
+        C: Into<Currency>,
         Pri: IntoIterator<Item = LabeledPrice> {
             let this = self;
             $body!(send_invoice this (chat_id: Ch, title: T, description: D, payload: Pa, provider_token: P, currency: C, prices: Pri))
@@ -1111,9 +1282,9 @@ macro_rules! requester_forward {
     (@method set_game_score $body:ident $ty:ident) => {
         type SetGameScore = $ty![SetGameScore];
 
-        fn set_game_score(&self, user_id: i64, score: u64, chat_id: u32, message_id: i64) -> Self::SetGameScore {
+        fn set_game_score(&self, user_id: i64, score: u64, chat_id: i64, message_id: i32) -> Self::SetGameScore {
             let this = self;
-            $body!(set_game_score this (user_id: i64, score: u64, chat_id: u32, message_id: i64))
+            $body!(set_game_score this (user_id: i64, score: u64, chat_id: i64, message_id: i32))
         }
     };
     (@method set_game_score_inline $body:ident $ty:ident) => {
@@ -2,16 +2,34 @@
 
 use std::time::Duration;
 
-pub use self::download::{download_file, download_file_stream, Download};
+pub use self::{
+    deprecation::{
+        deprecation_warnings, Deprecation, DeprecationWarnings, DEPRECATED_METHODS,
+        DEPRECATED_PARAMETERS,
+    },
+    download::{download_file, download_file_stream, Download},
+    traffic::TrafficRecord,
+};
+
+#[cfg(feature = "pinned_dns")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "pinned_dns")))]
+pub use self::dns::PinnedDns;
 
 pub(crate) use self::{
-    request::{request_json, request_multipart},
+    gzip::compress as gzip,
+    request::{request_json_failover, request_multipart_failover},
     telegram_response::TelegramResponse,
+    traffic::TrafficHook,
 };
 
+pub(crate) mod deprecation;
+#[cfg(feature = "pinned_dns")]
+mod dns;
 mod download;
+mod gzip;
 mod request;
 mod telegram_response;
+mod traffic;
 
 /// The default Telegram API URL.
 pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
@@ -84,28 +102,102 @@ pub fn default_reqwest_settings() -> reqwest::ClientBuilder {
         .default_headers(headers)
 }
 
+/// Replaces the bot token in the URL of `err` (if any) with `[redacted]`.
+///
+/// Errors returned by this library are already redacted, this function is
+/// useful if you make requests with your own client (e.g. with
+/// [`download_file`]).
+pub fn redact_token(mut err: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = err.url_mut() {
+        redact_token_in_url(url);
+    }
+
+    err
+}
+
+fn redact_token_in_url(url: &mut reqwest::Url) {
+    // Tokens are always in a `bot<token>` path segment.
+    let is_token = |segment: &str| segment.starts_with("bot") && segment.contains(':');
+
+    if url.path().split('/').any(is_token) {
+        let path = url
+            .path()
+            .split('/')
+            .map(|segment| {
+                if is_token(segment) {
+                    "bot[redacted]"
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        url.set_path(&path);
+    }
+}
+
 /// Creates URL for making HTTPS requests. See the [Telegram documentation].
 ///
+/// The path of the `base` is replaced, just like `base.join("/bot<token>/<method>")`
+/// would do, but without parsing a relative URL and with a single allocation
+/// for the path.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::net::{method_url, TELEGRAM_API_URL};
+///
+/// let url = method_url(TELEGRAM_API_URL.parse().unwrap(), "1:TOKEN", "getMe");
+/// assert_eq!(url.as_str(), "https://api.telegram.org/bot1:TOKEN/getMe");
+/// ```
+///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#making-requests
-fn method_url(base: reqwest::Url, token: &str, method_name: &str) -> reqwest::Url {
-    base.join(&format!(
-        "/bot{token}/{method}",
-        token = token,
-        method = method_name
-    ))
-    .expect("failed to format url")
+pub fn method_url(base: reqwest::Url, token: &str, method_name: &str) -> reqwest::Url {
+    let mut path = String::with_capacity("/bot/".len() + token.len() + method_name.len());
+    path.push_str("/bot");
+    path.push_str(token);
+    path.push('/');
+    path.push_str(method_name);
+
+    with_path(base, &path)
 }
 
 /// Creates URL for downloading a file. See the [Telegram documentation].
 ///
+/// The URL is relative to the `base`, just like
+/// `base.join("file/bot<token>/<file_path>")` would do, but without parsing a
+/// relative URL and with a single allocation for the path.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::net::file_url;
+///
+/// let url = file_url("http://localhost:8081/api/".parse().unwrap(), "1:TOKEN", "photos/1.jpg");
+/// assert_eq!(url.as_str(), "http://localhost:8081/api/file/bot1:TOKEN/photos/1.jpg");
+/// ```
+///
 /// [Telegram documentation]: https://core.telegram.org/bots/api#file
-fn file_url(base: reqwest::Url, token: &str, file_path: &str) -> reqwest::Url {
-    base.join(&format!(
-        "file/bot{token}/{file}",
-        token = token,
-        file = file_path
-    ))
-    .expect("failed to format url")
+pub fn file_url(base: reqwest::Url, token: &str, file_path: &str) -> reqwest::Url {
+    // Relative URLs are resolved against the "directory" of the base path
+    let dir = &base.path()[..base.path().rfind('/').map_or(0, |idx| idx + 1)];
+
+    let mut path =
+        String::with_capacity(dir.len() + "file/bot/".len() + token.len() + file_path.len());
+    path.push_str(dir);
+    path.push_str("file/bot");
+    path.push_str(token);
+    path.push('/');
+    path.push_str(file_path);
+
+    with_path(base, &path)
+}
+
+fn with_path(mut url: reqwest::Url, path: &str) -> reqwest::Url {
+    url.set_path(path);
+    url.set_query(None);
+    url.set_fragment(None);
+    url
 }
 
 #[cfg(test)]
@@ -126,6 +218,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn same_as_join() {
+        let bases = [
+            "https://api.telegram.org",
+            "https://example.com/custom/",
+            "https://example.com/custom",
+            "http://localhost:8081/?query#fragment",
+        ];
+        let token = "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao";
+
+        for base in &bases {
+            let base = reqwest::Url::parse(base).unwrap();
+
+            assert_eq!(
+                method_url(base.clone(), token, "getMe"),
+                base.join(&format!("/bot{}/getMe", token)).unwrap()
+            );
+            assert_eq!(
+                file_url(base.clone(), token, "photos/file_0.jpg"),
+                base.join(&format!("file/bot{}/photos/file_0.jpg", token))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn redact_token_test() {
+        let mut url = method_url(
+            reqwest::Url::parse(TELEGRAM_API_URL).unwrap(),
+            "535362388:AAF7-g0gYncWnm5IyfZlpPRqRRv6kNAGlao",
+            "methodName",
+        );
+        redact_token_in_url(&mut url);
+
+        assert_eq!(
+            url.as_str(),
+            "https://api.telegram.org/bot[redacted]/methodName"
+        );
+    }
+
     #[test]
     fn file_url_test() {
         let url = file_url(
@@ -0,0 +1,273 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use once_cell::sync::Lazy;
+
+/// A deprecated method or parameter of the Bot API, which is still supported
+/// by this library along with its replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Deprecation {
+    /// The name of the method (as in [`Payload::NAME`]) or of the parameter
+    /// (e.g. `switch_pm_text`).
+    ///
+    /// [`Payload::NAME`]: crate::requests::Payload::NAME
+    pub name: &'static str,
+
+    /// What should be used instead.
+    pub replacement: &'static str,
+}
+
+/// Deprecated methods known to this library, which have a replacement in
+/// this library.
+///
+/// There are none currently: replacements of the deprecated methods (e.g.
+/// `banChatMember` for [`KickChatMember`]) are not implemented yet.
+///
+/// [`KickChatMember`]: crate::payloads::KickChatMember
+pub const DEPRECATED_METHODS: &[Deprecation] = &[];
+
+/// Deprecated parameters known to this library, which have a replacement in
+/// this library.
+pub const DEPRECATED_PARAMETERS: &[Deprecation] = &[
+    Deprecation {
+        name: "switch_pm_text",
+        replacement: "`button`",
+    },
+    Deprecation {
+        name: "switch_pm_parameter",
+        replacement: "`button`",
+    },
+];
+
+static WARNINGS: Lazy<DeprecationWarnings> = Lazy::new(DeprecationWarnings::new);
+
+/// Returns the process-wide registry of deprecation warnings.
+///
+/// The first time a request uses a [deprecated method] or a [deprecated
+/// parameter], or Telegram mentions a deprecation in the description of a
+/// successful response, a warning with the suggested replacement is logged
+/// (with the `tracing` crate if the `tracing` feature is enabled, with `log`
+/// otherwise). Every method or parameter is reported once per process, unless
+/// it's silenced with the registry.
+///
+/// Only parameters of JSON requests are checked, i.e. parameters of requests
+/// which upload files are not.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::net::deprecation_warnings;
+///
+/// // We know, but can't migrate yet
+/// deprecation_warnings().silence("switch_pm_text");
+/// assert!(deprecation_warnings().is_silenced("switch_pm_text"));
+/// ```
+///
+/// [deprecated method]: DEPRECATED_METHODS
+/// [deprecated parameter]: DEPRECATED_PARAMETERS
+pub fn deprecation_warnings() -> &'static DeprecationWarnings {
+    &WARNINGS
+}
+
+/// Registry of deprecation warnings, see [`deprecation_warnings`].
+#[derive(Debug)]
+pub struct DeprecationWarnings {
+    silenced_all: AtomicBool,
+    silenced: Mutex<HashSet<String>>,
+    warned: Mutex<HashSet<String>>,
+}
+
+impl DeprecationWarnings {
+    fn new() -> Self {
+        Self {
+            silenced_all: AtomicBool::new(false),
+            silenced: Mutex::default(),
+            warned: Mutex::default(),
+        }
+    }
+
+    /// Silences warnings about the method or the parameter `name`, see
+    /// [`Deprecation::name`].
+    ///
+    /// Warnings caused by descriptions of responses are silenced by the name
+    /// of the method.
+    pub fn silence(&self, name: &str) {
+        self.silenced.lock().unwrap().insert(name.to_owned());
+    }
+
+    /// Silences all deprecation warnings.
+    pub fn silence_all(&self) {
+        self.silenced_all.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if warnings about `name` are silenced.
+    pub fn is_silenced(&self, name: &str) -> bool {
+        self.silenced_all.load(Ordering::Relaxed) || self.silenced.lock().unwrap().contains(name)
+    }
+
+    /// Returns names of methods and parameters which were reported (or would
+    /// have been reported, if they weren't silenced).
+    pub fn warned(&self) -> Vec<String> {
+        self.warned.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Returns `true` if `name` should be reported now, i.e. if it wasn't
+    /// reported before and isn't silenced.
+    fn first(&self, name: &str) -> bool {
+        let new = self.warned.lock().unwrap().insert(name.to_owned());
+        new && !self.is_silenced(name)
+    }
+
+    fn has_unreported(&self, name: &str) -> bool {
+        !self.warned.lock().unwrap().contains(name)
+    }
+
+    /// Reports deprecated `method` and parameters used in its JSON `params`.
+    pub(crate) fn check_request(&self, method: &str, params: Option<&[u8]>) {
+        if self.silenced_all.load(Ordering::Relaxed) {
+            return;
+        }
+
+        for dep in DEPRECATED_METHODS {
+            if dep.name == method && self.first(dep.name) {
+                warn(
+                    dep.name,
+                    format_args!(
+                        "`{}` is deprecated, use {} instead",
+                        dep.name, dep.replacement
+                    ),
+                );
+            }
+        }
+
+        let params = match params {
+            Some(params) => params,
+            None => return,
+        };
+        for dep in DEPRECATED_PARAMETERS {
+            // `"name":` can't appear inside of a JSON string, since the quotes would be
+            // escaped
+            if self.has_unreported(dep.name)
+                && contains(params, &format!("\"{}\":", dep.name))
+                && self.first(dep.name)
+            {
+                warn(
+                    dep.name,
+                    format_args!(
+                        "The `{}` parameter (used with `{}`) is deprecated, use {} instead",
+                        dep.name, method, dep.replacement
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Reports the `description` of a successful response to `method`, if it
+    /// mentions a deprecation.
+    pub(crate) fn check_response(&self, method: &str, description: Option<&str>) {
+        let description = match description {
+            Some(description) => description,
+            None => return,
+        };
+
+        if description.to_lowercase().contains("deprecated") && self.first(method) {
+            warn(
+                method,
+                format_args!("Telegram responded to `{}` with: {}", method, description),
+            );
+        }
+    }
+}
+
+/// Logs the warning about the method or the parameter `name`.
+fn warn(name: &str, message: fmt::Arguments<'_>) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(deprecated = name, "{}", message);
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = name;
+        log::warn!("{}", message);
+    }
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+pub(crate) fn check_request(method: &str, params: Option<&[u8]>) {
+    WARNINGS.check_request(method, params)
+}
+
+pub(crate) fn check_response(method: &str, description: Option<&str>) {
+    WARNINGS.check_response(method, description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warned(warnings: &DeprecationWarnings) -> Vec<String> {
+        let mut warned = warnings.warned();
+        warned.sort();
+        warned
+    }
+
+    #[test]
+    fn request() {
+        let warnings = DeprecationWarnings::new();
+
+        warnings.check_request(
+            "AnswerInlineQuery",
+            Some(br#"{"next_offset":"switch_pm_text"}"#),
+        );
+        warnings.check_request(
+            "AnswerInlineQuery",
+            Some(br#"{"next_offset":"\"switch_pm_text\":"}"#),
+        );
+        // Not reported, since `banChatMember` is not implemented
+        warnings.check_request("KickChatMember", None);
+        assert!(warned(&warnings).is_empty());
+
+        warnings.check_request(
+            "AnswerInlineQuery",
+            Some(br#"{"switch_pm_text":"Settings","switch_pm_parameter":"settings"}"#),
+        );
+        assert_eq!(warned(&warnings), ["switch_pm_parameter", "switch_pm_text"]);
+
+        assert!(!warnings.first("switch_pm_text"));
+    }
+
+    #[test]
+    fn response() {
+        let warnings = DeprecationWarnings::new();
+
+        warnings.check_response("SetWebhook", Some("Webhook was set"));
+        assert!(warned(&warnings).is_empty());
+
+        warnings.check_response("SetWebhook", Some("The parameter is Deprecated"));
+        assert_eq!(warned(&warnings), ["SetWebhook"]);
+    }
+
+    #[test]
+    fn silence() {
+        let warnings = DeprecationWarnings::new();
+
+        warnings.silence("switch_pm_text");
+        assert!(warnings.is_silenced("switch_pm_text"));
+        assert!(!warnings.is_silenced("switch_pm_parameter"));
+        assert!(!warnings.first("switch_pm_text"));
+        assert!(warnings.first("switch_pm_parameter"));
+
+        warnings.silence_all();
+        assert!(warnings.is_silenced("SetWebhook"));
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::Duration,
+};
+
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+use crate::errors::ResolveError;
+
+/// The host of the default Telegram API URL.
+const TELEGRAM_API_HOST: &str = "api.telegram.org";
+
+/// DNS resolver which pins addresses of Telegram API servers.
+///
+/// Addresses of pinned hosts (by default only `api.telegram.org`) are resolved
+/// once, on the first request, and then reused for all subsequent requests,
+/// being refreshed in the background (every 5 minutes by default). If a
+/// refresh fails, the previously resolved addresses are kept, so a broken
+/// resolver (which is not uncommon in containers) doesn't break the bot.
+///
+/// Addresses can also be supplied statically with [`PinnedDns::static_addrs`]
+/// to avoid DNS resolution entirely.
+///
+/// Other hosts (e.g. proxies) are resolved as usual, without pinning.
+///
+/// Resolution failures are returned as [`RequestError::DnsError`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{net::PinnedDns, Bot};
+///
+/// let addr = "149.154.167.220:443".parse().unwrap();
+/// let dns = PinnedDns::new().static_addrs("api.telegram.org", vec![addr]);
+/// let bot = Bot::with_pinned_dns("TOKEN", dns);
+/// # let _ = bot;
+/// ```
+///
+/// [`RequestError::DnsError`]: crate::RequestError::DnsError
+#[derive(Clone)]
+pub struct PinnedDns {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    hosts: Mutex<HashMap<String, Host>>,
+    refresh_interval: Mutex<Duration>,
+    refreshing: AtomicBool,
+}
+
+#[derive(Clone, Debug)]
+struct Host {
+    /// Resolved addresses, empty if the host wasn't resolved yet.
+    addrs: Vec<SocketAddr>,
+    /// `true` if addrs were supplied by the user and must not be refreshed.
+    is_static: bool,
+}
+
+impl PinnedDns {
+    /// Creates a resolver which pins `api.telegram.org`.
+    pub fn new() -> Self {
+        Self::empty().pin(TELEGRAM_API_HOST)
+    }
+
+    /// Creates a resolver which doesn't pin any hosts.
+    pub fn empty() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                hosts: Mutex::new(HashMap::new()),
+                refresh_interval: Mutex::new(Duration::from_secs(5 * 60)),
+                refreshing: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Pins addresses of the `host`, e.g. of a self-hosted bot API server.
+    pub fn pin<S>(self, host: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner.hosts.lock().unwrap().insert(
+            host.into(),
+            Host {
+                addrs: Vec::new(),
+                is_static: false,
+            },
+        );
+        self
+    }
+
+    /// Sets addresses of the `host`, these are never refreshed.
+    ///
+    /// Ports of the addresses are ignored, the port of the request URL is used
+    /// instead.
+    pub fn static_addrs<S>(self, host: S, addrs: Vec<SocketAddr>) -> Self
+    where
+        S: Into<String>,
+    {
+        self.inner.hosts.lock().unwrap().insert(
+            host.into(),
+            Host {
+                addrs,
+                is_static: true,
+            },
+        );
+        self
+    }
+
+    /// Sets how often addresses of pinned hosts are refreshed, 5 minutes by
+    /// default.
+    ///
+    /// The interval is shared by all clones of the resolver, a running
+    /// refresh uses the new interval after the current one elapses.
+    pub fn refresh_interval(self, interval: Duration) -> Self {
+        *self.inner.refresh_interval.lock().unwrap() = interval;
+        self
+    }
+
+    /// Resolves all pinned hosts now, e.g. to detect a broken resolver before
+    /// starting the bot.
+    pub async fn resolve_now(&self) -> Result<(), ResolveError> {
+        for host in self.inner.refreshable() {
+            let addrs = lookup(&host).await?;
+            self.inner.store(&host, addrs);
+        }
+
+        Ok(())
+    }
+
+    /// Returns currently pinned addresses of the `host`, `None` if the host is
+    /// not pinned or wasn't resolved yet.
+    pub fn addrs(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        self.inner.cached(host)
+    }
+
+    fn spawn_refresh(&self) {
+        if self.inner.refreshing.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(refresh(Arc::downgrade(&self.inner)));
+            }
+            Err(_) => self.inner.refreshing.store(false, Ordering::Release),
+        }
+    }
+}
+
+impl Default for PinnedDns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for PinnedDns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PinnedDns")
+            .field("hosts", &*self.inner.hosts.lock().unwrap())
+            .field("refresh_interval", &self.inner.refresh_interval())
+            .finish()
+    }
+}
+
+impl Inner {
+    fn refresh_interval(&self) -> Duration {
+        *self.refresh_interval.lock().unwrap()
+    }
+
+    /// Returns the `host` if it's pinned.
+    fn get(&self, host: &str) -> Option<Host> {
+        self.hosts.lock().unwrap().get(host).cloned()
+    }
+
+    fn cached(&self, host: &str) -> Option<Vec<SocketAddr>> {
+        self.get(host)
+            .map(|h| h.addrs)
+            .filter(|addrs| !addrs.is_empty())
+    }
+
+    fn store(&self, host: &str, addrs: Vec<SocketAddr>) {
+        if let Some(h) = self.hosts.lock().unwrap().get_mut(host) {
+            h.addrs = addrs;
+        }
+    }
+
+    /// Returns all pinned hosts which are not static.
+    fn refreshable(&self) -> Vec<String> {
+        let hosts = self.hosts.lock().unwrap();
+        hosts
+            .iter()
+            .filter(|(_, h)| !h.is_static)
+            .map(|(host, _)| host.clone())
+            .collect()
+    }
+}
+
+impl Resolve for PinnedDns {
+    fn resolve(&self, name: Name) -> Resolving {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let host = name.as_str();
+            let addrs = match this.inner.get(host) {
+                // Not pinned, resolve as usual
+                None => lookup(host).await?,
+                Some(Host {
+                    addrs,
+                    is_static: true,
+                }) if addrs.is_empty() => return Err(ResolveError::empty(host).into()),
+                Some(Host { addrs, .. }) if !addrs.is_empty() => {
+                    this.spawn_refresh();
+                    addrs
+                }
+                Some(_) => {
+                    let addrs = lookup(host).await?;
+                    this.inner.store(host, addrs.clone());
+                    this.spawn_refresh();
+                    addrs
+                }
+            };
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+async fn refresh(inner: Weak<Inner>) {
+    loop {
+        let interval = match inner.upgrade() {
+            Some(inner) => inner.refresh_interval(),
+            None => return,
+        };
+        tokio::time::sleep(interval).await;
+
+        let inner = match inner.upgrade() {
+            Some(inner) => inner,
+            // All clients using the resolver were dropped
+            None => return,
+        };
+
+        for host in inner.refreshable() {
+            match lookup(&host).await {
+                Ok(addrs) => inner.store(&host, addrs),
+                Err(err) => log::warn!("{}, using previously resolved addresses", err),
+            }
+        }
+    }
+}
+
+async fn lookup(host: &str) -> Result<Vec<SocketAddr>, ResolveError> {
+    let addrs: Vec<_> = tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|err| ResolveError::new(host, err))?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(ResolveError::empty(host));
+    }
+
+    Ok(addrs)
+}
+
+/// Finds a [`ResolveError`] in the source chain of `err`.
+pub(crate) fn find_resolve_error(err: &(dyn StdError + 'static)) -> Option<ResolveError> {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if let Some(err) = err.downcast_ref::<ResolveError>() {
+            return Some(err.clone());
+        }
+        source = err.source();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_error_is_found() {
+        let dns = PinnedDns::empty().static_addrs("api.telegram.org", Vec::new());
+        let client = reqwest::Client::builder()
+            .dns_resolver(Arc::new(dns))
+            .build()
+            .unwrap();
+
+        let rt = crate::test_utils::rt();
+        let err = rt
+            .block_on(client.get("http://api.telegram.org/").send())
+            .unwrap_err();
+
+        let err = find_resolve_error(&err).expect("resolve error in the source chain");
+        assert_eq!(err.host(), "api.telegram.org");
+    }
+
+    #[test]
+    fn static_addrs() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let dns = PinnedDns::new().static_addrs("example.com", vec![addr]);
+
+        assert_eq!(dns.addrs("example.com"), Some(vec![addr]));
+        assert_eq!(dns.addrs(TELEGRAM_API_HOST), None);
+        assert_eq!(dns.inner.refreshable(), [TELEGRAM_API_HOST]);
+    }
+
+    #[test]
+    fn refresh_interval_is_shared() {
+        let dns = PinnedDns::new();
+        let client = dns.clone();
+
+        let dns = dns.refresh_interval(Duration::from_secs(1));
+        assert_eq!(client.inner.refresh_interval(), Duration::from_secs(1));
+        assert_eq!(dns.inner.refresh_interval(), Duration::from_secs(1));
+    }
+}
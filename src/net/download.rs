@@ -9,7 +9,10 @@ use futures::{
 use reqwest::{Client, Response, Url};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
-use crate::{errors::DownloadError, net::file_url};
+use crate::{
+    errors::DownloadError,
+    net::{file_url, redact_token},
+};
 
 /// A trait for downloading files from Telegram.
 pub trait Download<'w>
@@ -42,9 +45,9 @@ pub trait Download<'w>
     /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
     /// let bot = Bot::new("TOKEN");
     ///
-    /// let TgFile { file_path, .. } = bot.get_file("*file_id*").send().await?;
-    /// let mut file = File::create("/tmp/test.png").await?;
-    /// bot.download_file(&file_path, &mut file).await?;
+    /// let file: TgFile = bot.get_file("*file_id*").send().await?;
+    /// let mut dst = File::create("/tmp/test.png").await?;
+    /// bot.download_file(file.path()?, &mut dst).await?;
     /// # Ok(()) }
     /// ```
     ///
@@ -98,9 +101,11 @@ where
         .get(file_url(api_url, token, path))
         .send()
         .then(move |r| async move {
-            let mut res = r?.error_for_status()?;
+            let mut res = r
+                .and_then(Response::error_for_status)
+                .map_err(redact_token)?;
 
-            while let Some(chunk) = res.chunk().await? {
+            while let Some(chunk) = res.chunk().await.map_err(redact_token)? {
                 dst.write_all(&chunk).await?;
             }
 
@@ -126,11 +131,11 @@ pub fn download_file_stream(
         .flat_map(|res| match res.and_then(Response::error_for_status) {
             Ok(res) => Either::Left(unfold(res, |mut res| async {
                 match res.chunk().await {
-                    Err(err) => Some((Err(err), res)),
+                    Err(err) => Some((Err(redact_token(err)), res)),
                     Ok(Some(c)) => Some((Ok(c), res)),
                     Ok(None) => None,
                 }
             })),
-            Err(err) => Either::Right(once(ready(Err(err)))),
+            Err(err) => Either::Right(once(ready(Err(redact_token(err))))),
         })
 }
@@ -0,0 +1,436 @@
+//! A small gzip encoder for compressing request bodies.
+//!
+//! Uses a single DEFLATE block with the fixed Huffman codes and LZ77 with hash
+//! chains. It doesn't compress as well as `zlib`, but JSON (which has lots of
+//! repeated keys) still shrinks a lot, and it saves a dependency. Data which
+//! doesn't compress (e.g. base64 blobs) is written as stored blocks, so it
+//! grows only by a few bytes.
+
+/// Size of the LZ77 window.
+const WINDOW: usize = 32 * 1024;
+
+/// Minimal and maximal lengths of a match.
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+/// Maximal number of previous positions checked for a match.
+const MAX_CHAIN: usize = 64;
+
+const HASH_BITS: u32 = 15;
+
+/// Maximal size of a stored (uncompressed) block.
+const MAX_STORED: usize = 0xffff;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Compresses `data` into the gzip format.
+pub(crate) fn compress(data: &[u8]) -> Vec<u8> {
+    // Header: magic, deflate, no flags, no mtime, no extra flags, unknown OS
+    let mut out = vec![0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff];
+
+    let fixed = fixed_block(data);
+    if fixed.len() <= stored_len(data.len()) {
+        out.extend_from_slice(&fixed);
+    } else {
+        write_stored(data, &mut out);
+    }
+
+    out.extend_from_slice(&crc32(data).to_le_bytes());
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out
+}
+
+/// Compresses `data` into a single final block with the fixed codes.
+fn fixed_block(data: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter {
+        out: Vec::new(),
+        acc: 0,
+        len: 0,
+    };
+    bits.write(1, 1);
+    bits.write(1, 2);
+    deflate(data, &mut bits);
+    write_literal(&mut bits, 256);
+    bits.finish()
+}
+
+/// Returns the size of `len` bytes written as stored blocks.
+fn stored_len(len: usize) -> usize {
+    // Every block has a 1-byte header (padded to a byte boundary) and 4 bytes
+    // of `LEN` and `NLEN`, an empty input still needs a block
+    let blocks = len.div_ceil(MAX_STORED).max(1);
+    len + blocks * 5
+}
+
+/// Writes `data` as stored blocks, the last one is final.
+fn write_stored(data: &[u8], out: &mut Vec<u8>) {
+    let mut rest = data;
+    loop {
+        let (block, tail) = rest.split_at(rest.len().min(MAX_STORED));
+        let last = tail.is_empty();
+
+        // `BFINAL`, `BTYPE` = 00 and padding
+        out.push(last as u8);
+        out.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        out.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        out.extend_from_slice(block);
+
+        if last {
+            break;
+        }
+        rest = tail;
+    }
+}
+
+/// Writes LZ77 matches and literals of `data`.
+fn deflate(data: &[u8], bits: &mut BitWriter) {
+    let mut chains = Chains {
+        head: vec![usize::MAX; 1 << HASH_BITS],
+        prev: vec![usize::MAX; WINDOW],
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let (len, dist) = chains.longest_match(data, i);
+
+        if len >= MIN_MATCH {
+            write_match(bits, len, dist);
+            for j in i..i + len {
+                chains.insert(data, j);
+            }
+            i += len;
+        } else {
+            write_literal(bits, u16::from(data[i]));
+            chains.insert(data, i);
+            i += 1;
+        }
+    }
+}
+
+/// Hash chains of positions of 3-byte sequences.
+struct Chains {
+    /// The last position of every hash.
+    head: Vec<usize>,
+    /// The previous position with the same hash, for every position in the
+    /// window.
+    prev: Vec<usize>,
+}
+
+impl Chains {
+    fn hash(data: &[u8], i: usize) -> usize {
+        let v = u32::from(data[i]) << 16 | u32::from(data[i + 1]) << 8 | u32::from(data[i + 2]);
+        (v.wrapping_mul(0x9E37_79B1) >> (32 - HASH_BITS)) as usize
+    }
+
+    fn insert(&mut self, data: &[u8], i: usize) {
+        if i + MIN_MATCH <= data.len() {
+            let h = Self::hash(data, i);
+            self.prev[i % WINDOW] = self.head[h];
+            self.head[h] = i;
+        }
+    }
+
+    /// Returns the length and distance of the longest match for `data[i..]`.
+    fn longest_match(&self, data: &[u8], i: usize) -> (usize, usize) {
+        if i + MIN_MATCH > data.len() {
+            return (0, 0);
+        }
+
+        let max = (data.len() - i).min(MAX_MATCH);
+        let (mut best_len, mut best_dist) = (0, 0);
+        let mut candidate = self.head[Self::hash(data, i)];
+
+        for _ in 0..MAX_CHAIN {
+            if candidate == usize::MAX || i - candidate > WINDOW {
+                break;
+            }
+
+            let len = data[candidate..]
+                .iter()
+                .zip(&data[i..i + max])
+                .take_while(|(a, b)| a == b)
+                .count();
+            if len > best_len {
+                best_len = len;
+                best_dist = i - candidate;
+                if len == max {
+                    break;
+                }
+            }
+
+            let next = self.prev[candidate % WINDOW];
+            // Entries of `prev` are overwritten when the window moves, so a
+            // chain must always go back
+            if next == usize::MAX || next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+
+        (best_len, best_dist)
+    }
+}
+
+fn write_literal(bits: &mut BitWriter, lit: u16) {
+    let (code, len) = match lit {
+        0..=143 => (0x30 + lit, 8),
+        144..=255 => (0x190 + lit - 144, 9),
+        256..=279 => (lit - 256, 7),
+        _ => (0xc0 + lit - 280, 8),
+    };
+    bits.write_code(code, len);
+}
+
+fn write_match(bits: &mut BitWriter, len: usize, dist: usize) {
+    let code = LENGTH_BASE
+        .iter()
+        .rposition(|&b| usize::from(b) <= len)
+        .unwrap();
+    write_literal(bits, 257 + code as u16);
+    bits.write(
+        (len - usize::from(LENGTH_BASE[code])) as u32,
+        LENGTH_EXTRA[code],
+    );
+
+    let code = DIST_BASE
+        .iter()
+        .rposition(|&b| usize::from(b) <= dist)
+        .unwrap();
+    bits.write_code(code as u16, 5);
+    bits.write(
+        (dist - usize::from(DIST_BASE[code])) as u32,
+        DIST_EXTRA[code],
+    );
+}
+
+/// Writes bits starting from the least significant one, as DEFLATE requires.
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u32,
+    len: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, len: u8) {
+        for bit in 0..len {
+            self.acc |= ((value >> bit) & 1) << self.len;
+            self.len += 1;
+            if self.len == 8 {
+                self.out.push(self.acc as u8);
+                self.acc = 0;
+                self.len = 0;
+            }
+        }
+    }
+
+    /// Writes a Huffman code, which are packed starting from the most
+    /// significant bit.
+    fn write_code(&mut self, code: u16, len: u8) {
+        let reversed = (code.reverse_bits() >> (16 - len)) as u32;
+        self.write(reversed, len);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.out.push(self.acc as u8);
+        }
+        self.out
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (!(crc & 1)).wrapping_add(1));
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decompresses the output of `compress`, i.e. gzip without optional
+    /// header fields with stored blocks and blocks with the fixed codes.
+    fn decompress(gzip: &[u8]) -> Vec<u8> {
+        assert_eq!(gzip[..10], [0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff]);
+        let (body, trailer) = gzip[10..].split_at(gzip.len() - 18);
+
+        let mut bits = BitReader { data: body, pos: 0 };
+        let mut out = Vec::new();
+        loop {
+            let last = bits.read(1) == 1;
+            match bits.read(2) {
+                0 => {
+                    bits.align();
+                    let len = bits.read(16) as usize;
+                    assert_eq!(bits.read(16) as usize, !len & 0xffff);
+                    let start = bits.pos / 8;
+                    out.extend_from_slice(&body[start..start + len]);
+                    bits.pos += len * 8;
+                }
+                1 => loop {
+                    let lit = bits.read_literal();
+                    match lit {
+                        0..=255 => out.push(lit as u8),
+                        256 => break,
+                        _ => {
+                            let code = usize::from(lit - 257);
+                            let len = usize::from(LENGTH_BASE[code])
+                                + bits.read(LENGTH_EXTRA[code]) as usize;
+                            let code = bits.read_code(5) as usize;
+                            let dist =
+                                usize::from(DIST_BASE[code]) + bits.read(DIST_EXTRA[code]) as usize;
+                            assert!(dist <= WINDOW && dist <= out.len());
+                            for _ in 0..len {
+                                out.push(out[out.len() - dist]);
+                            }
+                        }
+                    }
+                },
+                ty => panic!("unexpected block type {}", ty),
+            }
+            if last {
+                break;
+            }
+        }
+        assert_eq!((bits.pos + 7) / 8, body.len());
+
+        assert_eq!(trailer[..4], crc32(&out).to_le_bytes());
+        assert_eq!(trailer[4..], (out.len() as u32).to_le_bytes());
+        out
+    }
+
+    struct BitReader<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl BitReader<'_> {
+        fn read(&mut self, len: u8) -> u32 {
+            let mut value = 0;
+            for bit in 0..len {
+                let byte = self.data[self.pos / 8];
+                value |= u32::from((byte >> (self.pos % 8)) & 1) << bit;
+                self.pos += 1;
+            }
+            value
+        }
+
+        fn read_code(&mut self, len: u8) -> u16 {
+            (0..len).fold(0, |code, _| code << 1 | self.read(1) as u16)
+        }
+
+        fn read_literal(&mut self) -> u16 {
+            let code = self.read_code(7);
+            if code <= 0x17 {
+                return 256 + code;
+            }
+            let code = code << 1 | self.read(1) as u16;
+            match code {
+                0x30..=0xbf => code - 0x30,
+                0xc0..=0xc7 => 280 + code - 0xc0,
+                _ => 144 + (code << 1 | self.read(1) as u16) - 0x190,
+            }
+        }
+
+        fn align(&mut self) {
+            self.pos = (self.pos + 7) / 8 * 8;
+        }
+    }
+
+    /// Deterministic pseudo-random bytes (xorshift).
+    fn random(len: usize) -> Vec<u8> {
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crc() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn compresses() {
+        // Decompressed with `gzip -d`
+        assert_eq!(
+            compress(b"abcabcabc"),
+            [
+                0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 0xff, 0x4b, 0x4c, 0x4a, 0x86, 0x20, 0, 0x18, 0x48,
+                0x2d, 0x46, 9, 0, 0, 0
+            ]
+        );
+
+        let json = br#"{"results":[{"type":"article","id":"1"},{"type":"article","id":"2"}]}"#;
+        let compressed = compress(&json.repeat(10));
+        assert!(compressed.len() < json.len() * 2);
+
+        assert_eq!(compress(b"").len(), 20);
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        assert_eq!(decompress(&compress(b"")), b"");
+    }
+
+    #[test]
+    fn round_trip_random() {
+        for &len in &[1, 100, MAX_STORED, MAX_STORED + 1, 200_000] {
+            let data = random(len);
+            let compressed = compress(&data);
+            assert_eq!(decompress(&compressed), data);
+            // At most stored blocks plus the header and the trailer
+            assert!(compressed.len() <= stored_len(len) + 18);
+        }
+    }
+
+    #[test]
+    fn round_trip_long_run() {
+        let data = vec![b'a'; 100_000];
+        let compressed = compress(&data);
+        assert_eq!(decompress(&compressed), data);
+        assert!(compressed.len() < 1000);
+    }
+
+    #[test]
+    fn round_trip_beyond_window() {
+        // Repeats which are closer than the window, exactly at its size and
+        // further than it
+        for &period in &[WINDOW / 2, WINDOW, WINDOW + 1, 40 * 1024] {
+            let data = random(period).repeat(3);
+            assert_eq!(decompress(&compress(&data)), data);
+        }
+
+        let json = br#"{"type":"article","id":"1","title":"Some title"},"#;
+        let data = json.repeat(2000);
+        assert!(data.len() > WINDOW);
+        let compressed = compress(&data);
+        assert_eq!(decompress(&compressed), data);
+        assert!(compressed.len() < data.len() / 10);
+    }
+}
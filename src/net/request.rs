@@ -1,67 +1,420 @@
-use std::time::Duration;
+use std::{future::Future, io, time::Duration};
 
 use reqwest::{
-    header::{HeaderValue, CONTENT_TYPE},
+    header::{HeaderValue, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE},
     Client, Response,
 };
 use serde::de::DeserializeOwned;
 
-use crate::{net::TelegramResponse, requests::ResponseResult, RequestError};
+use crate::{
+    net::{traffic::TrafficHook, TelegramResponse, TrafficRecord},
+    requests::ResponseWithMeta,
+    RequestError,
+};
 
 const DELAY_ON_SERVER_ERROR: Duration = Duration::from_secs(10);
 
+/// The delay before the first retry of an upload, it doubles with every retry
+/// up to `MAX_DELAY_ON_UPLOAD_RETRY`.
+const DELAY_ON_UPLOAD_RETRY: Duration = Duration::from_secs(1);
+const MAX_DELAY_ON_UPLOAD_RETRY: Duration = Duration::from_secs(30);
+
 pub async fn request_multipart<T>(
     client: &Client,
     token: &str,
     api_url: reqwest::Url,
-    method_name: &str,
+    method_name: &'static str,
     params: reqwest::multipart::Form,
-) -> ResponseResult<T>
+    hook: Option<&TrafficHook>,
+) -> ResponseWithMeta<T>
 where
     T: DeserializeOwned,
 {
-    let response = client
+    let request = match client
         .post(crate::net::method_url(api_url, token, method_name))
         .multipart(params)
-        .send()
-        .await
-        .map_err(RequestError::NetworkError)?;
+        .build()
+    {
+        Ok(request) => request,
+        Err(err) => return ResponseWithMeta::error(network_error(err)),
+    };
+
+    // `Content-Length` is only set when the size of the form is known
+    let request_size = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|len| len.to_str().ok()?.parse().ok());
 
-    process_response(response).await
+    let response = match client.execute(request).await {
+        Ok(response) => response,
+        Err(err) => return ResponseWithMeta::error(network_error(err)),
+    };
+
+    let res = process_response(response, |response_size| {
+        report(hook, method_name, request_size, response_size)
+    })
+    .await;
+    check_deprecation(method_name, &res);
+    res
 }
 
+/// Sends a JSON request, `params` must be compressed with gzip if `gzip` is
+/// `true`.
 pub async fn request_json<T>(
     client: &Client,
     token: &str,
     api_url: reqwest::Url,
-    method_name: &str,
+    method_name: &'static str,
     params: Vec<u8>,
-) -> ResponseResult<T>
+    gzip: bool,
+    hook: Option<&TrafficHook>,
+) -> ResponseWithMeta<T>
 where
     T: DeserializeOwned,
 {
-    let response = client
+    let request_size = params.len() as u64;
+    let mut request = client
         .post(crate::net::method_url(api_url, token, method_name))
-        .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-        .body(params)
-        .send()
-        .await
-        .map_err(RequestError::NetworkError)?;
+        .header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    if gzip {
+        request = request.header(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+    }
 
-    process_response(response).await
+    let response = match request.body(params).send().await {
+        Ok(response) => response,
+        Err(err) => return ResponseWithMeta::error(network_error(err)),
+    };
+
+    let res = process_response(response, |response_size| {
+        report(hook, method_name, Some(request_size), response_size)
+    })
+    .await;
+    check_deprecation(method_name, &res);
+    res
 }
 
-async fn process_response<T>(response: Response) -> ResponseResult<T>
+/// Sends a JSON request to the first of `api_urls` which is reachable.
+///
+/// The next URL is only tried if a connection to the previous one couldn't be
+/// established, i.e. the request is never sent twice.
+///
+/// If `params` are at least `gzip_threshold` bytes long, they are compressed
+/// for the primary URL, unless it's [`TELEGRAM_API_URL`]. Fallbacks always get
+/// them uncompressed since they may not accept compressed requests.
+///
+/// [`TELEGRAM_API_URL`]: crate::net::TELEGRAM_API_URL
+pub async fn request_json_failover<T>(
+    client: &Client,
+    token: &str,
+    api_urls: &[reqwest::Url],
+    method_name: &'static str,
+    params: Vec<u8>,
+    gzip_threshold: Option<usize>,
+    hook: Option<&TrafficHook>,
+) -> ResponseWithMeta<T>
 where
     T: DeserializeOwned,
+{
+    let (primary, fallbacks) = api_urls.split_first().expect("api urls can't be empty");
+
+    let gzip = matches!(gzip_threshold, Some(min) if params.len() >= min) && !is_official(primary);
+    let (body, params) = match (gzip, fallbacks.is_empty()) {
+        (true, true) => (crate::net::gzip(&params), None),
+        (true, false) => (crate::net::gzip(&params), Some(params)),
+        (false, true) => (params, None),
+        (false, false) => (params.clone(), Some(params)),
+    };
+
+    // The response is dropped before trying fallbacks, so that the future is
+    // `Send` even if `T` isn't. `params` are only kept if there are fallbacks
+    let params = {
+        let res = request_json(
+            client,
+            token,
+            primary.clone(),
+            method_name,
+            body,
+            gzip,
+            hook,
+        )
+        .await;
+        match params {
+            Some(params) if is_unreachable(&res, primary) => params,
+            _ => return res,
+        }
+    };
+
+    let (last, fallbacks) = fallbacks.split_last().unwrap();
+    for api_url in fallbacks {
+        let res = request_json(
+            client,
+            token,
+            api_url.clone(),
+            method_name,
+            params.clone(),
+            false,
+            hook,
+        )
+        .await;
+        if !is_unreachable(&res, api_url) {
+            return res;
+        }
+    }
+
+    request_json(
+        client,
+        token,
+        last.clone(),
+        method_name,
+        params,
+        false,
+        hook,
+    )
+    .await
+}
+
+/// Returns `true` if `api_url` is the official Telegram Bot API server, which
+/// doesn't accept compressed requests.
+fn is_official(api_url: &reqwest::Url) -> bool {
+    api_url.as_str().trim_end_matches('/') == crate::net::TELEGRAM_API_URL
+}
+
+/// Sends a multipart request to the first of `api_urls` which is reachable,
+/// see [`request_json_failover`].
+///
+/// If the request fails because of a network error (e.g. the connection was
+/// lost while uploading a file), it's retried up to `retries` times with the
+/// same URL.
+///
+/// Since forms can't be reused, `forms` must yield a form for every attempt,
+/// i.e. one for every URL plus one for every retry.
+pub async fn request_multipart_failover<T, F>(
+    client: &Client,
+    token: &str,
+    api_urls: &[reqwest::Url],
+    method_name: &'static str,
+    mut forms: F,
+    mut retries: u32,
+    hook: Option<&TrafficHook>,
+) -> ResponseWithMeta<T>
+where
+    T: DeserializeOwned,
+    F: Iterator,
+    F::Item: Future<Output = io::Result<reqwest::multipart::Form>>,
+{
+    assert!(!api_urls.is_empty(), "api urls can't be empty");
+    let mut form = || {
+        let form = forms.next().expect("a form for every attempt");
+        async { form.await.map_err(RequestError::Io) }
+    };
+
+    let mut idx = 0;
+    let mut delay = DELAY_ON_UPLOAD_RETRY;
+    loop {
+        let api_url = &api_urls[idx];
+        let form = match form().await {
+            Ok(form) => form,
+            Err(err) => return ResponseWithMeta::error(err),
+        };
+
+        // The response is dropped before sleeping, so that the future is `Send`
+        // even if `T` isn't
+        {
+            let res =
+                request_multipart(client, token, api_url.clone(), method_name, form, hook).await;
+            if idx + 1 < api_urls.len() && is_unreachable(&res, api_url) {
+                idx += 1;
+                continue;
+            }
+
+            match &res.result {
+                Err(RequestError::NetworkError(err)) if retries > 0 => log::warn!(
+                    "Uploading `{}` failed: {}, retrying in {:?}",
+                    method_name,
+                    err,
+                    delay
+                ),
+                _ => return res,
+            }
+        }
+
+        retries -= 1;
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(MAX_DELAY_ON_UPLOAD_RETRY);
+    }
+}
+
+/// Returns `true` if `res` is an error caused by inability to connect to the
+/// server.
+fn is_unreachable<T>(res: &ResponseWithMeta<T>, api_url: &reqwest::Url) -> bool {
+    let err: &dyn std::fmt::Display = match &res.result {
+        Err(RequestError::NetworkError(err)) if err.is_connect() => err,
+        Err(RequestError::DnsError(err)) => err,
+        _ => return false,
+    };
+
+    log::warn!(
+        "Telegram API server {} is unreachable: {}, trying the next one",
+        api_url,
+        err
+    );
+    true
+}
+
+async fn process_response<T, F>(response: Response, on_body: F) -> ResponseWithMeta<T>
+where
+    T: DeserializeOwned,
+    F: FnOnce(u64),
 {
     if response.status().is_server_error() {
         tokio::time::sleep(DELAY_ON_SERVER_ERROR).await;
     }
 
-    serde_json::from_str::<TelegramResponse<T>>(
-        &response.text().await.map_err(RequestError::NetworkError)?,
-    )
-    .map_err(RequestError::InvalidJson)?
-    .into()
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(err) => return ResponseWithMeta::error(network_error(err)),
+    };
+    on_body(body.len() as u64);
+
+    match serde_json::from_str::<TelegramResponse<T>>(&body) {
+        Ok(response) => response.into(),
+        Err(err) => ResponseWithMeta::error(RequestError::InvalidJson(err)),
+    }
+}
+
+fn check_deprecation<T>(method_name: &str, res: &ResponseWithMeta<T>) {
+    if res.result.is_ok() {
+        crate::net::deprecation::check_response(method_name, res.description.as_deref());
+    }
+}
+
+fn report(
+    hook: Option<&TrafficHook>,
+    method: &'static str,
+    request_size: Option<u64>,
+    response_size: u64,
+) {
+    if let Some(hook) = hook {
+        hook.report(&TrafficRecord {
+            method,
+            request_size,
+            response_size,
+        });
+    }
+}
+
+fn network_error(err: reqwest::Error) -> RequestError {
+    #[cfg(feature = "pinned_dns")]
+    if let Some(err) = crate::net::dns::find_resolve_error(&err) {
+        return RequestError::DnsError(err);
+    }
+
+    RequestError::NetworkError(crate::net::redact_token(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::future;
+    use reqwest::multipart::Form;
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+    use crate::test_utils::{read_body, read_request, respond};
+
+    #[test]
+    fn multipart_retries() {
+        let rt = crate::test_utils::rt();
+
+        rt.block_on(async {
+            tokio::time::pause();
+            let started = tokio::time::Instant::now();
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url: reqwest::Url = format!("http://{}/", listener.local_addr().unwrap())
+                .parse()
+                .unwrap();
+
+            let server = tokio::spawn(async move {
+                // Drop the first connection in the middle of the request
+                let (mut conn, _) = listener.accept().await.unwrap();
+                conn.read_exact(&mut [0; 16]).await.unwrap();
+                drop(conn);
+
+                let (mut conn, _) = listener.accept().await.unwrap();
+                read_request(&mut conn).await;
+                respond(&mut conn, r#"{"ok":true,"result":true}"#).await;
+            });
+
+            let forms = (0..2).map(|_| future::ready(Ok(Form::new().text("chat_id", "1"))));
+            let res: ResponseWithMeta<bool> = request_multipart_failover(
+                &Client::new(),
+                "TOKEN",
+                &[api_url],
+                "SetChatPhoto",
+                forms,
+                1,
+                None,
+            )
+            .await;
+
+            assert!(res.result.unwrap());
+            assert!(started.elapsed() >= DELAY_ON_UPLOAD_RETRY);
+            server.await.unwrap();
+        });
+    }
+
+    #[test]
+    fn only_primary_url_gets_compressed_requests() {
+        let rt = crate::test_utils::rt();
+
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url: reqwest::Url = format!("http://{}/", listener.local_addr().unwrap())
+                .parse()
+                .unwrap();
+
+            // Nothing listens on this address
+            let unreachable = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let unreachable_url: reqwest::Url =
+                format!("http://{}/", unreachable.local_addr().unwrap())
+                    .parse()
+                    .unwrap();
+            drop(unreachable);
+
+            let params = br#"{"chat_id":1,"text":"hello"}"#.to_vec();
+            let server = tokio::spawn(async move {
+                let mut bodies = Vec::new();
+                for _ in 0..2 {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    bodies.push(read_body(&mut conn).await);
+                    respond(&mut conn, r#"{"ok":true,"result":true}"#).await;
+                }
+                bodies
+            });
+
+            let client = Client::new();
+            for api_urls in [
+                vec![api_url.clone(), unreachable_url.clone()],
+                vec![unreachable_url, api_url],
+            ] {
+                let res: ResponseWithMeta<bool> = request_json_failover(
+                    &client,
+                    "TOKEN",
+                    &api_urls,
+                    "SendMessage",
+                    params.clone(),
+                    Some(0),
+                    None,
+                )
+                .await;
+                assert!(res.result.unwrap());
+            }
+
+            let bodies = server.await.unwrap();
+            assert_eq!(bodies[0], crate::net::gzip(&params));
+            assert_eq!(bodies[1], params);
+        });
+
+        assert!(is_official(&crate::net::TELEGRAM_API_URL.parse().unwrap()));
+    }
 }
@@ -1,8 +1,8 @@
 use reqwest::StatusCode;
-use serde::Deserialize;
+use serde::{de::IntoDeserializer, Deserialize};
 
 use crate::{
-    requests::ResponseResult,
+    requests::{ResponseResult, ResponseWithMeta},
     types::{False, ResponseParameters, True},
     ApiError, RequestError,
 };
@@ -16,48 +16,76 @@ pub(crate) enum TelegramResponse<R> {
         ok: True,
         #[serde(rename = "result")]
         response: R,
+        description: Option<String>,
+        #[serde(rename = "parameters")]
+        response_parameters: Option<ResponseParameters>,
     },
     Err {
         /// A dummy field. Used only for deserialization.
         #[allow(dead_code)]
         ok: False,
 
-        #[serde(rename = "description")]
-        error: ApiError,
+        description: String,
         error_code: u16,
         #[serde(rename = "parameters")]
         response_parameters: Option<ResponseParameters>,
     },
 }
 
-impl<R> From<TelegramResponse<R>> for ResponseResult<R> {
-    fn from(this: TelegramResponse<R>) -> ResponseResult<R> {
+impl<R> From<TelegramResponse<R>> for ResponseWithMeta<R> {
+    fn from(this: TelegramResponse<R>) -> ResponseWithMeta<R> {
         match this {
-            TelegramResponse::Ok { response, .. } => Ok(response),
+            TelegramResponse::Ok {
+                response,
+                description,
+                response_parameters,
+                ..
+            } => ResponseWithMeta {
+                result: Ok(response),
+                description,
+                parameters: response_parameters,
+            },
             TelegramResponse::Err {
-                error,
+                description,
                 error_code,
                 response_parameters,
                 ..
             } => {
-                if let Some(params) = response_parameters {
-                    match params {
-                        ResponseParameters::RetryAfter(i) => Err(RequestError::RetryAfter(i)),
-                        ResponseParameters::MigrateToChatId(to) => {
-                            Err(RequestError::MigrateToChatId(to))
-                        }
+                let result = match response_parameters {
+                    Some(ResponseParameters::RetryAfter(i)) => Err(RequestError::RetryAfter(i)),
+                    Some(ResponseParameters::MigrateToChatId(to)) => {
+                        Err(RequestError::MigrateToChatId(to))
                     }
-                } else {
-                    Err(RequestError::ApiError {
-                        kind: error,
+                    None => Err(RequestError::ApiError {
+                        kind: api_error(&description),
                         status_code: StatusCode::from_u16(error_code).unwrap(),
-                    })
+                    }),
+                };
+
+                ResponseWithMeta {
+                    result,
+                    description: Some(description),
+                    parameters: response_parameters,
                 }
             }
         }
     }
 }
 
+impl<R> From<TelegramResponse<R>> for ResponseResult<R> {
+    fn from(this: TelegramResponse<R>) -> ResponseResult<R> {
+        ResponseWithMeta::from(this).result
+    }
+}
+
+fn api_error(description: &str) -> ApiError {
+    let de: serde::de::value::StrDeserializer<'_, serde::de::value::Error> =
+        description.into_deserializer();
+
+    // `ApiError` has a catch-all `Unknown` variant, so this never fails
+    ApiError::deserialize(de).unwrap_or_else(|_| ApiError::Unknown(description.to_owned()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,11 +97,25 @@ mod tests {
         let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
 
         assert!(matches!(
-            val,
-            TelegramResponse::Err {
-                error: ApiError::TerminatedByOtherGetUpdates,
+            ResponseResult::from(val),
+            Err(RequestError::ApiError {
+                kind: ApiError::TerminatedByOtherGetUpdates,
                 ..
-            }
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_webhook_conflict() {
+        let s = r#"{"ok":false,"error_code":409,"description":"Conflict: can't use getUpdates method while webhook is active; use deleteWebhook to delete the webhook first"}"#;
+        let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
+
+        assert!(matches!(
+            ResponseResult::from(val),
+            Err(RequestError::ApiError {
+                kind: ApiError::CantGetUpdates,
+                ..
+            })
         ));
     }
 
@@ -83,7 +125,33 @@ mod tests {
         let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
 
         assert!(
-            matches!(val, TelegramResponse::Err { error: ApiError::Unknown(s), .. } if s == "Unknown description that won't match anything")
+            matches!(ResponseResult::from(val), Err(RequestError::ApiError { kind: ApiError::Unknown(s), .. }) if s == "Unknown description that won't match anything")
         );
     }
+
+    #[test]
+    fn parse_meta() {
+        let s = r#"{"ok":false,"error_code":400,"description":"Bad Request: group chat was upgraded to a supergroup chat","parameters":{"migrate_to_chat_id":-1001}}"#;
+        let val = serde_json::from_str::<TelegramResponse<Update>>(s).unwrap();
+        let meta = ResponseWithMeta::from(val);
+
+        assert!(matches!(
+            meta.result,
+            Err(RequestError::MigrateToChatId(-1001))
+        ));
+        assert_eq!(
+            meta.description.as_deref(),
+            Some("Bad Request: group chat was upgraded to a supergroup chat")
+        );
+        assert_eq!(
+            meta.parameters,
+            Some(ResponseParameters::MigrateToChatId(-1001))
+        );
+
+        let s = r#"{"ok":true,"result":true}"#;
+        let meta =
+            ResponseWithMeta::from(serde_json::from_str::<TelegramResponse<True>>(s).unwrap());
+        assert!(meta.result.is_ok());
+        assert_eq!(meta.parameters, None);
+    }
 }
@@ -0,0 +1,43 @@
+use std::{fmt, sync::Arc};
+
+/// Sizes of a request and of its response, see [`Bot::on_traffic`].
+///
+/// [`Bot::on_traffic`]: crate::Bot::on_traffic
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TrafficRecord {
+    /// Name of the method, e.g.: `SendMessage`, or `DownloadFile` for
+    /// downloads of files.
+    pub method: &'static str,
+
+    /// Size of the serialized request body in bytes.
+    ///
+    /// `None` if the size is unknown, e.g. for a multipart request with a
+    /// file which is streamed.
+    pub request_size: Option<u64>,
+
+    /// Size of the response body in bytes.
+    pub response_size: u64,
+}
+
+/// A callback which receives [`TrafficRecord`]s.
+#[derive(Clone)]
+pub(crate) struct TrafficHook(Arc<dyn Fn(&TrafficRecord) + Send + Sync>);
+
+impl TrafficHook {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(&TrafficRecord) + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub(crate) fn report(&self, record: &TrafficRecord) {
+        (self.0)(record)
+    }
+}
+
+impl fmt::Debug for TrafficHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TrafficHook")
+    }
+}
@@ -25,16 +25,25 @@ mod answer_callback_query;
 mod answer_inline_query;
 mod answer_pre_checkout_query;
 mod answer_shipping_query;
+mod approve_chat_join_request;
+mod ban_chat_sender_chat;
 mod close;
+mod close_general_forum_topic;
 mod copy_message;
 mod create_chat_invite_link;
+mod create_chat_subscription_invite_link;
+mod create_forum_topic;
 mod create_new_sticker_set;
+mod decline_chat_join_request;
 mod delete_chat_photo;
 mod delete_chat_sticker_set;
 mod delete_message;
 mod delete_sticker_from_set;
 mod delete_webhook;
 mod edit_chat_invite_link;
+mod edit_chat_subscription_invite_link;
+mod edit_forum_topic;
+mod edit_general_forum_topic;
 mod edit_message_caption;
 mod edit_message_caption_inline;
 mod edit_message_live_location;
@@ -51,7 +60,9 @@ mod get_chat;
 mod get_chat_administrators;
 mod get_chat_member;
 mod get_chat_members_count;
+mod get_custom_emoji_stickers;
 mod get_file;
+mod get_forum_topic_icon_stickers;
 mod get_game_high_scores;
 mod get_me;
 mod get_my_commands;
@@ -59,11 +70,13 @@ mod get_sticker_set;
 mod get_updates;
 mod get_user_profile_photos;
 mod get_webhook_info;
+mod hide_general_forum_topic;
 mod kick_chat_member;
 mod leave_chat;
 mod log_out;
 mod pin_chat_message;
 mod promote_chat_member;
+mod reopen_general_forum_topic;
 mod restrict_chat_member;
 mod revoke_chat_invite_link;
 mod send_animation;
@@ -92,6 +105,7 @@ mod set_chat_sticker_set;
 mod set_chat_title;
 mod set_game_score;
 mod set_game_score_inline;
+mod set_message_reaction;
 mod set_my_commands;
 mod set_passport_data_errors;
 mod set_sticker_position_in_set;
@@ -101,7 +115,10 @@ mod stop_message_live_location;
 mod stop_message_live_location_inline;
 mod stop_poll;
 mod unban_chat_member;
+mod unban_chat_sender_chat;
+mod unhide_general_forum_topic;
 mod unpin_all_chat_messages;
+mod unpin_all_general_forum_topic_messages;
 mod unpin_chat_message;
 mod upload_sticker_file;
 
@@ -110,16 +127,29 @@ pub use answer_callback_query::{AnswerCallbackQuery, AnswerCallbackQuerySetters}
 pub use answer_inline_query::{AnswerInlineQuery, AnswerInlineQuerySetters};
 pub use answer_pre_checkout_query::{AnswerPreCheckoutQuery, AnswerPreCheckoutQuerySetters};
 pub use answer_shipping_query::{AnswerShippingQuery, AnswerShippingQuerySetters};
+pub use approve_chat_join_request::{ApproveChatJoinRequest, ApproveChatJoinRequestSetters};
+pub use ban_chat_sender_chat::{BanChatSenderChat, BanChatSenderChatSetters};
 pub use close::{Close, CloseSetters};
+pub use close_general_forum_topic::{CloseGeneralForumTopic, CloseGeneralForumTopicSetters};
 pub use copy_message::{CopyMessage, CopyMessageSetters};
 pub use create_chat_invite_link::{CreateChatInviteLink, CreateChatInviteLinkSetters};
+pub use create_chat_subscription_invite_link::{
+    CreateChatSubscriptionInviteLink, CreateChatSubscriptionInviteLinkSetters,
+};
+pub use create_forum_topic::{CreateForumTopic, CreateForumTopicSetters};
 pub use create_new_sticker_set::{CreateNewStickerSet, CreateNewStickerSetSetters};
+pub use decline_chat_join_request::{DeclineChatJoinRequest, DeclineChatJoinRequestSetters};
 pub use delete_chat_photo::{DeleteChatPhoto, DeleteChatPhotoSetters};
 pub use delete_chat_sticker_set::{DeleteChatStickerSet, DeleteChatStickerSetSetters};
 pub use delete_message::{DeleteMessage, DeleteMessageSetters};
 pub use delete_sticker_from_set::{DeleteStickerFromSet, DeleteStickerFromSetSetters};
 pub use delete_webhook::{DeleteWebhook, DeleteWebhookSetters};
 pub use edit_chat_invite_link::{EditChatInviteLink, EditChatInviteLinkSetters};
+pub use edit_chat_subscription_invite_link::{
+    EditChatSubscriptionInviteLink, EditChatSubscriptionInviteLinkSetters,
+};
+pub use edit_forum_topic::{EditForumTopic, EditForumTopicSetters};
+pub use edit_general_forum_topic::{EditGeneralForumTopic, EditGeneralForumTopicSetters};
 pub use edit_message_caption::{EditMessageCaption, EditMessageCaptionSetters};
 pub use edit_message_caption_inline::{EditMessageCaptionInline, EditMessageCaptionInlineSetters};
 pub use edit_message_live_location::{EditMessageLiveLocation, EditMessageLiveLocationSetters};
@@ -140,7 +170,11 @@ pub use get_chat::{GetChat, GetChatSetters};
 pub use get_chat_administrators::{GetChatAdministrators, GetChatAdministratorsSetters};
 pub use get_chat_member::{GetChatMember, GetChatMemberSetters};
 pub use get_chat_members_count::{GetChatMembersCount, GetChatMembersCountSetters};
+pub use get_custom_emoji_stickers::{GetCustomEmojiStickers, GetCustomEmojiStickersSetters};
 pub use get_file::{GetFile, GetFileSetters};
+pub use get_forum_topic_icon_stickers::{
+    GetForumTopicIconStickers, GetForumTopicIconStickersSetters,
+};
 pub use get_game_high_scores::{GetGameHighScores, GetGameHighScoresSetters};
 pub use get_me::{GetMe, GetMeSetters};
 pub use get_my_commands::{GetMyCommands, GetMyCommandsSetters};
@@ -148,11 +182,13 @@ pub use get_sticker_set::{GetStickerSet, GetStickerSetSetters};
 pub use get_updates::{GetUpdates, GetUpdatesSetters};
 pub use get_user_profile_photos::{GetUserProfilePhotos, GetUserProfilePhotosSetters};
 pub use get_webhook_info::{GetWebhookInfo, GetWebhookInfoSetters};
+pub use hide_general_forum_topic::{HideGeneralForumTopic, HideGeneralForumTopicSetters};
 pub use kick_chat_member::{KickChatMember, KickChatMemberSetters};
 pub use leave_chat::{LeaveChat, LeaveChatSetters};
 pub use log_out::{LogOut, LogOutSetters};
 pub use pin_chat_message::{PinChatMessage, PinChatMessageSetters};
 pub use promote_chat_member::{PromoteChatMember, PromoteChatMemberSetters};
+pub use reopen_general_forum_topic::{ReopenGeneralForumTopic, ReopenGeneralForumTopicSetters};
 pub use restrict_chat_member::{RestrictChatMember, RestrictChatMemberSetters};
 pub use revoke_chat_invite_link::{RevokeChatInviteLink, RevokeChatInviteLinkSetters};
 pub use send_animation::{SendAnimation, SendAnimationSetters};
@@ -183,6 +219,7 @@ pub use set_chat_sticker_set::{SetChatStickerSet, SetChatStickerSetSetters};
 pub use set_chat_title::{SetChatTitle, SetChatTitleSetters};
 pub use set_game_score::{SetGameScore, SetGameScoreSetters};
 pub use set_game_score_inline::{SetGameScoreInline, SetGameScoreInlineSetters};
+pub use set_message_reaction::{SetMessageReaction, SetMessageReactionSetters};
 pub use set_my_commands::{SetMyCommands, SetMyCommandsSetters};
 pub use set_passport_data_errors::{SetPassportDataErrors, SetPassportDataErrorsSetters};
 pub use set_sticker_position_in_set::{SetStickerPositionInSet, SetStickerPositionInSetSetters};
@@ -194,12 +231,416 @@ pub use stop_message_live_location_inline::{
 };
 pub use stop_poll::{StopPoll, StopPollSetters};
 pub use unban_chat_member::{UnbanChatMember, UnbanChatMemberSetters};
+pub use unban_chat_sender_chat::{UnbanChatSenderChat, UnbanChatSenderChatSetters};
+pub use unhide_general_forum_topic::{UnhideGeneralForumTopic, UnhideGeneralForumTopicSetters};
 pub use unpin_all_chat_messages::{UnpinAllChatMessages, UnpinAllChatMessagesSetters};
+pub use unpin_all_general_forum_topic_messages::{
+    UnpinAllGeneralForumTopicMessages, UnpinAllGeneralForumTopicMessagesSetters,
+};
 pub use unpin_chat_message::{UnpinChatMessage, UnpinChatMessageSetters};
 pub use upload_sticker_file::{UploadStickerFile, UploadStickerFileSetters};
 
 // end of auto generated block
 
 mod get_updates_fault_tolerant;
+mod parse_mode;
+mod thread;
+mod until_date;
 
 pub use get_updates_fault_tolerant::GetUpdatesFaultTolerant;
+pub use parse_mode::{HasParseMode, ParseModeSetters};
+pub use thread::{HasThreadId, ThreadSetters};
+pub use until_date::{HasUntilDate, UntilDateSetters};
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    /// Methods which were renamed in the Bot API, `(our name, schema name)`.
+    const RENAMED: &[(&str, &str)] = &[
+        ("kickChatMember", "banChatMember"),
+        ("getChatMembersCount", "getChatMemberCount"),
+        ("setStickerSetThumb", "setStickerSetThumbnail"),
+    ];
+
+    /// Types of flattened fields, with the parameters they are serialized as.
+    const FLATTENED: &[(&str, &[&str])] = &[
+        (
+            "InputSticker",
+            &["png_sticker", "tgs_sticker", "webm_sticker"],
+        ),
+        (
+            "TargetMessage",
+            &["chat_id", "message_id", "inline_message_id"],
+        ),
+    ];
+
+    /// Methods and parameters (`method.parameter`) of the schema which are not
+    /// implemented yet, with the version of the Bot API which introduced them.
+    const KNOWN_GAPS: &[(&str, &str)] = &[
+        ("deleteMyCommands", "5.3"),
+        ("getMyCommands.language_code", "5.3"),
+        ("getMyCommands.scope", "5.3"),
+        ("setMyCommands.language_code", "5.3"),
+        ("setMyCommands.scope", "5.3"),
+        ("copyMessage.protect_content", "5.6"),
+        ("forwardMessage.protect_content", "5.6"),
+        ("sendAnimation.protect_content", "5.6"),
+        ("sendAudio.protect_content", "5.6"),
+        ("sendContact.protect_content", "5.6"),
+        ("sendDice.protect_content", "5.6"),
+        ("sendDocument.protect_content", "5.6"),
+        ("sendGame.protect_content", "5.6"),
+        ("sendInvoice.protect_content", "5.6"),
+        ("sendLocation.protect_content", "5.6"),
+        ("sendMediaGroup.protect_content", "5.6"),
+        ("sendMessage.protect_content", "5.6"),
+        ("sendPhoto.protect_content", "5.6"),
+        ("sendPoll.protect_content", "5.6"),
+        ("sendSticker.protect_content", "5.6"),
+        ("sendVenue.protect_content", "5.6"),
+        ("sendVideo.protect_content", "5.6"),
+        ("sendVideoNote.protect_content", "5.6"),
+        ("sendVoice.protect_content", "5.6"),
+        ("answerWebAppQuery", "6.0"),
+        ("getChatMenuButton", "6.0"),
+        ("getMyDefaultAdministratorRights", "6.0"),
+        ("promoteChatMember.can_manage_video_chats", "6.0"),
+        ("setChatMenuButton", "6.0"),
+        ("setMyDefaultAdministratorRights", "6.0"),
+        ("createInvoiceLink", "6.1"),
+        ("createNewStickerSet.sticker_type", "6.2"),
+        ("closeForumTopic", "6.3"),
+        ("deleteForumTopic", "6.3"),
+        ("promoteChatMember.can_manage_topics", "6.3"),
+        ("reopenForumTopic", "6.3"),
+        ("sendChatAction.message_thread_id", "6.3"),
+        ("unpinAllForumTopicMessages", "6.3"),
+        ("sendAnimation.has_spoiler", "6.4"),
+        ("sendPhoto.has_spoiler", "6.4"),
+        ("sendVideo.has_spoiler", "6.4"),
+        ("restrictChatMember.use_independent_chat_permissions", "6.5"),
+        ("setChatPermissions.use_independent_chat_permissions", "6.5"),
+        ("addStickerToSet.sticker", "6.6"),
+        ("createNewStickerSet.needs_repainting", "6.6"),
+        ("createNewStickerSet.stickers", "6.6"),
+        ("deleteStickerSet", "6.6"),
+        ("getMyDescription", "6.6"),
+        ("getMyShortDescription", "6.6"),
+        ("sendSticker.emoji", "6.6"),
+        ("setCustomEmojiStickerSetThumbnail", "6.6"),
+        ("setMyDescription", "6.6"),
+        ("setMyShortDescription", "6.6"),
+        ("setStickerEmojiList", "6.6"),
+        ("setStickerKeywords", "6.6"),
+        ("setStickerMaskPosition", "6.6"),
+        ("setStickerSetThumbnail.thumbnail", "6.6"),
+        ("setStickerSetTitle", "6.6"),
+        ("uploadStickerFile.sticker", "6.6"),
+        ("uploadStickerFile.sticker_format", "6.6"),
+        ("getMyName", "6.7"),
+        ("setMyName", "6.7"),
+        ("promoteChatMember.can_delete_stories", "6.9"),
+        ("promoteChatMember.can_edit_stories", "6.9"),
+        ("promoteChatMember.can_post_stories", "6.9"),
+        ("copyMessage.reply_parameters", "7.0"),
+        ("copyMessages", "7.0"),
+        ("deleteMessages", "7.0"),
+        ("editMessageText.link_preview_options", "7.0"),
+        ("forwardMessages", "7.0"),
+        ("getUserChatBoosts", "7.0"),
+        ("sendAnimation.reply_parameters", "7.0"),
+        ("sendAudio.reply_parameters", "7.0"),
+        ("sendContact.reply_parameters", "7.0"),
+        ("sendDice.reply_parameters", "7.0"),
+        ("sendDocument.reply_parameters", "7.0"),
+        ("sendGame.reply_parameters", "7.0"),
+        ("sendInvoice.reply_parameters", "7.0"),
+        ("sendLocation.reply_parameters", "7.0"),
+        ("sendMediaGroup.reply_parameters", "7.0"),
+        ("sendMessage.link_preview_options", "7.0"),
+        ("sendMessage.reply_parameters", "7.0"),
+        ("sendPhoto.reply_parameters", "7.0"),
+        ("sendPoll.reply_parameters", "7.0"),
+        ("sendSticker.reply_parameters", "7.0"),
+        ("sendVenue.reply_parameters", "7.0"),
+        ("sendVideo.reply_parameters", "7.0"),
+        ("sendVideoNote.reply_parameters", "7.0"),
+        ("sendVoice.reply_parameters", "7.0"),
+        ("getBusinessConnection", "7.2"),
+        ("replaceStickerInSet", "7.2"),
+        ("sendAnimation.business_connection_id", "7.2"),
+        ("sendAudio.business_connection_id", "7.2"),
+        ("sendChatAction.business_connection_id", "7.2"),
+        ("sendContact.business_connection_id", "7.2"),
+        ("sendDice.business_connection_id", "7.2"),
+        ("sendDocument.business_connection_id", "7.2"),
+        ("sendGame.business_connection_id", "7.2"),
+        ("sendLocation.business_connection_id", "7.2"),
+        ("sendMediaGroup.business_connection_id", "7.2"),
+        ("sendMessage.business_connection_id", "7.2"),
+        ("sendPhoto.business_connection_id", "7.2"),
+        ("sendPoll.business_connection_id", "7.2"),
+        ("sendSticker.business_connection_id", "7.2"),
+        ("sendVenue.business_connection_id", "7.2"),
+        ("sendVideo.business_connection_id", "7.2"),
+        ("sendVideoNote.business_connection_id", "7.2"),
+        ("sendVoice.business_connection_id", "7.2"),
+        ("setStickerSetThumbnail.format", "7.2"),
+        ("sendPoll.question_entities", "7.3"),
+        ("sendPoll.question_parse_mode", "7.3"),
+        ("copyMessage.show_caption_above_media", "7.4"),
+        ("editMessageCaption.show_caption_above_media", "7.4"),
+        ("refundStarPayment", "7.4"),
+        ("sendAnimation.message_effect_id", "7.4"),
+        ("sendAnimation.show_caption_above_media", "7.4"),
+        ("sendAudio.message_effect_id", "7.4"),
+        ("sendContact.message_effect_id", "7.4"),
+        ("sendDice.message_effect_id", "7.4"),
+        ("sendDocument.message_effect_id", "7.4"),
+        ("sendGame.message_effect_id", "7.4"),
+        ("sendInvoice.message_effect_id", "7.4"),
+        ("sendLocation.message_effect_id", "7.4"),
+        ("sendMediaGroup.message_effect_id", "7.4"),
+        ("sendMessage.message_effect_id", "7.4"),
+        ("sendPhoto.message_effect_id", "7.4"),
+        ("sendPhoto.show_caption_above_media", "7.4"),
+        ("sendPoll.message_effect_id", "7.4"),
+        ("sendSticker.message_effect_id", "7.4"),
+        ("sendVenue.message_effect_id", "7.4"),
+        ("sendVideo.message_effect_id", "7.4"),
+        ("sendVideo.show_caption_above_media", "7.4"),
+        ("sendVideoNote.message_effect_id", "7.4"),
+        ("sendVoice.message_effect_id", "7.4"),
+        ("getStarTransactions", "7.5"),
+        ("editMessageLiveLocation.live_period", "7.6"),
+        ("sendPaidMedia", "7.6"),
+    ];
+
+    /// Parameters which are implemented, but are not a part of the schema, e.g.
+    /// because they were renamed or removed from the Bot API.
+    const OUTDATED: &[&str] = &[
+        "addStickerToSet.emojis",
+        "addStickerToSet.mask_position",
+        "addStickerToSet.png_sticker",
+        "addStickerToSet.tgs_sticker",
+        "addStickerToSet.webm_sticker",
+        "answerInlineQuery.switch_pm_parameter",
+        "answerInlineQuery.switch_pm_text",
+        "copyMessage.allow_sending_without_reply",
+        "copyMessage.reply_to_message_id",
+        "createNewStickerSet.contains_masks",
+        "createNewStickerSet.emojis",
+        "createNewStickerSet.mask_position",
+        "createNewStickerSet.png_sticker",
+        "createNewStickerSet.tgs_sticker",
+        "createNewStickerSet.webm_sticker",
+        "editMessageText.disable_web_page_preview",
+        "promoteChatMember.can_manage_voice_chats",
+        "sendAnimation.allow_sending_without_reply",
+        "sendAnimation.reply_to_message_id",
+        "sendAudio.allow_sending_without_reply",
+        "sendAudio.reply_to_message_id",
+        "sendContact.allow_sending_without_reply",
+        "sendContact.reply_to_message_id",
+        "sendDice.allow_sending_without_reply",
+        "sendDice.reply_to_message_id",
+        "sendDocument.allow_sending_without_reply",
+        "sendDocument.reply_to_message_id",
+        "sendGame.allow_sending_without_reply",
+        "sendGame.reply_to_message_id",
+        "sendInvoice.allow_sending_without_reply",
+        "sendInvoice.reply_to_message_id",
+        "sendLocation.allow_sending_without_reply",
+        "sendLocation.reply_to_message_id",
+        "sendMediaGroup.allow_sending_without_reply",
+        "sendMediaGroup.reply_to_message_id",
+        "sendMessage.allow_sending_without_reply",
+        "sendMessage.disable_web_page_preview",
+        "sendMessage.reply_to_message_id",
+        "sendPhoto.allow_sending_without_reply",
+        "sendPhoto.reply_to_message_id",
+        "sendPoll.allow_sending_without_reply",
+        "sendPoll.reply_to_message_id",
+        "sendSticker.allow_sending_without_reply",
+        "sendSticker.reply_to_message_id",
+        "sendVenue.allow_sending_without_reply",
+        "sendVenue.reply_to_message_id",
+        "sendVideo.allow_sending_without_reply",
+        "sendVideo.reply_to_message_id",
+        "sendVideoNote.allow_sending_without_reply",
+        "sendVideoNote.reply_to_message_id",
+        "sendVoice.allow_sending_without_reply",
+        "sendVoice.reply_to_message_id",
+        "setStickerSetThumbnail.thumb",
+        "stopMessageLiveLocation.latitude",
+        "stopMessageLiveLocation.longitude",
+        "uploadStickerFile.png_sticker",
+    ];
+
+    fn lower_camel_case(snake: &str) -> String {
+        let mut parts = snake.split('_');
+        let mut res = parts.next().unwrap_or_default().to_owned();
+        for part in parts {
+            let mut chars = part.chars();
+            res.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+            res.push_str(chars.as_str());
+        }
+        res
+    }
+
+    fn version(version: &str) -> (u8, u8) {
+        let mut parts = version.split('.').map(|p| p.parse().unwrap());
+        (parts.next().unwrap(), parts.next().unwrap())
+    }
+
+    /// Returns the parameters of the payload defined in the `module`.
+    fn parameters(module: &str) -> Vec<String> {
+        let path = format!("{}/src/payloads/{}.rs", env!("CARGO_MANIFEST_DIR"), module);
+        let source = std::fs::read_to_string(path).unwrap();
+
+        let mut res = Vec::new();
+        let mut attributes = Vec::new();
+        for line in source.lines().map(str::trim) {
+            if line.starts_with("#[serde(") {
+                attributes.push(line);
+                continue;
+            }
+
+            let field = match line.strip_prefix("pub ") {
+                Some(field) if field.contains(": ") => field,
+                _ => {
+                    attributes.clear();
+                    continue;
+                }
+            };
+            let colon = field.find(": ").unwrap();
+            let (name, ty) = (&field[..colon], &field[colon + 2..]);
+            let ty = ty.split(&[' ', ','][..]).next().unwrap();
+
+            if attributes.contains(&"#[serde(flatten)]") {
+                let (_, flattened) = FLATTENED.iter().find(|(t, _)| *t == ty).unwrap();
+                res.extend(flattened.iter().map(|&p| p.to_owned()));
+            } else {
+                let renamed = attributes
+                    .iter()
+                    .find_map(|a| a.strip_prefix("#[serde(rename = \"")?.strip_suffix("\")]"));
+                res.push(renamed.unwrap_or(name).to_owned());
+            }
+            attributes.clear();
+        }
+        res
+    }
+
+    /// Checks the generated payloads against the bundled schema: methods and
+    /// parameters which are missing must be listed in [`KNOWN_GAPS`] and be
+    /// newer than [`API_VERSION`], implemented parameters which are not in the
+    /// schema must be listed in [`OUTDATED`].
+    ///
+    /// [`API_VERSION`]: crate::API_VERSION
+    #[test]
+    fn schema_coverage() {
+        let schema = include_str!("../schema/methods.txt");
+        let mut lines = schema
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+        let schema_version = lines.next().unwrap();
+        assert!(version(crate::API_VERSION) <= version(schema_version));
+        let schema: BTreeMap<_, BTreeSet<_>> = lines
+            .map(|l| {
+                let mut words = l.split_whitespace().map(ToOwned::to_owned);
+                (words.next().unwrap(), words.collect())
+            })
+            .collect();
+
+        let generated = include_str!("payloads.rs");
+        let generated = &generated[..generated.find("// end of auto generated block").unwrap()];
+        let mut implemented = BTreeMap::<_, BTreeSet<_>>::new();
+        for module in generated
+            .lines()
+            .filter_map(|l| l.strip_prefix("mod ")?.strip_suffix(';'))
+        {
+            let method = lower_camel_case(module.trim_end_matches("_inline"));
+            let method = match RENAMED.iter().find(|(ours, _)| *ours == method) {
+                Some((_, theirs)) => (*theirs).to_owned(),
+                None => method,
+            };
+            implemented
+                .entry(method)
+                .or_default()
+                .extend(parameters(module));
+        }
+
+        let unknown: Vec<_> = implemented
+            .keys()
+            .filter(|m| !schema.contains_key(*m))
+            .collect();
+        assert!(unknown.is_empty(), "unknown methods: {:?}", unknown);
+
+        let mut missing = BTreeSet::new();
+        let mut outdated = BTreeSet::new();
+        for (method, parameters) in &schema {
+            match implemented.get(method) {
+                None => {
+                    missing.insert(method.clone());
+                }
+                Some(ours) => {
+                    missing.extend(
+                        parameters
+                            .difference(ours)
+                            .map(|p| format!("{}.{}", method, p)),
+                    );
+                    outdated.extend(
+                        ours.difference(parameters)
+                            .map(|p| format!("{}.{}", method, p)),
+                    );
+                }
+            }
+        }
+
+        let known: BTreeSet<_> = KNOWN_GAPS
+            .iter()
+            .map(|(gap, _)| (*gap).to_owned())
+            .collect();
+        assert_eq!(missing, known, "KNOWN_GAPS is out of date");
+        let known: BTreeSet<_> = OUTDATED.iter().map(|&p| p.to_owned()).collect();
+        assert_eq!(outdated, known, "OUTDATED is out of date");
+
+        for (gap, since) in KNOWN_GAPS {
+            assert!(
+                version(since) > version(crate::API_VERSION),
+                "{} of Bot API {} is not implemented, but API_VERSION is {}",
+                gap,
+                since,
+                crate::API_VERSION
+            );
+        }
+    }
+
+    #[test]
+    fn poll_type() {
+        let payload = super::SendPoll::new(0, "?", vec![], crate::types::PollType::Quiz);
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["type"], "quiz");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_thumb_setters() {
+        use super::{SendVideo, SendVideoSetters};
+        use crate::{
+            requests::{HasPayload, MultipartRequest},
+            types::{InputFile, InputMediaVideo},
+        };
+
+        let thumb = InputFile::file_id("thumb");
+        let request = SendVideo::new(0, InputFile::file_id("video"));
+        let request = MultipartRequest::new(crate::Bot::new("TOKEN"), request).thumb(thumb.clone());
+        assert_eq!(request.payload_ref().thumbnail, Some(thumb.clone()));
+
+        let media = InputMediaVideo::new(InputFile::file_id("video")).thumb(thumb.clone());
+        assert_eq!(media.thumbnail, Some(thumb));
+    }
+}
@@ -0,0 +1,24 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, True};
+
+impl_payload! {
+    /// Use this method to approve a chat join request. The bot must be an administrator in the chat for this to work and must have the _can\_invite\_users_ administrator right. Returns True on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub ApproveChatJoinRequest (ApproveChatJoinRequestSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// Unique identifier of the target user
+            pub user_id: i64,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, True};
+
+impl_payload! {
+    /// Use this method to ban a channel chat in a supergroup or a channel. Until the chat is [unbanned], the owner of the banned chat won't be able to send messages on behalf of **any of their channels**. The bot must be an administrator in the supergroup or channel for this to work and must have the appropriate administrator rights. Returns _True_ on success.
+    ///
+    /// [unbanned]: crate::payloads::UnbanChatSenderChat
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub BanChatSenderChat (BanChatSenderChatSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// Unique identifier of the target sender chat
+            pub sender_chat_id: i64,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, True};
+
+impl_payload! {
+    /// Use this method to close an open 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the _can\_manage\_topics_ administrator rights. Returns True on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub CloseGeneralForumTopic (CloseGeneralForumTopicSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+            pub chat_id: ChatId [into],
+        }
+    }
+}
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, MessageEntity, ParseMode, ReplyMarkup};
+use crate::types::{ChatId, Message, MessageEntity, ParseMode, ReplyMarkup, ThreadId};
 
 impl_payload! {
     /// Use this method to copy messages of any kind. The method is analogous to the method forwardMessage, but the copied message doesn't have a link to the original message. Returns the [`MessageId`] of the sent message on success.
@@ -25,6 +25,8 @@ impl_payload! {
             pub message_id: i32,
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// New caption for media, 0-1024 characters after entities parsing. If not specified, the original caption is kept
             pub caption: String [into],
             /// Mode for parsing entities in the photo caption. See [formatting options] for more details.
@@ -22,10 +22,14 @@ impl_payload! {
             pub chat_id: ChatId [into],
         }
         optional {
+            /// Invite link name; 0-32 characters
+            pub name: String [into],
             /// Point in time (Unix timestamp) when the link will expire
             pub expire_date: i64,
             /// Maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
             pub member_limit: u32,
+            /// `true`, if users joining the chat via the link need to be approved by chat administrators. If `true`, member_limit can't be specified
+            pub creates_join_request: bool,
         }
     }
 }
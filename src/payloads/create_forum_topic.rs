@@ -0,0 +1,36 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, ForumTopic, ForumTopicIconColor};
+
+impl_payload! {
+    /// Use this method to create a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have the _can\_manage\_topics_ administrator rights. Returns information about the created topic as a [`ForumTopic`] object.
+    ///
+    /// [`ForumTopic`]: crate::types::ForumTopic
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub CreateForumTopic (CreateForumTopicSetters) => ForumTopic {
+        required {
+            /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+            pub chat_id: ChatId [into],
+            /// Topic name, 1-128 characters
+            pub name: String [into],
+        }
+        optional {
+            /// Color of the topic icon, must be one of the [`ForumTopicIconColor::PALETTE`]
+            ///
+            /// [`ForumTopicIconColor::PALETTE`]: crate::types::ForumTopicIconColor::PALETTE
+            pub icon_color: ForumTopicIconColor,
+            /// Unique identifier of the custom emoji shown as the topic icon. Use [`GetForumTopicIconStickers`] to get all allowed custom emoji identifiers.
+            ///
+            /// [`GetForumTopicIconStickers`]: crate::payloads::GetForumTopicIconStickers
+            pub icon_custom_emoji_id: String [into],
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, ChatInviteLink};
+
+impl_payload! {
+    /// Use this method to edit a subscription invite link created by the bot. The bot must have the `can_invite_users` administrator rights. Returns the edited invite link as a [`ChatInviteLink`] object.
+    ///
+    /// [`ChatInviteLink`]: crate::types::ChatInviteLink
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub EditChatSubscriptionInviteLink (EditChatSubscriptionInviteLinkSetters) => ChatInviteLink {
+        required {
+            /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
+            pub chat_id: ChatId [into],
+            /// The invite link to edit
+            pub invite_link: String [into],
+        }
+        optional {
+            /// Invite link name; 0-32 characters
+            pub name: String [into],
+        }
+    }
+}
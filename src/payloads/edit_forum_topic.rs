@@ -0,0 +1,32 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, ThreadId, True};
+
+impl_payload! {
+    /// Use this method to edit name and icon of a topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have _can\_manage\_topics_ administrator rights, unless it is the creator of the topic. Returns True on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub EditForumTopic (EditForumTopicSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+            pub chat_id: ChatId [into],
+            /// Unique identifier for the target message thread of the forum topic
+            pub message_thread_id: ThreadId [into],
+        }
+        optional {
+            /// New topic name, 0-128 characters. If not specified or empty, the current name of the topic will be kept
+            pub name: String [into],
+            /// New unique identifier of the custom emoji shown as the topic icon. Use [`GetForumTopicIconStickers`] to get all allowed custom emoji identifiers. Pass an empty string to remove the icon. If not specified, the current icon will be kept
+            ///
+            /// [`GetForumTopicIconStickers`]: crate::payloads::GetForumTopicIconStickers
+            pub icon_custom_emoji_id: String [into],
+        }
+    }
+}
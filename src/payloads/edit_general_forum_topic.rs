@@ -0,0 +1,24 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::{ChatId, True};
+
+impl_payload! {
+    /// Use this method to edit the name of the 'General' topic in a forum supergroup chat. The bot must be an administrator in the chat for this to work and must have _can\_manage\_topics_ administrator rights. Returns True on success.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub EditGeneralForumTopic (EditGeneralForumTopicSetters) => True {
+        required {
+            /// Unique identifier for the target chat or username of the target supergroup (in the format `@supergroupusername`)
+            pub chat_id: ChatId [into],
+            /// New topic name, 1-128 characters
+            pub name: String [into],
+        }
+    }
+}
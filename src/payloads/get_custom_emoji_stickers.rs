@@ -0,0 +1,24 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::Sticker;
+
+impl_payload! {
+    /// Use this method to get information about custom emoji stickers by their identifiers. Returns an Array of [`Sticker`] objects.
+    ///
+    /// [`Sticker`]: crate::types::Sticker
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    pub GetCustomEmojiStickers (GetCustomEmojiStickersSetters) => Vec<Sticker> {
+        required {
+            /// List of custom emoji identifiers. At most 200 custom emoji identifiers can be specified.
+            pub custom_emoji_ids: Vec<String> [collect],
+        }
+    }
+}
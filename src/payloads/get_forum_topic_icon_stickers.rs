@@ -0,0 +1,21 @@
+// This file is auto generated by [`cg`] from [`schema`].
+//
+// **DO NOT EDIT THIS FILE**,
+//
+// Edit `cg` or `schema` instead.
+//
+// [cg]: https://github.com/teloxide/cg
+// [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
+use serde::Serialize;
+
+use crate::types::Sticker;
+
+impl_payload! {
+    /// Use this method to get custom emoji stickers, which can be used as a forum topic icon by any user. Requires no parameters. Returns an Array of [`Sticker`] objects.
+    ///
+    /// [`Sticker`]: crate::types::Sticker
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    pub GetForumTopicIconStickers (GetForumTopicIconStickersSetters) => Vec<Sticker> {
+
+    }
+}
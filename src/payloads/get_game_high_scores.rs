@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{TargetMessage, True};
+use crate::types::{GameHighScore, TargetMessage};
 
 impl_payload! {
     /// Use this method to get data for high score tables. Will return the score of the specified user and several of their neighbors in a game. On success, returns an Array of [`GameHighScore`] objects.
@@ -17,7 +17,7 @@ impl_payload! {
     ///
     /// [`GameHighScore`]: crate::types::GameHighScore
     #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub GetGameHighScores (GetGameHighScoresSetters) => True {
+    pub GetGameHighScores (GetGameHighScoresSetters) => Vec<GameHighScore> {
         required {
             /// User identifier
             pub user_id: i64,
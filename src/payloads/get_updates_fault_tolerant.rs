@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     payloads::GetUpdates,
@@ -7,7 +7,7 @@ use crate::{
 };
 
 /// The fault tolerant version of [`GetUpdates`].
-#[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+#[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct GetUpdatesFaultTolerant(pub GetUpdates);
 
@@ -0,0 +1,92 @@
+use crate::{
+    payloads::{
+        CopyMessage, EditMessageCaption, EditMessageCaptionInline, EditMessageText,
+        EditMessageTextInline, SendAnimation, SendAudio, SendDocument, SendMessage, SendPhoto,
+        SendPoll, SendVideo, SendVoice,
+    },
+    requests::HasPayload,
+    types::ParseMode,
+};
+
+/// Payloads which have a parse mode field, see [`ParseModeSetters`].
+pub trait HasParseMode {
+    /// Returns a mutable reference to the parse mode field (`parse_mode` or,
+    /// in case of [`SendPoll`], `explanation_parse_mode`).
+    fn parse_mode_mut(&mut self) -> &mut Option<ParseMode>;
+}
+
+macro_rules! impl_has_parse_mode {
+    ($($Payload:ident . $field:ident),* $(,)?) => {
+        $(
+            impl HasParseMode for $Payload {
+                fn parse_mode_mut(&mut self) -> &mut Option<ParseMode> {
+                    &mut self.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_has_parse_mode! {
+    SendMessage.parse_mode, CopyMessage.parse_mode, SendPhoto.parse_mode,
+    SendAudio.parse_mode, SendDocument.parse_mode, SendVideo.parse_mode,
+    SendAnimation.parse_mode, SendVoice.parse_mode, SendPoll.explanation_parse_mode,
+    EditMessageText.parse_mode, EditMessageTextInline.parse_mode,
+    EditMessageCaption.parse_mode, EditMessageCaptionInline.parse_mode,
+}
+
+/// Setters which allow to override the parse mode, available on all requests
+/// which have one.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{payloads::ParseModeSetters, prelude::*, types::ParseMode};
+///
+/// let bot = Bot::new("TOKEN").parse_mode(ParseMode::MarkdownV2);
+///
+/// // Send `*` as is, without interpreting it as markup
+/// let request = bot.send_message(0, "2 * 2 = 4").no_parse_mode();
+/// # let _ = request;
+/// ```
+pub trait ParseModeSetters: HasPayload
+where
+    Self::Payload: HasParseMode,
+{
+    /// Removes the parse mode, so that the text is sent as is.
+    ///
+    /// This overrides the default parse mode set by [`DefaultParseMode`].
+    ///
+    /// [`DefaultParseMode`]: crate::adaptors::DefaultParseMode
+    fn no_parse_mode(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        *self.payload_mut().parse_mode_mut() = None;
+        self
+    }
+}
+
+impl<P> ParseModeSetters for P
+where
+    P: HasPayload,
+    P::Payload: HasParseMode,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payloads::SendMessageSetters;
+
+    #[test]
+    fn no_parse_mode() {
+        let payload = SendMessage::new(1, "*text*")
+            .parse_mode(ParseMode::MarkdownV2)
+            .no_parse_mode();
+        assert_eq!(payload.parse_mode, None);
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert!(json.get("parse_mode").is_none());
+    }
+}
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, InputFile, Message, MessageEntity, ParseMode, ReplyMarkup};
+use crate::types::{ChatId, InputFile, Message, MessageEntity, ParseMode, ReplyMarkup, ThreadId};
 
 impl_payload! {
     @[multipart]
@@ -26,6 +26,8 @@ impl_payload! {
             pub animation: InputFile,
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Duration of the animation in seconds
             pub duration: u32,
             /// Animation width
@@ -35,7 +37,7 @@ impl_payload! {
             /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. [More info on Sending Files »]
             ///
             /// [More info on Sending Files »]: crate::types::InputFile
-            pub thumb: InputFile,
+            pub thumbnail: InputFile,
             /// Animation caption (may also be used when resending videos by _file\_id_), 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the animation caption. See [formatting options] for more details.
@@ -58,5 +60,9 @@ impl_payload! {
             /// [custom reply keyboard]: https://core.telegram.org/bots#keyboards
             pub reply_markup: ReplyMarkup [into],
         }
+        deprecated {
+            #[deprecated(note = "renamed to `thumbnail`")]
+            thumb => thumbnail: InputFile,
+        }
     }
 }
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, InputFile, Message, MessageEntity, ParseMode, ReplyMarkup};
+use crate::types::{ChatId, InputFile, Message, MessageEntity, ParseMode, ReplyMarkup, ThreadId};
 
 impl_payload! {
     @[multipart]
@@ -29,6 +29,8 @@ impl_payload! {
             pub audio: InputFile,
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Audio caption, 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the audio caption. See [formatting options] for more details.
@@ -46,7 +48,7 @@ impl_payload! {
             /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. [More info on Sending Files »]
             ///
             /// [More info on Sending Files »]: crate::types::InputFile
-            pub thumb: InputFile,
+            pub thumbnail: InputFile,
             /// Sends the message [silently]. Users will receive a notification with no sound.
             ///
             /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
@@ -61,5 +63,9 @@ impl_payload! {
             /// [custom reply keyboard]: https://core.telegram.org/bots#keyboards
             pub reply_markup: ReplyMarkup [into],
         }
+        deprecated {
+            #[deprecated(note = "renamed to `thumbnail`")]
+            thumb => thumbnail: InputFile,
+        }
     }
 }
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, ReplyMarkup};
+use crate::types::{ChatId, Message, ReplyMarkup, ThreadId};
 
 impl_payload! {
     /// Use this method to send phone contacts. On success, the sent [`Message`] is returned.
@@ -25,6 +25,8 @@ impl_payload! {
             pub first_name: String [into],
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Contact's last name
             pub last_name: String [into],
             /// Additional data about the contact in the form of a [vCard], 0-2048 bytes
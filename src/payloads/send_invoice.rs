@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, InlineKeyboardMarkup, LabeledPrice, Message};
+use crate::types::{ChatId, Currency, InlineKeyboardMarkup, LabeledPrice, Message, ThreadId};
 
 impl_payload! {
     /// Use this method to send invoices. On success, the sent [`Message`] is returned.
@@ -30,11 +30,13 @@ impl_payload! {
             /// [Botfather]: https://t.me/botfather
             pub provider_token: String [into],
             /// Three-letter ISO 4217 currency code, see more on currencies
-            pub currency: String [into],
+            pub currency: Currency,
             /// Price breakdown, a JSON-serialized list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
             pub prices: Vec<LabeledPrice> [collect],
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// The maximum accepted amount for tips in the smallest units of the currency (integer, **not** float/double). For example, for a maximum tip of `US$ 1.45` pass `max_tip_amount = 145`. See the exp parameter in [`currencies.json`], it shows the number of digits past the decimal point for each currency (2 for the majority of currencies). Defaults to 0
             ///
             /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, MessageEntity, ParseMode, ReplyMarkup};
+use crate::types::{ChatId, Message, MessageEntity, ParseMode, ReplyMarkup, ThreadId};
 
 impl_payload! {
     /// Use this method to send text messages. On success, the sent [`Message`] is returned.
@@ -23,6 +23,8 @@ impl_payload! {
             pub text: String [into],
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Mode for parsing entities in the message text. See [formatting options] for more details.
             ///
             /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
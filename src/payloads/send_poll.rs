@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, MessageEntity, ParseMode, PollType, ReplyMarkup};
+use crate::types::{ChatId, Message, MessageEntity, ParseMode, PollType, ReplyMarkup, ThreadId};
 
 impl_payload! {
     /// Use this method to send phone contacts. On success, the sent [`Message`] is returned.
@@ -24,9 +24,12 @@ impl_payload! {
             /// A JSON-serialized list of answer options, 2-10 strings 1-100 characters each
             pub options: Vec<String> [collect],
             /// Poll type, “quiz” or “regular”, defaults to “regular”
+            #[serde(rename = "type")]
             pub type_: PollType,
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// True, if the poll needs to be anonymous, defaults to True
             pub is_anonymous: bool,
             /// True, if the poll allows multiple answers, ignored for polls in quiz mode, defaults to False
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, InputFile, Message, ReplyMarkup};
+use crate::types::{ChatId, InputFile, Message, ReplyMarkup, ThreadId};
 
 impl_payload! {
     @[multipart]
@@ -26,6 +26,8 @@ impl_payload! {
             pub sticker: InputFile,
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Sends the message [silently]. Users will receive a notification with no sound.
             ///
             /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
@@ -8,7 +8,7 @@
 // [`schema`]: https://github.com/WaffleLapkin/tg-methods-schema
 use serde::Serialize;
 
-use crate::types::{ChatId, Message, ReplyMarkup};
+use crate::types::{ChatId, Message, ReplyMarkup, ThreadId};
 
 impl_payload! {
     /// Use this method to send information about a venue. On success, the sent [`Message`] is returned.
@@ -29,6 +29,8 @@ impl_payload! {
             pub address: String [into],
         }
         optional {
+            /// Unique identifier for the target message thread (topic) of the forum; for forum supergroups only
+            pub message_thread_id: ThreadId [into],
             /// Foursquare identifier of the venue
             pub foursquare_id: String [into],
             /// Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
@@ -24,9 +24,9 @@ impl_payload! {
             /// New score
             pub score: u64,
             /// Unique identifier for the target chat
-            pub chat_id: u32,
+            pub chat_id: i64,
             /// Identifier of the message to edit
-            pub message_id: i64,
+            pub message_id: i32,
         }
         optional {
             /// Pass True, if the high score is allowed to decrease. This can be useful when fixing mistakes or banning cheaters
@@ -9,22 +9,29 @@
 #[doc(no_inline)]
 pub use crate::payloads::{
     AddStickerToSetSetters as _, AnswerCallbackQuerySetters as _, AnswerInlineQuerySetters as _,
-    AnswerPreCheckoutQuerySetters as _, AnswerShippingQuerySetters as _, CloseSetters as _,
-    CopyMessageSetters as _, CreateChatInviteLinkSetters as _, CreateNewStickerSetSetters as _,
-    DeleteChatPhotoSetters as _, DeleteChatStickerSetSetters as _, DeleteMessageSetters as _,
-    DeleteStickerFromSetSetters as _, DeleteWebhookSetters as _, EditChatInviteLinkSetters as _,
-    EditMessageCaptionInlineSetters as _, EditMessageCaptionSetters as _,
-    EditMessageLiveLocationInlineSetters as _, EditMessageLiveLocationSetters as _,
-    EditMessageMediaInlineSetters as _, EditMessageMediaSetters as _,
-    EditMessageReplyMarkupInlineSetters as _, EditMessageReplyMarkupSetters as _,
-    EditMessageTextInlineSetters as _, EditMessageTextSetters as _,
-    ExportChatInviteLinkSetters as _, ForwardMessageSetters as _,
+    AnswerPreCheckoutQuerySetters as _, AnswerShippingQuerySetters as _,
+    ApproveChatJoinRequestSetters as _, BanChatSenderChatSetters as _,
+    CloseGeneralForumTopicSetters as _, CloseSetters as _, CopyMessageSetters as _,
+    CreateChatInviteLinkSetters as _, CreateChatSubscriptionInviteLinkSetters as _,
+    CreateForumTopicSetters as _, CreateNewStickerSetSetters as _,
+    DeclineChatJoinRequestSetters as _, DeleteChatPhotoSetters as _,
+    DeleteChatStickerSetSetters as _, DeleteMessageSetters as _, DeleteStickerFromSetSetters as _,
+    DeleteWebhookSetters as _, EditChatInviteLinkSetters as _,
+    EditChatSubscriptionInviteLinkSetters as _, EditForumTopicSetters as _,
+    EditGeneralForumTopicSetters as _, EditMessageCaptionInlineSetters as _,
+    EditMessageCaptionSetters as _, EditMessageLiveLocationInlineSetters as _,
+    EditMessageLiveLocationSetters as _, EditMessageMediaInlineSetters as _,
+    EditMessageMediaSetters as _, EditMessageReplyMarkupInlineSetters as _,
+    EditMessageReplyMarkupSetters as _, EditMessageTextInlineSetters as _,
+    EditMessageTextSetters as _, ExportChatInviteLinkSetters as _, ForwardMessageSetters as _,
     GetChatAdministratorsSetters as _, GetChatMemberSetters as _, GetChatMembersCountSetters as _,
-    GetChatSetters as _, GetFileSetters as _, GetGameHighScoresSetters as _, GetMeSetters as _,
+    GetChatSetters as _, GetCustomEmojiStickersSetters as _, GetFileSetters as _,
+    GetForumTopicIconStickersSetters as _, GetGameHighScoresSetters as _, GetMeSetters as _,
     GetMyCommandsSetters as _, GetStickerSetSetters as _, GetUpdatesSetters as _,
-    GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _, KickChatMemberSetters as _,
-    LeaveChatSetters as _, LogOutSetters as _, PinChatMessageSetters as _,
-    PromoteChatMemberSetters as _, RestrictChatMemberSetters as _,
+    GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _,
+    HideGeneralForumTopicSetters as _, KickChatMemberSetters as _, LeaveChatSetters as _,
+    LogOutSetters as _, PinChatMessageSetters as _, PromoteChatMemberSetters as _,
+    ReopenGeneralForumTopicSetters as _, RestrictChatMemberSetters as _,
     RevokeChatInviteLinkSetters as _, SendAnimationSetters as _, SendAudioSetters as _,
     SendChatActionSetters as _, SendContactSetters as _, SendDiceSetters as _,
     SendDocumentSetters as _, SendGameSetters as _, SendInvoiceSetters as _,
@@ -34,9 +41,11 @@ pub use crate::payloads::{
     SetChatAdministratorCustomTitleSetters as _, SetChatDescriptionSetters as _,
     SetChatPermissionsSetters as _, SetChatPhotoSetters as _, SetChatStickerSetSetters as _,
     SetChatTitleSetters as _, SetGameScoreInlineSetters as _, SetGameScoreSetters as _,
-    SetMyCommandsSetters as _, SetPassportDataErrorsSetters as _,
+    SetMessageReactionSetters as _, SetMyCommandsSetters as _, SetPassportDataErrorsSetters as _,
     SetStickerPositionInSetSetters as _, SetStickerSetThumbSetters as _, SetWebhookSetters as _,
     StopMessageLiveLocationInlineSetters as _, StopMessageLiveLocationSetters as _,
-    StopPollSetters as _, UnbanChatMemberSetters as _, UnpinAllChatMessagesSetters as _,
-    UnpinChatMessageSetters as _, UploadStickerFileSetters as _,
+    StopPollSetters as _, UnbanChatMemberSetters as _, UnbanChatSenderChatSetters as _,
+    UnhideGeneralForumTopicSetters as _, UnpinAllChatMessagesSetters as _,
+    UnpinAllGeneralForumTopicMessagesSetters as _, UnpinChatMessageSetters as _,
+    UploadStickerFileSetters as _,
 };
@@ -0,0 +1,86 @@
+use crate::{
+    payloads::{
+        CopyMessage, ForwardMessage, SendAnimation, SendAudio, SendContact, SendDice, SendDocument,
+        SendGame, SendInvoice, SendLocation, SendMediaGroup, SendMessage, SendPhoto, SendPoll,
+        SendSticker, SendVenue, SendVideo, SendVideoNote, SendVoice,
+    },
+    requests::HasPayload,
+    types::ThreadId,
+};
+
+/// Payloads which have a `message_thread_id` field, see [`ThreadSetters`].
+pub trait HasThreadId {
+    /// Returns a mutable reference to the `message_thread_id` field.
+    fn message_thread_id_mut(&mut self) -> &mut Option<ThreadId>;
+}
+
+macro_rules! impl_has_thread_id {
+    ($($Payload:ident),* $(,)?) => {
+        $(
+            impl HasThreadId for $Payload {
+                fn message_thread_id_mut(&mut self) -> &mut Option<ThreadId> {
+                    &mut self.message_thread_id
+                }
+            }
+        )*
+    };
+}
+
+impl_has_thread_id! {
+    SendMessage, ForwardMessage, CopyMessage, SendPhoto, SendAudio, SendDocument, SendVideo,
+    SendAnimation, SendVoice, SendVideoNote, SendMediaGroup, SendLocation, SendVenue,
+    SendContact, SendPoll, SendDice, SendSticker, SendInvoice, SendGame,
+}
+
+/// Setters which allow to send a message to a thread, available on all
+/// requests which send messages.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{payloads::ThreadSetters, prelude::*, types::Message};
+///
+/// # let message: Message = serde_json::from_str(r#"{"message_id":2,"date":0,"chat":{"id":-1001234567890,"type":"supergroup","title":"t"},"message_thread_id":1,"is_topic_message":true,"text":"hi"}"#).unwrap();
+/// let bot = Bot::new("TOKEN");
+///
+/// // Reply in the same topic
+/// let mut request = bot.send_message(message.chat.id, "hello");
+/// if let Some(thread) = message.thread_id() {
+///     request = request.in_thread(thread);
+/// }
+/// # let _ = request;
+/// ```
+pub trait ThreadSetters: HasPayload
+where
+    Self::Payload: HasThreadId,
+{
+    /// Sets `message_thread_id` to `thread`.
+    fn in_thread(mut self, thread: ThreadId) -> Self
+    where
+        Self: Sized,
+    {
+        *self.payload_mut().message_thread_id_mut() = Some(thread);
+        self
+    }
+}
+
+impl<P> ThreadSetters for P
+where
+    P: HasPayload,
+    P::Payload: HasThreadId,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_thread() {
+        let payload = SendMessage::new(1, "text").in_thread(ThreadId(7));
+        assert_eq!(payload.message_thread_id, Some(ThreadId(7)));
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["message_thread_id"], 7);
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    payloads::{KickChatMember, RestrictChatMember},
+    requests::HasPayload,
+};
+
+/// Payloads which have an `until_date` field, see [`UntilDateSetters`].
+pub trait HasUntilDate {
+    /// Returns a mutable reference to the `until_date` field.
+    fn until_date_mut(&mut self) -> &mut Option<u64>;
+}
+
+impl HasUntilDate for KickChatMember {
+    fn until_date_mut(&mut self) -> &mut Option<u64> {
+        &mut self.until_date
+    }
+}
+
+impl HasUntilDate for RestrictChatMember {
+    fn until_date_mut(&mut self) -> &mut Option<u64> {
+        &mut self.until_date
+    }
+}
+
+/// Setters of `until_date` which accept [`Duration`] and [`SystemTime`]
+/// instead of raw unix time.
+///
+/// Note that Telegram considers users banned or restricted for less than 30
+/// seconds or more than 366 days to be banned or restricted forever.
+///
+/// ## Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use teloxide_core::{
+///     payloads::UntilDateSetters,
+///     prelude::*,
+///     types::ChatPermissions,
+/// };
+///
+/// let bot = Bot::new("TOKEN");
+/// let _request = bot
+///     .restrict_chat_member(-1001234567890, 42, ChatPermissions::read_only())
+///     .until_date_in(Duration::from_secs(60 * 60));
+/// ```
+pub trait UntilDateSetters: HasPayload
+where
+    Self::Payload: HasUntilDate,
+{
+    /// Sets `until_date` to `duration` from now.
+    fn until_date_in(mut self, duration: Duration) -> Self
+    where
+        Self: Sized,
+    {
+        *self.payload_mut().until_date_mut() = Some(unix_time(SystemTime::now() + duration));
+        self
+    }
+
+    /// Sets `until_date` to the given `time`.
+    ///
+    /// Times before the unix epoch are clamped to the epoch.
+    fn until_time(mut self, time: SystemTime) -> Self
+    where
+        Self: Sized,
+    {
+        *self.payload_mut().until_date_mut() = Some(unix_time(time));
+        self
+    }
+}
+
+impl<P> UntilDateSetters for P
+where
+    P: HasPayload,
+    P::Payload: HasUntilDate,
+{
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ChatPermissions;
+
+    #[test]
+    fn until_time() {
+        let payload = RestrictChatMember::new(1, 2, ChatPermissions::read_only())
+            .until_time(UNIX_EPOCH + Duration::from_secs(100));
+        assert_eq!(payload.until_date, Some(100));
+
+        let payload = KickChatMember::new(1, 2).until_date_in(Duration::from_secs(60));
+        assert!(payload.until_date.unwrap() >= unix_time(SystemTime::now()) + 59);
+    }
+}
@@ -2,7 +2,7 @@
 
 #[doc(no_inline)]
 pub use crate::{
-    payloads::setters::*,
+    payloads::{setters::*, ParseModeSetters as _, ThreadSetters as _, UntilDateSetters as _},
     requests::{Request, Requester, RequesterExt},
     Bot,
 };
@@ -1,23 +1,64 @@
 //! Telegram API requests.
 
 pub use self::{
-    has_payload::HasPayload, json::JsonRequest, multipart::MultipartRequest,
-    multipart_payload::MultipartPayload, payload::Payload, request::Request, requester::Requester,
-    requester_ext::RequesterExt,
+    callback_query_guard::CallbackQueryGuard,
+    edit_message_target::{
+        EditMessageCaptionTarget, EditMessageCaptionTargetSend, EditMessageCaptionTargetSendRef,
+        EditMessageMediaTarget, EditMessageMediaTargetSend, EditMessageMediaTargetSendRef,
+        EditMessageReplyMarkupTarget, EditMessageReplyMarkupTargetSend,
+        EditMessageReplyMarkupTargetSendRef, EditMessageTextTarget, EditMessageTextTargetSend,
+        EditMessageTextTargetSendRef,
+    },
+    has_payload::HasPayload,
+    json::JsonRequest,
+    multipart::MultipartRequest,
+    multipart_payload::MultipartPayload,
+    payload::Payload,
+    polling::{ConflictPolicy, Polling, DEFAULT_POLLING_BACKOFF, DEFAULT_POLLING_JITTER},
+    request::Request,
+    requester::Requester,
+    requester_ext::{RequesterExt, MAX_CUSTOM_EMOJI_IDS},
+    response_with_meta::ResponseWithMeta,
+    sender_request::SenderRequest,
+    serialized_request::SerializedRequest,
+    set_game_score_target::{
+        SetGameScoreTarget, SetGameScoreTargetSend, SetGameScoreTargetSendRef,
+    },
+    update_filter::UpdateFilter,
 };
 
+#[cfg(feature = "tower")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "tower")))]
+pub use self::service::RequestService;
+
+#[cfg(feature = "to_curl")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "to_curl")))]
+pub use self::curl::ToCurl;
+
 /// A type that is returned after making a request to Telegram.
 pub type ResponseResult<T> = Result<T, crate::RequestError>;
 
 /// An output type of [`Payload`] in [`HasPayload`].
 pub type Output<T> = <<T as HasPayload>::Payload as Payload>::Output;
 
+mod callback_query_guard;
+#[cfg(feature = "to_curl")]
+mod curl;
+mod edit_message_target;
 mod has_payload;
 mod json;
 mod multipart;
 pub(crate) mod multipart_payload;
 mod payload;
+mod polling;
 mod request;
 mod requester;
 mod requester_ext;
+mod response_with_meta;
+mod sender_request;
+mod serialized_request;
+#[cfg(feature = "tower")]
+mod service;
+mod set_game_score_target;
+mod update_filter;
 mod utils;
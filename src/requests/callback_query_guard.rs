@@ -0,0 +1,161 @@
+use std::fmt;
+
+use crate::requests::{Request, Requester};
+
+/// A guard which answers a callback query when dropped, unless it was already
+/// answered.
+///
+/// Telegram clients show a progress bar until the bot answers a callback
+/// query, so handlers must always call [`answer_callback_query`], even if
+/// they have nothing to say. This guard makes it impossible to forget it,
+/// e.g. on an early return or `?`: if the query wasn't answered via
+/// [`CallbackQueryGuard::answer`], a query with no text is answered on drop.
+///
+/// The answer on drop is sent in the background with `tokio::spawn`, so the
+/// guard must be dropped inside of a tokio runtime. Errors of that request are
+/// logged.
+///
+/// This type is returned by [`RequesterExt::callback_query_guard`].
+///
+/// ## Examples
+///
+/// ```
+/// # async {
+/// use teloxide_core::{prelude::*, types::CallbackQuery};
+///
+/// let bot = Bot::new("TOKEN");
+/// # let query: CallbackQuery = todo!();
+///
+/// let mut guard = bot.callback_query_guard(&query);
+///
+/// if query.data.as_deref() != Some("ping") {
+///     // The query is answered (with no text) when `guard` is dropped
+///     return Ok(());
+/// }
+///
+/// guard.answer().text("pong").send().await?;
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`answer_callback_query`]: crate::requests::Requester::answer_callback_query
+/// [`RequesterExt::callback_query_guard`]: crate::requests::RequesterExt::callback_query_guard
+#[must_use = "The guard answers the query immediately if it's not used"]
+pub struct CallbackQueryGuard<B>
+where
+    B: Requester,
+    B::Err: fmt::Debug,
+    <B::AnswerCallbackQuery as Request>::Send: 'static,
+{
+    bot: B,
+    query_id: String,
+    answered: bool,
+}
+
+impl<B> CallbackQueryGuard<B>
+where
+    B: Requester,
+    B::Err: fmt::Debug,
+    <B::AnswerCallbackQuery as Request>::Send: 'static,
+{
+    /// Creates a guard for the callback query with id `query_id`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::callback_query_guard`]
+    /// instead.
+    ///
+    /// [`RequesterExt::callback_query_guard`]: crate::requests::RequesterExt::callback_query_guard
+    pub fn new<C>(bot: B, query_id: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            bot,
+            query_id: query_id.into(),
+            answered: false,
+        }
+    }
+
+    /// Returns a request answering the query and disarms the guard.
+    ///
+    /// Note that the guard doesn't check that the returned request is
+    /// actually sent.
+    pub fn answer(&mut self) -> B::AnswerCallbackQuery {
+        self.answered = true;
+        self.bot.answer_callback_query(self.query_id.clone())
+    }
+
+    /// Disarms the guard, so that it doesn't answer the query on drop, e.g.
+    /// if the query was answered in some other way.
+    pub fn disarm(&mut self) {
+        self.answered = true;
+    }
+
+    /// Returns `true` if the query was answered (or the guard was disarmed).
+    pub fn is_answered(&self) -> bool {
+        self.answered
+    }
+
+    /// Returns id of the guarded callback query.
+    pub fn query_id(&self) -> &str {
+        &self.query_id
+    }
+}
+
+impl<B> Drop for CallbackQueryGuard<B>
+where
+    B: Requester,
+    B::Err: fmt::Debug,
+    <B::AnswerCallbackQuery as Request>::Send: 'static,
+{
+    fn drop(&mut self) {
+        if self.answered {
+            return;
+        }
+
+        let send = self.bot.answer_callback_query(self.query_id.clone()).send();
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(err) = send.await {
+                        log::error!("Failed to answer a callback query on drop: {:?}", err);
+                    }
+                });
+            }
+            Err(_) => log::error!(
+                "A callback query guard was dropped outside of a tokio runtime, the query \
+                 {:?} was not answered",
+                self.query_id
+            ),
+        }
+    }
+}
+
+impl<B> fmt::Debug for CallbackQueryGuard<B>
+where
+    B: Requester + fmt::Debug,
+    B::Err: fmt::Debug,
+    <B::AnswerCallbackQuery as Request>::Send: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackQueryGuard")
+            .field("bot", &self.bot)
+            .field("query_id", &self.query_id)
+            .field("answered", &self.answered)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{requests::HasPayload, Bot};
+
+    #[test]
+    fn answer_disarms() {
+        let mut guard = CallbackQueryGuard::new(Bot::new("TOKEN"), "42");
+        assert!(!guard.is_answered());
+
+        let request = guard.answer();
+        assert_eq!(request.payload_ref().callback_query_id, "42");
+        assert!(guard.is_answered());
+    }
+}
@@ -0,0 +1,283 @@
+//! Rendering of requests as cURL commands and raw HTTP, see [`ToCurl`].
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Requests which can be rendered as a copy-pasteable [cURL] command or as raw
+/// HTTP text, useful for debugging and reporting Bot API issues.
+///
+/// The bot token is replaced by `<TOKEN>`.
+///
+/// This is implemented by [`JsonRequest`], [`MultipartRequest`] and requests
+/// of bot adaptors, which render the request they wrap.
+///
+/// For multipart requests this is best-effort: files are only recognized at
+/// the top level of the payload (e.g. [`SendPhoto::photo`], but not files in
+/// [`SendMediaGroup::media`]) and in-memory files are rendered by their names
+/// (`-F field=@name`) since their content can't be included.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{prelude::*, requests::ToCurl, Bot};
+///
+/// let bot = Bot::new("TOKEN");
+/// let curl = bot.send_message(0, "Hi").to_curl();
+///
+/// assert_eq!(
+///     curl,
+///     r#"curl -X POST 'https://api.telegram.org/bot<TOKEN>/SendMessage' -H 'Content-Type: application/json' -d '{"chat_id":0,"text":"Hi"}'"#
+/// );
+/// ```
+///
+/// [cURL]: https://curl.se
+/// [`JsonRequest`]: crate::requests::JsonRequest
+/// [`MultipartRequest`]: crate::requests::MultipartRequest
+/// [`SendPhoto::photo`]: crate::payloads::SendPhoto::photo
+/// [`SendMediaGroup::media`]: crate::payloads::SendMediaGroup::media
+pub trait ToCurl {
+    /// Renders this request as a cURL command.
+    fn to_curl(&self) -> String;
+
+    /// Renders this request as raw HTTP text.
+    ///
+    /// The content of files is not included in multipart requests, so they
+    /// have no `Content-Length`.
+    fn to_http(&self) -> String;
+}
+
+/// Placeholder which replaces the bot token.
+pub(crate) const REDACTED_TOKEN: &str = "<TOKEN>";
+
+/// Returns the URL of the `method` with the token redacted.
+///
+/// `method_url` can't be used since it would escape `<` and `>`.
+pub(crate) fn redacted_url(api_url: reqwest::Url, method: &str) -> String {
+    let root = api_url.join("/").expect("failed to format url");
+    format!("{}bot{}/{}", root, REDACTED_TOKEN, method)
+}
+
+/// Quotes `s` for a POSIX shell.
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+fn json_body<P: Serialize>(payload: &P) -> String {
+    serde_json::to_string(payload)
+        .unwrap_or_else(|err| format!("<failed to serialize the payload: {}>", err))
+}
+
+pub(crate) fn json_curl<P: Serialize>(api_url: reqwest::Url, method: &str, payload: &P) -> String {
+    format!(
+        "curl -X POST {} -H 'Content-Type: application/json' -d {}",
+        quote(&redacted_url(api_url, method)),
+        quote(&json_body(payload)),
+    )
+}
+
+pub(crate) fn json_http<P: Serialize>(api_url: reqwest::Url, method: &str, payload: &P) -> String {
+    let body = json_body(payload);
+
+    format!(
+        "{}Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        http_head(&api_url, method),
+        body.len(),
+        body
+    )
+}
+
+/// Returns the request line and the `Host` header of a request.
+fn http_head(api_url: &reqwest::Url, method: &str) -> String {
+    let host = api_url.host_str().unwrap_or_default().to_owned();
+    let host = match api_url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host,
+    };
+
+    format!(
+        "POST /bot{}/{} HTTP/1.1\r\nHost: {}\r\n",
+        REDACTED_TOKEN, method, host
+    )
+}
+
+/// Boundary of rendered multipart bodies.
+const BOUNDARY: &str = "teloxide-boundary";
+
+/// Renders a multipart request, files at the top level of the payload are
+/// rendered as `-F field=@path`, everything else as `--form-string` text/JSON
+/// fields (so that values starting with `@` or `<` are not read as files).
+pub(crate) fn multipart_curl<P: Serialize>(
+    api_url: reqwest::Url,
+    method: &str,
+    payload: &P,
+) -> String {
+    let mut res = format!("curl -X POST {}", quote(&redacted_url(api_url, method)));
+
+    let fields = match multipart_fields(payload) {
+        Some(fields) => fields,
+        None => return format!("{} <failed to serialize the payload>", res),
+    };
+
+    for (name, field) in fields {
+        let (flag, value) = match field {
+            Field::Text(text) => (" --form-string ", text),
+            Field::File(path) => (" -F ", format!("@{}", path)),
+        };
+
+        res.push_str(flag);
+        res.push_str(&quote(&format!("{}={}", name, value)));
+    }
+
+    res
+}
+
+/// Renders a multipart request as raw HTTP text, the content of files is
+/// replaced by `<content of file>`.
+pub(crate) fn multipart_http<P: Serialize>(
+    api_url: reqwest::Url,
+    method: &str,
+    payload: &P,
+) -> String {
+    let mut res = format!(
+        "{}Content-Type: multipart/form-data; boundary={}\r\n\r\n",
+        http_head(&api_url, method),
+        BOUNDARY
+    );
+
+    let fields = match multipart_fields(payload) {
+        Some(fields) => fields,
+        None => return format!("{}<failed to serialize the payload>", res),
+    };
+
+    for (name, field) in fields {
+        res.push_str(&format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            BOUNDARY, name
+        ));
+        match field {
+            Field::Text(text) => res.push_str(&format!("\r\n\r\n{}\r\n", text)),
+            Field::File(path) => {
+                let file_name = path.rsplit('/').next().unwrap_or_default();
+                res.push_str(&format!(
+                    "; filename=\"{}\"\r\n\r\n<content of {}>\r\n",
+                    file_name, path
+                ))
+            }
+        }
+    }
+    res.push_str(&format!("--{}--\r\n", BOUNDARY));
+
+    res
+}
+
+/// A rendered field of a multipart request.
+enum Field {
+    Text(String),
+    /// A file, by its path or its name.
+    File(String),
+}
+
+/// Returns the non-null fields of the `payload`, or `None` if it can't be
+/// serialized into a map.
+fn multipart_fields<P: Serialize>(payload: &P) -> Option<Vec<(String, Field)>> {
+    match serde_json::to_value(payload) {
+        Ok(Value::Object(fields)) => Some(
+            fields
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(name, value)| (name, field(value)))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Renders a value of a multipart field, recognizing serialized
+/// [`InputFile`]s.
+///
+/// [`InputFile`]: crate::types::InputFile
+fn field(value: Value) -> Field {
+    match value {
+        Value::String(s) => Field::Text(s),
+        Value::Object(map) if map.len() == 1 => {
+            let (kind, inner) = map.iter().next().unwrap();
+            match (kind.as_str(), inner) {
+                ("File", Value::String(path)) => Field::File(path.clone()),
+                ("Memory", Value::Object(memory)) | ("Reopenable", Value::Object(memory)) => {
+                    match memory.get("file_name") {
+                        Some(Value::String(name)) => Field::File(name.clone()),
+                        _ => Field::Text(Value::Object(map).to_string()),
+                    }
+                }
+                ("Url", Value::String(s)) | ("FileId", Value::String(s)) => Field::Text(s.clone()),
+                _ => Field::Text(Value::Object(map).to_string()),
+            }
+        }
+        value => Field::Text(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        payloads::{SendMessage, SendPhoto, SendPhotoSetters as _},
+        types::InputFile,
+    };
+
+    fn url() -> reqwest::Url {
+        reqwest::Url::parse("https://api.telegram.org").unwrap()
+    }
+
+    #[test]
+    fn json() {
+        let payload = SendMessage::new(1, "it's");
+        assert_eq!(
+            json_curl(url(), "SendMessage", &payload),
+            r#"curl -X POST 'https://api.telegram.org/bot<TOKEN>/SendMessage' -H 'Content-Type: application/json' -d '{"chat_id":1,"text":"it'\''s"}'"#
+        );
+
+        let http = json_http(url(), "SendMessage", &payload);
+        assert!(
+            http.starts_with("POST /bot<TOKEN>/SendMessage HTTP/1.1\r\nHost: api.telegram.org\r\n")
+        );
+        assert!(http.ends_with("Content-Length: 27\r\n\r\n{\"chat_id\":1,\"text\":\"it's\"}"));
+    }
+
+    #[test]
+    fn multipart() {
+        let payload = SendPhoto::new(1, InputFile::file("/tmp/cat.png")).caption("@user");
+        assert_eq!(
+            multipart_curl(url(), "SendPhoto", &payload),
+            "curl -X POST 'https://api.telegram.org/bot<TOKEN>/SendPhoto' --form-string 'caption=@user' --form-string 'chat_id=1' -F 'photo=@/tmp/cat.png'"
+        );
+
+        assert_eq!(
+            multipart_http(url(), "SendPhoto", &payload),
+            "POST /bot<TOKEN>/SendPhoto HTTP/1.1\r\nHost: api.telegram.org\r\nContent-Type: \
+             multipart/form-data; boundary=teloxide-boundary\r\n\r\n\
+             --teloxide-boundary\r\nContent-Disposition: form-data; \
+             name=\"caption\"\r\n\r\n@user\r\n\
+             --teloxide-boundary\r\nContent-Disposition: form-data; \
+             name=\"chat_id\"\r\n\r\n1\r\n\
+             --teloxide-boundary\r\nContent-Disposition: form-data; name=\"photo\"; \
+             filename=\"cat.png\"\r\n\r\n<content of /tmp/cat.png>\r\n\
+             --teloxide-boundary--\r\n"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "auto_send")]
+    fn adaptors() {
+        use crate::{
+            requests::{Requester, RequesterExt},
+            Bot,
+        };
+
+        let bot = Bot::new("TOKEN");
+        assert_eq!(
+            bot.clone().auto_send().send_message(0, "Hi").to_curl(),
+            bot.send_message(0, "Hi").to_curl()
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use futures::{
+    future::{Either, MapOk},
+    TryFutureExt,
+};
+
+use crate::{
+    payloads::{
+        EditMessageCaption, EditMessageCaptionInline, EditMessageCaptionInlineSetters,
+        EditMessageCaptionSetters, EditMessageMedia, EditMessageMediaInline,
+        EditMessageMediaInlineSetters, EditMessageMediaSetters, EditMessageReplyMarkup,
+        EditMessageReplyMarkupInline, EditMessageReplyMarkupInlineSetters,
+        EditMessageReplyMarkupSetters, EditMessageText, EditMessageTextInline,
+        EditMessageTextInlineSetters, EditMessageTextSetters,
+    },
+    requests::Request,
+    types::{InlineKeyboardMarkup, Message, MessageEntity, ParseMode, True},
+};
+
+macro_rules! edit_target {
+    (
+        $(#[$meta:meta])*
+        $Name:ident ($Send:ident, $SendRef:ident): $Common:ident, $Inline:ident, $ext:ident;
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Telegram returns the edited [`Message`] for messages in chats and
+        /// `True` for inline messages. This request unifies both: it resolves
+        /// to `Some(message)` for chat messages and to `None` for inline
+        /// messages.
+        #[doc = concat!(
+            "\n\nThis type is returned by [`RequesterExt::", stringify!($ext), "`].",
+            "\n\n[`RequesterExt::", stringify!($ext), "`]: crate::requests::RequesterExt::",
+            stringify!($ext),
+        )]
+        #[must_use = "Requests do nothing unless sent"]
+        #[derive(Clone, Debug)]
+        pub enum $Name<C, I> {
+            #[doc = concat!("[`", stringify!($Common), "`] request, targeting a message in a chat.")]
+            ///
+            #[doc = concat!("[`", stringify!($Common), "`]: crate::payloads::", stringify!($Common))]
+            Common(C),
+            #[doc = concat!("[`", stringify!($Inline), "`] request, targeting an inline message.")]
+            ///
+            #[doc = concat!("[`", stringify!($Inline), "`]: crate::payloads::", stringify!($Inline))]
+            Inline(I),
+        }
+
+        #[doc = concat!("A future returned by [`", stringify!($Name), "::send`].")]
+        pub type $Send<C, I> = Either<
+            MapOk<<C as Request>::Send, fn(Message) -> Option<Message>>,
+            MapOk<<I as Request>::Send, fn(True) -> Option<Message>>,
+        >;
+
+        #[doc = concat!("A future returned by [`", stringify!($Name), "::send_ref`].")]
+        pub type $SendRef<C, I> = Either<
+            MapOk<<C as Request>::SendRef, fn(Message) -> Option<Message>>,
+            MapOk<<I as Request>::SendRef, fn(True) -> Option<Message>>,
+        >;
+
+        impl<C, I> $Name<C, I>
+        where
+            C: Request<Payload = $Common>,
+            I: Request<Payload = $Inline, Err = C::Err>,
+        {
+            /// Additional interface options. A JSON-serialized object for an
+            /// [inline keyboard].
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub fn reply_markup(self, value: InlineKeyboardMarkup) -> Self {
+                map_both!(self, req => req.reply_markup(value))
+            }
+
+            /// Send this request.
+            ///
+            /// See also: [`Request::send`].
+            pub fn send(self) -> $Send<C, I> {
+                match self {
+                    Self::Common(req) => Either::Left(req.send().map_ok(Some as _)),
+                    Self::Inline(req) => Either::Right(req.send().map_ok(none as _)),
+                }
+            }
+
+            /// Send this request by reference.
+            ///
+            /// See also: [`Request::send_ref`].
+            pub fn send_ref(&self) -> $SendRef<C, I> {
+                match self {
+                    Self::Common(req) => Either::Left(req.send_ref().map_ok(Some as _)),
+                    Self::Inline(req) => Either::Right(req.send_ref().map_ok(none as _)),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! map_both {
+    ($this:expr, $req:ident => $e:expr) => {
+        match $this {
+            Self::Common($req) => Self::Common($e),
+            Self::Inline($req) => Self::Inline($e),
+        }
+    };
+}
+
+edit_target! {
+    /// A request to edit text of a message, which targets either a message in
+    /// a chat or an inline message.
+    EditMessageTextTarget (EditMessageTextTargetSend, EditMessageTextTargetSendRef):
+        EditMessageText, EditMessageTextInline, edit_message_text_target;
+}
+
+edit_target! {
+    /// A request to edit caption of a message, which targets either a message
+    /// in a chat or an inline message.
+    EditMessageCaptionTarget (EditMessageCaptionTargetSend, EditMessageCaptionTargetSendRef):
+        EditMessageCaption, EditMessageCaptionInline, edit_message_caption_target;
+}
+
+edit_target! {
+    /// A request to edit media of a message, which targets either a message in
+    /// a chat or an inline message.
+    EditMessageMediaTarget (EditMessageMediaTargetSend, EditMessageMediaTargetSendRef):
+        EditMessageMedia, EditMessageMediaInline, edit_message_media_target;
+}
+
+edit_target! {
+    /// A request to edit reply markup of a message, which targets either a
+    /// message in a chat or an inline message.
+    EditMessageReplyMarkupTarget (
+        EditMessageReplyMarkupTargetSend,
+        EditMessageReplyMarkupTargetSendRef
+    ): EditMessageReplyMarkup, EditMessageReplyMarkupInline, edit_message_reply_markup_target;
+}
+
+impl<C, I> EditMessageTextTarget<C, I>
+where
+    C: Request<Payload = EditMessageText>,
+    I: Request<Payload = EditMessageTextInline, Err = C::Err>,
+{
+    /// Mode for parsing entities in the message text.
+    pub fn parse_mode(self, value: ParseMode) -> Self {
+        map_both!(self, req => req.parse_mode(value))
+    }
+
+    /// List of special entities that appear in message text, which can be
+    /// specified instead of `parse_mode`.
+    pub fn entities<E>(self, value: E) -> Self
+    where
+        E: IntoIterator<Item = MessageEntity>,
+    {
+        map_both!(self, req => req.entities(value))
+    }
+
+    /// Disables link previews for links in this message.
+    pub fn disable_web_page_preview(self, value: bool) -> Self {
+        map_both!(self, req => req.disable_web_page_preview(value))
+    }
+}
+
+impl<C, I> EditMessageCaptionTarget<C, I>
+where
+    C: Request<Payload = EditMessageCaption>,
+    I: Request<Payload = EditMessageCaptionInline, Err = C::Err>,
+{
+    /// New caption of the message, 0-1024 characters after entities parsing.
+    pub fn caption<T>(self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        map_both!(self, req => req.caption(value))
+    }
+
+    /// Mode for parsing entities in the message caption.
+    pub fn parse_mode(self, value: ParseMode) -> Self {
+        map_both!(self, req => req.parse_mode(value))
+    }
+
+    /// List of special entities that appear in the caption, which can be
+    /// specified instead of `parse_mode`.
+    pub fn caption_entities<E>(self, value: E) -> Self
+    where
+        E: IntoIterator<Item = MessageEntity>,
+    {
+        map_both!(self, req => req.caption_entities(value))
+    }
+}
+
+fn none(_: True) -> Option<Message> {
+    None
+}
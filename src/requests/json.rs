@@ -1,15 +1,21 @@
+use std::future::Future;
+
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     bot::Bot,
-    requests::{HasPayload, Payload, Request, ResponseResult},
+    requests::{HasPayload, Payload, Request, ResponseResult, ResponseWithMeta},
     RequestError,
 };
 
+#[cfg(feature = "to_curl")]
+use crate::requests::ToCurl;
+
 /// A ready-to-send Telegram request whose payload is sent using [JSON].
 ///
 /// [JSON]: https://core.telegram.org/bots/api#making-requests
 #[must_use = "requests do nothing until sent"]
+#[derive(Clone)]
 pub struct JsonRequest<P> {
     bot: Bot,
     payload: P,
@@ -19,6 +25,48 @@ impl<P> JsonRequest<P> {
     pub const fn new(bot: Bot, payload: P) -> Self {
         Self { bot, payload }
     }
+
+    /// Sends this request to the `url` instead of the API URL(s) of the bot,
+    /// see [`Bot::set_api_url`].
+    pub fn api_url(mut self, url: reqwest::Url) -> Self {
+        self.bot = self.bot.set_api_url(url);
+        self
+    }
+}
+
+#[cfg(feature = "to_curl")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "to_curl")))]
+impl<P> ToCurl for JsonRequest<P>
+where
+    P: Payload + Serialize,
+{
+    fn to_curl(&self) -> String {
+        super::curl::json_curl(self.bot.api_url(), P::NAME, &self.payload)
+    }
+
+    fn to_http(&self) -> String {
+        super::curl::json_http(self.bot.api_url(), P::NAME, &self.payload)
+    }
+}
+
+impl<P> JsonRequest<P>
+where
+    P: 'static,
+    P: Payload + Serialize,
+    P::Output: DeserializeOwned,
+{
+    /// Sends this request, returning the result along with the metadata of
+    /// the response (description and [`ResponseParameters`]).
+    ///
+    /// This is useful to react to parameters which are not turned into errors,
+    /// e.g. a chat migration reported alongside a successful result.
+    ///
+    /// [`ResponseParameters`]: crate::types::ResponseParameters
+    pub fn send_with_meta(
+        self,
+    ) -> impl Future<Output = ResponseWithMeta<P::Output>> + std::marker::Send {
+        self.bot.execute_json_with_meta(&self.payload)
+    }
 }
 
 impl<P> Request for JsonRequest<P>
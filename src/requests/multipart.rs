@@ -1,16 +1,22 @@
+use std::future::Future;
+
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     bot::Bot,
-    requests::{HasPayload, MultipartPayload, Payload, Request, ResponseResult},
+    requests::{HasPayload, MultipartPayload, Payload, Request, ResponseResult, ResponseWithMeta},
     RequestError,
 };
 
+#[cfg(feature = "to_curl")]
+use crate::requests::ToCurl;
+
 /// A ready-to-send Telegram request whose payload is sent using
 /// [multipart/form-data].
 ///
 /// [multipart/form-data]: https://core.telegram.org/bots/api#making-requests
 #[must_use = "requests do nothing until sent"]
+#[derive(Clone)]
 pub struct MultipartRequest<P> {
     bot: Bot,
     payload: P,
@@ -20,6 +26,48 @@ impl<P> MultipartRequest<P> {
     pub const fn new(bot: Bot, payload: P) -> Self {
         Self { bot, payload }
     }
+
+    /// Sends this request to the `url` instead of the API URL(s) of the bot,
+    /// see [`Bot::set_api_url`].
+    pub fn api_url(mut self, url: reqwest::Url) -> Self {
+        self.bot = self.bot.set_api_url(url);
+        self
+    }
+}
+
+#[cfg(feature = "to_curl")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "to_curl")))]
+impl<P> ToCurl for MultipartRequest<P>
+where
+    P: Payload + MultipartPayload + Serialize,
+{
+    fn to_curl(&self) -> String {
+        super::curl::multipart_curl(self.bot.api_url(), P::NAME, &self.payload)
+    }
+
+    fn to_http(&self) -> String {
+        super::curl::multipart_http(self.bot.api_url(), P::NAME, &self.payload)
+    }
+}
+
+impl<P> MultipartRequest<P>
+where
+    P: 'static,
+    P: Payload + MultipartPayload + Serialize,
+    P::Output: DeserializeOwned,
+{
+    /// Sends this request, returning the result along with the metadata of
+    /// the response (description and [`ResponseParameters`]).
+    ///
+    /// This is useful to react to parameters which are not turned into errors,
+    /// e.g. a chat migration reported alongside a successful result.
+    ///
+    /// [`ResponseParameters`]: crate::types::ResponseParameters
+    pub fn send_with_meta(
+        self,
+    ) -> impl Future<Output = ResponseWithMeta<P::Output>> + std::marker::Send {
+        self.bot.execute_multipart_with_meta(&self.payload)
+    }
 }
 
 impl<P> Request for MultipartRequest<P>
@@ -0,0 +1,618 @@
+use std::{
+    collections::{hash_map::RandomState, VecDeque},
+    convert::TryFrom,
+    fmt,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use futures::{stream, Stream};
+
+use crate::{
+    errors::{ApiError, AsApiError, AsResponseParameters},
+    payloads::GetUpdatesSetters,
+    requests::{Request, Requester, UpdateFilter},
+    types::{AllowedUpdate, Update},
+};
+
+/// What [`Polling`] does when [`GetUpdates`] fails because updates are
+/// received by someone else, see [`ApiError::is_get_updates_conflict`].
+///
+/// This usually means that two instances of the bot are running, so retrying
+/// immediately just makes the instances terminate each other's requests in a
+/// tight loop.
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`ApiError::is_get_updates_conflict`]: crate::ApiError::is_get_updates_conflict
+#[derive(Clone)]
+pub enum ConflictPolicy {
+    /// Yield the error and retry after the delay.
+    Backoff(Duration),
+
+    /// Yield the error and end the stream.
+    Abort,
+
+    /// Yield the error and call the callback with the number of consecutive
+    /// conflicts. If it returns a delay, retry after it, otherwise end the
+    /// stream.
+    ///
+    /// Use this to alert about the conflict, see [`ConflictPolicy::custom`].
+    Custom(Arc<dyn Fn(u32) -> Option<Duration> + Send + Sync>),
+}
+
+impl ConflictPolicy {
+    /// Creates [`ConflictPolicy::Custom`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::requests::ConflictPolicy;
+    ///
+    /// // Alert and give up after a minute of conflicts
+    /// let policy = ConflictPolicy::custom(|conflicts| {
+    ///     log::warn!("another instance of the bot is running");
+    ///     if conflicts < 12 {
+    ///         Some(Duration::from_secs(5))
+    ///     } else {
+    ///         None
+    ///     }
+    /// });
+    /// # let _ = policy;
+    /// ```
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(u32) -> Option<Duration> + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Returns the delay before retrying after `conflicts` consecutive
+    /// conflicts, or `None` if polling should stop.
+    fn on_conflict(&self, conflicts: u32) -> Option<Duration> {
+        match self {
+            Self::Backoff(delay) => Some(*delay),
+            Self::Abort => None,
+            Self::Custom(f) => f(conflicts),
+        }
+    }
+}
+
+/// Backs off for 5 seconds.
+impl Default for ConflictPolicy {
+    fn default() -> Self {
+        Self::Backoff(Duration::from_secs(5))
+    }
+}
+
+impl fmt::Debug for ConflictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Backoff(delay) => f.debug_tuple("Backoff").field(delay).finish(),
+            Self::Abort => f.write_str("Abort"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// A stream of updates received by long polling, see
+/// [`RequesterExt::polling`].
+///
+/// [`GetUpdates`] is called with the offset of the last received update, so
+/// every update is yielded once. Errors are yielded too, after which:
+/// - a conflict with another instance of the bot is handled according to the
+///   [`ConflictPolicy`]
+/// - after exceeding flood control, polling waits for the [`retry_after`]
+/// - an [invalid token] ends the stream, since retrying can't succeed
+/// - after any other error polling backs off, see [`Polling::backoff`]
+///
+/// ## Examples
+///
+/// ```no_run
+/// # async {
+/// use futures::StreamExt;
+/// use teloxide_core::{prelude::*, requests::ConflictPolicy};
+///
+/// let bot = Bot::new("TOKEN");
+/// let mut updates = bot
+///     .polling()
+///     .timeout(30)
+///     .on_conflict(ConflictPolicy::Abort)
+///     .into_stream()
+///     .boxed();
+///
+/// while let Some(update) = updates.next().await {
+///     println!("{:?}", update?);
+/// }
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`GetUpdates`]: crate::payloads::GetUpdates
+/// [`RequesterExt::polling`]: crate::requests::RequesterExt::polling
+/// [`retry_after`]: crate::AsResponseParameters::retry_after
+/// [invalid token]: crate::ApiError::is_invalid_token
+#[must_use = "Polling does nothing unless turned into a stream"]
+#[derive(Clone, Debug)]
+pub struct Polling<B> {
+    bot: B,
+    timeout: Option<u32>,
+    limit: Option<u8>,
+    allowed_updates: Option<Vec<AllowedUpdate>>,
+    on_conflict: ConflictPolicy,
+    backoff: (Duration, Duration),
+    adaptive_timeout: Option<(u32, u32)>,
+    jitter: f64,
+    filter: Option<UpdateFilter>,
+}
+
+/// The default of [`Polling::jitter`].
+pub const DEFAULT_POLLING_JITTER: f64 = 0.1;
+
+/// The default of [`Polling::backoff`], the delay starts at a second and
+/// doubles up to a minute.
+pub const DEFAULT_POLLING_BACKOFF: (Duration, Duration) =
+    (Duration::from_secs(1), Duration::from_secs(60));
+
+impl<B> Polling<B>
+where
+    B: Requester,
+    B::Err: AsApiError + AsResponseParameters,
+{
+    /// Creates new `Polling`.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::polling`] instead.
+    ///
+    /// [`RequesterExt::polling`]: crate::requests::RequesterExt::polling
+    pub fn new(bot: B) -> Self {
+        Self {
+            bot,
+            timeout: None,
+            limit: None,
+            allowed_updates: None,
+            on_conflict: ConflictPolicy::default(),
+            backoff: DEFAULT_POLLING_BACKOFF,
+            adaptive_timeout: None,
+            jitter: DEFAULT_POLLING_JITTER,
+            filter: None,
+        }
+    }
+
+    /// Sets the long polling timeout in seconds, see [`GetUpdates::timeout`].
+    ///
+    /// Note that the timeout of the HTTP client should be bigger, see
+    /// [`default_reqwest_settings`].
+    ///
+    /// [`GetUpdates::timeout`]: crate::payloads::GetUpdates::timeout
+    /// [`default_reqwest_settings`]: crate::net::default_reqwest_settings
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of updates received at once, see
+    /// [`GetUpdates::limit`].
+    ///
+    /// [`GetUpdates::limit`]: crate::payloads::GetUpdates::limit
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets types of the updates to receive, see
+    /// [`GetUpdates::allowed_updates`].
+    ///
+    /// [`GetUpdates::allowed_updates`]: crate::payloads::GetUpdates::allowed_updates
+    pub fn allowed_updates<I>(mut self, allowed_updates: I) -> Self
+    where
+        I: IntoIterator<Item = AllowedUpdate>,
+    {
+        self.allowed_updates = Some(allowed_updates.into_iter().collect());
+        self
+    }
+
+    /// Sets a filter of updates, updates which don't match it are skipped
+    /// (but still confirmed, so they are not received again).
+    ///
+    /// Prefer [`allowed_updates`] for filtering by kind only, since it
+    /// prevents Telegram from sending such updates at all.
+    ///
+    /// [`allowed_updates`]: Polling::allowed_updates
+    pub fn filter(mut self, filter: UpdateFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the policy of handling conflicts with other instances of the bot,
+    /// by default backs off for 5 seconds.
+    pub fn on_conflict(mut self, policy: ConflictPolicy) -> Self {
+        self.on_conflict = policy;
+        self
+    }
+
+    /// Sets a delay before retrying after errors (other than conflicts, flood
+    /// control and invalid tokens), by default [`DEFAULT_POLLING_BACKOFF`].
+    ///
+    /// The delay starts at `initial` and doubles with every consecutive error
+    /// up to `max`.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = (initial, max);
+        self
+    }
+
+    /// Adapts the long polling timeout (in seconds) to the connection,
+    /// keeping it between `min` and `max`. This overrides
+    /// [`Polling::timeout`].
+    ///
+    /// The timeout starts at `max`. Network errors (e.g. the HTTP client
+    /// timing out before Telegram responds) halve it, successful requests
+    /// increase it by a second. If the observed latency (how much longer than
+    /// the timeout a request without updates took) is more than a quarter of
+    /// the timeout, it's increased to `max` straight away, so that slow
+    /// connections spend less time on round trips.
+    pub fn adaptive_timeout(mut self, min: u32, max: u32) -> Self {
+        assert!(min <= max, "`min` must not be greater than `max`");
+        self.adaptive_timeout = Some((min, max));
+        self
+    }
+
+    /// Sets the jitter, the fraction by which delays and timeouts are
+    /// randomized, by default [`DEFAULT_POLLING_JITTER`].
+    ///
+    /// Delays are changed by up to `jitter` in both directions, timeouts are
+    /// only decreased, e.g. with the jitter of `0.1` the timeout of 30 seconds
+    /// becomes anything from 27 to 30 seconds. Use `0.0` to disable the
+    /// jitter.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&jitter),
+            "`jitter` must be between 0 and 1"
+        );
+        self.jitter = jitter;
+        self
+    }
+
+    /// Turns this into a stream of updates.
+    pub fn into_stream(self) -> impl Stream<Item = Result<Update, B::Err>> {
+        let state = State {
+            timeout: self.adaptive_timeout.map(|(_, max)| max),
+            polling: self,
+            offset: None,
+            buffer: VecDeque::new(),
+            conflicts: 0,
+            failures: 0,
+            delay: None,
+            done: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            let item = state.next().await?;
+            Some((item, state))
+        })
+    }
+}
+
+struct State<B> {
+    polling: Polling<B>,
+    /// Offset for the next `GetUpdates`, i.e. the last update id plus one.
+    offset: Option<i32>,
+    /// Received, but not yet yielded updates.
+    buffer: VecDeque<Update>,
+    /// Number of consecutive conflicts.
+    conflicts: u32,
+    /// Number of consecutive errors, other than conflicts and flood control.
+    failures: u32,
+    /// The current adaptive timeout, if enabled.
+    timeout: Option<u32>,
+    /// Delay before the next `GetUpdates`.
+    delay: Option<Duration>,
+    done: bool,
+}
+
+impl<B> State<B>
+where
+    B: Requester,
+    B::Err: AsApiError + AsResponseParameters,
+{
+    async fn next(&mut self) -> Option<Result<Update, B::Err>> {
+        loop {
+            if let Some(update) = self.buffer.pop_front() {
+                match &self.polling.filter {
+                    Some(filter) if !filter.matches(&update) => continue,
+                    _ => return Some(Ok(update)),
+                }
+            }
+
+            if self.done {
+                return None;
+            }
+
+            if let Some(delay) = self.delay.take() {
+                tokio::time::sleep(delay).await;
+            }
+
+            let timeout = self
+                .timeout
+                .or(self.polling.timeout)
+                .map(|t| jitter_timeout(t, self.polling.jitter, random()));
+
+            let mut request = self.polling.bot.get_updates();
+            if let Some(offset) = self.offset {
+                request = request.offset(offset);
+            }
+            if let Some(timeout) = timeout {
+                request = request.timeout(timeout);
+            }
+            if let Some(limit) = self.polling.limit {
+                request = request.limit(limit);
+            }
+            if let Some(allowed_updates) = &self.polling.allowed_updates {
+                request = request.allowed_updates(allowed_updates.iter().cloned());
+            }
+
+            let started = Instant::now();
+            match request.send().await {
+                Ok(updates) => {
+                    self.conflicts = 0;
+                    self.failures = 0;
+                    if let (Some(current), Some(timeout)) = (&mut self.timeout, timeout) {
+                        let latency = if updates.is_empty() {
+                            started
+                                .elapsed()
+                                .checked_sub(Duration::from_secs(timeout.into()))
+                        } else {
+                            None
+                        };
+                        *current = grow_timeout(*current, self.polling.adaptive_timeout, latency);
+                    }
+                    if let Some(last) = updates.last() {
+                        self.offset = Some(last.id + 1);
+                    }
+                    self.buffer.extend(updates);
+                }
+                Err(err) => {
+                    if let Some(true) = err.api_error().map(ApiError::is_get_updates_conflict) {
+                        self.conflicts += 1;
+                        match self.polling.on_conflict.on_conflict(self.conflicts) {
+                            Some(delay) => self.delay = Some(self.jitter(delay)),
+                            None => self.done = true,
+                        }
+                    } else if let Some(secs) = err.retry_after() {
+                        let secs = u64::try_from(secs).unwrap_or(0);
+                        self.delay = Some(Duration::from_secs(secs));
+                    } else if let Some(true) = err.api_error().map(ApiError::is_invalid_token) {
+                        self.done = true;
+                    } else {
+                        self.failures += 1;
+                        // Network errors may be caused by the HTTP client timing out
+                        // before Telegram responds
+                        if let (None, Some(current), Some((min, _))) = (
+                            err.api_error(),
+                            &mut self.timeout,
+                            self.polling.adaptive_timeout,
+                        ) {
+                            *current = (*current / 2).max(min);
+                        }
+                        let (initial, max) = self.polling.backoff;
+                        self.delay = Some(self.jitter(backoff(initial, max, self.failures)));
+                    }
+
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+impl<B> State<B> {
+    fn jitter(&self, delay: Duration) -> Duration {
+        jitter_delay(delay, self.polling.jitter, random())
+    }
+}
+
+/// Returns the delay after `failures` consecutive failures.
+fn backoff(initial: Duration, max: Duration, failures: u32) -> Duration {
+    let factor = 1u32
+        .checked_shl(failures.saturating_sub(1))
+        .unwrap_or(u32::MAX);
+    initial
+        .checked_mul(factor)
+        .map_or(max, |delay| delay.min(max))
+}
+
+/// Changes `delay` by up to `jitter` in both directions, `random` must be
+/// in `[0, 1)`.
+fn jitter_delay(delay: Duration, jitter: f64, random: f64) -> Duration {
+    delay.mul_f64(1.0 + jitter * (2.0 * random - 1.0))
+}
+
+/// Decreases `timeout` by up to `jitter`, `random` must be in `[0, 1)`.
+fn jitter_timeout(timeout: u32, jitter: f64, random: f64) -> u32 {
+    timeout - (f64::from(timeout) * jitter * random) as u32
+}
+
+/// Returns the next adaptive timeout after a successful request.
+fn grow_timeout(current: u32, bounds: Option<(u32, u32)>, latency: Option<Duration>) -> u32 {
+    let max = match bounds {
+        Some((_, max)) => max,
+        None => return current,
+    };
+
+    match latency {
+        Some(latency) if latency > Duration::from_secs(current.into()) / 4 => max,
+        _ => current.saturating_add(1).min(max),
+    }
+}
+
+/// Returns a random number in `[0, 1)`.
+fn random() -> f64 {
+    // `RandomState` is randomly seeded, which is enough for jitter and saves
+    // a dependency.
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::{
+        requests::RequesterExt,
+        test_utils::{read_body, respond},
+        Bot, RequestError,
+    };
+
+    #[test]
+    fn conflict_policy() {
+        assert_eq!(
+            ConflictPolicy::default().on_conflict(1),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(ConflictPolicy::Abort.on_conflict(1), None);
+
+        let policy = ConflictPolicy::custom(|n| {
+            if n < 3 {
+                Some(Duration::from_secs(1))
+            } else {
+                None
+            }
+        });
+        assert_eq!(policy.on_conflict(2), Some(Duration::from_secs(1)));
+        assert_eq!(policy.on_conflict(3), None);
+    }
+
+    #[test]
+    fn stream() {
+        let answer = |id| {
+            format!(
+                r#"{{"update_id":{},"poll_answer":{{"poll_id":"p","option_ids":[0],"user":{{"id":1,"is_bot":false,"first_name":"A"}}}}}}"#,
+                id
+            )
+        };
+        let responses = vec![
+            "not json".to_owned(),
+            format!(r#"{{"ok":true,"result":[{},{}]}}"#, answer(1), answer(2)),
+            format!(r#"{{"ok":true,"result":[{}]}}"#, answer(3)),
+        ];
+        let delay = Duration::from_millis(200);
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn(async move {
+                let mut requests = Vec::new();
+                for body in &responses {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    let request: serde_json::Value =
+                        serde_json::from_slice(&read_body(&mut conn).await).unwrap();
+                    requests.push((Instant::now(), request));
+                    respond(&mut conn, body).await;
+                }
+                requests
+            });
+
+            let bot = Bot::new("TOKEN").set_api_url(api_url.parse().unwrap());
+            let items: Vec<_> = bot
+                .polling()
+                .backoff(delay, delay * 4)
+                .jitter(0.0)
+                .into_stream()
+                .take(4)
+                .collect()
+                .await;
+
+            assert!(matches!(items[0], Err(RequestError::InvalidJson(_))));
+            let ids: Vec<_> = items[1..].iter().map(|u| u.as_ref().unwrap().id).collect();
+            assert_eq!(ids, [1, 2, 3]);
+
+            let requests = server.await.unwrap();
+            assert!(requests[1].0 - requests[0].0 >= delay);
+            assert_eq!(requests[1].1.get("offset"), None);
+            assert_eq!(requests[2].1["offset"], 3);
+        });
+    }
+
+    #[test]
+    fn errors() {
+        let responses = [
+            r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 1","parameters":{"retry_after":1}}"#,
+            r#"{"ok":false,"error_code":400,"description":"Bad Request: something new"}"#,
+            r#"{"ok":false,"error_code":401,"description":"Unauthorized"}"#,
+        ];
+        let delay = Duration::from_millis(200);
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn(async move {
+                let mut requests = Vec::new();
+                for body in responses.iter() {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    read_body(&mut conn).await;
+                    requests.push(Instant::now());
+                    respond(&mut conn, body).await;
+                }
+                requests
+            });
+
+            let bot = Bot::new("TOKEN").set_api_url(api_url.parse().unwrap());
+            let items: Vec<_> = bot
+                .polling()
+                .backoff(delay, delay * 4)
+                .jitter(0.0)
+                .into_stream()
+                .collect()
+                .await;
+
+            // The stream ends after the invalid token
+            assert_eq!(items.len(), 3);
+            assert_eq!(items[0].as_ref().unwrap_err().retry_after(), Some(1));
+            assert!(matches!(
+                items[2].as_ref().unwrap_err().api_error(),
+                Some(kind) if kind.is_invalid_token()
+            ));
+
+            let requests = server.await.unwrap();
+            assert!(requests[1] - requests[0] >= Duration::from_secs(1));
+            assert!(requests[2] - requests[1] >= delay);
+        });
+    }
+
+    #[test]
+    fn backoff_and_jitter() {
+        let secs = Duration::from_secs;
+        assert_eq!(backoff(secs(1), secs(10), 1), secs(1));
+        assert_eq!(backoff(secs(1), secs(10), 3), secs(4));
+        assert_eq!(backoff(secs(1), secs(10), 5), secs(10));
+        assert_eq!(backoff(secs(1), secs(10), 100), secs(10));
+
+        let (initial, max) = DEFAULT_POLLING_BACKOFF;
+        assert_eq!(backoff(initial, max, 1), secs(1));
+        assert_eq!(backoff(initial, max, 7), secs(60));
+
+        assert_eq!(jitter_delay(secs(10), 0.1, 0.0), secs(9));
+        assert_eq!(jitter_delay(secs(10), 0.0, 0.7), secs(10));
+        assert_eq!(jitter_timeout(30, 0.1, 0.0), 30);
+        assert_eq!(jitter_timeout(30, 0.1, 0.999), 28);
+
+        let r = random();
+        assert!((0.0..1.0).contains(&r));
+    }
+
+    #[test]
+    fn adaptive_timeout() {
+        let bounds = Some((5, 30));
+        assert_eq!(grow_timeout(10, bounds, None), 11);
+        assert_eq!(grow_timeout(30, bounds, None), 30);
+        assert_eq!(grow_timeout(10, bounds, Some(Duration::from_secs(1))), 11);
+        assert_eq!(grow_timeout(10, bounds, Some(Duration::from_secs(3))), 30);
+        assert_eq!(grow_timeout(10, None, None), 10);
+    }
+}
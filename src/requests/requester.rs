@@ -5,8 +5,9 @@ use crate::{
     payloads::{GetMe, SendMessage, *},
     requests::Request,
     types::{
-        BotCommand, ChatAction, ChatId, ChatPermissions, InlineQueryResult, InputFile, InputMedia,
-        InputSticker, LabeledPrice, PassportElementError, PollType, TargetMessage,
+        BotCommand, ChatAction, ChatId, ChatPermissions, Currency, InlineQueryResult, InputFile,
+        InputMedia, InputSticker, LabeledPrice, PassportElementError, PollType, TargetMessage,
+        ThreadId,
     },
 };
 
@@ -331,6 +332,24 @@ pub trait Requester {
     where
         C: Into<ChatId>;
 
+    type BanChatSenderChat: Request<Payload = BanChatSenderChat, Err = Self::Err>;
+
+    /// For Telegram documentation see [`BanChatSenderChat`].
+    fn ban_chat_sender_chat<C>(&self, chat_id: C, sender_chat_id: i64) -> Self::BanChatSenderChat
+    where
+        C: Into<ChatId>;
+
+    type UnbanChatSenderChat: Request<Payload = UnbanChatSenderChat, Err = Self::Err>;
+
+    /// For Telegram documentation see [`UnbanChatSenderChat`].
+    fn unban_chat_sender_chat<C>(
+        &self,
+        chat_id: C,
+        sender_chat_id: i64,
+    ) -> Self::UnbanChatSenderChat
+    where
+        C: Into<ChatId>;
+
     type RestrictChatMember: Request<Payload = RestrictChatMember, Err = Self::Err>;
 
     /// For Telegram documentation see [`RestrictChatMember`].
@@ -399,6 +418,36 @@ pub trait Requester {
         C: Into<ChatId>,
         I: Into<String>;
 
+    type CreateChatSubscriptionInviteLink: Request<
+        Payload = CreateChatSubscriptionInviteLink,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`CreateChatSubscriptionInviteLink`].
+    fn create_chat_subscription_invite_link<C>(
+        &self,
+        chat_id: C,
+        subscription_period: u32,
+        subscription_price: u32,
+    ) -> Self::CreateChatSubscriptionInviteLink
+    where
+        C: Into<ChatId>;
+
+    type EditChatSubscriptionInviteLink: Request<
+        Payload = EditChatSubscriptionInviteLink,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`EditChatSubscriptionInviteLink`].
+    fn edit_chat_subscription_invite_link<C, I>(
+        &self,
+        chat_id: C,
+        invite_link: I,
+    ) -> Self::EditChatSubscriptionInviteLink
+    where
+        C: Into<ChatId>,
+        I: Into<String>;
+
     type RevokeChatInviteLink: Request<Payload = RevokeChatInviteLink, Err = Self::Err>;
 
     /// For Telegram documentation see [`RevokeChatInviteLink`].
@@ -411,6 +460,28 @@ pub trait Requester {
         C: Into<ChatId>,
         I: Into<String>;
 
+    type ApproveChatJoinRequest: Request<Payload = ApproveChatJoinRequest, Err = Self::Err>;
+
+    /// For Telegram documentation see [`ApproveChatJoinRequest`].
+    fn approve_chat_join_request<C>(
+        &self,
+        chat_id: C,
+        user_id: i64,
+    ) -> Self::ApproveChatJoinRequest
+    where
+        C: Into<ChatId>;
+
+    type DeclineChatJoinRequest: Request<Payload = DeclineChatJoinRequest, Err = Self::Err>;
+
+    /// For Telegram documentation see [`DeclineChatJoinRequest`].
+    fn decline_chat_join_request<C>(
+        &self,
+        chat_id: C,
+        user_id: i64,
+    ) -> Self::DeclineChatJoinRequest
+    where
+        C: Into<ChatId>;
+
     type SetChatPhoto: Request<Payload = SetChatPhoto, Err = Self::Err>;
 
     /// For Telegram documentation see [`SetChatPhoto`].
@@ -461,6 +532,76 @@ pub trait Requester {
     where
         C: Into<ChatId>;
 
+    type CreateForumTopic: Request<Payload = CreateForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`CreateForumTopic`].
+    fn create_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::CreateForumTopic
+    where
+        C: Into<ChatId>,
+        N: Into<String>;
+
+    type EditForumTopic: Request<Payload = EditForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`EditForumTopic`].
+    fn edit_forum_topic<C, T>(&self, chat_id: C, message_thread_id: T) -> Self::EditForumTopic
+    where
+        C: Into<ChatId>,
+        T: Into<ThreadId>;
+
+    type GetForumTopicIconStickers: Request<Payload = GetForumTopicIconStickers, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetForumTopicIconStickers`].
+    fn get_forum_topic_icon_stickers(&self) -> Self::GetForumTopicIconStickers;
+
+    type EditGeneralForumTopic: Request<Payload = EditGeneralForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`EditGeneralForumTopic`].
+    fn edit_general_forum_topic<C, N>(&self, chat_id: C, name: N) -> Self::EditGeneralForumTopic
+    where
+        C: Into<ChatId>,
+        N: Into<String>;
+
+    type CloseGeneralForumTopic: Request<Payload = CloseGeneralForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`CloseGeneralForumTopic`].
+    fn close_general_forum_topic<C>(&self, chat_id: C) -> Self::CloseGeneralForumTopic
+    where
+        C: Into<ChatId>;
+
+    type ReopenGeneralForumTopic: Request<Payload = ReopenGeneralForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`ReopenGeneralForumTopic`].
+    fn reopen_general_forum_topic<C>(&self, chat_id: C) -> Self::ReopenGeneralForumTopic
+    where
+        C: Into<ChatId>;
+
+    type HideGeneralForumTopic: Request<Payload = HideGeneralForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`HideGeneralForumTopic`].
+    fn hide_general_forum_topic<C>(&self, chat_id: C) -> Self::HideGeneralForumTopic
+    where
+        C: Into<ChatId>;
+
+    type UnhideGeneralForumTopic: Request<Payload = UnhideGeneralForumTopic, Err = Self::Err>;
+
+    /// For Telegram documentation see [`UnhideGeneralForumTopic`].
+    fn unhide_general_forum_topic<C>(&self, chat_id: C) -> Self::UnhideGeneralForumTopic
+    where
+        C: Into<ChatId>;
+
+    type UnpinAllGeneralForumTopicMessages: Request<
+        Payload = UnpinAllGeneralForumTopicMessages,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`UnpinAllGeneralForumTopicMessages`].
+    fn unpin_all_general_forum_topic_messages<C>(
+        &self,
+        chat_id: C,
+    ) -> Self::UnpinAllGeneralForumTopicMessages
+    where
+        C: Into<ChatId>;
+
     type LeaveChat: Request<Payload = LeaveChat, Err = Self::Err>;
 
     /// For Telegram documentation see [`LeaveChat`].
@@ -645,6 +786,13 @@ pub trait Requester {
     where
         C: Into<ChatId>;
 
+    type SetMessageReaction: Request<Payload = SetMessageReaction, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetMessageReaction`].
+    fn set_message_reaction<C>(&self, chat_id: C, message_id: i32) -> Self::SetMessageReaction
+    where
+        C: Into<ChatId>;
+
     type SendSticker: Request<Payload = SendSticker, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendSticker`].
@@ -659,6 +807,13 @@ pub trait Requester {
     where
         N: Into<String>;
 
+    type GetCustomEmojiStickers: Request<Payload = GetCustomEmojiStickers, Err = Self::Err>;
+
+    /// For Telegram documentation see [`GetCustomEmojiStickers`].
+    fn get_custom_emoji_stickers<C>(&self, custom_emoji_ids: C) -> Self::GetCustomEmojiStickers
+    where
+        C: IntoIterator<Item = String>;
+
     type UploadStickerFile: Request<Payload = UploadStickerFile, Err = Self::Err>;
 
     /// For Telegram documentation see [`UploadStickerFile`].
@@ -738,7 +893,7 @@ pub trait Requester {
         D: Into<String>,
         Pa: Into<String>,
         P: Into<String>,
-        C: Into<String>,
+        C: Into<Currency>,
         Pri: IntoIterator<Item = LabeledPrice>;
 
     type AnswerShippingQuery: Request<Payload = AnswerShippingQuery, Err = Self::Err>;
@@ -780,8 +935,8 @@ pub trait Requester {
         &self,
         user_id: i64,
         score: u64,
-        chat_id: u32,
-        message_id: i64,
+        chat_id: i64,
+        message_id: i32,
     ) -> Self::SetGameScore;
 
     type SetGameScoreInline: Request<Payload = SetGameScoreInline, Err = Self::Err>;
@@ -831,17 +986,26 @@ macro_rules! forward_all {
             stop_message_live_location, stop_message_live_location_inline, send_venue,
             send_contact, send_poll, send_dice, send_chat_action, get_user_profile_photos,
             get_file, kick_chat_member, unban_chat_member, restrict_chat_member,
+            ban_chat_sender_chat, unban_chat_sender_chat,
             promote_chat_member, set_chat_administrator_custom_title, set_chat_permissions,
             export_chat_invite_link, create_chat_invite_link, edit_chat_invite_link,
+            create_chat_subscription_invite_link, edit_chat_subscription_invite_link,
             revoke_chat_invite_link, set_chat_photo, delete_chat_photo, set_chat_title,
+            approve_chat_join_request, decline_chat_join_request,
             set_chat_description, pin_chat_message, unpin_chat_message, unpin_all_chat_messages,
+            create_forum_topic, edit_forum_topic, get_forum_topic_icon_stickers,
+            edit_general_forum_topic, close_general_forum_topic, reopen_general_forum_topic,
+            hide_general_forum_topic, unhide_general_forum_topic,
+            unpin_all_general_forum_topic_messages,
             leave_chat, get_chat, get_chat_administrators, get_chat_members_count,get_chat_member,
             set_chat_sticker_set, delete_chat_sticker_set, answer_callback_query,
             set_my_commands, get_my_commands, answer_inline_query, edit_message_text,
             edit_message_text_inline, edit_message_caption, edit_message_caption_inline,
             edit_message_media, edit_message_media_inline, edit_message_reply_markup,
             edit_message_reply_markup_inline, stop_poll, delete_message, send_sticker,
+            set_message_reaction,
             get_sticker_set, upload_sticker_file, create_new_sticker_set,
+            get_custom_emoji_stickers,
             add_sticker_to_set, set_sticker_position_in_set, delete_sticker_from_set,
             set_sticker_set_thumb, send_invoice, answer_shipping_query,
             answer_pre_checkout_query, set_passport_data_errors, send_game,
@@ -1,4 +1,23 @@
-use crate::{adaptors::DefaultParseMode, requests::Requester, types::ParseMode};
+use std::{collections::HashMap, time::Duration};
+
+use futures::future::BoxFuture;
+
+use crate::{
+    adaptors::DefaultParseMode,
+    errors::{AsApiError, AsResponseParameters},
+    payloads::{DeleteWebhookSetters, SetMessageReactionSetters, SetWebhook, UntilDateSetters},
+    requests::{
+        CallbackQueryGuard, EditMessageCaptionTarget, EditMessageMediaTarget,
+        EditMessageReplyMarkupTarget, EditMessageTextTarget, HasPayload, Polling, Request,
+        Requester, SenderRequest, SetGameScoreTarget,
+    },
+    types::{
+        CallbackQuery, ChatAdministratorRights, ChatId, ChatJoinRequest, ChatMemberKind,
+        ChatPermissions, GameTarget, InputMedia, Message, ParseMode, PreCheckoutAnswer,
+        ReactionType, ShippingAnswer, Sticker, TargetMessage,
+    },
+    utils::custom_emoji,
+};
 
 #[cfg(feature = "cache_me")]
 use crate::adaptors::CacheMe;
@@ -9,6 +28,36 @@ use crate::adaptors::AutoSend;
 #[cfg(feature = "throttle")]
 use crate::adaptors::throttle::{Limits, Throttle};
 
+#[cfg(feature = "audit")]
+use crate::adaptors::Audit;
+
+#[cfg(feature = "chat_queue")]
+use crate::adaptors::ChatQueue;
+
+#[cfg(feature = "concurrency_limit")]
+use crate::adaptors::ConcurrencyLimit;
+
+#[cfg(feature = "media_cache")]
+use crate::adaptors::MediaCache;
+
+#[cfg(feature = "chat_migration")]
+use crate::adaptors::ChatMigration;
+
+#[cfg(feature = "error_hook")]
+use crate::adaptors::{error_hook::ErrorContext, ErrorHook};
+
+#[cfg(feature = "outbox")]
+use crate::adaptors::{Outbox, OutboxStore};
+
+#[cfg(feature = "validate")]
+use crate::adaptors::ValidateRequests;
+
+/// The maximum number of custom emoji identifiers in one
+/// [`GetCustomEmojiStickers`] request.
+///
+/// [`GetCustomEmojiStickers`]: crate::payloads::GetCustomEmojiStickers
+pub const MAX_CUSTOM_EMOJI_IDS: usize = 200;
+
 /// Extensions methods for [`Requester`].
 pub trait RequesterExt: Requester {
     /// Add `get_me` caching ability, see [`CacheMe`] for more.
@@ -48,6 +97,97 @@ pub trait RequesterExt: Requester {
         Throttle::new_spawn(self, limits)
     }
 
+    /// Log all sent requests with redacted payloads, see [`Audit`] for more.
+    #[cfg(feature = "audit")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "audit")))]
+    fn audit(self) -> Audit<Self>
+    where
+        Self: Sized,
+    {
+        Audit::new(self)
+    }
+
+    /// Deliver messages to the same chat in order, see [`ChatQueue`] for
+    /// more.
+    #[cfg(feature = "chat_queue")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_queue")))]
+    fn chat_queue(self) -> ChatQueue<Self>
+    where
+        Self: Sized,
+    {
+        ChatQueue::new(self)
+    }
+
+    /// Limit the number of requests in flight to `limit`, see
+    /// [`ConcurrencyLimit`] for more.
+    #[cfg(feature = "concurrency_limit")]
+    #[cfg_attr(
+        all(docsrs, feature = "nightly"),
+        doc(cfg(feature = "concurrency_limit"))
+    )]
+    fn concurrency_limit(self, limit: usize) -> ConcurrencyLimit<Self>
+    where
+        Self: Sized,
+    {
+        ConcurrencyLimit::new(self, limit)
+    }
+
+    /// Reuse `file_id`s of already uploaded files, see [`MediaCache`] for
+    /// more.
+    #[cfg(feature = "media_cache")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "media_cache")))]
+    fn media_cache(self) -> MediaCache<Self>
+    where
+        Self: Sized,
+    {
+        MediaCache::new(self)
+    }
+
+    /// Retry requests to chats which were migrated to supergroups, see
+    /// [`ChatMigration`] for more.
+    #[cfg(feature = "chat_migration")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "chat_migration")))]
+    fn chat_migration(self) -> ChatMigration<Self>
+    where
+        Self: Sized,
+        Self::Err: crate::errors::AsResponseParameters,
+    {
+        ChatMigration::new(self)
+    }
+
+    /// Call `hook` on every request error, see [`ErrorHook`] for more.
+    #[cfg(feature = "error_hook")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "error_hook")))]
+    fn error_hook<H>(self, hook: H) -> ErrorHook<Self, H>
+    where
+        Self: Sized,
+        H: Fn(&ErrorContext, Self::Err) -> Self::Err + Send + Sync + 'static,
+    {
+        ErrorHook::new(self, hook)
+    }
+
+    /// Reject obviously invalid requests before sending them, see
+    /// [`ValidateRequests`] for more.
+    #[cfg(feature = "validate")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "validate")))]
+    fn validate(self) -> ValidateRequests<Self>
+    where
+        Self: Sized,
+    {
+        ValidateRequests::new(self)
+    }
+
+    /// Persist requests before sending them, see [`Outbox`] for more.
+    #[cfg(feature = "outbox")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "outbox")))]
+    fn outbox<S>(self, store: S) -> Outbox<Self>
+    where
+        Self: Sized,
+        S: OutboxStore + 'static,
+    {
+        Outbox::new(self, store)
+    }
+
     /// Specifies default [`ParseMode`], which will be used during all calls to:
     ///
     ///  - [`send_message`]
@@ -82,6 +222,848 @@ pub trait RequesterExt: Requester {
     {
         DefaultParseMode::new(self, parse_mode)
     }
+
+    /// Sets the score of the specified user in a game, targeting either a
+    /// message in a chat or an inline message.
+    ///
+    /// This calls [`set_game_score`] or [`set_game_score_inline`] depending on
+    /// the `target`, so you don't need to handle both cases yourself.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::GameTarget};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// # let inline_message_id = String::new();
+    ///
+    /// let target = GameTarget::from(inline_message_id);
+    /// let edited = bot.set_game_score_target(0, 100, target).force(true).send().await?;
+    /// // Inline messages are not returned by telegram
+    /// assert_eq!(edited, None);
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`set_game_score`]: crate::requests::Requester::set_game_score
+    /// [`set_game_score_inline`]: crate::requests::Requester::set_game_score_inline
+    fn set_game_score_target<T>(
+        &self,
+        user_id: i64,
+        score: u64,
+        target: T,
+    ) -> SetGameScoreTarget<Self::SetGameScore, Self::SetGameScoreInline>
+    where
+        T: Into<GameTarget>,
+    {
+        match target.into() {
+            GameTarget::Common {
+                chat_id,
+                message_id,
+            } => {
+                SetGameScoreTarget::Common(self.set_game_score(user_id, score, chat_id, message_id))
+            }
+            GameTarget::Inline { inline_message_id } => SetGameScoreTarget::Inline(
+                self.set_game_score_inline(user_id, score, inline_message_id),
+            ),
+        }
+    }
+
+    /// Edits text of a message, which is either a message in a chat or an
+    /// inline message.
+    ///
+    /// This calls [`edit_message_text`] or [`edit_message_text_inline`]
+    /// depending on the `target`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     types::{Message, ParseMode, TargetMessage},
+    /// };
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// # let message: Message = todo!();
+    ///
+    /// // Edits a message in a chat
+    /// bot.edit_message_text_target(&message, "*edited*")
+    ///     .parse_mode(ParseMode::MarkdownV2)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// // Edits an inline message
+    /// # let inline_message_id: String = todo!();
+    /// bot.edit_message_text_target(inline_message_id, "edited")
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`edit_message_text`]: crate::requests::Requester::edit_message_text
+    /// [`edit_message_text_inline`]: crate::requests::Requester::edit_message_text_inline
+    fn edit_message_text_target<T, X>(
+        &self,
+        target: T,
+        text: X,
+    ) -> EditMessageTextTarget<Self::EditMessageText, Self::EditMessageTextInline>
+    where
+        T: Into<TargetMessage>,
+        X: Into<String>,
+    {
+        match target.into() {
+            TargetMessage::Common {
+                chat_id,
+                message_id,
+            } => EditMessageTextTarget::Common(self.edit_message_text(chat_id, message_id, text)),
+            TargetMessage::Inline { inline_message_id } => EditMessageTextTarget::Inline(
+                self.edit_message_text_inline(inline_message_id, text),
+            ),
+        }
+    }
+
+    /// Edits caption of a message, which is either a message in a chat or an
+    /// inline message.
+    ///
+    /// This calls [`edit_message_caption`] or [`edit_message_caption_inline`]
+    /// depending on the `target`, see also [`edit_message_text_target`].
+    ///
+    /// [`edit_message_caption`]: crate::requests::Requester::edit_message_caption
+    /// [`edit_message_caption_inline`]: crate::requests::Requester::edit_message_caption_inline
+    /// [`edit_message_text_target`]: RequesterExt::edit_message_text_target
+    fn edit_message_caption_target<T>(
+        &self,
+        target: T,
+    ) -> EditMessageCaptionTarget<Self::EditMessageCaption, Self::EditMessageCaptionInline>
+    where
+        T: Into<TargetMessage>,
+    {
+        match target.into() {
+            TargetMessage::Common {
+                chat_id,
+                message_id,
+            } => EditMessageCaptionTarget::Common(self.edit_message_caption(chat_id, message_id)),
+            TargetMessage::Inline { inline_message_id } => EditMessageCaptionTarget::Inline(
+                self.edit_message_caption_inline(inline_message_id),
+            ),
+        }
+    }
+
+    /// Edits media of a message, which is either a message in a chat or an
+    /// inline message.
+    ///
+    /// This calls [`edit_message_media`] or [`edit_message_media_inline`]
+    /// depending on the `target`, see also [`edit_message_text_target`].
+    ///
+    /// [`edit_message_media`]: crate::requests::Requester::edit_message_media
+    /// [`edit_message_media_inline`]: crate::requests::Requester::edit_message_media_inline
+    /// [`edit_message_text_target`]: RequesterExt::edit_message_text_target
+    fn edit_message_media_target<T>(
+        &self,
+        target: T,
+        media: InputMedia,
+    ) -> EditMessageMediaTarget<Self::EditMessageMedia, Self::EditMessageMediaInline>
+    where
+        T: Into<TargetMessage>,
+    {
+        match target.into() {
+            TargetMessage::Common {
+                chat_id,
+                message_id,
+            } => {
+                EditMessageMediaTarget::Common(self.edit_message_media(chat_id, message_id, media))
+            }
+            TargetMessage::Inline { inline_message_id } => EditMessageMediaTarget::Inline(
+                self.edit_message_media_inline(inline_message_id, media),
+            ),
+        }
+    }
+
+    /// Edits reply markup of a message, which is either a message in a chat or
+    /// an inline message.
+    ///
+    /// This calls [`edit_message_reply_markup`] or
+    /// [`edit_message_reply_markup_inline`] depending on the `target`, see
+    /// also [`edit_message_text_target`].
+    ///
+    /// [`edit_message_reply_markup`]: crate::requests::Requester::edit_message_reply_markup
+    /// [`edit_message_reply_markup_inline`]: crate::requests::Requester::edit_message_reply_markup_inline
+    /// [`edit_message_text_target`]: RequesterExt::edit_message_text_target
+    fn edit_message_reply_markup_target<T>(
+        &self,
+        target: T,
+    ) -> EditMessageReplyMarkupTarget<
+        Self::EditMessageReplyMarkup,
+        Self::EditMessageReplyMarkupInline,
+    >
+    where
+        T: Into<TargetMessage>,
+    {
+        match target.into() {
+            TargetMessage::Common {
+                chat_id,
+                message_id,
+            } => EditMessageReplyMarkupTarget::Common(
+                self.edit_message_reply_markup(chat_id, message_id),
+            ),
+            TargetMessage::Inline { inline_message_id } => EditMessageReplyMarkupTarget::Inline(
+                self.edit_message_reply_markup_inline(inline_message_id),
+            ),
+        }
+    }
+
+    /// Returns a guard which answers the callback `query` when dropped, unless
+    /// it was answered via the guard, see [`CallbackQueryGuard`].
+    fn callback_query_guard(&self, query: &CallbackQuery) -> CallbackQueryGuard<Self>
+    where
+        Self: Clone,
+        Self::Err: std::fmt::Debug,
+        <Self::AnswerCallbackQuery as Request>::Send: 'static,
+    {
+        CallbackQueryGuard::new(self.clone(), query.id.clone())
+    }
+
+    /// Bans the sender of the `message` in the chat the message was sent to.
+    ///
+    /// If the message was sent on behalf of a chat (e.g.: by a channel), the
+    /// chat is banned with [`ban_chat_sender_chat`], otherwise the user is
+    /// banned with [`kick_chat_member`].
+    ///
+    /// Returns `None` if the message has no sender that can be banned, e.g.
+    /// if it was sent by an anonymous administrator on behalf of the chat
+    /// itself, or if it's a channel post [automatically forwarded] to the
+    /// discussion group (which would otherwise ban the linked channel).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::Message};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// # let message: Message = todo!();
+    ///
+    /// if let Some(ban) = bot.ban_message_sender(&message) {
+    ///     ban.send().await?;
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [automatically forwarded]: Message::is_automatic_forward
+    /// [`ban_chat_sender_chat`]: crate::requests::Requester::ban_chat_sender_chat
+    /// [`kick_chat_member`]: crate::requests::Requester::kick_chat_member
+    fn ban_message_sender(
+        &self,
+        message: &Message,
+    ) -> Option<SenderRequest<Self::KickChatMember, Self::BanChatSenderChat>> {
+        if message.is_automatic_forward() {
+            return None;
+        }
+
+        match (message.sender_chat(), message.from()) {
+            (Some(sender), _) if sender.id == message.chat.id => None,
+            (Some(sender), _) => Some(SenderRequest::Chat(
+                self.ban_chat_sender_chat(message.chat.id, sender.id),
+            )),
+            (None, Some(user)) => Some(SenderRequest::User(
+                self.kick_chat_member(message.chat.id, user.id),
+            )),
+            (None, None) => None,
+        }
+    }
+
+    /// Unbans the sender of the `message` in the chat the message was sent
+    /// to.
+    ///
+    /// This is the reverse of [`ban_message_sender`], see its documentation
+    /// for more.
+    ///
+    /// [`ban_message_sender`]: RequesterExt::ban_message_sender
+    fn unban_message_sender(
+        &self,
+        message: &Message,
+    ) -> Option<SenderRequest<Self::UnbanChatMember, Self::UnbanChatSenderChat>> {
+        if message.is_automatic_forward() {
+            return None;
+        }
+
+        match (message.sender_chat(), message.from()) {
+            (Some(sender), _) if sender.id == message.chat.id => None,
+            (Some(sender), _) => Some(SenderRequest::Chat(
+                self.unban_chat_sender_chat(message.chat.id, sender.id),
+            )),
+            (None, Some(user)) => Some(SenderRequest::User(
+                self.unban_chat_member(message.chat.id, user.id),
+            )),
+            (None, None) => None,
+        }
+    }
+
+    /// Receives updates by long polling, see [`Polling`].
+    fn polling(self) -> Polling<Self>
+    where
+        Self: Sized,
+        Self::Err: AsApiError + AsResponseParameters,
+    {
+        Polling::new(self)
+    }
+
+    /// Sets the webhook with the `params`, but only if it's not already set
+    /// up this way.
+    ///
+    /// Fetches the current [`WebhookInfo`] and calls [`set_webhook`] only if
+    /// it differs from the `params` (see [`WebhookInfo::is_up_to_date`]),
+    /// since Telegram heavily rate-limits [`set_webhook`]. Returns `true` if
+    /// the webhook was changed.
+    ///
+    /// Telegram doesn't report the secret token of the webhook, so it's
+    /// assumed to be unchanged. Use [`ensure_webhook_with`] after changing
+    /// the secret.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{payloads::SetWebhook, prelude::*};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let changed = bot
+    ///     .ensure_webhook(SetWebhook::new("https://example.com/webhook"))
+    ///     .await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`WebhookInfo`]: crate::types::WebhookInfo
+    /// [`WebhookInfo::is_up_to_date`]: crate::types::WebhookInfo::is_up_to_date
+    /// [`set_webhook`]: crate::requests::Requester::set_webhook
+    /// [`ensure_webhook_with`]: RequesterExt::ensure_webhook_with
+    fn ensure_webhook(&self, params: SetWebhook) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+    {
+        self.ensure_webhook_with(params, false)
+    }
+
+    /// Sets the webhook with the `params`, but only if it's not already set
+    /// up this way or if `secret_changed` is `true`.
+    ///
+    /// This is the same as [`ensure_webhook`], but allows to set the webhook
+    /// when only its secret token was changed (e.g. rotated), since Telegram
+    /// doesn't report the current secret token.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{payloads::SetWebhook, prelude::*};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let params = SetWebhook::new("https://example.com/webhook").secret_token("new-secret");
+    /// let changed = bot.ensure_webhook_with(params, true).await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`ensure_webhook`]: RequesterExt::ensure_webhook
+    fn ensure_webhook_with(
+        &self,
+        params: SetWebhook,
+        secret_changed: bool,
+    ) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+    {
+        Box::pin(async move {
+            if !secret_changed {
+                let info = self.get_webhook_info().send().await?;
+                if info.is_up_to_date(&params) {
+                    return Ok(false);
+                }
+            }
+
+            let send = {
+                let mut request = self.set_webhook(params.url.clone());
+                *request.payload_mut() = params;
+                request.send()
+            };
+            send.await?;
+
+            Ok(true)
+        })
+    }
+
+    /// Switches the bot to receiving updates by polling, i.e. deletes the
+    /// webhook, and verifies the switch with [`get_webhook_info`].
+    ///
+    /// Telegram doesn't allow [`get_updates`] while a webhook is set, so call
+    /// this before starting [`polling`]. If `drop_pending_updates` is `true`,
+    /// updates which were not delivered to the webhook are dropped, otherwise
+    /// they will be received by polling.
+    ///
+    /// Returns `false` if the webhook is still set after deleting (e.g. it was
+    /// set again by another instance of the bot).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::prelude::*;
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// assert!(bot.switch_to_polling(false).await?);
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`get_webhook_info`]: crate::requests::Requester::get_webhook_info
+    /// [`get_updates`]: crate::requests::Requester::get_updates
+    /// [`polling`]: RequesterExt::polling
+    fn switch_to_polling(
+        &self,
+        drop_pending_updates: bool,
+    ) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+    {
+        Box::pin(async move {
+            self.delete_webhook()
+                .drop_pending_updates(drop_pending_updates)
+                .send()
+                .await?;
+
+            let info = self.get_webhook_info().send().await?;
+            Ok(info.url.is_empty())
+        })
+    }
+
+    /// Switches the bot to receiving updates by a webhook set up with the
+    /// `params`, and verifies the switch with [`get_webhook_info`].
+    ///
+    /// The webhook is only set if it's not already set up this way (see
+    /// [`ensure_webhook`], the secret token is assumed to be unchanged).
+    /// Setting a webhook terminates pending [`get_updates`] requests, so
+    /// polling must be stopped before calling this, otherwise it'll get
+    /// conflict errors. Use [`SetWebhook::drop_pending_updates`] to drop
+    /// updates which were not received by polling.
+    ///
+    /// Returns `false` if the webhook info doesn't match the `params` after
+    /// setting (e.g. it was changed by another instance of the bot). All
+    /// fields reported by Telegram are compared, as in
+    /// [`WebhookInfo::is_up_to_date`], except for the secret token and pending
+    /// updates, which can't be verified.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{payloads::SetWebhook, prelude::*};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let params = SetWebhook::new("https://example.com/webhook");
+    /// assert!(bot.switch_to_webhook(params).await?);
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`get_webhook_info`]: crate::requests::Requester::get_webhook_info
+    /// [`ensure_webhook`]: RequesterExt::ensure_webhook
+    /// [`get_updates`]: crate::requests::Requester::get_updates
+    /// [`SetWebhook::drop_pending_updates`]: crate::payloads::SetWebhook::drop_pending_updates
+    /// [`WebhookInfo::is_up_to_date`]: crate::types::WebhookInfo::is_up_to_date
+    fn switch_to_webhook(&self, params: SetWebhook) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+    {
+        Box::pin(async move {
+            let mut expected = params.clone();
+            expected.drop_pending_updates = None;
+            self.ensure_webhook(params).await?;
+
+            let info = self.get_webhook_info().send().await?;
+            Ok(info.is_up_to_date(&expected))
+        })
+    }
+
+    /// Mutes a user in a supergroup for the `duration`, i.e. restricts them
+    /// with [`ChatPermissions::read_only`] until `duration` from now.
+    ///
+    /// Note that Telegram considers users restricted for less than 30 seconds
+    /// or more than 366 days to be restricted forever.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use std::time::Duration;
+    ///
+    /// use teloxide_core::prelude::*;
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// bot.mute_for(-1001234567890, 42, Duration::from_secs(60 * 60))
+    ///     .send()
+    ///     .await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    fn mute_for<C>(&self, chat_id: C, user_id: i64, duration: Duration) -> Self::RestrictChatMember
+    where
+        C: Into<ChatId>,
+    {
+        self.restrict_chat_member(chat_id, user_id, ChatPermissions::read_only())
+            .until_date_in(duration)
+    }
+
+    /// Promotes or demotes a chat member, so that their administrator rights
+    /// match the `desired` ones.
+    ///
+    /// `current` is the current status of the member, e.g. as returned by
+    /// [`get_chat_member`]. Returns `None` if the rights already match or if
+    /// the member is the creator of the chat (whose rights can't be changed).
+    ///
+    /// Passing [`ChatAdministratorRights::default()`] demotes the member.
+    ///
+    /// [`get_chat_member`]: crate::requests::Requester::get_chat_member
+    fn promote_to_match<C>(
+        &self,
+        chat_id: C,
+        user_id: i64,
+        current: &ChatMemberKind,
+        desired: &ChatAdministratorRights,
+    ) -> Option<Self::PromoteChatMember>
+    where
+        C: Into<ChatId>,
+    {
+        if current.is_creator() || current.administrator_rights() == *desired {
+            return None;
+        }
+
+        let mut request = self.promote_chat_member(chat_id, user_id);
+        desired.apply(request.payload_mut());
+        Some(request)
+    }
+
+    /// Approves the join `request`.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::ChatJoinRequest};
+    ///
+    /// # let request: ChatJoinRequest = todo!();
+    /// let bot = Bot::new("TOKEN");
+    /// if request.is_via("https://t.me/+AbCdEfGh") {
+    ///     bot.approve(&request).send().await?;
+    /// } else {
+    ///     bot.decline(&request).send().await?;
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    fn approve(&self, request: &ChatJoinRequest) -> Self::ApproveChatJoinRequest {
+        self.approve_chat_join_request(request.chat.id, request.from.id)
+    }
+
+    /// Declines the join `request`, see [`approve`] for an example.
+    ///
+    /// [`approve`]: RequesterExt::approve
+    fn decline(&self, request: &ChatJoinRequest) -> Self::DeclineChatJoinRequest {
+        self.decline_chat_join_request(request.chat.id, request.from.id)
+    }
+
+    /// Answers a shipping query, see [`ShippingAnswer`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # async {
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     types::{CountryCode, LabeledPrice, ShippingAnswer, ShippingOption, ShippingQuery},
+    /// };
+    ///
+    /// # let query: ShippingQuery = todo!();
+    /// let bot = Bot::new("TOKEN");
+    /// let answer = if query.shipping_address.country_code == CountryCode::US {
+    ///     let post = ShippingOption::new("post", "Post", vec![LabeledPrice::new("Post", 500)]);
+    ///     ShippingAnswer::ok(vec![post])
+    /// } else {
+    ///     ShippingAnswer::error("Sorry, we only deliver to the US")
+    /// };
+    ///
+    /// bot.answer_shipping(query.id, answer).send().await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    fn answer_shipping<S>(
+        &self,
+        shipping_query_id: S,
+        answer: ShippingAnswer,
+    ) -> Self::AnswerShippingQuery
+    where
+        S: Into<String>,
+    {
+        let mut request = self.answer_shipping_query(shipping_query_id, true);
+        answer.apply(request.payload_mut());
+        request
+    }
+
+    /// Answers a pre-checkout query, see [`PreCheckoutAnswer`].
+    fn answer_pre_checkout<S>(
+        &self,
+        pre_checkout_query_id: S,
+        answer: PreCheckoutAnswer,
+    ) -> Self::AnswerPreCheckoutQuery
+    where
+        S: Into<String>,
+    {
+        let mut request = self.answer_pre_checkout_query(pre_checkout_query_id, true);
+        answer.apply(request.payload_mut());
+        request
+    }
+
+    /// Reacts to the `message` with the `emoji`, replacing the previous
+    /// reaction of the bot.
+    ///
+    /// Use [`is_big`] to set the reaction with a big animation.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::Message};
+    ///
+    /// # let message: Message = todo!();
+    /// let bot = Bot::new("TOKEN");
+    /// bot.react(&message, "👍").is_big(true).send().await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`is_big`]: crate::payloads::SetMessageReactionSetters::is_big
+    fn react<E>(&self, message: &Message, emoji: E) -> Self::SetMessageReaction
+    where
+        E: Into<String>,
+    {
+        self.set_message_reaction(message.chat.id, message.id)
+            .reaction(Some(ReactionType::emoji(emoji)))
+    }
+
+    /// Fetches stickers of the custom emoji `ids`, returning a map from a
+    /// custom emoji identifier to its sticker.
+    ///
+    /// Duplicate identifiers are requested only once and the identifiers are
+    /// split into as many [`get_custom_emoji_stickers`] requests (sent one
+    /// after another) as needed to fit in the limit of
+    /// [`MAX_CUSTOM_EMOJI_IDS`] per request. Identifiers unknown to Telegram
+    /// are missing from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::Message};
+    ///
+    /// # let message: Message = todo!();
+    /// let bot = Bot::new("TOKEN");
+    /// let stickers = bot.custom_emoji_stickers(message.custom_emoji_ids()).await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`get_custom_emoji_stickers`]: crate::requests::Requester::get_custom_emoji_stickers
+    fn custom_emoji_stickers<I, S>(
+        &self,
+        ids: I,
+    ) -> BoxFuture<'_, Result<HashMap<String, Sticker>, Self::Err>>
+    where
+        Self: Sync,
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut unique = Vec::new();
+        for id in ids {
+            let id = id.into();
+            if !unique.contains(&id) {
+                unique.push(id);
+            }
+        }
+
+        let chunks: Vec<Vec<String>> = unique
+            .chunks(MAX_CUSTOM_EMOJI_IDS)
+            .map(<[_]>::to_vec)
+            .collect();
+
+        Box::pin(async move {
+            let mut stickers = HashMap::new();
+            for chunk in chunks {
+                let send = self.get_custom_emoji_stickers(chunk).send();
+                for sticker in send.await? {
+                    if let Some(id) = sticker.custom_emoji_id.clone() {
+                        stickers.insert(id, sticker);
+                    }
+                }
+            }
+
+            Ok(stickers)
+        })
+    }
+
+    /// Returns the text (or the caption) of the `message` with custom emoji
+    /// replaced by the emoji associated with their stickers, e.g. to mirror
+    /// the message to a platform which doesn't support custom emoji.
+    ///
+    /// Stickers are fetched with [`custom_emoji_stickers`], custom emoji
+    /// unknown to Telegram are left as is. Returns `None` if the message has
+    /// neither a text nor a caption. See also [`utils::custom_emoji`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::Message};
+    ///
+    /// # let message: Message = todo!();
+    /// let bot = Bot::new("TOKEN");
+    /// if let Some(text) = bot.render_custom_emoji(&message).await? {
+    ///     println!("{}", text);
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`custom_emoji_stickers`]: RequesterExt::custom_emoji_stickers
+    /// [`utils::custom_emoji`]: crate::utils::custom_emoji
+    fn render_custom_emoji(
+        &self,
+        message: &Message,
+    ) -> BoxFuture<'_, Result<Option<String>, Self::Err>>
+    where
+        Self: Sync,
+    {
+        let text = message
+            .text()
+            .or_else(|| message.caption())
+            .map(ToOwned::to_owned);
+        let entities = message
+            .entities()
+            .or_else(|| message.caption_entities())
+            .unwrap_or(&[])
+            .to_vec();
+        let ids = message.custom_emoji_ids();
+        let stickers = if ids.is_empty() {
+            None
+        } else {
+            Some(self.custom_emoji_stickers(ids))
+        };
+
+        Box::pin(async move {
+            let text = match text {
+                Some(text) => text,
+                None => return Ok(None),
+            };
+            let stickers = match stickers {
+                Some(stickers) => stickers.await?,
+                None => return Ok(Some(text)),
+            };
+
+            Ok(Some(custom_emoji::replace_with_sticker_emoji(
+                &text, &entities, &stickers,
+            )))
+        })
+    }
+
+    /// Returns `true` if the user is present in the chat, see
+    /// [`ChatMemberKind::is_present`].
+    ///
+    /// This calls [`get_chat_member`], whose responses can be cached with
+    /// [`CacheMe::cache_chat_members`].
+    ///
+    /// [`get_chat_member`]: crate::requests::Requester::get_chat_member
+    /// [`CacheMe::cache_chat_members`]: crate::adaptors::cache_me::CacheMe::cache_chat_members
+    fn is_member<C>(&self, chat_id: C, user_id: i64) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+        C: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+
+        Box::pin(async move {
+            let member = self.get_chat_member(chat_id, user_id).send().await?;
+            Ok(member.kind.is_present())
+        })
+    }
+
+    /// Returns `true` if the user is the creator or an administrator of the
+    /// chat, see [`ChatMemberKind::is_privileged`].
+    ///
+    /// This calls [`get_chat_member`], whose responses can be cached with
+    /// [`CacheMe::cache_chat_members`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # async {
+    /// use teloxide_core::prelude::*;
+    ///
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// if bot.is_admin(-1001234567890, 42).await? {
+    ///     // ...
+    /// }
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`get_chat_member`]: crate::requests::Requester::get_chat_member
+    /// [`CacheMe::cache_chat_members`]: crate::adaptors::cache_me::CacheMe::cache_chat_members
+    fn is_admin<C>(&self, chat_id: C, user_id: i64) -> BoxFuture<'_, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+        C: Into<ChatId>,
+    {
+        let chat_id = chat_id.into();
+
+        Box::pin(async move {
+            let member = self.get_chat_member(chat_id, user_id).send().await?;
+            Ok(member.kind.is_privileged())
+        })
+    }
+
+    /// Returns `true` if the custom emoji can be used as a forum topic icon,
+    /// i.e. if it's one of the stickers returned by
+    /// [`get_forum_topic_icon_stickers`].
+    ///
+    /// This can be used to validate `icon_custom_emoji_id` before
+    /// [creating] or [editing] a topic.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # async {
+    /// use teloxide_core::{prelude::*, types::ForumTopicIconColor};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let icon = "5312536423851630001";
+    ///
+    /// let mut request = bot
+    ///     .create_forum_topic(-1001234567890, "News")
+    ///     .icon_color(ForumTopicIconColor::Green);
+    /// if bot.is_forum_topic_icon(icon).await? {
+    ///     request = request.icon_custom_emoji_id(icon);
+    /// }
+    /// request.send().await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    ///
+    /// [`get_forum_topic_icon_stickers`]: crate::requests::Requester::get_forum_topic_icon_stickers
+    /// [creating]: crate::requests::Requester::create_forum_topic
+    /// [editing]: crate::requests::Requester::edit_forum_topic
+    fn is_forum_topic_icon<'a>(
+        &'a self,
+        custom_emoji_id: &'a str,
+    ) -> BoxFuture<'a, Result<bool, Self::Err>>
+    where
+        Self: Sync,
+    {
+        Box::pin(async move {
+            let stickers = self.get_forum_topic_icon_stickers().send().await?;
+            Ok(stickers
+                .iter()
+                .any(|s| s.custom_emoji_id.as_deref() == Some(custom_emoji_id)))
+        })
+    }
 }
 
 impl<T> RequesterExt for T
@@ -90,3 +1072,206 @@ where
 {
     /* use default impls */
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use crate::{
+        requests::{HasPayload, RequesterExt, SenderRequest},
+        test_utils::{read_body, respond},
+        types::Message,
+        Bot,
+    };
+
+    use super::MAX_CUSTOM_EMOJI_IDS;
+
+    fn message(sender: &str) -> Message {
+        let json = format!(
+            r#"{{
+              "message_id": 10,
+              "chat": {{"id": -1001, "title": "Group", "type": "supergroup"}},
+              "date": 1568290622,
+              "text": "hi",
+              {}
+            }}"#,
+            sender
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    const USER: &str = r#""from": {"id": 42, "is_bot": false, "first_name": "A"}"#;
+    const CHANNEL: &str = r#""from": {"id": 136817688, "is_bot": true, "first_name": "Channel"},
+              "sender_chat": {"id": -1002, "title": "Channel", "type": "channel"}"#;
+
+    #[test]
+    fn ban_message_sender() {
+        let bot = Bot::new("TOKEN");
+
+        match bot.ban_message_sender(&message(USER)) {
+            Some(SenderRequest::User(req)) => {
+                assert_eq!(req.payload_ref().chat_id, (-1001).into());
+                assert_eq!(req.payload_ref().user_id, 42);
+            }
+            _ => panic!("expected a user request"),
+        }
+
+        match bot.unban_message_sender(&message(CHANNEL)) {
+            Some(SenderRequest::Chat(req)) => {
+                assert_eq!(req.payload_ref().chat_id, (-1001).into());
+                assert_eq!(req.payload_ref().sender_chat_id, -1002);
+            }
+            _ => panic!("expected a chat request"),
+        }
+
+        // Anonymous administrator
+        let anonymous = message(
+            r#""from": {"id": 1087968824, "is_bot": true, "first_name": "Group"},
+              "sender_chat": {"id": -1001, "title": "Group", "type": "supergroup"}"#,
+        );
+        assert!(bot.ban_message_sender(&anonymous).is_none());
+
+        // A post of the linked channel
+        let forward = message(&format!(
+            r#"{}, "is_automatic_forward": true,
+              "forward_date": 1568290622,
+              "forward_from_chat": {{"id": -1002, "title": "Channel", "type": "channel"}},
+              "forward_from_message_id": 1"#,
+            CHANNEL
+        ));
+        assert!(forward.is_automatic_forward());
+        assert!(bot.ban_message_sender(&forward).is_none());
+        assert!(bot.unban_message_sender(&forward).is_none());
+    }
+
+    #[test]
+    fn custom_emoji_stickers() {
+        let sticker = |id: &str| {
+            format!(
+                r#"{{"file_id":"f{0}","file_unique_id":"u{0}","width":100,"height":100,"is_animated":false,"is_video":false,"custom_emoji_id":"{0}"}}"#,
+                id
+            )
+        };
+        // 250 unique ids, each twice
+        let ids: Vec<String> = (0..500).map(|i| (i % 250).to_string()).collect();
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn(async move {
+                let mut requests = Vec::new();
+                for _ in 0..2 {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    let request: serde_json::Value =
+                        serde_json::from_slice(&read_body(&mut conn).await).unwrap();
+                    let ids: Vec<String> =
+                        serde_json::from_value(request["custom_emoji_ids"].clone()).unwrap();
+                    let stickers: Vec<_> = ids.iter().map(|id| sticker(id)).collect();
+                    let body = format!(r#"{{"ok":true,"result":[{}]}}"#, stickers.join(","));
+                    respond(&mut conn, &body).await;
+                    requests.push(ids);
+                }
+                requests
+            });
+
+            let bot = Bot::new("TOKEN").set_api_url(api_url.parse().unwrap());
+            let stickers = bot.custom_emoji_stickers(ids).await.unwrap();
+
+            assert_eq!(stickers.len(), 250);
+            assert_eq!(stickers["249"].file_id, "f249");
+
+            let requests = server.await.unwrap();
+            assert_eq!(requests[0].len(), MAX_CUSTOM_EMOJI_IDS);
+            assert_eq!(requests[1].len(), 50);
+            assert_eq!(requests[0][0], "0");
+            assert_eq!(requests[1][0], "200");
+        });
+    }
+
+    #[test]
+    fn ensure_webhook_secret() {
+        use crate::payloads::{SetWebhook, SetWebhookSetters};
+
+        let url = "https://example.com/webhook";
+        let responses = vec![
+            format!(
+                r#"{{"ok":true,"result":{{"url":"{}","has_custom_certificate":false,"pending_update_count":0}}}}"#,
+                url
+            ),
+            r#"{"ok":true,"result":true}"#.to_owned(),
+        ];
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn(async move {
+                let mut methods = Vec::new();
+                for body in &responses {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    let mut buf = vec![0; 1024];
+                    let n = conn.peek(&mut buf).await.unwrap();
+                    let head = String::from_utf8_lossy(&buf[..n]).into_owned();
+                    methods.push(head.contains("/SetWebhook"));
+                    read_body(&mut conn).await;
+                    respond(&mut conn, body).await;
+                }
+                methods
+            });
+
+            let bot = Bot::new("TOKEN").set_api_url(api_url.parse().unwrap());
+            let params = SetWebhook::new(url).secret_token("secret");
+
+            // A webhook with a secret token can be up to date
+            assert!(!bot.ensure_webhook(params.clone()).await.unwrap());
+            // A changed secret is set without checking the current webhook
+            assert!(bot.ensure_webhook_with(params, true).await.unwrap());
+
+            assert_eq!(server.await.unwrap(), [false, true]);
+        });
+    }
+
+    #[test]
+    fn switch_to_webhook() {
+        use crate::payloads::{SetWebhook, SetWebhookSetters};
+
+        let info = |url, max_connections| {
+            format!(
+                r#"{{"ok":true,"result":{{"url":"{}","has_custom_certificate":false,"pending_update_count":0,"max_connections":{}}}}}"#,
+                url, max_connections
+            )
+        };
+        let url = "https://example.com/webhook";
+        let responses = vec![
+            info("", 40),
+            r#"{"ok":true,"result":true}"#.to_owned(),
+            // Another instance has set the webhook with other parameters
+            info(url, 40),
+        ];
+
+        let rt = crate::test_utils::rt();
+        rt.block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let api_url = format!("http://{}/", listener.local_addr().unwrap());
+
+            let server = tokio::spawn(async move {
+                for body in &responses {
+                    let (mut conn, _) = listener.accept().await.unwrap();
+                    read_body(&mut conn).await;
+                    respond(&mut conn, body).await;
+                }
+            });
+
+            let bot = Bot::new("TOKEN").set_api_url(api_url.parse().unwrap());
+            let params = SetWebhook::new(url)
+                .max_connections(10)
+                .secret_token("secret");
+            assert!(!bot.switch_to_webhook(params).await.unwrap());
+
+            server.await.unwrap();
+        });
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    errors::AsResponseParameters, requests::ResponseResult, types::ResponseParameters, RequestError,
+};
+
+/// The result of a request along with the metadata of the Telegram response.
+///
+/// This type is returned by [`JsonRequest::send_with_meta`] and
+/// [`MultipartRequest::send_with_meta`].
+///
+/// [`JsonRequest::send_with_meta`]: crate::requests::JsonRequest::send_with_meta
+/// [`MultipartRequest::send_with_meta`]: crate::requests::MultipartRequest::send_with_meta
+#[derive(Debug)]
+pub struct ResponseWithMeta<T> {
+    /// The result of the request, the same as returned by [`Request::send`].
+    ///
+    /// [`Request::send`]: crate::requests::Request::send
+    pub result: ResponseResult<T>,
+
+    /// A human-readable description of the result. Always present on errors
+    /// returned by Telegram, occasionally on success.
+    pub description: Option<String>,
+
+    /// Parameters of the response, e.g. the new id of a chat which was
+    /// migrated to a supergroup. Can be present on success, as well as on
+    /// errors.
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl<T> ResponseWithMeta<T> {
+    /// Creates a response with an error which occurred before a response was
+    /// received (or parsed), so there is no metadata.
+    pub(crate) fn error(err: RequestError) -> Self {
+        Self {
+            result: Err(err),
+            description: None,
+            parameters: None,
+        }
+    }
+
+    /// Discards the metadata.
+    pub fn into_result(self) -> ResponseResult<T> {
+        self.result
+    }
+}
+
+impl<T> AsResponseParameters for ResponseWithMeta<T> {
+    fn response_parameters(&self) -> Option<ResponseParameters> {
+        self.parameters
+    }
+}
@@ -0,0 +1,47 @@
+use futures::future::Either;
+
+use crate::requests::{Output, Payload, Request};
+
+/// A request which targets the sender of a message, that is either a user or
+/// a chat (e.g.: a channel or an anonymous group administrator).
+///
+/// This type is returned by [`RequesterExt::ban_message_sender`] and
+/// [`RequesterExt::unban_message_sender`].
+///
+/// [`RequesterExt::ban_message_sender`]: crate::requests::RequesterExt::ban_message_sender
+/// [`RequesterExt::unban_message_sender`]: crate::requests::RequesterExt::unban_message_sender
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone, Debug)]
+pub enum SenderRequest<U, C> {
+    /// A request targeting a user (the `from` field of a message).
+    User(U),
+    /// A request targeting a chat (the `sender_chat` field of a message).
+    Chat(C),
+}
+
+impl<U, C> SenderRequest<U, C>
+where
+    U: Request,
+    C: Request<Err = U::Err>,
+    C::Payload: Payload<Output = Output<U>>,
+{
+    /// Send this request.
+    ///
+    /// See also: [`Request::send`].
+    pub fn send(self) -> Either<U::Send, C::Send> {
+        match self {
+            Self::User(req) => Either::Left(req.send()),
+            Self::Chat(req) => Either::Right(req.send()),
+        }
+    }
+
+    /// Send this request by reference.
+    ///
+    /// See also: [`Request::send_ref`].
+    pub fn send_ref(&self) -> Either<U::SendRef, C::SendRef> {
+        match self {
+            Self::User(req) => Either::Left(req.send_ref()),
+            Self::Chat(req) => Either::Right(req.send_ref()),
+        }
+    }
+}
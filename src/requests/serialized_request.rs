@@ -0,0 +1,624 @@
+use std::fmt;
+
+use serde::{
+    de::DeserializeOwned,
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Deserialize, Serialize, Serializer,
+};
+use serde_json::{value::Serializer as Json, Map, Value};
+
+use crate::{requests::Payload, types::InputFile};
+
+/// A request in a stable format suitable for storing on disk or in a job
+/// queue, e.g. to send it later.
+///
+/// The payload is stored as JSON ([`payload_json`]) alongside the name of the
+/// method ([`method`]), so that the consumer of the queue knows which payload
+/// type to restore. Files which need to be uploaded ([`InputFile::File`],
+/// [`InputFile::Memory`], [`InputFile::Reopenable`] and
+/// [`InputFile::WithMetadata`]) are moved from the
+/// payload into [`attachments`] and replaced by `{"Attachment": <index>}`, so
+/// they can be stored separately (e.g. in a blob storage) if needed.
+///
+/// Note that [reopenable files] can only be restored in the process which
+/// created them. Memory files are stored with their content, which is not
+/// shown by the `Debug` implementation though.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     payloads::{SendMessage, SendPhoto},
+///     requests::{Payload, SerializedRequest},
+///     types::InputFile,
+/// };
+///
+/// let photo = SendPhoto::new(1, InputFile::file("cat.jpg"));
+/// let serialized = SerializedRequest::new(&photo).unwrap();
+/// assert_eq!(serialized.method, SendPhoto::NAME);
+/// assert_eq!(serialized.attachments, [InputFile::file("cat.jpg")]);
+///
+/// // e.g. store the request in a queue ...
+/// let json = serde_json::to_string(&serialized).unwrap();
+/// // ... and later restore it
+/// let serialized: SerializedRequest = serde_json::from_str(&json).unwrap();
+/// match &*serialized.method {
+///     SendPhoto::NAME => assert_eq!(serialized.into_payload::<SendPhoto>().unwrap(), photo),
+///     SendMessage::NAME => { /* ... */ }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// [`payload_json`]: SerializedRequest::payload_json
+/// [`method`]: SerializedRequest::method
+/// [`attachments`]: SerializedRequest::attachments
+/// [reopenable files]: crate::types::InputFile::reopenable
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializedRequest {
+    /// Name of the method, same as [`Payload::NAME`], e.g. `SendMessage`.
+    pub method: String,
+
+    /// The payload serialized to JSON, with files to upload replaced by
+    /// references to [`attachments`].
+    ///
+    /// [`attachments`]: SerializedRequest::attachments
+    pub payload_json: Value,
+
+    /// Files to upload with the request.
+    #[serde(default)]
+    pub attachments: Vec<InputFile>,
+}
+
+/// The key of objects which replace attachments in
+/// [`SerializedRequest::payload_json`].
+const ATTACHMENT: &str = "Attachment";
+
+impl SerializedRequest {
+    /// Serializes the `payload`.
+    pub fn new<P>(payload: &P) -> serde_json::Result<Self>
+    where
+        P: Payload + Serialize,
+    {
+        let mut attachments = Vec::new();
+        let payload_json = payload.serialize(Extractor {
+            files: &mut attachments,
+        })?;
+
+        Ok(Self {
+            method: P::NAME.to_owned(),
+            payload_json,
+            attachments,
+        })
+    }
+
+    /// Returns `true` if this is a serialized `P`.
+    ///
+    /// Method names are compared case insensitively, just as Telegram does.
+    pub fn is<P>(&self) -> bool
+    where
+        P: Payload,
+    {
+        self.method.eq_ignore_ascii_case(P::NAME)
+    }
+
+    /// Restores the payload.
+    ///
+    /// Fails if the request is not a serialized `P` (see [`is`]) or if
+    /// the JSON doesn't match `P`.
+    ///
+    /// [`is`]: SerializedRequest::is
+    pub fn into_payload<P>(self) -> serde_json::Result<P>
+    where
+        P: Payload + DeserializeOwned,
+    {
+        if !self.is::<P>() {
+            return Err(serde::de::Error::custom(format!(
+                "expected a serialized `{}` request, found `{}`",
+                P::NAME,
+                self.method
+            )));
+        }
+
+        let mut payload_json = self.payload_json;
+        let mut attachments: Vec<_> = self.attachments.into_iter().map(Some).collect();
+        restore_attachments(&mut payload_json, &mut attachments)?;
+
+        serde_json::from_value(payload_json)
+    }
+}
+
+impl fmt::Debug for SerializedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let attachments: Vec<_> = self.attachments.iter().map(Attachment).collect();
+        f.debug_struct("SerializedRequest")
+            .field("method", &self.method)
+            .field("payload_json", &self.payload_json)
+            .field("attachments", &attachments)
+            .finish()
+    }
+}
+
+/// Debug representation of an attachment, without the content of memory
+/// files.
+struct Attachment<'a>(&'a InputFile);
+
+impl fmt::Debug for Attachment<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            InputFile::Memory { file_name, data } => f
+                .debug_struct("Memory")
+                .field("file_name", file_name)
+                .field("len", &data.len())
+                .finish(),
+            InputFile::WithMetadata(meta) => f
+                .debug_struct("WithMetadata")
+                .field("file", &Attachment(&meta.file))
+                .field("file_name", &meta.file_name)
+                .field("mime_type", &meta.mime_type)
+                .finish(),
+            file => file.fmt(f),
+        }
+    }
+}
+
+/// Returns `true` if the `InputFile` serialized as `variant` needs to be
+/// uploaded.
+fn is_upload(variant: &str) -> bool {
+    matches!(variant, "File" | "Memory" | "Reopenable" | "WithMetadata")
+}
+
+/// Serializer into [`Value`] which moves [`InputFile`]s that need to be
+/// uploaded into `files`.
+///
+/// Files are recognized by the name of the type, so objects which merely
+/// look like serialized files are left as is.
+struct Extractor<'a> {
+    files: &'a mut Vec<InputFile>,
+}
+
+impl Extractor<'_> {
+    fn attach(self, file: Value) -> serde_json::Result<Value> {
+        let idx = self.files.len();
+        self.files.push(serde_json::from_value(file)?);
+        Ok(serde_json::json!({ ATTACHMENT: idx }))
+    }
+}
+
+/// Compound serializer of [`Extractor`].
+struct Compound<'a, S> {
+    inner: S,
+    files: &'a mut Vec<InputFile>,
+    /// `true` if this is the (struct) variant of an [`InputFile`], which
+    /// needs to be uploaded.
+    file: bool,
+}
+
+impl<'a, S> Compound<'a, S> {
+    fn new(inner: S, files: &'a mut Vec<InputFile>) -> Self {
+        Self {
+            inner,
+            files,
+            file: false,
+        }
+    }
+
+    fn extract<T>(&mut self, value: &T) -> serde_json::Result<Value>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(Extractor { files: self.files })
+    }
+}
+
+macro_rules! forward {
+    ($($method:ident($($ty:ty),*);)*) => {
+        $(
+            fn $method(self, $(v: $ty),*) -> serde_json::Result<Value> {
+                Json.$method($(v as $ty),*)
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for Extractor<'a> {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = Compound<'a, <Json as Serializer>::SerializeSeq>;
+    type SerializeTuple = Compound<'a, <Json as Serializer>::SerializeTuple>;
+    type SerializeTupleStruct = Compound<'a, <Json as Serializer>::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<'a, <Json as Serializer>::SerializeTupleVariant>;
+    type SerializeMap = Compound<'a, <Json as Serializer>::SerializeMap>;
+    type SerializeStruct = Compound<'a, <Json as Serializer>::SerializeStruct>;
+    type SerializeStructVariant = Compound<'a, <Json as Serializer>::SerializeStructVariant>;
+
+    forward! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> serde_json::Result<Value> {
+        Json.serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> serde_json::Result<Value>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> serde_json::Result<Value>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> serde_json::Result<Value>
+    where
+        T: ?Sized + Serialize,
+    {
+        if name == "InputFile" && is_upload(variant) {
+            let file = serde_json::json!({ variant: value });
+            return self.attach(file);
+        }
+
+        let value = value.serialize(Extractor { files: self.files })?;
+        Ok(serde_json::json!({ variant: value }))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> serde_json::Result<Self::SerializeSeq> {
+        Ok(Compound::new(Json.serialize_seq(len)?, self.files))
+    }
+
+    fn serialize_tuple(self, len: usize) -> serde_json::Result<Self::SerializeTuple> {
+        Ok(Compound::new(Json.serialize_tuple(len)?, self.files))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> serde_json::Result<Self::SerializeTupleStruct> {
+        Ok(Compound::new(
+            Json.serialize_tuple_struct(name, len)?,
+            self.files,
+        ))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> serde_json::Result<Self::SerializeTupleVariant> {
+        let inner = Json.serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(Compound::new(inner, self.files))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> serde_json::Result<Self::SerializeMap> {
+        Ok(Compound::new(Json.serialize_map(len)?, self.files))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> serde_json::Result<Self::SerializeStruct> {
+        Ok(Compound::new(Json.serialize_struct(name, len)?, self.files))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> serde_json::Result<Self::SerializeStructVariant> {
+        let inner = Json.serialize_struct_variant(name, variant_index, variant, len)?;
+        let mut compound = Compound::new(inner, self.files);
+        compound.file = name == "InputFile" && is_upload(variant);
+        Ok(compound)
+    }
+}
+
+impl<S> SerializeSeq for Compound<'_, S>
+where
+    S: SerializeSeq<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeTuple for Compound<'_, S>
+where
+    S: SerializeTuple<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeTupleStruct for Compound<'_, S>
+where
+    S: SerializeTupleStruct<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeTupleVariant for Compound<'_, S>
+where
+    S: SerializeTupleVariant<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeMap for Compound<'_, S>
+where
+    S: SerializeMap<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.serialize_key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_value(&value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeStruct for Compound<'_, S>
+where
+    S: SerializeStruct<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        self.inner.end()
+    }
+}
+
+impl<S> SerializeStructVariant for Compound<'_, S>
+where
+    S: SerializeStructVariant<Ok = Value, Error = serde_json::Error>,
+{
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> serde_json::Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = self.extract(value)?;
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn end(self) -> serde_json::Result<Value> {
+        let value = self.inner.end()?;
+        if self.file {
+            Extractor { files: self.files }.attach(value)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+fn restore_attachments(
+    value: &mut Value,
+    attachments: &mut [Option<InputFile>],
+) -> serde_json::Result<()> {
+    match value {
+        Value::Object(map) if map.len() == 1 && map.contains_key(ATTACHMENT) => {
+            let file = map[ATTACHMENT]
+                .as_u64()
+                .and_then(|idx| attachments.get_mut(idx as usize))
+                .and_then(Option::take)
+                .ok_or_else(|| {
+                    serde::de::Error::custom(format!("invalid attachment reference: {:?}", map))
+                })?;
+            *map = match serde_json::to_value(file)? {
+                Value::Object(file) => file,
+                _ => Map::new(),
+            };
+        }
+        Value::Object(map) => {
+            for value in map.values_mut() {
+                restore_attachments(value, attachments)?;
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                restore_attachments(value, attachments)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::{
+        payloads::{SendMediaGroup, SendMessage, SendMessageSetters},
+        types::{InputMedia, InputMediaPhoto, ParseMode},
+    };
+
+    #[test]
+    fn round_trip() {
+        let payload = SendMessage::new(1, "*hi*").parse_mode(ParseMode::MarkdownV2);
+        let serialized = SerializedRequest::new(&payload).unwrap();
+        assert!(serialized.is::<SendMessage>());
+        assert!(serialized.attachments.is_empty());
+
+        let json = serde_json::to_string(&serialized).unwrap();
+        let restored: SerializedRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.into_payload::<SendMessage>().unwrap(), payload);
+    }
+
+    #[test]
+    fn attachments() {
+        let memory = InputFile::memory("a.png", Cow::Borrowed(&b"png"[..]));
+        let payload = SendMediaGroup::new(
+            1,
+            vec![
+                InputMedia::Photo(InputMediaPhoto::new(InputFile::file("b.png"))),
+                InputMedia::Photo(InputMediaPhoto::new(memory.clone())),
+                InputMedia::Photo(InputMediaPhoto::new(InputFile::file_id("id"))),
+            ],
+        );
+
+        let serialized = SerializedRequest::new(&payload).unwrap();
+        assert_eq!(serialized.attachments, [InputFile::file("b.png"), memory]);
+        assert_eq!(
+            serialized.payload_json["media"][1]["media"],
+            serde_json::json!({ "Attachment": 1 })
+        );
+
+        assert_eq!(
+            serialized.into_payload::<SendMediaGroup>().unwrap(),
+            payload
+        );
+    }
+
+    #[test]
+    fn only_files_are_extracted() {
+        let mut files = Vec::new();
+        let value = serde_json::json!({ "File": "a.png", "media": { "Memory": [] } });
+        let extracted = value.serialize(Extractor { files: &mut files }).unwrap();
+        assert_eq!(extracted, value);
+        assert!(files.is_empty());
+
+        let file = InputFile::file("a.png").file_name("b.png");
+        let extracted = (&file, InputFile::url("https://example.com/"))
+            .serialize(Extractor { files: &mut files })
+            .unwrap();
+        assert_eq!(
+            extracted,
+            serde_json::json!([{ "Attachment": 0 }, { "Url": "https://example.com/" }])
+        );
+        assert_eq!(files, [file]);
+    }
+
+    #[test]
+    fn memory_files_are_not_debug_printed() {
+        let memory = InputFile::memory("a.png", Cow::Borrowed(&b"secret"[..]));
+        let payload = SendMediaGroup::new(1, vec![InputMedia::Photo(InputMediaPhoto::new(memory))]);
+        let serialized = SerializedRequest::new(&payload).unwrap();
+
+        let debug = format!("{:?}", serialized);
+        assert!(debug.contains("len: 6"));
+        // The bytes of "sec"
+        assert!(!debug.contains("115, 101, 99"));
+    }
+
+    #[test]
+    fn wrong_method() {
+        let serialized = SerializedRequest::new(&SendMessage::new(1, "hi")).unwrap();
+        assert!(serialized.into_payload::<SendMediaGroup>().is_err());
+    }
+}
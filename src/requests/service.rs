@@ -0,0 +1,60 @@
+use std::task::{Context, Poll};
+
+use tower_service::Service;
+
+use crate::requests::{Output, Request};
+
+/// A [`tower::Service`] which sends requests.
+///
+/// Requests already know which bot sends them, so this service is stateless:
+/// [`call`] just calls [`Request::send`]. The point is to be able to wrap it
+/// in `tower` middleware (rate limit, retry, buffer, timeout, etc) instead of
+/// writing a bespoke bot adaptor. The method of a request can be inspected
+/// with [`HasPayload::payload_ref`] and [`Payload::NAME`].
+///
+/// Note that many `tower` middlewares require requests to be [`Clone`] (e.g.
+/// to retry them). [`JsonRequest`] and [`MultipartRequest`] are, requests of
+/// bot adaptors are if the requests they wrap are (`AutoRequest` can only be
+/// cloned before it's polled).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     payloads::GetMe,
+///     requests::{JsonRequest, RequestService},
+/// };
+/// use tower_service::Service;
+///
+/// fn assert_service<S: Service<JsonRequest<GetMe>>>(_: S) {}
+///
+/// assert_service(RequestService);
+/// ```
+///
+/// [`tower::Service`]: tower_service::Service
+/// [`JsonRequest`]: crate::requests::JsonRequest
+/// [`MultipartRequest`]: crate::requests::MultipartRequest
+/// [`call`]: RequestService::call
+/// [`HasPayload::payload_ref`]: crate::requests::HasPayload::payload_ref
+/// [`Payload::NAME`]: crate::requests::Payload::NAME
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RequestService;
+
+impl<R> Service<R> for RequestService
+where
+    R: Request,
+{
+    type Response = Output<R>;
+    type Error = R::Err;
+    type Future = R::Send;
+
+    /// Always ready, backpressure (if any) is applied by the bot when the
+    /// request is sent.
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        request.send()
+    }
+}
@@ -0,0 +1,90 @@
+use futures::{
+    future::{Either, MapOk},
+    TryFutureExt,
+};
+
+use crate::{
+    payloads::{SetGameScore, SetGameScoreInline, SetGameScoreInlineSetters, SetGameScoreSetters},
+    requests::Request,
+    types::{Message, True},
+};
+
+/// A request to set the score of a user in a game, which targets either a
+/// message in a chat or an inline message.
+///
+/// Telegram returns the edited [`Message`] for the former and `True` for the
+/// latter. This request unifies both: it resolves to `Some(message)` for chat
+/// messages and to `None` for inline messages.
+///
+/// This type is returned by [`RequesterExt::set_game_score_target`], see its
+/// documentation for an example.
+///
+/// [`RequesterExt::set_game_score_target`]: crate::requests::RequesterExt::set_game_score_target
+#[must_use = "Requests do nothing unless sent"]
+#[derive(Clone, Debug)]
+pub enum SetGameScoreTarget<C, I> {
+    /// [`SetGameScore`] request, targeting a message in a chat.
+    Common(C),
+    /// [`SetGameScoreInline`] request, targeting an inline message.
+    Inline(I),
+}
+
+/// A future returned by [`SetGameScoreTarget::send`].
+pub type SetGameScoreTargetSend<C, I> = Either<
+    MapOk<<C as Request>::Send, fn(Message) -> Option<Message>>,
+    MapOk<<I as Request>::Send, fn(True) -> Option<Message>>,
+>;
+
+/// A future returned by [`SetGameScoreTarget::send_ref`].
+pub type SetGameScoreTargetSendRef<C, I> = Either<
+    MapOk<<C as Request>::SendRef, fn(Message) -> Option<Message>>,
+    MapOk<<I as Request>::SendRef, fn(True) -> Option<Message>>,
+>;
+
+impl<C, I> SetGameScoreTarget<C, I>
+where
+    C: Request<Payload = SetGameScore>,
+    I: Request<Payload = SetGameScoreInline, Err = C::Err>,
+{
+    /// Pass `true`, if the high score is allowed to decrease. This can be
+    /// useful when fixing mistakes or banning cheaters.
+    pub fn force(self, value: bool) -> Self {
+        match self {
+            Self::Common(req) => Self::Common(req.force(value)),
+            Self::Inline(req) => Self::Inline(req.force(value)),
+        }
+    }
+
+    /// Pass `true`, if the game message should not be automatically edited to
+    /// include the current scoreboard.
+    pub fn disable_edit_message(self, value: bool) -> Self {
+        match self {
+            Self::Common(req) => Self::Common(req.disable_edit_message(value)),
+            Self::Inline(req) => Self::Inline(req.disable_edit_message(value)),
+        }
+    }
+
+    /// Send this request.
+    ///
+    /// See also: [`Request::send`].
+    pub fn send(self) -> SetGameScoreTargetSend<C, I> {
+        match self {
+            Self::Common(req) => Either::Left(req.send().map_ok(Some as _)),
+            Self::Inline(req) => Either::Right(req.send().map_ok(none as _)),
+        }
+    }
+
+    /// Send this request by reference.
+    ///
+    /// See also: [`Request::send_ref`].
+    pub fn send_ref(&self) -> SetGameScoreTargetSendRef<C, I> {
+        match self {
+            Self::Common(req) => Either::Left(req.send_ref().map_ok(Some as _)),
+            Self::Inline(req) => Either::Right(req.send_ref().map_ok(none as _)),
+        }
+    }
+}
+
+fn none(_: True) -> Option<Message> {
+    None
+}
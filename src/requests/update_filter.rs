@@ -0,0 +1,196 @@
+use std::{fmt, ops::Not, sync::Arc};
+
+use crate::types::{AllowedUpdate, Update};
+
+/// A client-side filter of updates, see [`Polling::filter`].
+///
+/// Filters are composed with [`and`], [`or`] and `!`, e.g. to cheaply drop
+/// floods of updates before processing them. Unlike [`allowed_updates`],
+/// filters are applied after the updates are received, so they can use any
+/// information of the update.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     requests::UpdateFilter,
+///     types::{AllowedUpdate, Update},
+/// };
+///
+/// // Messages in the chat 1 which are not sent by the user 2
+/// let filter = UpdateFilter::by_kind(AllowedUpdate::Message)
+///     .and(UpdateFilter::by_chat(1))
+///     .and(!UpdateFilter::by_user(2));
+///
+/// // Updates caused by users which are not bots
+/// let humans = UpdateFilter::custom(|update: &Update| update.user().map_or(false, |u| !u.is_bot));
+/// # let _ = (filter, humans);
+/// ```
+///
+/// [`Polling::filter`]: crate::requests::Polling::filter
+/// [`and`]: UpdateFilter::and
+/// [`or`]: UpdateFilter::or
+/// [`allowed_updates`]: crate::requests::Polling::allowed_updates
+#[derive(Clone)]
+pub enum UpdateFilter {
+    /// Updates in the chat with this id, see [`Update::chat`].
+    Chat(i64),
+
+    /// Updates caused by the user with this id, see [`Update::user`].
+    User(i64),
+
+    /// Updates of this kind, see [`UpdateKind::allowed_update`].
+    ///
+    /// [`UpdateKind::allowed_update`]: crate::types::UpdateKind::allowed_update
+    Kind(AllowedUpdate),
+
+    /// Updates for which the function returns `true`.
+    Custom(Arc<dyn Fn(&Update) -> bool + Send + Sync>),
+
+    /// Updates which match all of the filters.
+    All(Vec<UpdateFilter>),
+
+    /// Updates which match any of the filters.
+    Any(Vec<UpdateFilter>),
+
+    /// Updates which don't match the filter.
+    Not(Box<UpdateFilter>),
+}
+
+impl UpdateFilter {
+    /// Creates [`UpdateFilter::Chat`].
+    pub fn by_chat(chat_id: i64) -> Self {
+        Self::Chat(chat_id)
+    }
+
+    /// Creates [`UpdateFilter::User`].
+    pub fn by_user(user_id: i64) -> Self {
+        Self::User(user_id)
+    }
+
+    /// Creates [`UpdateFilter::Kind`].
+    pub fn by_kind(kind: AllowedUpdate) -> Self {
+        Self::Kind(kind)
+    }
+
+    /// Creates [`UpdateFilter::Custom`].
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: Fn(&Update) -> bool + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Returns a filter which matches updates matched by both `self` and
+    /// `other`.
+    pub fn and(self, other: Self) -> Self {
+        match self {
+            Self::All(mut filters) => {
+                filters.push(other);
+                Self::All(filters)
+            }
+            this => Self::All(vec![this, other]),
+        }
+    }
+
+    /// Returns a filter which matches updates matched by either `self` or
+    /// `other`.
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Any(mut filters) => {
+                filters.push(other);
+                Self::Any(filters)
+            }
+            this => Self::Any(vec![this, other]),
+        }
+    }
+
+    /// Returns `true` if the `update` matches the filter.
+    pub fn matches(&self, update: &Update) -> bool {
+        match self {
+            Self::Chat(id) => update.chat_id() == Some(*id),
+            Self::User(id) => update.user().map(|user| user.id) == Some(*id),
+            Self::Kind(kind) => update.kind.allowed_update() == *kind,
+            Self::Custom(f) => f(update),
+            Self::All(filters) => filters.iter().all(|filter| filter.matches(update)),
+            Self::Any(filters) => filters.iter().any(|filter| filter.matches(update)),
+            Self::Not(filter) => !filter.matches(update),
+        }
+    }
+}
+
+impl Not for UpdateFilter {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(filter) => *filter,
+            this => Self::Not(Box::new(this)),
+        }
+    }
+}
+
+impl fmt::Debug for UpdateFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Chat(id) => f.debug_tuple("Chat").field(id).finish(),
+            Self::User(id) => f.debug_tuple("User").field(id).finish(),
+            Self::Kind(kind) => f.debug_tuple("Kind").field(kind).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+            Self::All(filters) => f.debug_tuple("All").field(filters).finish(),
+            Self::Any(filters) => f.debug_tuple("Any").field(filters).finish(),
+            Self::Not(filter) => f.debug_tuple("Not").field(filter).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(chat_id: i64, user_id: i64) -> Update {
+        serde_json::from_value(serde_json::json!({
+            "update_id": 1,
+            "message": {
+                "message_id": 1,
+                "date": 0,
+                "chat": { "id": chat_id, "type": "group", "title": "g" },
+                "from": { "id": user_id, "is_bot": false, "first_name": "u" },
+                "text": "hi"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn matches() {
+        let update = message(-1, 2);
+
+        assert!(UpdateFilter::by_chat(-1).matches(&update));
+        assert!(!UpdateFilter::by_chat(2).matches(&update));
+        assert!(UpdateFilter::by_user(2).matches(&update));
+        assert!(UpdateFilter::by_kind(AllowedUpdate::Message).matches(&update));
+        assert!(!UpdateFilter::by_kind(AllowedUpdate::EditedMessage).matches(&update));
+        assert!(UpdateFilter::custom(|u| u.id == 1).matches(&update));
+    }
+
+    #[test]
+    fn combinators() {
+        let filter = UpdateFilter::by_chat(-1)
+            .and(!UpdateFilter::by_user(3))
+            .or(UpdateFilter::by_user(4));
+
+        assert!(filter.matches(&message(-1, 2)));
+        assert!(!filter.matches(&message(-1, 3)));
+        assert!(!filter.matches(&message(-2, 2)));
+        assert!(filter.matches(&message(-2, 4)));
+
+        assert!(matches!(!!UpdateFilter::by_chat(1), UpdateFilter::Chat(1)));
+        assert!(matches!(
+            UpdateFilter::by_chat(1)
+                .and(UpdateFilter::by_user(1))
+                .and(UpdateFilter::by_user(2)),
+            UpdateFilter::All(filters) if filters.len() == 3
+        ));
+    }
+}
@@ -0,0 +1,975 @@
+//! Scheduled sending of requests.
+//!
+//! [`Scheduler`] sends requests at a given time or after a delay, and can
+//! also send a request periodically or on a [`Schedule`] (e.g. a daily
+//! broadcast at 9:00). Requests are
+//! built with any [`Requester`] (e.g. [`Bot`] or one wrapped in adaptors) as
+//! usual and are sent through it when they are due.
+//!
+//! ## Ordering
+//!
+//! Due requests are sent in the order of their time (requests scheduled at
+//! the same time are sent in the order in which they were scheduled), and
+//! each request is polled for the first time before the next one is sent.
+//! Since adaptors like [`Throttle`] and [`ChatQueue`] register requests
+//! when they are sent or first polled, scheduled requests keep this order
+//! in their queues as well.
+//!
+//! ## Persistence
+//!
+//! Scheduled requests only live in memory. A [`SchedulerStore`] can be used
+//! to persist them (as [`SerializedRequest`]s) and to re-schedule them after
+//! a restart.
+//!
+//! [`Requester`]: crate::requests::Requester
+//! [`Bot`]: crate::Bot
+//! [`Throttle`]: crate::adaptors::Throttle
+//! [`ChatQueue`]: crate::adaptors::ChatQueue
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+
+use futures::{
+    future::{self, BoxFuture},
+    task::noop_waker_ref,
+    FutureExt,
+};
+use serde::Serialize;
+use tokio::{
+    sync::{oneshot, Notify},
+    time::Instant,
+};
+
+use crate::requests::{Output, Request, SerializedRequest};
+
+/// The `log` target used by [`Scheduler`].
+pub const LOG_TARGET: &str = "teloxide_core::scheduler";
+
+/// About 30 years, used instead of times which are too far in the future to
+/// be represented (same as tokio does for its timers).
+const FAR_FUTURE: Duration = Duration::from_secs(30 * 365 * 24 * 60 * 60);
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Persistence hooks of a [`Scheduler`], see [`Scheduler::with_store`].
+///
+/// Only requests scheduled with [`Scheduler::send_at`] and
+/// [`Scheduler::send_in`] are passed to the store, periodic requests are
+/// expected to be re-created by the application on start.
+pub trait SchedulerStore: Send + Sync {
+    /// Called when a request is scheduled to be sent `at` the given time.
+    fn scheduled(&self, id: u64, at: SystemTime, request: &SerializedRequest);
+
+    /// Called when a request was sent (i.e. its result is known) or
+    /// cancelled.
+    fn removed(&self, id: u64);
+}
+
+/// Sends requests at a given time, see the [module-level docs] for more.
+///
+/// The scheduler is cheap to clone, all clones share the same queue. When all
+/// clones are dropped, requests which are not yet due are dropped too.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use teloxide_core::{prelude::*, scheduler::Scheduler};
+///
+/// # async {
+/// let bot = Bot::new("TOKEN");
+/// let scheduler = Scheduler::new();
+///
+/// let reminder = scheduler.send_in(
+///     bot.send_message(1234567890, "Time to stretch!"),
+///     Duration::from_secs(60 * 60),
+/// );
+///
+/// // Changed our mind
+/// reminder.cancel();
+///
+/// let message = scheduler
+///     .send_in(bot.send_message(1234567890, "Hi!"), Duration::from_secs(5))
+///     .await
+///     .expect("not cancelled")?;
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [module-level docs]: self
+#[derive(Clone)]
+pub struct Scheduler {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    notify: Arc<Notify>,
+    store: Option<Arc<dyn SchedulerStore>>,
+    /// The same moment as both `SystemTime` and `Instant`, used to convert
+    /// between them, so that equal `SystemTime`s map to equal `Instant`s.
+    epoch: (SystemTime, Instant),
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    /// Jobs ordered by the time they are due, ties are broken by id.
+    queue: BTreeMap<(Instant, u64), Job>,
+    /// The time at which each job in the queue is due.
+    times: HashMap<u64, Instant>,
+}
+
+type SendOnce = Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send>;
+
+/// Creates a request of a periodic job, it's shared so that it can be called
+/// without holding the lock of the [`State`].
+type Make = Arc<Mutex<dyn FnMut() -> BoxFuture<'static, ()> + Send>>;
+
+enum Job {
+    Once(SendOnce),
+    Every {
+        repeat: Repeat,
+        make: Make,
+        // Never used, dropped when the job is cancelled.
+        _tx: oneshot::Sender<()>,
+    },
+}
+
+/// When a periodic job is sent next.
+enum Repeat {
+    Period(Duration),
+    /// `last` is the time of the last send, so that it's not repeated if the
+    /// job is due before the system clock reaches it.
+    Schedule {
+        schedule: Schedule,
+        last: SystemTime,
+    },
+}
+
+/// A job which is due, taken out of the [`State`].
+enum Due {
+    Once(u64, SendOnce),
+    Every(Make),
+}
+
+impl Scheduler {
+    /// Creates new `Scheduler`, spawning its worker with `tokio::spawn`.
+    ///
+    /// Note: this function needs to be called inside of a tokio runtime.
+    pub fn new() -> Self {
+        Self::spawn(None)
+    }
+
+    /// Creates new `Scheduler` which reports scheduled requests to the
+    /// `store`, spawning its worker with `tokio::spawn`.
+    ///
+    /// Note: this function needs to be called inside of a tokio runtime.
+    pub fn with_store<S>(store: S) -> Self
+    where
+        S: SchedulerStore + 'static,
+    {
+        Self::spawn(Some(Arc::new(store)))
+    }
+
+    fn spawn(store: Option<Arc<dyn SchedulerStore>>) -> Self {
+        let notify = Arc::new(Notify::new());
+        let inner = Arc::new(Inner {
+            state: Mutex::new(State::default()),
+            notify: Arc::clone(&notify),
+            store,
+            epoch: (SystemTime::now(), Instant::now()),
+        });
+
+        tokio::spawn(worker(Arc::downgrade(&inner), notify));
+
+        Self { inner }
+    }
+
+    /// Schedules the `request` to be sent at the given time.
+    ///
+    /// Requests scheduled in the past are sent immediately. The time is
+    /// converted to the monotonic clock, so changes of the system clock don't
+    /// affect already scheduled requests.
+    ///
+    /// The returned handle resolves to the result of the request, or to `None`
+    /// if the request was [cancelled]. Dropping the handle does **not** cancel
+    /// the request.
+    ///
+    /// [cancelled]: JobHandle::cancel
+    pub fn send_at<R>(&self, request: R, at: SystemTime) -> JobHandle<Result<Output<R>, R::Err>>
+    where
+        R: Request + Send + 'static,
+        R::Payload: Serialize,
+        R::Err: 'static,
+        Output<R>: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        let id = self.inner.next_id();
+
+        // The store is called without holding the lock, since it may be slow
+        // or use the scheduler
+        if let Some(store) = &self.inner.store {
+            match SerializedRequest::new(request.payload_ref()) {
+                Ok(serialized) => store.scheduled(id, at, &serialized),
+                Err(err) => log::warn!(
+                    target: LOG_TARGET,
+                    "couldn't serialize scheduled request {}: {}",
+                    id,
+                    err
+                ),
+            }
+        }
+
+        let job = Job::Once(Box::new(move || {
+            async move {
+                let _ = tx.send(request.send().await);
+            }
+            .boxed()
+        }));
+        self.inner.insert(id, self.inner.instant_at(at), job);
+        self.handle(id, rx)
+    }
+
+    /// Schedules the `request` to be sent after the `delay`.
+    ///
+    /// See [`send_at`] for more.
+    ///
+    /// [`send_at`]: Scheduler::send_at
+    pub fn send_in<R>(&self, request: R, delay: Duration) -> JobHandle<Result<Output<R>, R::Err>>
+    where
+        R: Request + Send + 'static,
+        R::Payload: Serialize,
+        R::Err: 'static,
+        Output<R>: Send + 'static,
+    {
+        let now = SystemTime::now();
+        self.send_at(request, now.checked_add(delay).unwrap_or(now + FAR_FUTURE))
+    }
+
+    /// Sends a request created by `make` every `period`, starting at the given
+    /// time.
+    ///
+    /// If the scheduler falls behind (e.g. because the runtime was blocked),
+    /// missed sends are not repeated. Errors are logged to the [`LOG_TARGET`]
+    /// with the `warn` level.
+    ///
+    /// The returned handle resolves to `None` when the job is [cancelled].
+    ///
+    /// Note that the `period` is measured with the monotonic clock, so sends
+    /// drift away from the system clock over time (e.g. after the system was
+    /// suspended). Use [`send_on`] to send requests at a given time of day.
+    ///
+    /// ## Panics
+    ///
+    /// If the `period` is zero.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use teloxide_core::{prelude::*, scheduler::Scheduler};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// let scheduler = Scheduler::new();
+    ///
+    /// let day = Duration::from_secs(24 * 60 * 60);
+    /// let digest = scheduler.send_every(SystemTime::now(), day, move || {
+    ///     bot.send_message(-1001234567890, "Daily digest is here")
+    /// });
+    /// # };
+    /// ```
+    ///
+    /// [cancelled]: JobHandle::cancel
+    /// [`send_on`]: Scheduler::send_on
+    pub fn send_every<F, R>(&self, start: SystemTime, period: Duration, make: F) -> JobHandle<()>
+    where
+        F: FnMut() -> R + Send + 'static,
+        R: Request + 'static,
+        R::Err: fmt::Display,
+    {
+        assert!(
+            period > Duration::from_secs(0),
+            "the period must not be zero"
+        );
+
+        let at = self.inner.instant_at(start);
+        self.repeat(at, Repeat::Period(period), make)
+    }
+
+    /// Sends a request created by `make` at every time of the `schedule`.
+    ///
+    /// Times of the schedule are checked against the system clock. If the
+    /// scheduler falls behind, missed sends are not repeated. Errors are
+    /// logged to the [`LOG_TARGET`] with the `warn` level.
+    ///
+    /// The returned handle resolves to `None` when the job is [cancelled].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     prelude::*,
+    ///     scheduler::{Schedule, Scheduler, Weekday},
+    /// };
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    /// let scheduler = Scheduler::new();
+    ///
+    /// // Every Monday at 9:30 in UTC+3
+    /// let schedule = Schedule::weekly(Weekday::Monday, 9, 30).utc_offset(3 * 60 * 60);
+    /// let digest = scheduler.send_on(schedule, move || {
+    ///     bot.send_message(-1001234567890, "Weekly digest is here")
+    /// });
+    /// # };
+    /// ```
+    ///
+    /// [cancelled]: JobHandle::cancel
+    pub fn send_on<F, R>(&self, schedule: Schedule, make: F) -> JobHandle<()>
+    where
+        F: FnMut() -> R + Send + 'static,
+        R: Request + 'static,
+        R::Err: fmt::Display,
+    {
+        let now = SystemTime::now();
+        let first = schedule.next_after(now);
+        let at = Inner::instant_in(first, now, Instant::now());
+        self.repeat(
+            at,
+            Repeat::Schedule {
+                schedule,
+                last: first,
+            },
+            make,
+        )
+    }
+
+    fn repeat<F, R>(&self, at: Instant, repeat: Repeat, mut make: F) -> JobHandle<()>
+    where
+        F: FnMut() -> R + Send + 'static,
+        R: Request + 'static,
+        R::Err: fmt::Display,
+    {
+        let (tx, rx) = oneshot::channel();
+        let id = self.inner.next_id();
+
+        let job = Job::Every {
+            repeat,
+            make: Arc::new(Mutex::new(move || {
+                let send = make().send();
+                async move {
+                    if let Err(err) = send.await {
+                        log::warn!(target: LOG_TARGET, "periodic request {} failed: {}", id, err);
+                    }
+                }
+                .boxed()
+            })),
+            _tx: tx,
+        };
+        self.inner.insert(id, at, job);
+        self.handle(id, rx)
+    }
+
+    /// Returns the number of requests which are not yet sent (including
+    /// periodic ones).
+    pub fn pending(&self) -> usize {
+        self.inner.state.lock().unwrap().queue.len()
+    }
+
+    fn handle<T>(&self, id: u64, rx: oneshot::Receiver<T>) -> JobHandle<T> {
+        JobHandle {
+            id,
+            inner: Arc::downgrade(&self.inner),
+            rx,
+        }
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("pending", &self.pending())
+            .finish()
+    }
+}
+
+impl State {
+    fn insert(&mut self, id: u64, at: Instant, job: Job) {
+        self.queue.insert((at, id), job);
+        self.times.insert(id, at);
+    }
+
+    fn remove(&mut self, id: u64) -> Option<Job> {
+        let at = self.times.remove(&id)?;
+        self.queue.remove(&(at, id))
+    }
+}
+
+impl Inner {
+    fn next_id(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id;
+        state.next_id += 1;
+        id
+    }
+
+    fn insert(&self, id: u64, at: Instant, job: Job) {
+        self.state.lock().unwrap().insert(id, at, job);
+        self.notify.notify_one();
+    }
+
+    fn instant_at(&self, at: SystemTime) -> Instant {
+        let (system, instant) = self.epoch;
+        Self::instant_in(at, system, instant)
+    }
+
+    /// Converts the time `at` to an `Instant`, given that `system` and
+    /// `instant` are the same moment.
+    fn instant_in(at: SystemTime, system: SystemTime, instant: Instant) -> Instant {
+        match at.duration_since(system) {
+            Ok(later) => later_than(instant, later),
+            Err(err) => instant.checked_sub(err.duration()).unwrap_or(instant),
+        }
+    }
+
+    /// Sends all due requests and returns the time when the next one is due.
+    fn dispatch_due(&self) -> Option<Instant> {
+        let now = Instant::now();
+        let mut due = Vec::new();
+
+        let next = {
+            let mut state = self.state.lock().unwrap();
+            while let Some(&(at, id)) = state.queue.keys().next() {
+                if at > now {
+                    break;
+                }
+
+                match state.remove(id) {
+                    Some(Job::Once(send)) => due.push(Due::Once(id, send)),
+                    Some(Job::Every { repeat, make, _tx }) => {
+                        due.push(Due::Every(Arc::clone(&make)));
+                        let (next, repeat) = repeat.next(at, now);
+                        state.insert(id, next, Job::Every { repeat, make, _tx });
+                    }
+                    None => {}
+                }
+            }
+
+            state.queue.keys().next().map(|&(at, _)| at)
+        };
+
+        // Requests are created without holding the lock, since they may use
+        // the scheduler
+        for job in due {
+            let mut send = match job {
+                Due::Once(id, send) => {
+                    let send = send();
+                    let store = self.store.clone();
+                    async move {
+                        send.await;
+                        if let Some(store) = store {
+                            store.removed(id);
+                        }
+                    }
+                    .boxed()
+                }
+                Due::Every(make) => (make.lock().unwrap())(),
+            };
+
+            // Poll every request once in order, so that adaptors which
+            // register requests on the first poll see them in order too.
+            let mut cx = Context::from_waker(noop_waker_ref());
+            if send.as_mut().poll(&mut cx).is_pending() {
+                tokio::spawn(send);
+            }
+        }
+
+        next
+    }
+
+    fn cancel(&self, id: u64) -> bool {
+        let removed = self.state.lock().unwrap().remove(id);
+        // Periodic jobs are not passed to the store
+        if let (Some(Job::Once(_)), Some(store)) = (&removed, &self.store) {
+            store.removed(id);
+        }
+
+        removed.is_some()
+    }
+}
+
+impl Repeat {
+    /// Returns the time of the next send of a job which was due `at`, which is
+    /// always after `now`.
+    fn next(self, at: Instant, now: Instant) -> (Instant, Self) {
+        match self {
+            Repeat::Period(period) => {
+                // Skip the missed sends, keeping the phase
+                let behind = (now - at).as_nanos() % period.as_nanos();
+                let next = later_than(now, period - Duration::from_nanos(behind as u64));
+                (next, self)
+            }
+            Repeat::Schedule { schedule, last } => {
+                let system_now = SystemTime::now();
+                let next = schedule.next_after(std::cmp::max(last, system_now));
+                let at = Inner::instant_in(next, system_now, now);
+                (
+                    at,
+                    Repeat::Schedule {
+                        schedule,
+                        last: next,
+                    },
+                )
+            }
+        }
+    }
+}
+
+/// Returns `instant + duration`, or a time in the far future if it can't be
+/// represented.
+fn later_than(instant: Instant, duration: Duration) -> Instant {
+    instant
+        .checked_add(duration)
+        .unwrap_or_else(|| Instant::now() + FAR_FUTURE)
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        // Wake up the worker, so it can notice that the scheduler is gone.
+        self.notify.notify_one();
+    }
+}
+
+async fn worker(inner: Weak<Inner>, notify: Arc<Notify>) {
+    loop {
+        let next = match inner.upgrade() {
+            Some(inner) => inner.dispatch_due(),
+            None => break,
+        };
+
+        let notified = notify.notified();
+        match next {
+            Some(at) => {
+                let sleep = tokio::time::sleep_until(at);
+                futures::pin_mut!(notified, sleep);
+                future::select(notified, sleep).await;
+            }
+            None => notified.await,
+        }
+    }
+}
+
+/// Times at which a periodic job is sent, see [`Scheduler::send_on`].
+///
+/// Times are in UTC, unless a different [offset] is set. Since the offset is
+/// fixed, daylight saving time is not taken into account.
+///
+/// [offset]: Schedule::utc_offset
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Schedule {
+    /// The period in seconds.
+    period: u64,
+    /// The offset of the times from the start of a period in seconds, in the
+    /// local time. Periods are counted from the unix epoch.
+    phase: u64,
+    /// The offset of the local time from UTC in seconds.
+    offset: i32,
+}
+
+/// A day of the week, see [`Schedule::weekly`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Schedule {
+    /// Every hour at the given `minute`.
+    ///
+    /// ## Panics
+    ///
+    /// If the `minute` is not less than 60.
+    pub fn hourly(minute: u8) -> Self {
+        assert!(minute < 60, "invalid minute: {}", minute);
+        Self {
+            period: 60 * 60,
+            phase: u64::from(minute) * 60,
+            offset: 0,
+        }
+    }
+
+    /// Every day at the given time, e.g. `Schedule::daily(9, 30)` for 9:30.
+    ///
+    /// ## Panics
+    ///
+    /// If the `hour` is not less than 24 or the `minute` is not less than 60.
+    pub fn daily(hour: u8, minute: u8) -> Self {
+        Self {
+            period: DAY,
+            phase: time_of_day(hour, minute),
+            offset: 0,
+        }
+    }
+
+    /// Every week on the `weekday` at the given time.
+    ///
+    /// ## Panics
+    ///
+    /// If the `hour` is not less than 24 or the `minute` is not less than 60.
+    pub fn weekly(weekday: Weekday, hour: u8, minute: u8) -> Self {
+        Self {
+            period: 7 * DAY,
+            // The unix epoch is Thursday, Monday is 4 days later
+            phase: (weekday as u64 + 4) % 7 * DAY + time_of_day(hour, minute),
+            offset: 0,
+        }
+    }
+
+    /// Sets the offset of the times from UTC in seconds, e.g. `3 * 60 * 60`
+    /// for UTC+3.
+    pub fn utc_offset(self, offset: i32) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Returns the first time of the schedule after the `time`.
+    pub fn next_after(&self, time: SystemTime) -> SystemTime {
+        let since_epoch = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        // The phase in UTC
+        let offset = i64::from(self.offset).rem_euclid(self.period as i64) as u64;
+        let phase = (self.phase + self.period - offset) % self.period;
+
+        let since_time = (since_epoch + self.period - phase) % self.period;
+        let next = since_epoch - since_time + self.period;
+        SystemTime::UNIX_EPOCH + Duration::from_secs(next)
+    }
+}
+
+fn time_of_day(hour: u8, minute: u8) -> u64 {
+    assert!(hour < 24, "invalid hour: {}", hour);
+    assert!(minute < 60, "invalid minute: {}", minute);
+    u64::from(hour) * 60 * 60 + u64::from(minute) * 60
+}
+
+/// A handle of a request scheduled in a [`Scheduler`].
+///
+/// Resolves to the result of the request once it's sent or to `None` if the
+/// request was cancelled (or the scheduler was dropped).
+pub struct JobHandle<T> {
+    id: u64,
+    inner: Weak<Inner>,
+    rx: oneshot::Receiver<T>,
+}
+
+impl<T> JobHandle<T> {
+    /// Returns the id of the job, same as the one passed to the
+    /// [`SchedulerStore`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Cancels the job, returns `false` if it was already sent (or
+    /// cancelled).
+    ///
+    /// For periodic jobs this cancels all future sends.
+    pub fn cancel(&self) -> bool {
+        match self.inner.upgrade() {
+            Some(inner) => inner.cancel(self.id),
+            None => false,
+        }
+    }
+}
+
+impl<T> Future for JobHandle<T> {
+    type Output = Option<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx.poll_unpin(cx).map(Result::ok)
+    }
+}
+
+impl<T> fmt::Debug for JobHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JobHandle").field("id", &self.id).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{
+        payloads::DeleteMessage,
+        test_utils::{rt, MockRequest},
+        types::True,
+    };
+
+    /// Returns a request which records the order in which it was sent and,
+    /// if the `gate` is set, completes only when the `gate` is notified.
+    fn recorded(
+        id: i32,
+        log: &Arc<Mutex<Vec<i32>>>,
+        gate: Option<Arc<Notify>>,
+    ) -> MockRequest<DeleteMessage> {
+        let log = Arc::clone(log);
+        MockRequest::new(DeleteMessage::new(1, id), move |payload| {
+            log.lock().unwrap().push(payload.message_id);
+            let gate = gate.clone();
+            async move {
+                if let Some(gate) = gate {
+                    gate.notified().await;
+                }
+                Ok(True)
+            }
+        })
+    }
+
+    #[derive(Default)]
+    struct Store {
+        scheduled: Mutex<Vec<(u64, String)>>,
+        removed: AtomicUsize,
+    }
+
+    impl SchedulerStore for Arc<Store> {
+        fn scheduled(&self, id: u64, _: SystemTime, request: &SerializedRequest) {
+            self.scheduled
+                .lock()
+                .unwrap()
+                .push((id, request.method.clone()));
+        }
+
+        fn removed(&self, _: u64) {
+            self.removed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn sends_in_order() {
+        rt().block_on(async {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let store = Arc::new(Store::default());
+            let scheduler = Scheduler::with_store(Arc::clone(&store));
+            let request = |id| recorded(id, &log, None);
+
+            let now = SystemTime::now();
+            let ms = Duration::from_millis;
+            let third = scheduler.send_at(request(3), now + ms(40));
+            let first = scheduler.send_at(request(1), now + ms(20));
+            let second = scheduler.send_at(request(2), now + ms(20));
+            let cancelled = scheduler.send_at(request(4), now + ms(30));
+
+            assert!(cancelled.cancel());
+            assert!(!cancelled.cancel());
+            assert_eq!(scheduler.pending(), 3);
+
+            assert!(third.await.unwrap().is_ok());
+            assert!(first.await.is_some());
+            assert!(second.await.is_some());
+            assert!(cancelled.await.is_none());
+
+            assert_eq!(*log.lock().unwrap(), [1, 2, 3]);
+            assert_eq!(scheduler.pending(), 0);
+            assert_eq!(store.scheduled.lock().unwrap().len(), 4);
+            assert_eq!(store.scheduled.lock().unwrap()[0].1, "DeleteMessage");
+            assert_eq!(store.removed.load(Ordering::SeqCst), 4);
+        });
+    }
+
+    #[test]
+    fn removed_when_completed() {
+        rt().block_on(async {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let gate = Arc::new(Notify::new());
+            let store = Arc::new(Store::default());
+            let scheduler = Scheduler::with_store(Arc::clone(&store));
+
+            let request = recorded(0, &log, Some(Arc::clone(&gate)));
+            let handle = scheduler.send_in(request, Duration::from_millis(0));
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            assert_eq!(log.lock().unwrap().len(), 1);
+            assert_eq!(store.removed.load(Ordering::SeqCst), 0);
+
+            gate.notify_one();
+            assert!(handle.await.unwrap().is_ok());
+            tokio::task::yield_now().await;
+            assert_eq!(store.removed.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    #[test]
+    fn periodic() {
+        rt().block_on(async {
+            tokio::time::pause();
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let store = Arc::new(Store::default());
+            let scheduler = Scheduler::with_store(Arc::clone(&store));
+
+            let job = scheduler.send_every(SystemTime::now(), Duration::from_millis(10), {
+                let log = Arc::clone(&log);
+                let scheduler = scheduler.clone();
+                move || {
+                    // Requests can be created with the scheduler
+                    assert_eq!(scheduler.pending(), 1);
+                    recorded(0, &log, None)
+                }
+            });
+
+            tokio::time::sleep(Duration::from_millis(55)).await;
+            assert!(job.cancel());
+            assert_eq!(log.lock().unwrap().len(), 6);
+
+            assert!(job.await.is_none());
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            assert_eq!(log.lock().unwrap().len(), 6);
+            // Periodic requests are not persisted
+            assert_eq!(store.removed.load(Ordering::SeqCst), 0);
+        });
+    }
+
+    #[test]
+    fn periodic_behind() {
+        rt().block_on(async {
+            tokio::time::pause();
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let scheduler = Scheduler::new();
+
+            // Two sends are already missed
+            let start = SystemTime::now() - Duration::from_millis(25);
+            let job = scheduler.send_every(start, Duration::from_millis(10), {
+                let log = Arc::clone(&log);
+                move || recorded(0, &log, None)
+            });
+
+            tokio::time::sleep(Duration::from_millis(4)).await;
+            assert_eq!(log.lock().unwrap().len(), 1);
+            tokio::time::sleep(Duration::from_millis(2)).await;
+            assert_eq!(log.lock().unwrap().len(), 2);
+            assert!(job.cancel());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "the period must not be zero")]
+    fn zero_period() {
+        rt().block_on(async {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            Scheduler::new().send_every(SystemTime::now(), Duration::from_secs(0), move || {
+                recorded(0, &log, None)
+            });
+        });
+    }
+
+    #[test]
+    fn far_future() {
+        rt().block_on(async {
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let scheduler = Scheduler::new();
+
+            let once = scheduler.send_in(recorded(0, &log, None), Duration::MAX);
+            let every = scheduler.send_every(SystemTime::now(), Duration::MAX, {
+                let log = Arc::clone(&log);
+                move || recorded(1, &log, None)
+            });
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            assert_eq!(*log.lock().unwrap(), [1]);
+            assert_eq!(scheduler.pending(), 2);
+            assert!(once.cancel());
+            assert!(every.cancel());
+        });
+    }
+
+    #[test]
+    fn schedules() {
+        let time = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+        // Monday, 2024-01-01 00:00 UTC
+        let monday = 1_704_067_200;
+        let hour = 60 * 60;
+
+        assert_eq!(
+            Schedule::hourly(15).next_after(time(monday)),
+            time(monday + 15 * 60)
+        );
+        assert_eq!(
+            Schedule::daily(9, 30).next_after(time(monday)),
+            time(monday + 9 * hour + 30 * 60)
+        );
+        // Strictly after the given time
+        assert_eq!(
+            Schedule::daily(0, 0).next_after(time(monday)),
+            time(monday + DAY)
+        );
+        assert_eq!(
+            Schedule::daily(9, 30)
+                .utc_offset(3 * hour as i32)
+                .next_after(time(monday)),
+            time(monday + 6 * hour + 30 * 60)
+        );
+        assert_eq!(
+            Schedule::daily(1, 0)
+                .utc_offset(-3 * hour as i32)
+                .next_after(time(monday)),
+            time(monday + 4 * hour)
+        );
+        assert_eq!(
+            Schedule::weekly(Weekday::Monday, 9, 30).next_after(time(monday)),
+            time(monday + 9 * hour + 30 * 60)
+        );
+        assert_eq!(
+            Schedule::weekly(Weekday::Sunday, 0, 0).next_after(time(monday + 1)),
+            time(monday + 6 * DAY)
+        );
+        assert_eq!(
+            Schedule::weekly(Weekday::Monday, 0, 0).next_after(time(monday)),
+            time(monday + 7 * DAY)
+        );
+    }
+
+    #[test]
+    fn scheduled() {
+        rt().block_on(async {
+            tokio::time::pause();
+            let log = Arc::new(Mutex::new(Vec::new()));
+            let scheduler = Scheduler::new();
+
+            let job = scheduler.send_on(Schedule::hourly(0), {
+                let log = Arc::clone(&log);
+                move || recorded(0, &log, None)
+            });
+
+            let to_next_hour = Schedule::hourly(0)
+                .next_after(SystemTime::now())
+                .duration_since(SystemTime::now())
+                .unwrap();
+            tokio::time::sleep(to_next_hour + Duration::from_millis(10)).await;
+            assert_eq!(log.lock().unwrap().len(), 1);
+            assert!(job.cancel());
+        });
+    }
+}
@@ -13,19 +13,16 @@
 mod serializers;
 mod unserializers;
 
-use std::future::Future;
-
-use reqwest::multipart::Form;
 use serde::Serialize;
 
 use serializers::MultipartTopLvlSerializer;
 
-pub(crate) use serializers::Error;
+pub(crate) use serializers::{Error, FormTemplate};
 
-/// Serializes given value into [`Form`]
+/// Serializes given value into a [`FormTemplate`], from which [`Form`]s can be
+/// built
 ///
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form<T: ?Sized + Serialize>(val: &T) -> impl Future<Output = Result<Form, Error>> {
-    let fut = val.serialize(MultipartTopLvlSerializer {});
-    async { Ok(fut?.await?) }
+pub(crate) fn to_template<T: ?Sized + Serialize>(val: &T) -> Result<FormTemplate, Error> {
+    val.serialize(MultipartTopLvlSerializer {})
 }
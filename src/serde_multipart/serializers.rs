@@ -8,13 +8,13 @@ use futures::{
     stream::FuturesUnordered,
     FutureExt, StreamExt, TryStreamExt,
 };
-use reqwest::multipart::{Form, Part};
+use reqwest::multipart::Form;
 use serde::{
     ser,
     ser::{Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant},
     Serialize, Serializer,
 };
-use std::{fmt, fmt::Display, io};
+use std::{borrow::Cow, fmt, fmt::Display, io, mem};
 
 #[derive(Debug, derive_more::From)]
 pub(crate) enum Error {
@@ -242,8 +242,52 @@ impl Serializer for MultipartTopLvlSerializer {
     }
 }
 
+/// Serialized form, which can be turned into a [`Form`] any number of times.
+#[derive(Clone)]
+pub(crate) struct FormTemplate {
+    parts: Vec<(Cow<'static, str>, String)>,
+    files: Vec<(String, InputFile)>,
+}
+
+impl FormTemplate {
+    /// Reads files on disk which are at most `max_size` bytes long into memory,
+    /// so that every form built from the template uploads the same data.
+    pub(crate) async fn buffer_files(&mut self, max_size: u64) -> io::Result<()> {
+        for (_, file) in &mut self.files {
+            let taken = mem::replace(file, InputFile::FileId(String::new()));
+            *file = taken.buffer(max_size).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds the form, opening all files.
+    pub(crate) fn into_form(self) -> BoxFuture<'static, io::Result<Form>> {
+        let form = self
+            .parts
+            .into_iter()
+            .fold(Form::new(), |acc, (key, value)| acc.text(key, value));
+
+        if self.files.is_empty() {
+            //Ok(Either::Left(ready(Ok(form))))
+            Box::pin(ready(Ok(form)))
+        } else {
+            let fut = self
+                .files
+                .into_iter()
+                .map(|(k, f)| f.into_part().map(move |p| (k, p)))
+                .collect::<FuturesUnordered<_>>()
+                .map(Ok)
+                .try_fold(form, |acc, (k, p)| async { Ok(acc.part(k, p?)) });
+
+            //Ok(Either::Right(fut))
+            Box::pin(fut)
+        }
+    }
+}
+
 pub(crate) struct MultipartSerializer {
-    parts: Vec<(&'static str, Part)>, // TODO: Array vecs
+    parts: Vec<(Cow<'static, str>, String)>, // TODO: Array vecs
     files: Vec<(String, InputFile)>,
 }
 
@@ -257,7 +301,7 @@ impl MultipartSerializer {
 }
 
 impl SerializeStruct for MultipartSerializer {
-    type Ok = BoxFuture<'static, io::Result<Form>>; // impl Future<Output = io::Result<Form>>
+    type Ok = FormTemplate;
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -268,39 +312,24 @@ impl SerializeStruct for MultipartSerializer {
     where
         T: Serialize,
     {
-        let (part, file) = value.serialize(PartSerializer {})?;
-        self.parts.push((key, part));
-        self.files.extend(file);
+        let files = mem::take(&mut self.files);
+        let (part, files) = value.serialize(PartSerializer { files })?;
+        self.parts.push((Cow::Borrowed(key), part));
+        self.files = files;
 
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let form = self
-            .parts
-            .into_iter()
-            .fold(Form::new(), |acc, (key, value)| acc.part(key, value));
-
-        if self.files.is_empty() {
-            //Ok(Either::Left(ready(Ok(form))))
-            Ok(Box::pin(ready(Ok(form))))
-        } else {
-            let fut = self
-                .files
-                .into_iter()
-                .map(|(k, f)| f.into_part().map(move |p| (k, p)))
-                .collect::<FuturesUnordered<_>>()
-                .map(Ok)
-                .try_fold(form, |acc, (k, p)| async { Ok(acc.part(k, p?)) });
-
-            //Ok(Either::Right(fut))
-            Ok(Box::pin(fut))
-        }
+        Ok(FormTemplate {
+            parts: self.parts,
+            files: self.files,
+        })
     }
 }
 
 pub(crate) struct MultipartMapSerializer {
-    parts: Vec<(String, Part)>, // TODO: Array vecs
+    parts: Vec<(Cow<'static, str>, String)>, // TODO: Array vecs
     files: Vec<(String, InputFile)>,
     key: Option<String>,
 }
@@ -323,41 +352,33 @@ impl SerializeMap for MultipartMapSerializer {
     {
         let key = self.key.take().unwrap();
 
-        let (part, file) = value.serialize(PartSerializer {})?;
-        self.parts.push((key, part));
-        self.files.extend(file);
+        let files = mem::take(&mut self.files);
+        let (part, files) = value.serialize(PartSerializer { files })?;
+        self.parts.push((Cow::Owned(key), part));
+        self.files = files;
 
         Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        let form = self
-            .parts
-            .into_iter()
-            .fold(Form::new(), |acc, (key, value)| acc.part(key, value));
-
-        if self.files.is_empty() {
-            //Ok(Either::Left(ready(Ok(form))))
-            Ok(Box::pin(ready(Ok(form))))
-        } else {
-            let fut = self
-                .files
-                .into_iter()
-                .map(|(k, f)| f.into_part().map(move |p| (k, p)))
-                .collect::<FuturesUnordered<_>>()
-                .map(Ok)
-                .try_fold(form, |acc, (k, p)| async { Ok(acc.part(k, p?)) });
-
-            //Ok(Either::Right(fut))
-            Ok(Box::pin(fut))
-        }
+        Ok(FormTemplate {
+            parts: self.parts,
+            files: self.files,
+        })
     }
 }
 
-struct PartSerializer {}
+/// Serializer of a single part of a form.
+///
+/// `files` are the files attached to the form so far, they are passed through
+/// the whole form, so that the same file is attached only once.
+#[derive(Default)]
+struct PartSerializer {
+    files: Vec<(String, InputFile)>,
+}
 
 impl Serializer for PartSerializer {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (String, Vec<(String, InputFile)>);
     type Error = Error;
     type SerializeSeq = InnerPartSerializer;
     type SerializeTuple = Impossible<Self::Ok, Self::Error>;
@@ -368,7 +389,7 @@ impl Serializer for PartSerializer {
     type SerializeStructVariant = PartFromFile;
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string(), self.files))
     }
 
     fn serialize_i8(self, _: i8) -> Result<Self::Ok, Self::Error> {
@@ -380,11 +401,11 @@ impl Serializer for PartSerializer {
     }
 
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string(), self.files))
     }
 
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_string()), Vec::new()))
+        Ok((v.to_string(), self.files))
     }
 
     fn serialize_u8(self, _: u8) -> Result<Self::Ok, Self::Error> {
@@ -416,7 +437,7 @@ impl Serializer for PartSerializer {
     }
 
     fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(v.to_owned()), Vec::new()))
+        Ok((v.to_owned(), self.files))
     }
 
     fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
@@ -448,7 +469,7 @@ impl Serializer for PartSerializer {
         _: u32,
         variant_name: &'static str,
     ) -> Result<Self::Ok, Self::Error> {
-        Ok((Part::text(variant_name), Vec::new()))
+        Ok((variant_name.to_owned(), self.files))
     }
 
     fn serialize_newtype_struct<T: ?Sized>(
@@ -479,20 +500,15 @@ impl Serializer for PartSerializer {
             value,
         )?;
 
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                let part = Part::text(format!("attach://{}", uuid));
-                Ok((part, vec![(uuid, f)]))
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => Ok((Part::text(s), Vec::new())),
-        }
+        let mut files = self.files;
+        let part = attach(file, &mut files);
+        Ok((part, files))
     }
 
     fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         Ok(Self::SerializeSeq {
             array_json_parts: vec![],
-            files: vec![],
+            files: self.files,
         })
     }
 
@@ -532,7 +548,7 @@ impl Serializer for PartSerializer {
         Ok(PartSerializerStruct(
             ser, // TODO: capcity
             serde_json::ser::State::First,
-            Vec::new(),
+            self.files,
         ))
     }
 
@@ -550,16 +566,18 @@ impl Serializer for PartSerializer {
                 variant,
                 len,
             )?,
+            files: self.files,
         })
     }
 }
 
 struct PartFromFile {
     inner: InputFileUnserializer,
+    files: Vec<(String, InputFile)>,
 }
 
 impl SerializeStructVariant for PartFromFile {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (String, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -578,16 +596,9 @@ impl SerializeStructVariant for PartFromFile {
     fn end(self) -> Result<Self::Ok, Self::Error> {
         let file = self.inner.end()?;
 
-        // TODO: to method
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                let part = Part::text(format!("attach://{}", uuid));
-
-                Ok((part, vec![(uuid, f)]))
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => Ok((Part::text(s), vec![])),
-        }
+        let mut files = self.files;
+        let part = attach(file, &mut files);
+        Ok((part, files))
     }
 }
 
@@ -597,7 +608,7 @@ struct InnerPartSerializer {
 }
 
 impl SerializeSeq for InnerPartSerializer {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (String, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_element<T: ?Sized>(&mut self, value: &T) -> Result<(), Self::Error>
@@ -608,16 +619,12 @@ impl SerializeSeq for InnerPartSerializer {
         //       but at least it works
         let mut value = serde_json::to_value(value)?;
         let file: InputFile = serde_json::from_value(value["media"].take())?;
+        value["media"] = serde_json::Value::String(attach(file, &mut self.files));
 
-        match file {
-            f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                let uuid = uuid::Uuid::new_v4().to_string();
-                value["media"] = serde_json::Value::String(format!("attach://{}", uuid));
-                self.files.push((uuid, f));
-            }
-            InputFile::FileId(s) | InputFile::Url(s) => {
-                value["media"] = serde_json::Value::String(s);
-            }
+        // Thumbnails need to be attached just like the media itself
+        if let Some(thumbnail) = value.get_mut("thumbnail").filter(|t| !t.is_null()) {
+            let file: InputFile = serde_json::from_value(thumbnail.take())?;
+            *thumbnail = serde_json::Value::String(attach(file, &mut self.files));
         }
 
         self.array_json_parts.push(value);
@@ -627,7 +634,7 @@ impl SerializeSeq for InnerPartSerializer {
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
         let s = serde_json::to_string(&self.array_json_parts)?;
-        Ok((Part::text(s), self.files))
+        Ok((s, self.files))
     }
 }
 
@@ -638,7 +645,7 @@ struct PartSerializerStruct(
 );
 
 impl SerializeStruct for PartSerializerStruct {
-    type Ok = (Part, Vec<(String, InputFile)>);
+    type Ok = (String, Vec<(String, InputFile)>);
     type Error = Error;
 
     fn serialize_field<T: ?Sized>(
@@ -662,22 +669,18 @@ impl SerializeStruct for PartSerializerStruct {
         // special case media (required for `edit_message_media` to work)
         if key == "media" {
             let file = value.serialize(InputFileUnserializer::NotMem)?;
+            let attach = attach(file, &mut self.2);
 
-            match file {
-                f @ InputFile::Memory { .. } | f @ InputFile::File(_) => {
-                    let uuid = uuid::Uuid::new_v4().to_string();
-                    let attach = format!("attach://{}", uuid);
-
-                    SerializeStruct::serialize_field(&mut ser, key, attach.as_str())?;
-                    self.1 = get_state(ser);
+            SerializeStruct::serialize_field(&mut ser, key, attach.as_str())?;
+            self.1 = get_state(ser);
+        } else if key == "thumbnail" {
+            // thumbnails are optional, so we can't use `InputFileUnserializer` here
+            let file: Option<InputFile> = serde_json::from_value(serde_json::to_value(value)?)?;
+            let files = &mut self.2;
+            let attach = file.map(|file| attach(file, files));
 
-                    self.2.push((uuid, f));
-                }
-                InputFile::FileId(s) | InputFile::Url(s) => {
-                    SerializeStruct::serialize_field(&mut ser, key, &s)?;
-                    self.1 = get_state(ser)
-                }
-            }
+            SerializeStruct::serialize_field(&mut ser, key, &attach)?;
+            self.1 = get_state(ser);
         } else {
             SerializeStruct::serialize_field(&mut ser, key, value)?;
             self.1 = get_state(ser);
@@ -698,8 +701,35 @@ impl SerializeStruct for PartSerializerStruct {
         };
         SerializeStruct::end(ser)?;
 
-        let json = self.0.into_inner();
-        Ok((Part::bytes(json), self.2))
+        let json = String::from_utf8(self.0.into_inner()).expect("JSON is valid UTF-8");
+        Ok((json, self.2))
+    }
+}
+
+/// Returns a string which should be sent in place of the `file`, remembering
+/// the file in `files` if it needs to be uploaded.
+///
+/// Attachments are named by their index in `files`. If the same file is
+/// already attached, its name is reused, so that the file is uploaded only
+/// once.
+fn attach(file: InputFile, files: &mut Vec<(String, InputFile)>) -> String {
+    match file {
+        f @ InputFile::Memory { .. }
+        | f @ InputFile::File(_)
+        | f @ InputFile::Reopenable(_)
+        | f @ InputFile::WithMetadata(_) => {
+            let name = match files.iter().find(|(_, attached)| *attached == f) {
+                Some((name, _)) => name.clone(),
+                None => {
+                    let name = format!("file{}", files.len());
+                    files.push((name.clone(), f));
+                    name
+                }
+            };
+
+            format!("attach://{}", name)
+        }
+        InputFile::FileId(s) | InputFile::Url(s) => s,
     }
 }
 
@@ -713,3 +743,144 @@ fn get_state(
         _ => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, str::FromStr};
+
+    use serde::Serialize;
+
+    use super::PartSerializer;
+    use crate::types::{InputFile, InputMedia, InputMediaPhoto, InputMediaVideo};
+
+    #[test]
+    fn thumbnails_are_attached() {
+        let thumbnail = InputFile::Memory {
+            file_name: String::from("thumb.jpg"),
+            data: Cow::Borrowed(&[1, 2, 3]),
+        };
+        let video = InputMediaVideo::new(InputFile::file_id("id")).thumbnail(thumbnail.clone());
+
+        // `send_media_group`
+        let (_, files) = vec![InputMedia::Video(video.clone())]
+            .serialize(PartSerializer::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, thumbnail);
+
+        // `edit_message_media`
+        let (_, files) = InputMedia::Video(video)
+            .serialize(PartSerializer::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, thumbnail);
+    }
+
+    #[test]
+    fn duplicate_files_are_attached_once() {
+        let photo = InputMediaPhoto::new(InputFile::file("a/b/c.jpg"));
+        let other = InputMediaPhoto::new(InputFile::file("a/b/d.jpg"));
+        let media = vec![
+            InputMedia::Photo(photo.clone()),
+            InputMedia::Photo(other),
+            InputMedia::Photo(photo.clone()),
+        ];
+
+        let (_, files) = media.serialize(PartSerializer::default()).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(
+            files[0],
+            (String::from("file0"), InputFile::file("a/b/c.jpg"))
+        );
+        assert_eq!(
+            files[1],
+            (String::from("file1"), InputFile::file("a/b/d.jpg"))
+        );
+
+        // Files attached by previous fields are reused
+        let (_, files) = InputMedia::Photo(photo)
+            .serialize(PartSerializer { files })
+            .unwrap();
+        assert_eq!(files.len(), 2);
+
+        // Different files with the same name are not merged
+        let memory = |data: &'static [u8]| InputFile::Memory {
+            file_name: String::from("a.jpg"),
+            data: Cow::Borrowed(data),
+        };
+        let media = vec![
+            InputMedia::Photo(InputMediaPhoto::new(memory(&[1]))),
+            InputMedia::Photo(InputMediaPhoto::new(memory(&[2]))),
+        ];
+        let (_, files) = media.serialize(PartSerializer::default()).unwrap();
+        assert_eq!(files.len(), 2);
+    }
+
+    #[test]
+    fn reopenable_files_are_attached() {
+        let file = InputFile::reopenable("a.jpg", || async {
+            Ok(futures::stream::iter(vec![Ok(bytes::Bytes::from("data"))]))
+        });
+        let photo = InputMediaPhoto::new(file.clone());
+
+        // `send_media_group`
+        let (_, files) = vec![InputMedia::Photo(photo.clone())]
+            .serialize(PartSerializer::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, file);
+
+        // `edit_message_media`
+        let (_, files) = InputMedia::Photo(photo)
+            .serialize(PartSerializer::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, file);
+    }
+
+    #[test]
+    fn files_with_metadata_are_attached() {
+        let file = InputFile::file("a/b/recording")
+            .file_name("voice.ogg")
+            .mime_type(mime::Mime::from_str("audio/ogg").unwrap());
+        assert_eq!(
+            InputFile::file_id("id").file_name("a.ogg"),
+            InputFile::file_id("id")
+        );
+
+        let (_, files) = InputMediaPhoto::new(file.clone())
+            .serialize(PartSerializer::default())
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1, file);
+
+        let (_, files) = InputFile::file("a.jpg")
+            .file_name("b.jpg")
+            .file_name("c.jpg")
+            .serialize(PartSerializer::default())
+            .unwrap();
+        match &files[0].1 {
+            InputFile::WithMetadata(meta) => {
+                assert_eq!(*meta.file, InputFile::file("a.jpg"));
+                assert_eq!(meta.file_name.as_deref(), Some("c.jpg"));
+                assert_eq!(meta.mime_type, None);
+            }
+            file => panic!("unexpected file: {:?}", file),
+        }
+
+        // The same file with different names is attached twice
+        let media = vec![
+            InputMedia::Photo(InputMediaPhoto::new(
+                InputFile::file("a.jpg").file_name("b.jpg"),
+            )),
+            InputMedia::Photo(InputMediaPhoto::new(
+                InputFile::file("a.jpg").file_name("c.jpg"),
+            )),
+            InputMedia::Photo(InputMediaPhoto::new(
+                InputFile::file("a.jpg").file_name("b.jpg"),
+            )),
+        ];
+        let (_, files) = media.serialize(PartSerializer::default()).unwrap();
+        assert_eq!(files.len(), 2);
+    }
+}
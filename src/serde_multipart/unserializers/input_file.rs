@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use serde::{
-    ser::{Impossible, SerializeStructVariant},
+    ser::{self, Impossible, SerializeStructVariant},
     Serialize, Serializer,
 };
 
@@ -64,9 +64,20 @@ impl Serializer for InputFileUnserializer {
             "File" => Ok(InputFile::File(value.serialize(StringUnserializer)?.into())),
             "Url" => Ok(InputFile::Url(value.serialize(StringUnserializer)?)),
             "FileId" => Ok(InputFile::FileId(value.serialize(StringUnserializer)?)),
+            "Reopenable" => {
+                // The file is looked up by the serialized id
+                let value = serde_json::to_value(value).map_err(ser::Error::custom)?;
+                let file = serde_json::from_value(value).map_err(ser::Error::custom)?;
+                Ok(InputFile::Reopenable(file))
+            }
+            "WithMetadata" => {
+                let value = serde_json::to_value(value).map_err(ser::Error::custom)?;
+                let file = serde_json::from_value(value).map_err(ser::Error::custom)?;
+                Ok(InputFile::WithMetadata(file))
+            }
             name => Err(UnserializerError::UnexpectedVariant {
                 name,
-                expected: &["File", "Url", "FileId"], // TODO
+                expected: &["File", "Url", "FileId", "Reopenable", "WithMetadata"], // TODO
             }),
         }
     }
@@ -0,0 +1,121 @@
+//! Helpers shared by tests.
+
+use std::{future::Future, sync::Arc};
+
+use futures::{future::BoxFuture, FutureExt};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::{
+    requests::{HasPayload, Payload, Request},
+    RequestError,
+};
+
+/// Returns a single-threaded runtime with IO and time drivers enabled.
+pub(crate) fn rt() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+type Respond<P> =
+    dyn Fn(&P) -> BoxFuture<'static, Result<<P as Payload>::Output, RequestError>> + Send + Sync;
+
+/// A request which responds with the result of a callback, which is called
+/// every time the request is sent.
+pub(crate) struct MockRequest<P: Payload> {
+    payload: P,
+    respond: Arc<Respond<P>>,
+}
+
+impl<P: Payload> MockRequest<P> {
+    pub(crate) fn new<F, Fut>(payload: P, respond: F) -> Self
+    where
+        F: Fn(&P) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<P::Output, RequestError>> + Send + 'static,
+    {
+        Self {
+            payload,
+            respond: Arc::new(move |payload| respond(payload).boxed()),
+        }
+    }
+}
+
+impl<P: Payload> HasPayload for MockRequest<P> {
+    type Payload = P;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        &mut self.payload
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        &self.payload
+    }
+}
+
+impl<P> Request for MockRequest<P>
+where
+    P: Payload + Send + Sync,
+{
+    type Err = RequestError;
+    type Send = BoxFuture<'static, Result<P::Output, RequestError>>;
+    type SendRef = Self::Send;
+
+    fn send(self) -> Self::Send {
+        self.send_ref()
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        (self.respond)(&self.payload)
+    }
+}
+
+/// Reads a request with `Content-Length` from `conn` and returns the value of
+/// `Content-Length`.
+pub(crate) async fn read_request(conn: &mut TcpStream) -> usize {
+    read_body(conn).await.len()
+}
+
+/// Reads a request with `Content-Length` (or without a body) from `conn` and
+/// returns its body.
+pub(crate) async fn read_body(conn: &mut TcpStream) -> Vec<u8> {
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    loop {
+        let n = conn.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let head = String::from_utf8_lossy(&request);
+        if let Some(end) = head.find("\r\n\r\n") {
+            let len: usize = head
+                .lines()
+                .find_map(|l| {
+                    l.to_ascii_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let body = end + 4;
+            if request.len() >= body + len {
+                return request[body..body + len].to_vec();
+            }
+        }
+
+        assert_ne!(n, 0, "unexpected end of the request");
+    }
+}
+
+/// Writes a response with the JSON `body` to `conn`.
+pub(crate) async fn respond(conn: &mut TcpStream, body: &str) {
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    conn.write_all(response.as_bytes()).await.unwrap();
+}
@@ -1,15 +1,28 @@
 //! Telergam API types.
 
+pub use accent_color::*;
 pub use allowed_update::*;
 pub use animation::*;
 pub use audio::*;
+pub use background_fill::*;
+pub use background_type::*;
+pub use birthdate::*;
 pub use bot_command::*;
+pub use business_connection::*;
+pub use business_intro::*;
+pub use business_location::*;
+pub use business_messages_deleted::*;
+pub use business_opening_hours::*;
 pub use callback_game::*;
 pub use callback_query::*;
 pub use chat::*;
 pub use chat_action::*;
+pub use chat_administrator_rights::*;
+pub use chat_background::*;
+pub use chat_full_info::*;
 pub use chat_id::*;
 pub use chat_invite_link::*;
+pub use chat_join_request::*;
 pub use chat_location::*;
 pub use chat_member::*;
 pub use chat_member_updated::*;
@@ -18,6 +31,7 @@ pub use chat_photo::*;
 pub use chat_type::*;
 pub use chosen_inline_result::*;
 pub use contact::*;
+pub use coordinates::*;
 pub use dice::*;
 pub use dice_emoji::*;
 pub use document::*;
@@ -25,8 +39,12 @@ pub use encrypted_credentials::*;
 pub use encrypted_passport_element::*;
 pub use file::*;
 pub use force_reply::*;
+pub use forum_topic::*;
+pub use forum_topic_icon_color::*;
 pub use game::*;
 pub use game_high_score::*;
+pub use has_file::*;
+pub use inaccessible_message::*;
 pub use inline_keyboard_button::*;
 pub use inline_keyboard_markup::*;
 pub use inline_query::*;
@@ -51,6 +69,7 @@ pub use inline_query_result_photo::*;
 pub use inline_query_result_venue::*;
 pub use inline_query_result_video::*;
 pub use inline_query_result_voice::*;
+pub use inline_query_results_button::*;
 pub use input_file::*;
 pub use input_media::*;
 pub use input_message_content::*;
@@ -59,14 +78,19 @@ pub use invoice::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
 pub use label_price::*;
+pub use language_code::*;
 pub use location::*;
 pub use login_url::*;
 pub use mask_position::*;
+pub use maybe_inaccessible_message::*;
 pub use me::*;
 pub use message::*;
 pub use message_auto_delete_timer_changed::*;
 pub use message_entity::*;
+pub use message_entity_ref::*;
 pub use message_id::*;
+pub use message_reaction_count_updated::*;
+pub use message_reaction_updated::*;
 pub use order_info::*;
 pub use parse_mode::*;
 pub use passport_data::*;
@@ -78,6 +102,9 @@ pub use poll_answer::*;
 pub use poll_type::*;
 pub use pre_checkout_query::*;
 pub use proximity_alert_triggered::*;
+pub use query_answer::*;
+pub use reaction_count::*;
+pub use reaction_type::*;
 pub use reply_keyboard_markup::*;
 pub use reply_keyboard_remove::*;
 pub use reply_markup::*;
@@ -86,9 +113,13 @@ pub use shipping_address::*;
 pub use shipping_option::*;
 pub use shipping_query::*;
 pub use sticker::*;
+pub use sticker_format::*;
 pub use sticker_set::*;
+pub use story::*;
 pub use successful_payment::*;
+pub use switch_inline_query_chosen_chat::*;
 pub use target_message::*;
+pub use thread_id::*;
 pub use unit_false::*;
 pub use unit_true::*;
 pub use update::*;
@@ -96,24 +127,38 @@ pub use user::*;
 pub use user_profile_photos::*;
 pub use venue::*;
 pub use video::*;
+pub use video_chat_ended::*;
+pub use video_chat_participants_invited::*;
+pub use video_chat_scheduled::*;
+pub use video_chat_started::*;
 pub use video_note::*;
 pub use voice::*;
-pub use voice_chat_ended::*;
-pub use voice_chat_participants_invited::*;
-pub use voice_chat_scheduled::*;
-pub use voice_chat_started::*;
+pub use web_app_info::*;
 pub use webhook_info::*;
 
+mod accent_color;
 mod allowed_update;
 mod animation;
 mod audio;
+mod background_fill;
+mod background_type;
+mod birthdate;
 mod bot_command;
+mod business_connection;
+mod business_intro;
+mod business_location;
+mod business_messages_deleted;
+mod business_opening_hours;
 mod callback_game;
 mod callback_query;
 mod chat;
 mod chat_action;
+mod chat_administrator_rights;
+mod chat_background;
+mod chat_full_info;
 mod chat_id;
 mod chat_invite_link;
+mod chat_join_request;
 mod chat_location;
 mod chat_member;
 mod chat_member_updated;
@@ -122,15 +167,21 @@ mod chat_photo;
 mod chat_type;
 mod chosen_inline_result;
 mod contact;
+mod coordinates;
 mod dice;
 mod dice_emoji;
 mod document;
 mod file;
 mod force_reply;
+mod forum_topic;
+mod forum_topic_icon_color;
 mod game;
 mod game_high_score;
+mod has_file;
+mod inaccessible_message;
 mod inline_keyboard_button;
 mod inline_keyboard_markup;
+mod inline_query_results_button;
 mod input_file;
 mod input_media;
 mod input_message_content;
@@ -139,14 +190,19 @@ mod invoice;
 mod keyboard_button;
 mod keyboard_button_poll_type;
 mod label_price;
+mod language_code;
 mod location;
 mod login_url;
 mod mask_position;
+mod maybe_inaccessible_message;
 mod me;
 mod message;
 mod message_auto_delete_timer_changed;
 mod message_entity;
+mod message_entity_ref;
 mod message_id;
+mod message_reaction_count_updated;
+mod message_reaction_updated;
 mod order_info;
 mod parse_mode;
 mod photo_size;
@@ -155,6 +211,9 @@ mod poll_answer;
 mod poll_type;
 mod pre_checkout_query;
 mod proximity_alert_triggered;
+mod query_answer;
+mod reaction_count;
+mod reaction_type;
 mod reply_keyboard_markup;
 mod reply_keyboard_remove;
 mod reply_markup;
@@ -163,9 +222,13 @@ mod shipping_address;
 mod shipping_option;
 mod shipping_query;
 mod sticker;
+mod sticker_format;
 mod sticker_set;
+mod story;
 mod successful_payment;
+mod switch_inline_query_chosen_chat;
 mod target_message;
+mod thread_id;
 mod unit_false;
 mod unit_true;
 mod update;
@@ -173,12 +236,13 @@ mod user;
 mod user_profile_photos;
 mod venue;
 mod video;
+mod video_chat_ended;
+mod video_chat_participants_invited;
+mod video_chat_scheduled;
+mod video_chat_started;
 mod video_note;
 mod voice;
-mod voice_chat_ended;
-mod voice_chat_participants_invited;
-mod voice_chat_scheduled;
-mod voice_chat_started;
+mod web_app_info;
 mod webhook_info;
 
 mod inline_query;
@@ -210,10 +274,13 @@ mod passport_data;
 mod passport_element_error;
 mod passport_file;
 
+pub mod non_strict;
+
 pub use non_telegram_types::{country_code::*, currency::*, semiparsed_vec::*};
 mod non_telegram_types {
     pub(super) mod country_code;
     pub(super) mod currency;
+    pub(crate) mod duration;
     pub(crate) mod mime;
     pub(super) mod semiparsed_vec;
 }
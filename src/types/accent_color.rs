@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+
+/// An accent color of a chat or a user, used for the name and backgrounds of
+/// the chat photo, reply header, and link preview.
+///
+/// Telegram currently defines 7 base colors (ids `0..=6`) and 14 multi-colored
+/// variants of them (ids `7..=20`), those are represented by [`Multi`]. Any
+/// other id is represented by [`Unknown`].
+///
+/// [`Multi`]: AccentColor::Multi
+/// [`Unknown`]: AccentColor::Unknown
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum AccentColor {
+    /// Red, id `0`.
+    Red,
+    /// Orange, id `1`.
+    Orange,
+    /// Violet (purple), id `2`.
+    Violet,
+    /// Green, id `3`.
+    Green,
+    /// Cyan, id `4`.
+    Cyan,
+    /// Blue, id `5`.
+    Blue,
+    /// Pink, id `6`.
+    Pink,
+    /// A multi-colored accent color with the given id.
+    Multi(u8),
+    /// An accent color with an id unknown to this library.
+    Unknown(u8),
+}
+
+impl AccentColor {
+    /// Returns the id of this accent color.
+    pub fn id(self) -> u8 {
+        match self {
+            Self::Red => 0,
+            Self::Orange => 1,
+            Self::Violet => 2,
+            Self::Green => 3,
+            Self::Cyan => 4,
+            Self::Blue => 5,
+            Self::Pink => 6,
+            Self::Multi(id) | Self::Unknown(id) => id,
+        }
+    }
+
+    /// Returns the base color of this accent color.
+    ///
+    /// Base colors are returned as is, multi-colored variants are mapped to
+    /// the base color they are derived from. Returns `None` for unknown
+    /// colors.
+    pub fn base(self) -> Option<Self> {
+        match self {
+            Self::Multi(id @ 7..=13) => Some(Self::from(id - 7)),
+            Self::Multi(id @ 14..=20) => Some(Self::from(id - 14)),
+            Self::Multi(_) | Self::Unknown(_) => None,
+            base => Some(base),
+        }
+    }
+
+    /// Returns RGB value of the [base] color, as used by official clients in
+    /// the light theme, in the `0xRRGGBB` format.
+    ///
+    /// Returns `None` for unknown colors.
+    ///
+    /// [base]: AccentColor::base
+    pub fn rgb(self) -> Option<u32> {
+        let rgb = match self.base()? {
+            Self::Red => 0xCC5049,
+            Self::Orange => 0xD67722,
+            Self::Violet => 0x955CDB,
+            Self::Green => 0x40A920,
+            Self::Cyan => 0x309EBA,
+            Self::Blue => 0x368AD1,
+            Self::Pink => 0xC7508B,
+            Self::Multi(_) | Self::Unknown(_) => return None,
+        };
+
+        Some(rgb)
+    }
+
+    /// Returns [RGB value] of this color split into `(r, g, b)` components.
+    ///
+    /// [RGB value]: AccentColor::rgb
+    pub fn rgb_components(self) -> Option<(u8, u8, u8)> {
+        self.rgb()
+            .map(|rgb| ((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+    }
+}
+
+impl From<u8> for AccentColor {
+    fn from(id: u8) -> Self {
+        match id {
+            0 => Self::Red,
+            1 => Self::Orange,
+            2 => Self::Violet,
+            3 => Self::Green,
+            4 => Self::Cyan,
+            5 => Self::Blue,
+            6 => Self::Pink,
+            7..=20 => Self::Multi(id),
+            _ => Self::Unknown(id),
+        }
+    }
+}
+
+impl From<AccentColor> for u8 {
+    fn from(color: AccentColor) -> Self {
+        color.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        for id in 0..=u8::MAX {
+            assert_eq!(AccentColor::from(id).id(), id);
+        }
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(AccentColor::Blue.rgb(), Some(0x368AD1));
+        assert_eq!(AccentColor::from(12).base(), Some(AccentColor::Blue));
+        assert_eq!(AccentColor::from(14).rgb(), AccentColor::Red.rgb());
+        assert_eq!(AccentColor::Unknown(42).rgb(), None);
+        assert_eq!(AccentColor::Red.rgb_components(), Some((0xCC, 0x50, 0x49)));
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(serde_json::to_string(&AccentColor::Cyan).unwrap(), "4");
+        assert_eq!(
+            serde_json::from_str::<AccentColor>("15").unwrap(),
+            AccentColor::Multi(15)
+        );
+    }
+}
@@ -16,4 +16,11 @@ pub enum AllowedUpdate {
     PollAnswer,
     MyChatMember,
     ChatMember,
+    ChatJoinRequest,
+    MessageReaction,
+    MessageReactionCount,
+    BusinessConnection,
+    BusinessMessage,
+    EditedBusinessMessage,
+    DeletedBusinessMessages,
 }
@@ -28,7 +28,8 @@ pub struct Animation {
     pub duration: u32,
 
     /// An animation thumbnail as defined by a sender.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 
     /// An original animation filename as defined by a sender.
     pub file_name: Option<String>,
@@ -39,6 +40,13 @@ pub struct Animation {
 
     /// A size of a file.
     pub file_size: Option<u32>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(test)]
@@ -69,7 +77,7 @@ mod tests {
             width: 320,
             height: 320,
             duration: 59,
-            thumb: Some(PhotoSize {
+            thumbnail: Some(PhotoSize {
                 file_id: "id".to_string(),
                 file_unique_id: "".to_string(),
                 width: 320,
@@ -79,6 +87,7 @@ mod tests {
             file_name: Some("some".to_string()),
             mime_type: Some("video/gif".parse().unwrap()),
             file_size: Some(6500),
+            extra: <_>::default(),
         };
         let actual = serde_json::from_str::<Animation>(json).unwrap();
         assert_eq!(actual, expected)
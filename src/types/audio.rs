@@ -38,7 +38,15 @@ pub struct Audio {
     pub file_size: Option<u32>,
 
     /// A thumbnail of the album cover to which the music file belongs.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(test)]
@@ -71,7 +79,7 @@ mod tests {
             title: Some("Title".to_string()),
             mime_type: Some("application/zip".parse().unwrap()),
             file_size: Some(123_456),
-            thumb: Some(PhotoSize {
+            thumbnail: Some(PhotoSize {
                 file_id: "id".to_string(),
                 file_unique_id: "".to_string(),
                 width: 320,
@@ -79,6 +87,7 @@ mod tests {
                 file_size: Some(3452),
             }),
             file_name: None,
+            extra: <_>::default(),
         };
         let actual = serde_json::from_str::<Audio>(json).unwrap();
         assert_eq!(actual, expected)
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// This object describes the way a background is filled based on the selected
+/// colors.
+///
+/// Colors are in the RGB24 format.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundfill).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundFill {
+    /// The background is filled using the selected color.
+    Solid {
+        /// The color of the background fill.
+        color: u32,
+    },
+
+    /// The background is a gradient fill.
+    Gradient {
+        /// Top color of the gradient.
+        top_color: u32,
+
+        /// Bottom color of the gradient.
+        bottom_color: u32,
+
+        /// Clockwise rotation angle of the background fill in degrees; 0-359.
+        rotation_angle: u16,
+    },
+
+    /// The background is a freeform gradient that rotates after every message
+    /// in the chat.
+    FreeformGradient {
+        /// A list of the 3 or 4 base colors that are used to generate the
+        /// freeform gradient.
+        colors: Vec<u32>,
+    },
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BackgroundFill, Document};
+
+/// This object describes the type of a background.
+///
+/// [The official docs](https://core.telegram.org/bots/api#backgroundtype).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum BackgroundType {
+    /// The background is automatically filled based on the selected colors.
+    Fill {
+        /// The background fill.
+        fill: BackgroundFill,
+
+        /// Dimming of the background in dark themes, as a percentage; 0-100.
+        dark_theme_dimming: u8,
+    },
+
+    /// The background is a wallpaper in the JPEG format.
+    Wallpaper {
+        /// Document with the wallpaper.
+        document: Document,
+
+        /// Dimming of the background in dark themes, as a percentage; 0-100.
+        dark_theme_dimming: u8,
+
+        /// `true`, if the wallpaper is downscaled to fit in a 450x450 square
+        /// and then box-blurred with radius 12.
+        #[serde(default)]
+        is_blurred: bool,
+
+        /// `true`, if the background moves slightly when the device is tilted.
+        #[serde(default)]
+        is_moving: bool,
+    },
+
+    /// The background is a PNG or TGV (gzipped subset of SVG with MIME type
+    /// `application/x-tgwallpattern`) pattern to be combined with the
+    /// background fill chosen by the user.
+    Pattern {
+        /// Document with the pattern.
+        document: Document,
+
+        /// The background fill that is combined with the pattern.
+        fill: BackgroundFill,
+
+        /// Intensity of the pattern when it is shown above the filled
+        /// background; 0-100.
+        intensity: u8,
+
+        /// `true`, if the background fill must be applied only to the pattern
+        /// itself. All other pixels are black in this case. For dark themes
+        /// only.
+        #[serde(default)]
+        is_inverted: bool,
+
+        /// `true`, if the background moves slightly when the device is tilted.
+        #[serde(default)]
+        is_moving: bool,
+    },
+
+    /// The background is taken directly from a built-in chat theme.
+    ChatTheme {
+        /// Name of the chat theme, which is usually an emoji.
+        theme_name: String,
+    },
+}
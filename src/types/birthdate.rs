@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the birthdate of a user.
+///
+/// [The official docs](https://core.telegram.org/bots/api#birthdate).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Birthdate {
+    /// Day of the user's birth; 1-31.
+    pub day: u8,
+
+    /// Month of the user's birth; 1-12.
+    pub month: u8,
+
+    /// Year of the user's birth.
+    pub year: Option<u16>,
+}
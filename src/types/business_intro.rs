@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Sticker;
+
+/// Contains information about the start page settings of a Telegram Business
+/// account.
+///
+/// [The official docs](https://core.telegram.org/bots/api#businessintro).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessIntro {
+    /// Title text of the business intro.
+    pub title: Option<String>,
+
+    /// Message text of the business intro.
+    pub message: Option<String>,
+
+    /// Sticker of the business intro.
+    pub sticker: Option<Sticker>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Location;
+
+/// Contains information about the location of a Telegram Business account.
+///
+/// [The official docs](https://core.telegram.org/bots/api#businesslocation).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BusinessLocation {
+    /// Address of the business.
+    pub address: String,
+
+    /// Location of the business.
+    pub location: Option<Location>,
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes the opening hours of a business.
+///
+/// [The official docs](https://core.telegram.org/bots/api#businessopeninghours).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BusinessOpeningHours {
+    /// Unique name of the time zone for which the opening hours are defined.
+    pub time_zone_name: String,
+
+    /// List of time intervals describing business opening hours.
+    pub opening_hours: Vec<BusinessOpeningHoursInterval>,
+}
+
+/// Describes an interval of time during which a business is open.
+///
+/// [The official docs](https://core.telegram.org/bots/api#businessopeninghoursinterval).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BusinessOpeningHoursInterval {
+    /// The minute's sequence number in a week, starting on Monday, marking the
+    /// start of the time interval during which the business is open; 0 - 7 *
+    /// 24 * 60.
+    pub opening_minute: u16,
+
+    /// The minute's sequence number in a week, starting on Monday, marking the
+    /// end of the time interval during which the business is open; 0 - 8 * 24
+    /// * 60.
+    pub closing_minute: u16,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Message, User};
+use crate::types::{MaybeInaccessibleMessage, User};
 
 /// This object represents an incoming callback query from a callback button in
 /// an [inline keyboard].
@@ -25,9 +25,10 @@ pub struct CallbackQuery {
     pub from: User,
 
     /// A message with the callback button that originated the query. Note that
-    /// message content and message date will not be available if the message
-    /// is too old.
-    pub message: Option<Message>,
+    /// the message will be [inaccessible] if it is too old.
+    ///
+    /// [inaccessible]: MaybeInaccessibleMessage::Inaccessible
+    pub message: Option<MaybeInaccessibleMessage>,
 
     /// An identifier of the message sent via the bot in inline mode, that
     /// originated the query.
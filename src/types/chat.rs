@@ -1,10 +1,19 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{ChatLocation, ChatPermissions, ChatPhoto, Message};
+use crate::{
+    types::{ChatLocation, ChatPermissions},
+    utils::{html, markdown},
+};
 
 /// This object represents a chat.
 ///
+/// Full information about a chat is returned by [`GetChat`] as
+/// [`ChatFullInfo`].
+///
 /// [The official docs](https://core.telegram.org/bots/api#chat).
+///
+/// [`GetChat`]: crate::payloads::GetChat
+/// [`ChatFullInfo`]: crate::types::ChatFullInfo
 #[serde_with_macros::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Chat {
@@ -17,23 +26,6 @@ pub struct Chat {
 
     #[serde(flatten)]
     pub kind: ChatKind,
-
-    /// A chat photo. Returned only in [`GetChat`].
-    ///
-    /// [`GetChat`]: crate::payloads::GetChat
-    pub photo: Option<ChatPhoto>,
-
-    /// The most recent pinned message (by sending date). Returned only in
-    /// [`GetChat`].
-    ///
-    /// [`GetChat`]: crate::payloads::GetChat
-    pub pinned_message: Option<Box<Message>>,
-
-    /// The time after which all messages sent to the chat will be automatically
-    /// deleted; in seconds. Returned only in [`GetChat`].
-    ///
-    /// [`GetChat`]: crate::payloads::GetChat
-    pub message_auto_delete_time: Option<u32>,
 }
 
 #[serde_with_macros::skip_serializing_none]
@@ -398,6 +390,65 @@ impl Chat {
         }
     }
 
+    /// The first and the last (if any) names of the other party in a private
+    /// chat joined with a space.
+    pub fn full_name(&self) -> Option<String> {
+        let first_name = self.first_name()?;
+        Some(match self.last_name() {
+            Some(last_name) => format!("{} {}", first_name, last_name),
+            None => first_name.to_owned(),
+        })
+    }
+
+    /// Returns a name to display: the title of a group or a channel, the
+    /// [full name] of the other party in a private chat, falling back to
+    /// `@username` and then to the id of the chat.
+    ///
+    /// [full name]: Chat::full_name
+    pub fn preferred_name(&self) -> String {
+        self.title()
+            .map(ToOwned::to_owned)
+            .or_else(|| self.full_name())
+            .filter(|name| !name.trim().is_empty())
+            .or_else(|| self.username().map(|username| format!("@{}", username)))
+            .unwrap_or_else(|| self.id.to_string())
+    }
+
+    /// Returns a link to the chat for [`ParseMode::Html`], which is displayed
+    /// as the [preferred name].
+    ///
+    /// Private chats are linked as inline mentions, public chats with a
+    /// username as `t.me` links. Returns `None` for other chats, which can't
+    /// be linked.
+    ///
+    /// [`ParseMode::Html`]: crate::types::ParseMode::Html
+    /// [preferred name]: Chat::preferred_name
+    pub fn mention_html(&self) -> Option<String> {
+        let name = self.preferred_name();
+        match (self.is_private(), self.username()) {
+            (true, _) => Some(html::user_mention(self.id, &name)),
+            (false, Some(username)) => {
+                Some(html::link(&format!("https://t.me/{}", username), &name))
+            }
+            (false, None) => None,
+        }
+    }
+
+    /// Returns a link to the chat for [`ParseMode::MarkdownV2`], see
+    /// [`Chat::mention_html`].
+    ///
+    /// [`ParseMode::MarkdownV2`]: crate::types::ParseMode::MarkdownV2
+    pub fn mention_markdown(&self) -> Option<String> {
+        let name = self.preferred_name();
+        match (self.is_private(), self.username()) {
+            (true, _) => Some(markdown::user_mention(self.id, &name)),
+            (false, Some(username)) => {
+                Some(markdown::link(&format!("https://t.me/{}", username), &name))
+            }
+            (false, None) => None,
+        }
+    }
+
     /// Bio of the other party in a private chat. Returned only in [`GetChat`].
     ///
     /// [`GetChat`]: crate::payloads::GetChat
@@ -415,6 +466,30 @@ mod tests {
 
     use crate::types::*;
 
+    #[test]
+    fn names() {
+        let chat: Chat = from_str(
+            r#"{"id":1,"type":"private","first_name":"A","last_name":"B","username":"ab"}"#,
+        )
+        .unwrap();
+        assert_eq!(chat.preferred_name(), "A B");
+        assert_eq!(
+            chat.mention_html().unwrap(),
+            "<a href=\"tg://user?id=1\">A B</a>"
+        );
+
+        let chat: Chat =
+            from_str(r#"{"id":-1,"type":"channel","title":"News","username":"news"}"#).unwrap();
+        assert_eq!(chat.preferred_name(), "News");
+        assert_eq!(
+            chat.mention_markdown().unwrap(),
+            "[News](https://t.me/news)"
+        );
+
+        let chat: Chat = from_str(r#"{"id":-2,"type":"group","title":"G"}"#).unwrap();
+        assert_eq!(chat.mention_html(), None);
+    }
+
     #[test]
     fn channel_de() {
         let expected = Chat {
@@ -428,9 +503,6 @@ mod tests {
                 description: None,
                 invite_link: None,
             }),
-            photo: None,
-            pinned_message: None,
-            message_auto_delete_time: None,
         };
         let actual = from_str(r#"{"id":-1,"type":"channel","username":"channelname"}"#).unwrap();
         assert_eq!(expected, actual);
@@ -448,9 +520,6 @@ mod tests {
                     last_name: None,
                     bio: None,
                 }),
-                photo: None,
-                pinned_message: None,
-                message_auto_delete_time: None,
             },
             from_str(r#"{"id":0,"type":"private","username":"username","first_name":"Anon"}"#)
                 .unwrap()
@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    payloads::PromoteChatMember,
+    types::{Administrator, ChatMemberKind},
+};
+
+/// Represents the rights of an administrator in a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatadministratorrights).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ChatAdministratorRights {
+    /// `true` if the user's presence in the chat is hidden.
+    pub is_anonymous: bool,
+
+    /// `true` if the administrator can access the chat event log, chat
+    /// statistics, message statistics in channels, see channel members, see
+    /// anonymous administrators in supergroups and ignore slow mode. Implied by
+    /// any other administrator privilege.
+    pub can_manage_chat: bool,
+
+    /// `true` if the administrator can change the chat title, photo and other
+    /// settings.
+    pub can_change_info: bool,
+
+    /// `true` if the administrator can post in the channel, channels only.
+    #[serde(default)]
+    pub can_post_messages: bool,
+
+    /// `true` if the administrator can edit messages of other users and can pin
+    /// messages, channels only.
+    #[serde(default)]
+    pub can_edit_messages: bool,
+
+    /// `true` if the administrator can delete messages of other users.
+    pub can_delete_messages: bool,
+
+    /// `true` if the administrator can manage voice chats.
+    pub can_manage_voice_chats: bool,
+
+    /// `true` if the administrator can invite new users to the chat.
+    pub can_invite_users: bool,
+
+    /// `true` if the administrator can restrict, ban or unban chat members.
+    pub can_restrict_members: bool,
+
+    /// `true` if the administrator can pin messages, supergroups only.
+    #[serde(default)]
+    pub can_pin_messages: bool,
+
+    /// `true` if the administrator can add new administrators with a subset of
+    /// their own privileges or demote administrators that they have promoted,
+    /// directly or indirectly.
+    pub can_promote_members: bool,
+}
+
+impl ChatAdministratorRights {
+    /// Returns rights with all privileges set, i.e. rights of the chat
+    /// creator.
+    ///
+    /// Note that [`is_anonymous`] is not a privilege and is left unset.
+    ///
+    /// [`is_anonymous`]: ChatAdministratorRights::is_anonymous
+    pub const fn all() -> Self {
+        Self {
+            is_anonymous: false,
+            can_manage_chat: true,
+            can_change_info: true,
+            can_post_messages: true,
+            can_edit_messages: true,
+            can_delete_messages: true,
+            can_manage_voice_chats: true,
+            can_invite_users: true,
+            can_restrict_members: true,
+            can_pin_messages: true,
+            can_promote_members: true,
+        }
+    }
+
+    /// Returns `true` if no privileges are set (and the user is not
+    /// anonymous).
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns rights which are set in `self`, but not in `other`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::ChatAdministratorRights;
+    ///
+    /// let desired = ChatAdministratorRights {
+    ///     can_delete_messages: true,
+    ///     can_pin_messages: true,
+    ///     ..<_>::default()
+    /// };
+    /// let current = ChatAdministratorRights {
+    ///     can_pin_messages: true,
+    ///     can_invite_users: true,
+    ///     ..<_>::default()
+    /// };
+    ///
+    /// // Rights which need to be granted
+    /// assert_eq!(
+    ///     desired.difference(&current),
+    ///     ChatAdministratorRights {
+    ///         can_delete_messages: true,
+    ///         ..<_>::default()
+    ///     }
+    /// );
+    ///
+    /// // Rights which need to be revoked
+    /// assert_eq!(
+    ///     current.difference(&desired),
+    ///     ChatAdministratorRights {
+    ///         can_invite_users: true,
+    ///         ..<_>::default()
+    ///     }
+    /// );
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            is_anonymous: self.is_anonymous && !other.is_anonymous,
+            can_manage_chat: self.can_manage_chat && !other.can_manage_chat,
+            can_change_info: self.can_change_info && !other.can_change_info,
+            can_post_messages: self.can_post_messages && !other.can_post_messages,
+            can_edit_messages: self.can_edit_messages && !other.can_edit_messages,
+            can_delete_messages: self.can_delete_messages && !other.can_delete_messages,
+            can_manage_voice_chats: self.can_manage_voice_chats && !other.can_manage_voice_chats,
+            can_invite_users: self.can_invite_users && !other.can_invite_users,
+            can_restrict_members: self.can_restrict_members && !other.can_restrict_members,
+            can_pin_messages: self.can_pin_messages && !other.can_pin_messages,
+            can_promote_members: self.can_promote_members && !other.can_promote_members,
+        }
+    }
+
+    /// Sets all the rights in the `payload`, so that after the request the
+    /// member has exactly these rights.
+    pub fn apply(&self, payload: &mut PromoteChatMember) {
+        payload.is_anonymous = Some(self.is_anonymous);
+        payload.can_manage_chat = Some(self.can_manage_chat);
+        payload.can_change_info = Some(self.can_change_info);
+        payload.can_post_messages = Some(self.can_post_messages);
+        payload.can_edit_messages = Some(self.can_edit_messages);
+        payload.can_delete_messages = Some(self.can_delete_messages);
+        payload.can_manage_voice_chats = Some(self.can_manage_voice_chats);
+        payload.can_invite_users = Some(self.can_invite_users);
+        payload.can_restrict_members = Some(self.can_restrict_members);
+        payload.can_pin_messages = Some(self.can_pin_messages);
+        payload.can_promote_members = Some(self.can_promote_members);
+    }
+}
+
+impl From<&Administrator> for ChatAdministratorRights {
+    fn from(admin: &Administrator) -> Self {
+        Self {
+            is_anonymous: admin.is_anonymous,
+            can_manage_chat: admin.can_manage_chat,
+            can_change_info: admin.can_change_info,
+            can_post_messages: admin.can_post_messages.unwrap_or(false),
+            can_edit_messages: admin.can_edit_messages.unwrap_or(false),
+            can_delete_messages: admin.can_delete_messages,
+            can_manage_voice_chats: admin.can_manage_voice_chats,
+            can_invite_users: admin.can_invite_users,
+            can_restrict_members: admin.can_restrict_members,
+            can_pin_messages: admin.can_pin_messages.unwrap_or(false),
+            can_promote_members: admin.can_promote_members,
+        }
+    }
+}
+
+impl ChatMemberKind {
+    /// Returns administrator rights of the member.
+    ///
+    /// For the creator of the chat all privileges are set (see
+    /// [`ChatAdministratorRights::all`]), for non-administrators no
+    /// privileges are set.
+    pub fn administrator_rights(&self) -> ChatAdministratorRights {
+        match self {
+            Self::Creator(creator) => ChatAdministratorRights {
+                is_anonymous: creator.is_anonymous,
+                ..ChatAdministratorRights::all()
+            },
+            Self::Administrator(admin) => admin.into(),
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => {
+                ChatAdministratorRights::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn difference() {
+        let a = ChatAdministratorRights {
+            can_manage_chat: true,
+            can_pin_messages: true,
+            ..<_>::default()
+        };
+        let b = ChatAdministratorRights {
+            can_manage_chat: true,
+            can_invite_users: true,
+            ..<_>::default()
+        };
+
+        assert_eq!(
+            a.difference(&b),
+            ChatAdministratorRights {
+                can_pin_messages: true,
+                ..<_>::default()
+            }
+        );
+        assert!(a.difference(&a).is_empty());
+        assert!(a.difference(&ChatAdministratorRights::all()).is_empty());
+    }
+
+    #[test]
+    fn member_rights() {
+        assert!(ChatMemberKind::Member.administrator_rights().is_empty());
+        assert!(ChatMemberKind::Left.administrator_rights().is_empty());
+    }
+
+    #[test]
+    fn apply() {
+        let rights = ChatAdministratorRights {
+            can_delete_messages: true,
+            ..<_>::default()
+        };
+        let mut payload = PromoteChatMember::new(1, 2);
+        rights.apply(&mut payload);
+
+        assert_eq!(payload.can_delete_messages, Some(true));
+        assert_eq!(payload.can_promote_members, Some(false));
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::BackgroundType;
+
+/// This object represents a chat background.
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatbackground).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatBackground {
+    /// Type of the background.
+    #[serde(rename = "type")]
+    pub kind: BackgroundType,
+}
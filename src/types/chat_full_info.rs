@@ -0,0 +1,286 @@
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    AccentColor, Birthdate, BusinessIntro, BusinessLocation, BusinessOpeningHours, Chat, ChatPhoto,
+    Message, ReactionType,
+};
+
+/// This object contains full information about a chat.
+///
+/// Returned only in [`GetChat`], while the lighter [`Chat`] is used
+/// everywhere else (e.g. in [`Message`]s). Basic information (and fields that
+/// depend on the kind of the chat) are available via [`ChatFullInfo::chat`] or
+/// through [`Deref`].
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatfullinfo).
+///
+/// [`GetChat`]: crate::payloads::GetChat
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatFullInfo {
+    /// Basic information about the chat, the same as in [`Chat`].
+    #[serde(flatten)]
+    pub chat: Chat,
+
+    /// A chat photo.
+    pub photo: Option<ChatPhoto>,
+
+    /// If non-empty, the list of all [active chat usernames]; for private
+    /// chats, supergroups and channels.
+    ///
+    /// [active chat usernames]: https://telegram.org/blog/topics-in-groups-collectible-usernames#collectible-usernames
+    pub active_usernames: Option<Vec<String>>,
+
+    /// For private chats, the date of birth of the user.
+    pub birthdate: Option<Birthdate>,
+
+    /// For private chats with business accounts, the intro of the business.
+    pub business_intro: Option<BusinessIntro>,
+
+    /// For private chats with business accounts, the location of the
+    /// business.
+    pub business_location: Option<BusinessLocation>,
+
+    /// For private chats with business accounts, the opening hours of the
+    /// business.
+    pub business_opening_hours: Option<BusinessOpeningHours>,
+
+    /// For private chats, the personal channel of the user.
+    pub personal_chat: Option<Box<Chat>>,
+
+    /// Identifier of the accent color for the chat name and backgrounds of
+    /// the chat photo, reply header, and link preview. See also
+    /// [`ChatFullInfo::accent_color`].
+    pub accent_color_id: u8,
+
+    /// List of available reactions allowed in the chat. If omitted, then all
+    /// [emoji reactions] are allowed. See also [`ChatFullInfo::allows_reaction`].
+    ///
+    /// [emoji reactions]: ReactionType::Emoji
+    pub available_reactions: Option<Vec<ReactionType>>,
+
+    /// The maximum number of reactions that can be set on a message in the
+    /// chat.
+    pub max_reaction_count: u32,
+
+    /// Custom emoji identifier of the emoji chosen by the chat for the reply
+    /// header and link preview background.
+    pub background_custom_emoji_id: Option<String>,
+
+    /// Identifier of the accent color for the chat's profile background.
+    pub profile_accent_color_id: Option<u8>,
+
+    /// Custom emoji identifier of the emoji chosen by the chat for its profile
+    /// background.
+    pub profile_background_custom_emoji_id: Option<String>,
+
+    /// Custom emoji identifier of the emoji status of the chat or the other
+    /// party in a private chat.
+    pub emoji_status_custom_emoji_id: Option<String>,
+
+    /// Expiration date of the emoji status of the chat or the other party in a
+    /// private chat, in Unix time, if any.
+    pub emoji_status_expiration_date: Option<i64>,
+
+    /// `true`, if privacy settings of the other party in the private chat
+    /// allows to use `tg://user?id=<user_id>` links only in chats with the
+    /// user.
+    #[serde(default)]
+    pub has_private_forwards: bool,
+
+    /// `true`, if the privacy settings of the other party restrict sending
+    /// voice and video note messages in the private chat.
+    #[serde(default)]
+    pub has_restricted_voice_and_video_messages: bool,
+
+    /// `true`, if users need to join the supergroup before they can send
+    /// messages.
+    #[serde(default)]
+    pub join_to_send_messages: bool,
+
+    /// `true`, if all users directly joining the supergroup need to be
+    /// approved by supergroup administrators.
+    #[serde(default)]
+    pub join_by_request: bool,
+
+    /// The most recent pinned message (by sending date).
+    pub pinned_message: Option<Box<Message>>,
+
+    /// For supergroups, the minimum number of boosts that a non-administrator
+    /// user needs to add in order to ignore slow mode and chat permissions.
+    pub unrestrict_boost_count: Option<u32>,
+
+    /// The time after which all messages sent to the chat will be automatically
+    /// deleted; in seconds.
+    pub message_auto_delete_time: Option<u32>,
+
+    /// `true`, if aggressive anti-spam checks are enabled in the supergroup.
+    /// The field is only available to chat administrators.
+    #[serde(default)]
+    pub has_aggressive_anti_spam_enabled: bool,
+
+    /// `true`, if non-administrators can only get the list of bots and
+    /// administrators in the chat.
+    #[serde(default)]
+    pub has_hidden_members: bool,
+
+    /// `true`, if messages from the chat can't be forwarded to other chats.
+    #[serde(default)]
+    pub has_protected_content: bool,
+
+    /// `true`, if new chat members will have access to old messages; available
+    /// only to chat administrators.
+    #[serde(default)]
+    pub has_visible_history: bool,
+
+    /// For supergroups, the name of the group's custom emoji sticker set.
+    /// Custom emoji from this set can be used by all users and bots in the
+    /// group.
+    pub custom_emoji_sticker_set_name: Option<String>,
+}
+
+impl ChatFullInfo {
+    /// Accent color for the chat name and backgrounds of the chat photo, reply
+    /// header, and link preview.
+    pub fn accent_color(&self) -> AccentColor {
+        AccentColor::from(self.accent_color_id)
+    }
+
+    /// `true`, if the `reaction` is allowed in the chat, see
+    /// [`ChatFullInfo::available_reactions`].
+    pub fn allows_reaction(&self, reaction: &ReactionType) -> bool {
+        match &self.available_reactions {
+            Some(available) => available.contains(reaction),
+            None => matches!(reaction, ReactionType::Emoji { .. }),
+        }
+    }
+
+    /// `true`, if the `reactions` can be set on a message in the chat, i.e. if
+    /// all of them are [allowed] and there are no more than
+    /// [`max_reaction_count`] of them.
+    ///
+    /// This allows to validate reactions before calling [`SetMessageReaction`].
+    ///
+    /// [allowed]: ChatFullInfo::allows_reaction
+    /// [`max_reaction_count`]: ChatFullInfo::max_reaction_count
+    /// [`SetMessageReaction`]: crate::payloads::SetMessageReaction
+    pub fn allows_reactions(&self, reactions: &[ReactionType]) -> bool {
+        reactions.len() <= self.max_reaction_count as usize
+            && reactions
+                .iter()
+                .all(|reaction| self.allows_reaction(reaction))
+    }
+
+    /// `true`, if the chat (or the other party in a private chat) has an emoji
+    /// status which hasn't expired by `now` (Unix time).
+    pub fn has_emoji_status(&self, now: i64) -> bool {
+        self.emoji_status_custom_emoji_id.is_some()
+            && !matches!(self.emoji_status_expiration_date, Some(exp) if exp <= now)
+    }
+}
+
+impl Deref for ChatFullInfo {
+    type Target = Chat;
+
+    fn deref(&self) -> &Self::Target {
+        &self.chat
+    }
+}
+
+impl From<ChatFullInfo> for Chat {
+    fn from(info: ChatFullInfo) -> Self {
+        info.chat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_de() {
+        let json = r#"{
+            "id": 0,
+            "type": "private",
+            "first_name": "Anon",
+            "bio": "bio",
+            "birthdate": { "day": 1, "month": 4 },
+            "business_location": { "address": "Somewhere" },
+            "accent_color_id": 3,
+            "max_reaction_count": 11,
+            "has_private_forwards": true
+        }"#;
+        let info = serde_json::from_str::<ChatFullInfo>(json).unwrap();
+
+        assert!(info.is_private());
+        assert_eq!(info.first_name(), Some("Anon"));
+        assert_eq!(info.bio(), Some("bio"));
+        assert_eq!(
+            info.birthdate,
+            Some(Birthdate {
+                day: 1,
+                month: 4,
+                year: None
+            })
+        );
+        assert_eq!(info.business_location.unwrap().address, "Somewhere");
+        assert_eq!(info.accent_color_id, 3);
+        assert!(info.has_private_forwards);
+        assert!(!info.has_protected_content);
+    }
+
+    #[test]
+    fn accent_color_and_emoji_status() {
+        let json = r#"{
+            "id": 0,
+            "type": "private",
+            "first_name": "Anon",
+            "accent_color_id": 3,
+            "max_reaction_count": 11,
+            "emoji_status_custom_emoji_id": "1",
+            "emoji_status_expiration_date": 100
+        }"#;
+        let mut info = serde_json::from_str::<ChatFullInfo>(json).unwrap();
+
+        assert_eq!(info.accent_color(), AccentColor::Green);
+        assert!(info.has_emoji_status(99));
+        assert!(!info.has_emoji_status(100));
+
+        info.emoji_status_expiration_date = None;
+        assert!(info.has_emoji_status(100));
+        info.emoji_status_custom_emoji_id = None;
+        assert!(!info.has_emoji_status(0));
+    }
+
+    #[test]
+    fn reactions() {
+        let json = r#"{
+            "id": -1,
+            "type": "supergroup",
+            "title": "Group",
+            "accent_color_id": 0,
+            "available_reactions": [
+                { "type": "emoji", "emoji": "👍" },
+                { "type": "custom_emoji", "custom_emoji_id": "1" }
+            ],
+            "max_reaction_count": 1
+        }"#;
+        let mut info = serde_json::from_str::<ChatFullInfo>(json).unwrap();
+        let like = ReactionType::emoji("👍");
+        let custom = ReactionType::CustomEmoji {
+            custom_emoji_id: "1".to_owned(),
+        };
+
+        assert!(info.allows_reaction(&like));
+        assert!(info.allows_reaction(&custom));
+        assert!(!info.allows_reaction(&ReactionType::emoji("👎")));
+        assert!(info.allows_reactions(std::slice::from_ref(&custom)));
+        assert!(!info.allows_reactions(&[like.clone(), custom.clone()]));
+
+        info.available_reactions = None;
+        assert!(info.allows_reaction(&ReactionType::emoji("👎")));
+        assert!(!info.allows_reaction(&custom));
+    }
+}
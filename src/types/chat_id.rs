@@ -1,6 +1,8 @@
 use derive_more::{Display, From};
 use serde::{Deserialize, Serialize};
 
+use crate::errors::{InvalidChatUrl, InvalidUsername};
+
 /// A unique identifier for the target chat or username of the target channel
 /// (in the format `@channelusername`).
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Display, From)]
@@ -15,10 +17,255 @@ pub enum ChatId {
     ChannelUsername(String),
 }
 
+impl ChatId {
+    /// Creates [`ChatId::ChannelUsername`] from a username, with or without
+    /// the leading `@`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{types::ChatId, InvalidUsername};
+    ///
+    /// assert_eq!(
+    ///     ChatId::channel_username("rustlang"),
+    ///     Ok(ChatId::ChannelUsername("@rustlang".to_owned()))
+    /// );
+    /// assert_eq!(ChatId::channel_username("@rust"), Err(InvalidUsername::InvalidLength(4)));
+    /// ```
+    pub fn channel_username(username: &str) -> Result<Self, InvalidUsername> {
+        let username = username.strip_prefix('@').unwrap_or(username);
+        Self::validate_username(username)?;
+
+        Ok(Self::ChannelUsername(format!("@{}", username)))
+    }
+
+    /// Checks that the `username` (without `@`) is 5-32 characters long,
+    /// consists of letters, digits and underscores, starts with a letter and
+    /// doesn't end with an underscore.
+    pub fn validate_username(username: &str) -> Result<(), InvalidUsername> {
+        let len = username.chars().count();
+        if !(5..=32).contains(&len) {
+            return Err(InvalidUsername::InvalidLength(len));
+        }
+
+        if !username
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        {
+            return Err(InvalidUsername::InvalidCharacter);
+        }
+
+        if !username.as_bytes()[0].is_ascii_alphabetic() {
+            return Err(InvalidUsername::InvalidStart);
+        }
+
+        if username.ends_with('_') {
+            return Err(InvalidUsername::TrailingUnderscore);
+        }
+
+        Ok(())
+    }
+
+    /// Parses a link to a chat, e.g. `https://t.me/username`.
+    ///
+    /// The following links are supported (the scheme is optional and
+    /// `telegram.me` or `telegram.dog` can be used instead of `t.me`):
+    ///
+    /// - `https://t.me/<username>` and links to messages or channel previews,
+    ///   i.e. `https://t.me/<username>/<message id>` and
+    ///   `https://t.me/s/<username>`;
+    /// - links to messages in private chats, `https://t.me/c/<chat id>/<message
+    ///   id>`, either with the chat id used in the link or with the full id
+    ///   (as produced by [`Message::url`]);
+    /// - `tg://resolve?domain=<username>`.
+    ///
+    /// Invite links are rejected with [`InvalidChatUrl::InviteLink`], other
+    /// `t.me` links which don't lead to a chat (e.g.
+    /// `https://t.me/addstickers/<name>` or `https://t.me/share/url`) with
+    /// [`InvalidChatUrl::NotChatLink`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{types::ChatId, InvalidChatUrl};
+    ///
+    /// assert_eq!(
+    ///     ChatId::from_url("https://t.me/rustlang/42"),
+    ///     Ok(ChatId::ChannelUsername("@rustlang".to_owned()))
+    /// );
+    /// assert_eq!(
+    ///     ChatId::from_url("t.me/c/1234567890/1"),
+    ///     Ok(ChatId::Id(-1001234567890))
+    /// );
+    /// assert_eq!(
+    ///     ChatId::from_url("https://t.me/+AbCdEf"),
+    ///     Err(InvalidChatUrl::InviteLink)
+    /// );
+    /// ```
+    ///
+    /// [`Message::url`]: crate::types::Message::url
+    pub fn from_url(url: &str) -> Result<Self, InvalidChatUrl> {
+        let url = url.trim();
+
+        if let Some(query) = url.strip_prefix("tg://resolve?") {
+            let domain = query
+                .split('&')
+                .find_map(|param| param.strip_prefix("domain="))
+                .ok_or(InvalidChatUrl::NotChatLink)?;
+            return Ok(Self::channel_username(domain)?);
+        }
+
+        let url = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .unwrap_or(url);
+        let url = url.split(&['?', '#'][..]).next().unwrap_or(url);
+
+        let mut segments = url.split('/');
+        let host = segments.next().unwrap_or("");
+        let host = host.strip_prefix("www.").unwrap_or(host);
+        if !["t.me", "telegram.me", "telegram.dog"].contains(&host) {
+            return Err(InvalidChatUrl::NotChatLink);
+        }
+
+        match segments.next() {
+            None | Some("") => Err(InvalidChatUrl::NotChatLink),
+            Some(path) if path.eq_ignore_ascii_case("joinchat") => Err(InvalidChatUrl::InviteLink),
+            Some(invite) if invite.starts_with('+') => Err(InvalidChatUrl::InviteLink),
+            Some(path) if RESERVED_PATHS.iter().any(|p| path.eq_ignore_ascii_case(p)) => {
+                Err(InvalidChatUrl::NotChatLink)
+            }
+            Some("c") => {
+                let id: i64 = segments
+                    .next()
+                    .and_then(|id| id.parse().ok())
+                    .ok_or(InvalidChatUrl::InvalidId)?;
+                match id {
+                    id if id < 0 => Ok(Self::Id(id)),
+                    id if id > 0 && id < 1_000_000_000_000 => Ok(Self::Id(-1_000_000_000_000 - id)),
+                    _ => Err(InvalidChatUrl::InvalidId),
+                }
+            }
+            Some("s") => {
+                let username = segments.next().ok_or(InvalidChatUrl::NotChatLink)?;
+                Ok(Self::channel_username(username)?)
+            }
+            Some(username) => Ok(Self::channel_username(username)?),
+        }
+    }
+}
+
+/// First segments of `t.me` links which are not usernames, e.g.
+/// `t.me/addstickers/<sticker set>`.
+const RESERVED_PATHS: &[&str] = &[
+    "addstickers",
+    "addemoji",
+    "share",
+    "proxy",
+    "socks",
+    "setlanguage",
+    "iv",
+    "boost",
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn username_validation() {
+        assert!(ChatId::validate_username("a_1_b").is_ok());
+        assert_eq!(
+            ChatId::validate_username(&"a".repeat(33)),
+            Err(InvalidUsername::InvalidLength(33))
+        );
+        assert_eq!(
+            ChatId::validate_username("user-name"),
+            Err(InvalidUsername::InvalidCharacter)
+        );
+        assert_eq!(
+            ChatId::validate_username("юзернейм"),
+            Err(InvalidUsername::InvalidCharacter)
+        );
+        assert_eq!(
+            ChatId::validate_username("1user"),
+            Err(InvalidUsername::InvalidStart)
+        );
+        assert_eq!(
+            ChatId::validate_username("user_"),
+            Err(InvalidUsername::TrailingUnderscore)
+        );
+        assert_eq!(
+            ChatId::channel_username("@@user"),
+            Err(InvalidUsername::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn from_url() {
+        let username = Ok(ChatId::ChannelUsername("@username".to_owned()));
+
+        for url in &[
+            "https://t.me/username",
+            "http://telegram.me/username/",
+            "t.me/username?start=1",
+            "https://www.t.me/s/username",
+            "https://telegram.dog/username/10#x",
+            "tg://resolve?domain=username&post=1",
+        ] {
+            assert_eq!(&ChatId::from_url(url), &username, "{}", url);
+        }
+
+        assert_eq!(
+            ChatId::from_url("https://t.me/c/-1001234/5/"),
+            Ok(ChatId::Id(-1001234))
+        );
+        assert_eq!(
+            ChatId::from_url("https://t.me/c/abc/5"),
+            Err(InvalidChatUrl::InvalidId)
+        );
+        assert_eq!(
+            ChatId::from_url("https://t.me/joinchat/AbCd"),
+            Err(InvalidChatUrl::InviteLink)
+        );
+        assert_eq!(
+            ChatId::from_url("https://t.me/JoinChat/AbCd"),
+            Err(InvalidChatUrl::InviteLink)
+        );
+        for url in &[
+            "https://t.me/addstickers/animals",
+            "t.me/addemoji/animals",
+            "https://t.me/share/url?url=https://example.com",
+            "https://t.me/proxy?server=example.com",
+            "https://t.me/socks?server=example.com",
+            "https://t.me/setlanguage/en",
+            "https://t.me/iv?url=https://example.com",
+            "https://t.me/boost/username",
+            "https://t.me/AddStickers/animals",
+        ] {
+            assert_eq!(
+                ChatId::from_url(url),
+                Err(InvalidChatUrl::NotChatLink),
+                "{}",
+                url
+            );
+        }
+        assert_eq!(
+            ChatId::from_url("https://example.com/username"),
+            Err(InvalidChatUrl::NotChatLink)
+        );
+        assert_eq!(
+            ChatId::from_url("https://t.me/"),
+            Err(InvalidChatUrl::NotChatLink)
+        );
+        assert_eq!(
+            ChatId::from_url("https://t.me/a"),
+            Err(InvalidChatUrl::InvalidUsername(
+                InvalidUsername::InvalidLength(1)
+            ))
+        );
+    }
+
     #[test]
     fn chat_id_id_serialization() {
         let expected_json = String::from(r#"123456"#);
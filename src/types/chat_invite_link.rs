@@ -9,6 +9,14 @@ pub struct ChatInviteLink {
     pub invite_link: String,
     /// Creator of the link
     pub creator: User,
+    /// Invite link name
+    pub name: Option<String>,
+    /// `true`, if users joining the chat via the link need to be approved by
+    /// chat administrators, see [`ChatJoinRequest`]
+    ///
+    /// [`ChatJoinRequest`]: crate::types::ChatJoinRequest
+    #[serde(default)]
+    pub creates_join_request: bool,
     /// `true`, if the link is primary
     pub is_primary: bool,
     /// `true`, if the link is revoked
@@ -19,4 +27,23 @@ pub struct ChatInviteLink {
     /// Maximum number of users that can be members of the chat simultaneously
     /// after joining the chat via this invite link; 1-99999
     pub member_limit: Option<u32>,
+    /// Number of pending join requests created using this link
+    pub pending_join_request_count: Option<u32>,
+    /// The number of seconds the subscription will be active for before the
+    /// next payment
+    pub subscription_period: Option<u32>,
+    /// The amount of Telegram Stars a user must pay initially and after each
+    /// subsequent subscription period to be a member of the chat using the
+    /// link
+    pub subscription_price: Option<u32>,
+}
+
+impl ChatInviteLink {
+    /// `true`, if this is a [subscription invite link], i.e. users have to pay
+    /// to join the chat using it.
+    ///
+    /// [subscription invite link]: crate::payloads::CreateChatSubscriptionInviteLink
+    pub fn is_subscription(&self) -> bool {
+        self.subscription_period.is_some()
+    }
 }
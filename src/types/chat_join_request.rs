@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Chat, ChatInviteLink, User};
+
+/// Represents a join request sent to a chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatjoinrequest).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+    /// User that sent the join request
+    pub from: User,
+    /// Identifier of a private chat with the user who sent the join request.
+    /// The bot can use this identifier for 5 minutes to send messages until
+    /// the join request is processed, assuming no other administrator
+    /// contacted the user.
+    pub user_chat_id: i64,
+    /// Date the request was sent in Unix time
+    pub date: i64,
+    /// Bio of the user
+    pub bio: Option<String>,
+    /// Chat invite link that was used by the user to send the join request
+    pub invite_link: Option<ChatInviteLink>,
+}
+
+impl ChatJoinRequest {
+    /// Returns the name of the invite link used to send the request, if any.
+    pub fn invite_link_name(&self) -> Option<&str> {
+        self.invite_link.as_ref()?.name.as_deref()
+    }
+
+    /// Returns `true` if the request was sent using the `invite_link`.
+    ///
+    /// Links created by other administrators are partially hidden (the second
+    /// part is replaced with “…”) in [`ChatInviteLink::invite_link`], so they
+    /// are compared by the visible prefix.
+    pub fn is_via(&self, invite_link: &str) -> bool {
+        let used = match &self.invite_link {
+            Some(link) => &link.invite_link,
+            None => return false,
+        };
+
+        match used.strip_suffix('…') {
+            Some(prefix) => invite_link.starts_with(prefix),
+            None => used == invite_link,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "chat": {"id": -1001, "title": "Chat", "type": "supergroup"},
+            "from": {"id": 42, "is_bot": false, "first_name": "Name"},
+            "user_chat_id": 42,
+            "date": 1640000000,
+            "invite_link": {
+                "invite_link": "https://t.me/+AbCd…",
+                "creator": {"id": 1, "is_bot": false, "first_name": "Admin"},
+                "name": "Ads",
+                "creates_join_request": true,
+                "is_primary": false,
+                "is_revoked": false,
+                "pending_join_request_count": 3
+            }
+        }"#;
+        let request: ChatJoinRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.user_chat_id, 42);
+        assert_eq!(request.invite_link_name(), Some("Ads"));
+        assert!(request.invite_link.as_ref().unwrap().creates_join_request);
+        assert!(request.is_via("https://t.me/+AbCdEfGh"));
+        assert!(!request.is_via("https://t.me/+XyZ"));
+    }
+}
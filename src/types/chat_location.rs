@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::Location;
+use crate::types::{Coordinates, Location};
 
 /// Represents a location to which a chat is connected.
+///
+/// [The official docs](https://core.telegram.org/bots/api#chatlocation).
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ChatLocation {
     /// The location to which the supergroup is connected. Can't be a live
@@ -11,3 +13,56 @@ pub struct ChatLocation {
     /// Location address; 1-64 characters, as defined by the chat owner.
     pub address: String,
 }
+
+impl ChatLocation {
+    /// Creates new `ChatLocation` at the given `coordinates`.
+    pub fn new<S>(coordinates: Coordinates, address: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            location: Location {
+                longitude: coordinates.longitude(),
+                latitude: coordinates.latitude(),
+                horizontal_accuracy: None,
+                live_period: None,
+                heading: None,
+                proximity_alert_radius: None,
+            },
+            address: address.into(),
+        }
+    }
+
+    /// Returns coordinates of the location or `None` if they are out of range.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        self.location.coordinates()
+    }
+
+    /// Returns `true` if the chat is connected to a location within `radius`
+    /// meters of `point`.
+    pub fn is_within(&self, point: Coordinates, radius: f64) -> bool {
+        match self.coordinates() {
+            Some(coordinates) => coordinates.is_within(point, radius),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "location": {"longitude": 2.3522, "latitude": 48.8566},
+            "address": "Paris"
+        }"#;
+        let location = serde_json::from_str::<ChatLocation>(json).unwrap();
+
+        let paris = Coordinates::new(48.8566, 2.3522).unwrap();
+        assert_eq!(location, ChatLocation::new(paris, "Paris"));
+        assert!(location.is_within(paris, 1.0));
+        assert!(!location.is_within(Coordinates::new(51.5074, -0.1278).unwrap(), 1000.0));
+    }
+}
@@ -99,6 +99,21 @@ pub struct Restricted {
     /// Date when restrictions will be lifted for this user, unix time.
     pub until_date: i64,
 
+    /// `true` if the user is a member of the chat at the moment of the request.
+    pub is_member: bool,
+
+    /// `true` if the user can change the chat title, photo and other settings.
+    pub can_change_info: bool,
+
+    /// `true` if the user can invite new users to the chat.
+    pub can_invite_users: bool,
+
+    /// `true` if the user can pin messages, supergroups only.
+    pub can_pin_messages: bool,
+
+    /// `true` if the user is allowed to send polls.
+    pub can_send_polls: bool,
+
     /// `true` if the user can send text messages, contacts, locations and
     /// venues.
     pub can_send_messages: bool,
@@ -216,12 +231,19 @@ impl ChatMemberKind {
     }
 
     /// Returns `true` if the user is currently present in the chat. i.e. if the
-    /// user **hasn't** [left] or been [kicked].
+    /// user **hasn't** [left] or been [kicked] and, if the user is
+    /// [restricted], is a [member] of the chat.
     ///
     /// [left]: ChatMemberKind::Left
     /// [kicked]: ChatMemberKind::Kicked
+    /// [restricted]: ChatMemberKind::Restricted
+    /// [member]: Restricted::is_member
     pub fn is_present(&self) -> bool {
-        !(self.is_left() || self.is_kicked())
+        match self {
+            Self::Restricted(Restricted { is_member, .. }) => *is_member,
+            Self::Creator(_) | Self::Administrator(_) | Self::Member => true,
+            Self::Left | Self::Kicked(_) => false,
+        }
     }
 }
 
@@ -295,7 +317,7 @@ impl ChatMemberKind {
             Self::Administrator(Administrator {
                 can_manage_chat, ..
             }) => *can_manage_chat,
-            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => true,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => false,
         }
     }
 
@@ -306,16 +328,21 @@ impl ChatMemberKind {
     /// - is the creator of the chat
     /// - is an administrator in the given chat and has the [`can_change_info`]
     ///   privilege.
+    /// - is restricted and has the [`can_change_info`][restricted] right.
     /// Returns `false` otherwise.
     ///
     /// [`can_change_info`]: Administrator::can_change_info
+    /// [restricted]: Restricted::can_change_info
     pub fn can_change_info(&self) -> bool {
         match self {
             Self::Creator(_) => true,
             Self::Administrator(Administrator {
                 can_change_info, ..
+            })
+            | Self::Restricted(Restricted {
+                can_change_info, ..
             }) => *can_change_info,
-            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => false,
+            Self::Member | Self::Left | Self::Kicked(_) => false,
         }
     }
 
@@ -404,16 +431,21 @@ impl ChatMemberKind {
     /// - is the creator of the chat
     /// - is an administrator in the given chat and has the [`can_invite_users`]
     ///   privilege.
+    /// - is restricted and has the [`can_invite_users`][restricted] right.
     /// Returns `false` otherwise.
     ///
     /// [`can_invite_users`]: Administrator::can_invite_users
+    /// [restricted]: Restricted::can_invite_users
     pub fn can_invite_users(&self) -> bool {
         match &self {
             Self::Creator(_) => true,
             Self::Administrator(Administrator {
                 can_invite_users, ..
+            })
+            | Self::Restricted(Restricted {
+                can_invite_users, ..
             }) => *can_invite_users,
-            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => false,
+            Self::Member | Self::Left | Self::Kicked(_) => false,
         }
     }
 
@@ -443,16 +475,21 @@ impl ChatMemberKind {
     /// - is the creator of the chat (even if the chat is not a supergroup)
     /// - is an administrator in the given chat and has the [`can_pin_messages`]
     ///   privilege.
+    /// - is restricted and has the [`can_pin_messages`][restricted] right.
     /// Returns `false` otherwise.
     ///
     /// [`can_pin_messages`]: Administrator::can_pin_messages
+    /// [restricted]: Restricted::can_pin_messages
     pub fn can_pin_messages(&self) -> bool {
         match self {
             Self::Creator(_) => true,
             Self::Administrator(Administrator {
                 can_pin_messages, ..
             }) => can_pin_messages.unwrap_or_default(),
-            Self::Member | Self::Restricted(_) | Self::Left | Self::Kicked(_) => false,
+            Self::Restricted(Restricted {
+                can_pin_messages, ..
+            }) => *can_pin_messages,
+            Self::Member | Self::Left | Self::Kicked(_) => false,
         }
     }
 
@@ -521,6 +558,23 @@ impl ChatMemberKind {
         }
     }
 
+    /// Returns `true` if the user is allowed to send polls.
+    ///
+    /// I.e. returns **`false`** if the user
+    /// - has left or has been kicked from the chat
+    /// - is restricted and doesn't have the [`can_send_polls`] right
+    ///
+    /// Returns `true` otherwise.
+    ///
+    /// [`can_send_polls`]: Restricted::can_send_polls
+    pub fn can_send_polls(&self) -> bool {
+        match &self {
+            Self::Restricted(Restricted { can_send_polls, .. }) => *can_send_polls,
+            Self::Creator(_) | Self::Administrator(_) | Self::Member => true,
+            Self::Left | Self::Kicked(_) => false,
+        }
+    }
+
     /// Returns `true` if the user is allowed to send animations, games,
     /// stickers and use inline bots.
     ///
@@ -628,4 +682,39 @@ mod tests {
         let actual = serde_json::from_str::<ChatMember>(json).unwrap();
         assert_eq!(actual, expected)
     }
+
+    #[test]
+    fn restricted() {
+        let json = r#"{
+            "user": {
+                "id": 1,
+                "is_bot": false,
+                "first_name": "First"
+            },
+            "status": "restricted",
+            "until_date": 0,
+            "is_member": false,
+            "can_change_info": true,
+            "can_invite_users": false,
+            "can_pin_messages": true,
+            "can_send_messages": true,
+            "can_send_media_messages": false,
+            "can_send_polls": false,
+            "can_send_other_messages": true,
+            "can_add_web_page_previews": false
+        }"#;
+        let member = serde_json::from_str::<ChatMember>(json).unwrap();
+
+        assert!(member.is_restricted());
+        assert!(!member.is_present());
+        assert!(!member.is_privileged());
+        assert!(!member.can_manage_chat());
+        assert!(member.can_change_info());
+        assert!(!member.can_invite_users());
+        assert!(member.can_pin_messages());
+        assert!(!member.can_delete_messages());
+        assert!(member.can_send_messages());
+        assert!(!member.can_send_polls());
+        assert_eq!(member.until_date(), Some(0));
+    }
 }
@@ -54,6 +54,51 @@ impl ChatPermissions {
         }
     }
 
+    /// Permissions which forbid sending anything, i.e. the user can only
+    /// read the chat.
+    pub const fn read_only() -> Self {
+        Self {
+            can_send_messages: Some(false),
+            can_send_media_messages: Some(false),
+            can_send_polls: Some(false),
+            can_send_other_messages: Some(false),
+            can_add_web_page_previews: Some(false),
+            can_change_info: Some(false),
+            can_invite_users: Some(false),
+            can_pin_messages: Some(false),
+        }
+    }
+
+    /// Permissions which allow sending only text messages (and contacts,
+    /// locations and venues).
+    pub const fn text_only() -> Self {
+        Self::read_only().can_send_messages(true)
+    }
+
+    /// Permissions which allow sending text and media messages, but not
+    /// polls, stickers, animations, games, inline bot results or web page
+    /// previews.
+    pub const fn media_only() -> Self {
+        Self::text_only().can_send_media_messages(true)
+    }
+
+    /// Permissions which allow everything, passing these to
+    /// [`RestrictChatMember`] lifts all restrictions from a user.
+    ///
+    /// [`RestrictChatMember`]: crate::payloads::RestrictChatMember
+    pub const fn all() -> Self {
+        Self {
+            can_send_messages: Some(true),
+            can_send_media_messages: Some(true),
+            can_send_polls: Some(true),
+            can_send_other_messages: Some(true),
+            can_add_web_page_previews: Some(true),
+            can_change_info: Some(true),
+            can_invite_users: Some(true),
+            can_pin_messages: Some(true),
+        }
+    }
+
     pub const fn can_send_messages(mut self, val: bool) -> Self {
         self.can_send_messages = Some(val);
         self
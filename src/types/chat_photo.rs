@@ -1,4 +1,11 @@
 use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWrite;
+
+use crate::{
+    net::Download,
+    requests::{Request, Requester},
+    DownloadError,
+};
 
 /// This object represents a chat photo.
 ///
@@ -25,3 +32,81 @@ pub struct ChatPhoto {
     /// download or reuse the file.
     pub big_file_unique_id: String,
 }
+
+impl ChatPhoto {
+    /// Downloads the small (160x160) chat photo into `destination`.
+    ///
+    /// This is a shortcut for [`GetFile`] with the [`small_file_id`] followed
+    /// by [`Download::download_file`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     requests::{Request, Requester},
+    ///     Bot,
+    /// };
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// let chat = bot.get_chat(-1001234567890).send().await?;
+    /// if let Some(photo) = &chat.photo {
+    ///     let mut file = tokio::fs::File::create("/tmp/chat.jpg").await?;
+    ///     photo.download_small(&bot, &mut file).await?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`small_file_id`]: ChatPhoto::small_file_id
+    pub async fn download_small<'w, B>(
+        &self,
+        bot: &B,
+        destination: &'w mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<(), <B as Requester>::Err>
+    where
+        B: Requester + Download<'w>,
+        <B as Download<'w>>::Err: Into<<B as Requester>::Err>,
+        DownloadError: Into<<B as Requester>::Err>,
+    {
+        download(bot, &self.small_file_id, destination).await
+    }
+
+    /// Downloads the big (640x640) chat photo into `destination`.
+    ///
+    /// This is a shortcut for [`GetFile`] with the [`big_file_id`] followed
+    /// by [`Download::download_file`].
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`big_file_id`]: ChatPhoto::big_file_id
+    pub async fn download_big<'w, B>(
+        &self,
+        bot: &B,
+        destination: &'w mut (dyn AsyncWrite + Unpin + Send),
+    ) -> Result<(), <B as Requester>::Err>
+    where
+        B: Requester + Download<'w>,
+        <B as Download<'w>>::Err: Into<<B as Requester>::Err>,
+        DownloadError: Into<<B as Requester>::Err>,
+    {
+        download(bot, &self.big_file_id, destination).await
+    }
+}
+
+async fn download<'w, B>(
+    bot: &B,
+    file_id: &str,
+    destination: &'w mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), <B as Requester>::Err>
+where
+    B: Requester + Download<'w>,
+    <B as Download<'w>>::Err: Into<<B as Requester>::Err>,
+    DownloadError: Into<<B as Requester>::Err>,
+{
+    let file = bot.get_file(file_id).send().await?;
+    let path = file.path().map_err(Into::into)?;
+    bot.download_file(path, destination)
+        .await
+        .map_err(Into::into)
+}
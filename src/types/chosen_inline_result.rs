@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Location, User};
+use crate::{
+    types::{Location, User},
+    utils::inline_results::InlineResultStore,
+};
 
 /// Represents a [result] of an inline query that was chosen by the user and
 /// sent to their chat partner.
@@ -32,3 +35,13 @@ pub struct ChosenInlineResult {
     /// The query that was used to obtain the result.
     pub query: String,
 }
+
+impl ChosenInlineResult {
+    /// Returns the value associated with the chosen result in the `store`.
+    pub fn lookup<S>(&self, store: &S) -> Option<S::Value>
+    where
+        S: InlineResultStore,
+    {
+        store.lookup(self.from.id, &self.result_id)
+    }
+}
@@ -0,0 +1,173 @@
+use std::convert::TryFrom;
+
+use crate::errors::InvalidCoordinates;
+
+/// Mean radius of the Earth in meters, used by [`Coordinates::distance_to`].
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A validated pair of latitude and longitude, in degrees.
+///
+/// Latitude must be in `-90.0..=90.0` and longitude in `-180.0..=180.0`.
+///
+/// Coordinates can be obtained from a [`Location`], [`Venue`] or
+/// [`InlineQueryResultLocation`] with their `coordinates` methods and
+/// converted from/to `(latitude, longitude)` tuples, which is the shape most
+/// geo crates can convert into. With the `geo_types` feature they can also be
+/// converted from/to `geo_types` points (`x` is the longitude and `y` is the
+/// latitude).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::Coordinates;
+///
+/// let paris = Coordinates::new(48.8566, 2.3522).unwrap();
+/// let london = Coordinates::new(51.5074, -0.1278).unwrap();
+///
+/// let km = paris.distance_to(london) / 1000.0;
+/// assert!((km - 343.5).abs() < 1.0);
+///
+/// assert_eq!(Coordinates::new(91.0, 0.0), None);
+/// ```
+///
+/// [`Location`]: crate::types::Location
+/// [`Venue`]: crate::types::Venue
+/// [`InlineQueryResultLocation`]: crate::types::InlineQueryResultLocation
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Coordinates {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Coordinates {
+    /// Creates new `Coordinates`, returns `None` if latitude or longitude are
+    /// out of range (or are `NaN`).
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        Self::checked(latitude, longitude).ok()
+    }
+
+    /// Creates new `Coordinates`, like [`Coordinates::new`], but returns an
+    /// error telling which of the values is out of range.
+    pub fn checked(latitude: f64, longitude: f64) -> Result<Self, InvalidCoordinates> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(InvalidCoordinates::Latitude(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(InvalidCoordinates::Longitude(longitude));
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Latitude in degrees.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Longitude in degrees.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the great-circle distance to `other` in meters, computed with
+    /// the [haversine formula].
+    ///
+    /// The Earth is treated as a sphere with the radius of [`EARTH_RADIUS`],
+    /// so the result may be off by up to ~0.5%.
+    ///
+    /// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+    pub fn distance_to(&self, other: Self) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Returns `true` if `other` is within `radius` meters.
+    pub fn is_within(&self, other: Self, radius: f64) -> bool {
+        self.distance_to(other) <= radius
+    }
+}
+
+impl TryFrom<(f64, f64)> for Coordinates {
+    type Error = InvalidCoordinates;
+
+    /// Converts a `(latitude, longitude)` tuple.
+    fn try_from((latitude, longitude): (f64, f64)) -> Result<Self, Self::Error> {
+        Self::checked(latitude, longitude)
+    }
+}
+
+impl From<Coordinates> for (f64, f64) {
+    /// Converts to a `(latitude, longitude)` tuple.
+    fn from(coords: Coordinates) -> Self {
+        (coords.latitude, coords.longitude)
+    }
+}
+
+#[cfg(feature = "geo_types")]
+impl TryFrom<geo_types::Point<f64>> for Coordinates {
+    type Error = InvalidCoordinates;
+
+    fn try_from(point: geo_types::Point<f64>) -> Result<Self, Self::Error> {
+        Self::checked(point.y(), point.x())
+    }
+}
+
+#[cfg(feature = "geo_types")]
+impl From<Coordinates> for geo_types::Point<f64> {
+    fn from(coords: Coordinates) -> Self {
+        geo_types::Point::new(coords.longitude, coords.latitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        assert!(Coordinates::new(90.0, -180.0).is_some());
+        assert!(Coordinates::new(-90.1, 0.0).is_none());
+        assert!(Coordinates::new(0.0, 180.1).is_none());
+        assert!(Coordinates::new(f64::NAN, 0.0).is_none());
+        assert!(Coordinates::try_from((10.0, 20.0)).is_ok());
+        assert_eq!(
+            Coordinates::try_from((0.0, 180.1)),
+            Err(InvalidCoordinates::Longitude(180.1))
+        );
+        assert_eq!(
+            Coordinates::checked(-90.1, 0.0),
+            Err(InvalidCoordinates::Latitude(-90.1))
+        );
+    }
+
+    #[cfg(feature = "geo_types")]
+    #[test]
+    fn geo_types() {
+        let coords = Coordinates::new(48.8566, 2.3522).unwrap();
+        let point = geo_types::Point::from(coords);
+        assert_eq!((point.x(), point.y()), (2.3522, 48.8566));
+        assert_eq!(Coordinates::try_from(point), Ok(coords));
+        assert_eq!(
+            Coordinates::try_from(geo_types::Point::new(0.0, 91.0)),
+            Err(InvalidCoordinates::Latitude(91.0))
+        );
+    }
+
+    #[test]
+    fn distance() {
+        let a = Coordinates::new(0.0, 0.0).unwrap();
+        let b = Coordinates::new(0.0, 180.0).unwrap();
+
+        assert_eq!(a.distance_to(a), 0.0);
+        assert!((a.distance_to(b) - std::f64::consts::PI * EARTH_RADIUS).abs() < 1e-6);
+        assert!(a.is_within(Coordinates::new(0.0, 0.001).unwrap(), 112.0));
+        assert!(!a.is_within(Coordinates::new(0.0, 0.001).unwrap(), 111.0));
+    }
+}
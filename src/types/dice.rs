@@ -11,11 +11,248 @@ pub struct Dice {
 
     /// Value of the dice.
     ///
-    /// 1-6 for [`DiceEmoji::Dice`] and [`DiceEmoji::Darts`], 1-5 for
-    /// [`DiceEmoji::Basketball`].
+    /// 1-6 for [`DiceEmoji::Dice`], [`DiceEmoji::Darts`] and
+    /// [`DiceEmoji::Bowling`], 1-5 for [`DiceEmoji::Basketball`] and
+    /// [`DiceEmoji::Football`], 1-64 for [`DiceEmoji::SlotMachine`]. See also
+    /// [`Dice::outcome`].
     ///
     /// [`DiceEmoji::Dice`]: crate::types::DiceEmoji::Dice
-    /// [`DiceEmoji::Darts`]:crate::types::DiceEmoji::Darts
-    /// [`DiceEmoji::Basketball`]:crate::types::DiceEmoji::Basketball
+    /// [`DiceEmoji::Darts`]: crate::types::DiceEmoji::Darts
+    /// [`DiceEmoji::Bowling`]: crate::types::DiceEmoji::Bowling
+    /// [`DiceEmoji::Basketball`]: crate::types::DiceEmoji::Basketball
+    /// [`DiceEmoji::Football`]: crate::types::DiceEmoji::Football
+    /// [`DiceEmoji::SlotMachine`]: crate::types::DiceEmoji::SlotMachine
     pub value: i32,
 }
+
+impl Dice {
+    /// Returns `true` if the dice has the maximum possible value for its
+    /// emoji (see [`DiceEmoji::max_value`]).
+    ///
+    /// Note that for [`DiceEmoji::SlotMachine`] this means "three sevens",
+    /// other wins can be detected with [`Dice::is_win`].
+    pub fn is_max(&self) -> bool {
+        self.value == self.emoji.max_value()
+    }
+
+    /// Returns `true` if the dice represents a "win": the maximum value for
+    /// [`DiceEmoji::Dice`], a bullseye for [`DiceEmoji::Darts`], a strike for
+    /// [`DiceEmoji::Bowling`], a score for [`DiceEmoji::Basketball`] and
+    /// [`DiceEmoji::Football`] and three of a kind for
+    /// [`DiceEmoji::SlotMachine`].
+    pub fn is_win(&self) -> bool {
+        match self.outcome() {
+            Some(DiceOutcome::Dice(_)) => self.is_max(),
+            Some(DiceOutcome::Darts(o)) => o == DartsOutcome::Bullseye,
+            Some(DiceOutcome::Basketball(o)) => o == BasketballOutcome::Score,
+            Some(DiceOutcome::Football(o)) => o == FootballOutcome::Goal,
+            Some(DiceOutcome::Bowling(o)) => o == BowlingOutcome::Strike,
+            Some(DiceOutcome::SlotMachine(reels)) => reels.is_three_of_a_kind(),
+            None => false,
+        }
+    }
+
+    /// Decodes the value of the dice, returns `None` if the value is out of
+    /// range for the emoji.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{Dice, DiceEmoji, DiceOutcome, SlotMachineReels, SlotSymbol};
+    ///
+    /// let dice = Dice {
+    ///     emoji: DiceEmoji::SlotMachine,
+    ///     value: 22,
+    /// };
+    /// assert_eq!(
+    ///     dice.outcome(),
+    ///     Some(DiceOutcome::SlotMachine(SlotMachineReels([SlotSymbol::Grapes; 3])))
+    /// );
+    /// assert!(dice.is_win());
+    /// ```
+    pub fn outcome(&self) -> Option<DiceOutcome> {
+        let value = self.value;
+        if value < 1 || value > self.emoji.max_value() {
+            return None;
+        }
+
+        let outcome = match self.emoji {
+            DiceEmoji::Dice => DiceOutcome::Dice(value as u8),
+            DiceEmoji::Darts => DiceOutcome::Darts(match value {
+                1 => DartsOutcome::Miss,
+                6 => DartsOutcome::Bullseye,
+                _ => DartsOutcome::Ring(value as u8 - 1),
+            }),
+            DiceEmoji::Basketball => DiceOutcome::Basketball(match value {
+                1 | 2 => BasketballOutcome::Miss,
+                3 => BasketballOutcome::Stuck,
+                _ => BasketballOutcome::Score,
+            }),
+            DiceEmoji::Football => DiceOutcome::Football(match value {
+                1 | 2 => FootballOutcome::Miss,
+                _ => FootballOutcome::Goal,
+            }),
+            DiceEmoji::Bowling => DiceOutcome::Bowling(match value {
+                1 => BowlingOutcome::Miss,
+                2 => BowlingOutcome::Pins(1),
+                6 => BowlingOutcome::Strike,
+                _ => BowlingOutcome::Pins(value as u8),
+            }),
+            DiceEmoji::SlotMachine => {
+                let v = value - 1;
+                let symbol = |shift: i32| SlotSymbol::from_index((v >> shift) & 0b11);
+                DiceOutcome::SlotMachine(SlotMachineReels([symbol(0), symbol(2), symbol(4)]))
+            }
+        };
+
+        Some(outcome)
+    }
+}
+
+/// Decoded value of a [`Dice`], see [`Dice::outcome`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiceOutcome {
+    /// The number on the dice, 1-6.
+    Dice(u8),
+    Darts(DartsOutcome),
+    Basketball(BasketballOutcome),
+    Football(FootballOutcome),
+    Bowling(BowlingOutcome),
+    SlotMachine(SlotMachineReels),
+}
+
+/// Outcome of a [`DiceEmoji::Darts`] throw.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DartsOutcome {
+    /// The dart missed the board.
+    Miss,
+
+    /// The dart hit a ring of the board, from 1 (the outermost) to 4 (the
+    /// closest to the center).
+    Ring(u8),
+
+    /// The dart hit the center of the board.
+    Bullseye,
+}
+
+/// Outcome of a [`DiceEmoji::Basketball`] throw.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BasketballOutcome {
+    /// The ball missed the basket.
+    Miss,
+
+    /// The ball got stuck on the rim.
+    Stuck,
+
+    /// The ball went through the basket.
+    Score,
+}
+
+/// Outcome of a [`DiceEmoji::Football`] kick.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FootballOutcome {
+    /// The ball missed the goal.
+    Miss,
+
+    /// The ball went into the goal.
+    Goal,
+}
+
+/// Outcome of a [`DiceEmoji::Bowling`] throw.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BowlingOutcome {
+    /// No pins were knocked down.
+    Miss,
+
+    /// Some pins (1-5) were knocked down.
+    Pins(u8),
+
+    /// All pins were knocked down.
+    Strike,
+}
+
+/// Symbols on the reels of a [`DiceEmoji::SlotMachine`], left to right.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SlotMachineReels(pub [SlotSymbol; 3]);
+
+impl SlotMachineReels {
+    /// Returns `true` if all reels show the same symbol.
+    pub fn is_three_of_a_kind(&self) -> bool {
+        let [a, b, c] = self.0;
+        a == b && b == c
+    }
+
+    /// Returns `true` if all reels show [`SlotSymbol::Seven`].
+    pub fn is_jackpot(&self) -> bool {
+        self.0 == [SlotSymbol::Seven; 3]
+    }
+}
+
+/// A symbol on a reel of a [`DiceEmoji::SlotMachine`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SlotSymbol {
+    Bar,
+    Grapes,
+    Lemon,
+    Seven,
+}
+
+impl SlotSymbol {
+    fn from_index(index: i32) -> Self {
+        match index {
+            0 => Self::Bar,
+            1 => Self::Grapes,
+            2 => Self::Lemon,
+            _ => Self::Seven,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dice(emoji: DiceEmoji, value: i32) -> Dice {
+        Dice { emoji, value }
+    }
+
+    #[test]
+    fn slot_machine() {
+        use SlotSymbol::*;
+
+        let reels = |value| match dice(DiceEmoji::SlotMachine, value).outcome() {
+            Some(DiceOutcome::SlotMachine(SlotMachineReels(reels))) => reels,
+            _ => panic!("unexpected outcome"),
+        };
+
+        assert_eq!(reels(1), [Bar; 3]);
+        assert_eq!(reels(2), [Grapes, Bar, Bar]);
+        assert_eq!(reels(5), [Bar, Grapes, Bar]);
+        assert_eq!(reels(43), [Lemon; 3]);
+        assert_eq!(reels(64), [Seven; 3]);
+
+        let wins: Vec<_> = (1..=64)
+            .filter(|&v| dice(DiceEmoji::SlotMachine, v).is_win())
+            .collect();
+        assert_eq!(wins, [1, 22, 43, 64]);
+        assert!(dice(DiceEmoji::SlotMachine, 64).is_max());
+    }
+
+    #[test]
+    fn outcomes() {
+        assert!(dice(DiceEmoji::Dice, 6).is_win());
+        assert!(!dice(DiceEmoji::Dice, 5).is_win());
+        assert_eq!(
+            dice(DiceEmoji::Darts, 3).outcome(),
+            Some(DiceOutcome::Darts(DartsOutcome::Ring(2)))
+        );
+        assert!(dice(DiceEmoji::Basketball, 4).is_win());
+        assert!(!dice(DiceEmoji::Football, 2).is_win());
+        assert_eq!(
+            dice(DiceEmoji::Bowling, 2).outcome(),
+            Some(DiceOutcome::Bowling(BowlingOutcome::Pins(1)))
+        );
+        assert_eq!(dice(DiceEmoji::Basketball, 6).outcome(), None);
+        assert!(!dice(DiceEmoji::Dice, 0).is_win());
+    }
+}
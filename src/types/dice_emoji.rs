@@ -18,7 +18,7 @@ pub enum DiceEmoji {
     #[serde(rename = "⚽")]
     Football,
 
-    /// Values 1-5
+    /// Values 1-6
     #[serde(rename = "🎳")]
     Bowling,
 
@@ -26,3 +26,14 @@ pub enum DiceEmoji {
     #[serde(rename = "🎰")]
     SlotMachine,
 }
+
+impl DiceEmoji {
+    /// Returns the maximum value of a dice with this emoji.
+    pub const fn max_value(self) -> i32 {
+        match self {
+            Self::Dice | Self::Darts | Self::Bowling => 6,
+            Self::Basketball | Self::Football => 5,
+            Self::SlotMachine => 64,
+        }
+    }
+}
@@ -23,7 +23,8 @@ pub struct Document {
     pub file_unique_id: String,
 
     /// A document thumbnail as defined by a sender.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 
     /// An original filename as defined by a sender.
     pub file_name: Option<String>,
@@ -34,4 +35,11 @@ pub struct Document {
 
     /// A size of a file.
     pub file_size: Option<u32>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::DownloadError;
+
 /// This object represents a file ready to be downloaded.
 ///
 /// The file can be downloaded via the [`Bot::download_file(file_path, dst)`]
@@ -11,6 +13,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`GetFile`]: crate::payloads::GetFile
 /// [`Bot::download_file(file_path, dst)`]: crate::net::Download::download_file
+#[serde_with_macros::skip_serializing_none]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct File {
     /// Identifier for this file.
@@ -22,11 +25,49 @@ pub struct File {
     pub file_unique_id: String,
 
     /// File size, if known.
-    pub file_size: u32,
+    pub file_size: Option<u32>,
 
     /// File path. Use [`Bot::download_file(file_path, dst)`] to get the file.
     ///
     /// [`Bot::download_file(file_path, dst)`]:
     /// crate::net::Download::download_file
-    pub file_path: String,
+    ///
+    /// `None` for files which are not returned by [`GetFile`] (e.g.
+    /// [`Sticker::premium_animation`]).
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    /// [`Sticker::premium_animation`]: crate::types::Sticker::premium_animation
+    pub file_path: Option<String>,
+}
+
+impl File {
+    /// Returns the [`file_path`], which is needed to download the file.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`DownloadError::NoFilePath`] if the path is not known.
+    ///
+    /// [`file_path`]: File::file_path
+    pub fn path(&self) -> Result<&str, DownloadError> {
+        self.file_path.as_deref().ok_or(DownloadError::NoFilePath)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path() {
+        let mut file = File {
+            file_id: "id".to_owned(),
+            file_unique_id: "unique".to_owned(),
+            file_size: None,
+            file_path: Some("photos/file_0.jpg".to_owned()),
+        };
+        assert_eq!(file.path().unwrap(), "photos/file_0.jpg");
+
+        file.file_path = None;
+        assert!(matches!(file.path(), Err(DownloadError::NoFilePath)));
+    }
 }
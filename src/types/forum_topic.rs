@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{ForumTopicIconColor, ThreadId};
+
+/// This object represents a forum topic.
+///
+/// [The official docs](https://core.telegram.org/bots/api#forumtopic).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ForumTopic {
+    /// Unique identifier of the forum topic.
+    pub message_thread_id: ThreadId,
+
+    /// Name of the topic.
+    pub name: String,
+
+    /// Color of the topic icon.
+    pub icon_color: ForumTopicIconColor,
+
+    /// Unique identifier of the custom emoji shown as the topic icon.
+    pub icon_custom_emoji_id: Option<String>,
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+/// Color of a forum topic icon.
+///
+/// Topics can only be created with one of the 6 colors of the palette, any
+/// other color (which may appear in topics created by users in the future)
+/// is represented by [`Unknown`].
+///
+/// [`Unknown`]: ForumTopicIconColor::Unknown
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum ForumTopicIconColor {
+    /// `0x6FB9F0`.
+    Blue,
+    /// `0xFFD67E`.
+    Yellow,
+    /// `0xCB86DB`.
+    Violet,
+    /// `0x8EEE98`.
+    Green,
+    /// `0xFF93B2`.
+    Rose,
+    /// `0xFB6F5F`.
+    Red,
+    /// A color which is not in the palette, in the `0xRRGGBB` format.
+    Unknown(u32),
+}
+
+impl ForumTopicIconColor {
+    /// All colors which can be used to create a forum topic.
+    pub const PALETTE: [Self; 6] = [
+        Self::Blue,
+        Self::Yellow,
+        Self::Violet,
+        Self::Green,
+        Self::Rose,
+        Self::Red,
+    ];
+
+    /// Returns RGB value of this color in the `0xRRGGBB` format.
+    pub fn rgb(self) -> u32 {
+        match self {
+            Self::Blue => 0x6FB9F0,
+            Self::Yellow => 0xFFD67E,
+            Self::Violet => 0xCB86DB,
+            Self::Green => 0x8EEE98,
+            Self::Rose => 0xFF93B2,
+            Self::Red => 0xFB6F5F,
+            Self::Unknown(rgb) => rgb,
+        }
+    }
+}
+
+impl From<u32> for ForumTopicIconColor {
+    fn from(rgb: u32) -> Self {
+        match rgb {
+            0x6FB9F0 => Self::Blue,
+            0xFFD67E => Self::Yellow,
+            0xCB86DB => Self::Violet,
+            0x8EEE98 => Self::Green,
+            0xFF93B2 => Self::Rose,
+            0xFB6F5F => Self::Red,
+            _ => Self::Unknown(rgb),
+        }
+    }
+}
+
+impl From<ForumTopicIconColor> for u32 {
+    fn from(color: ForumTopicIconColor) -> Self {
+        color.rgb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette() {
+        for color in ForumTopicIconColor::PALETTE.iter().copied() {
+            assert_eq!(ForumTopicIconColor::from(color.rgb()), color);
+        }
+        assert_eq!(
+            ForumTopicIconColor::from(0x123456),
+            ForumTopicIconColor::Unknown(0x123456)
+        );
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(
+            serde_json::to_string(&ForumTopicIconColor::Blue).unwrap(),
+            "7322096"
+        );
+        assert_eq!(
+            serde_json::from_str::<ForumTopicIconColor>("16478047").unwrap(),
+            ForumTopicIconColor::Red
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Deref;
+
 use serde::{Deserialize, Serialize};
 
 use crate::types::user::User;
@@ -16,3 +18,152 @@ pub struct GameHighScore {
     /// Score.
     pub score: u32,
 }
+
+/// A high scores table of a game.
+///
+/// This is a thin wrapper around a list of [`GameHighScore`]s (as returned by
+/// [`GetGameHighScores`]) which keeps the rows ordered by [`position`] and
+/// allows to merge tables obtained by several calls.
+///
+/// [`GetGameHighScores`]: crate::payloads::GetGameHighScores
+/// [`position`]: GameHighScore::position
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GameHighScores(Vec<GameHighScore>);
+
+impl GameHighScores {
+    /// Creates a new table from the given rows, ordering them by position.
+    pub fn new<I>(scores: I) -> Self
+    where
+        I: IntoIterator<Item = GameHighScore>,
+    {
+        let mut scores: Vec<_> = scores.into_iter().collect();
+        scores.sort_by_key(|s| s.position);
+        Self(scores)
+    }
+
+    /// Returns the row of the user with the given id, if it's in the table.
+    pub fn get(&self, user_id: i64) -> Option<&GameHighScore> {
+        self.0.iter().find(|s| s.user.id == user_id)
+    }
+
+    /// Returns the position of the user with the given id, if it's in the
+    /// table.
+    pub fn position_of(&self, user_id: i64) -> Option<u32> {
+        self.get(user_id).map(|s| s.position)
+    }
+
+    /// Returns the score of the user with the given id, if it's in the table.
+    pub fn score_of(&self, user_id: i64) -> Option<u32> {
+        self.get(user_id).map(|s| s.score)
+    }
+
+    /// Returns the rows with the `n` best positions.
+    pub fn top(&self, n: usize) -> &[GameHighScore] {
+        &self.0[..n.min(self.0.len())]
+    }
+
+    /// Merges `other` table into `self`.
+    ///
+    /// [`GetGameHighScores`] only returns the neighbourhood of the requested
+    /// user, so to build a bigger table you need to merge results of several
+    /// calls. If a user is present in both tables, the row with the higher
+    /// score wins (it's the more recent one, since scores can only go up
+    /// unless `force` was used). Rows are then re-ordered by score and
+    /// positions are recomputed, so ties share a position.
+    ///
+    /// [`GetGameHighScores`]: crate::payloads::GetGameHighScores
+    pub fn merge(&mut self, other: GameHighScores) {
+        for row in other.0 {
+            match self.0.iter_mut().find(|s| s.user.id == row.user.id) {
+                Some(existing) if existing.score < row.score => *existing = row,
+                Some(_) => {}
+                None => self.0.push(row),
+            }
+        }
+
+        self.0.sort_by_key(|s| std::cmp::Reverse(s.score));
+
+        let mut position = 0;
+        let mut prev_score = None;
+        for (i, row) in self.0.iter_mut().enumerate() {
+            if prev_score != Some(row.score) {
+                position = i as u32 + 1;
+                prev_score = Some(row.score);
+            }
+            row.position = position;
+        }
+    }
+
+    /// Unwraps the rows.
+    pub fn into_inner(self) -> Vec<GameHighScore> {
+        self.0
+    }
+}
+
+impl From<Vec<GameHighScore>> for GameHighScores {
+    fn from(scores: Vec<GameHighScore>) -> Self {
+        Self::new(scores)
+    }
+}
+
+impl Deref for GameHighScores {
+    type Target = [GameHighScore];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for GameHighScores {
+    type Item = GameHighScore;
+    type IntoIter = std::vec::IntoIter<GameHighScore>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(position: u32, id: i64, score: u32) -> GameHighScore {
+        GameHighScore {
+            position,
+            user: User {
+                id,
+                is_bot: false,
+                first_name: id.to_string(),
+                last_name: None,
+                username: None,
+                language_code: None,
+            },
+            score,
+        }
+    }
+
+    #[test]
+    fn new_orders_by_position() {
+        let table = GameHighScores::new(vec![row(2, 2, 10), row(1, 1, 20)]);
+
+        assert_eq!(table.position_of(1), Some(1));
+        assert_eq!(table.top(1), &[row(1, 1, 20)]);
+        assert_eq!(table.score_of(3), None);
+    }
+
+    #[test]
+    fn merge() {
+        let mut table = GameHighScores::new(vec![row(1, 1, 30), row(2, 2, 20)]);
+        table.merge(GameHighScores::new(vec![
+            row(1, 2, 40),
+            row(2, 3, 30),
+            row(3, 4, 5),
+        ]));
+
+        assert_eq!(
+            table.into_inner(),
+            vec![row(1, 2, 40), row(2, 1, 30), row(2, 3, 30), row(4, 4, 5)]
+        );
+    }
+}
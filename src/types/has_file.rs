@@ -0,0 +1,126 @@
+use crate::types::{
+    Animation, Audio, Document, File, PassportFile, PhotoSize, Sticker, Video, VideoNote, Voice,
+};
+
+/// The maximum size of a file which can be downloaded by a bot, 20MB.
+///
+/// See [`GetFile`](crate::payloads::GetFile).
+pub const MAX_DOWNLOAD_SIZE: u64 = 20 * 1024 * 1024;
+
+/// Types which represent a file stored on Telegram servers.
+///
+/// This trait allows to write code which works with any kind of files, e.g.:
+///
+/// ```no_run
+/// use teloxide_core::{
+///     net::Download,
+///     requests::{Request, Requester},
+///     types::HasFile,
+///     Bot, RequestError,
+/// };
+///
+/// async fn download_any<T: HasFile>(bot: &Bot, file: &T) -> Result<Vec<u8>, RequestError> {
+///     let file = bot.get_file(file.file_id()).send().await?;
+///     let mut buf = Vec::new();
+///     bot.download_file(file.path()?, &mut buf).await?;
+///     Ok(buf)
+/// }
+/// ```
+pub trait HasFile {
+    /// Identifier for the file, which can be used to download or reuse it.
+    fn file_id(&self) -> &str;
+
+    /// Unique identifier for the file, which is supposed to be the same over
+    /// time and for different bots. Can't be used to download or reuse the
+    /// file.
+    fn file_unique_id(&self) -> &str;
+
+    /// File size in bytes, if known.
+    fn file_size(&self) -> Option<u64>;
+
+    /// Returns `false` if the file is known to be larger than
+    /// [`MAX_DOWNLOAD_SIZE`], so it can't be downloaded by a bot.
+    fn is_downloadable(&self) -> bool {
+        match self.file_size() {
+            Some(size) => size <= MAX_DOWNLOAD_SIZE,
+            None => true,
+        }
+    }
+}
+
+macro_rules! impl_has_file {
+    ($($T:ty => |$this:ident| $size:expr),* $(,)?) => {
+        $(
+            impl HasFile for $T {
+                fn file_id(&self) -> &str {
+                    &self.file_id
+                }
+
+                fn file_unique_id(&self) -> &str {
+                    &self.file_unique_id
+                }
+
+                fn file_size(&self) -> Option<u64> {
+                    let $this = self;
+                    $size
+                }
+            }
+        )*
+    };
+}
+
+impl_has_file! {
+    Animation => |this| this.file_size.map(u64::from),
+    Audio => |this| this.file_size.map(u64::from),
+    Document => |this| this.file_size.map(u64::from),
+    File => |this| this.file_size.map(u64::from),
+    PassportFile => |this| Some(this.file_size),
+    PhotoSize => |this| this.file_size.map(u64::from),
+    Sticker => |this| this.file_size.map(u64::from),
+    Video => |this| this.file_size.map(u64::from),
+    VideoNote => |this| this.file_size.map(u64::from),
+    Voice => |this| this.file_size,
+}
+
+impl<T: HasFile + ?Sized> HasFile for &T {
+    fn file_id(&self) -> &str {
+        (**self).file_id()
+    }
+
+    fn file_unique_id(&self) -> &str {
+        (**self).file_unique_id()
+    }
+
+    fn file_size(&self) -> Option<u64> {
+        (**self).file_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downloadable() {
+        let mut photo = PhotoSize {
+            file_id: "id".to_owned(),
+            file_unique_id: "unique".to_owned(),
+            width: 1,
+            height: 1,
+            file_size: None,
+        };
+        assert!(photo.is_downloadable());
+        assert_eq!(HasFile::file_id(&photo), "id");
+
+        photo.file_size = Some(MAX_DOWNLOAD_SIZE as u32);
+        assert!(photo.is_downloadable());
+
+        photo.file_size = Some(MAX_DOWNLOAD_SIZE as u32 + 1);
+        assert!(!photo.is_downloadable());
+
+        fn id<T: HasFile>(file: T) -> String {
+            file.file_id().to_owned()
+        }
+        assert_eq!(id(&photo), "id");
+    }
+}
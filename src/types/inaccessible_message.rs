@@ -0,0 +1,32 @@
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+
+use crate::types::Chat;
+
+/// This object describes a message that was deleted or is otherwise
+/// inaccessible to the bot.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inaccessiblemessage).
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct InaccessibleMessage {
+    /// Chat the message belonged to.
+    pub chat: Chat,
+
+    /// Unique message identifier inside the chat.
+    #[serde(rename = "message_id")]
+    pub id: i32,
+}
+
+impl Serialize for InaccessibleMessage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Telegram distinguishes inaccessible messages by the `date` field which
+        // is always `0`.
+        let mut s = serializer.serialize_struct("InaccessibleMessage", 3)?;
+        s.serialize_field("chat", &self.chat)?;
+        s.serialize_field("message_id", &self.id)?;
+        s.serialize_field("date", &0)?;
+        s.end()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::{CallbackGame, LoginUrl};
+use crate::types::{CallbackGame, LoginUrl, SwitchInlineQueryChosenChat};
 use serde::{Deserialize, Serialize};
 
 /// This object represents one button of an inline keyboard.
@@ -80,6 +80,11 @@ pub enum InlineKeyboardButtonKind {
     /// the same chat – good for selecting something from multiple options.
     SwitchInlineQueryCurrentChat(String),
 
+    /// If set, pressing the button will prompt the user to select one of their
+    /// chats of the specified type, open that chat and insert the bot's
+    /// username and the specified inline query in the input field.
+    SwitchInlineQueryChosenChat(SwitchInlineQueryChosenChat),
+
     /// Description of the game that will be launched when the user presses the
     /// button.
     ///
@@ -139,4 +144,36 @@ impl InlineKeyboardButton {
             ),
         }
     }
+
+    pub fn switch_inline_query_chosen_chat(
+        text: String,
+        switch_inline_query_chosen_chat: SwitchInlineQueryChosenChat,
+    ) -> InlineKeyboardButton {
+        InlineKeyboardButton {
+            text,
+            kind: InlineKeyboardButtonKind::SwitchInlineQueryChosenChat(
+                switch_inline_query_chosen_chat,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switch_inline_query_chosen_chat() {
+        let button = InlineKeyboardButton::switch_inline_query_chosen_chat(
+            "Share".to_owned(),
+            SwitchInlineQueryChosenChat::new("q").allow_group_chats(true),
+        );
+        let json = r#"{"text":"Share","switch_inline_query_chosen_chat":{"query":"q","allow_user_chats":false,"allow_bot_chats":false,"allow_group_chats":true,"allow_channel_chats":false}}"#;
+
+        assert_eq!(serde_json::to_string(&button).unwrap(), json);
+        assert_eq!(
+            serde_json::from_str::<InlineKeyboardButton>(json).unwrap(),
+            button
+        );
+    }
 }
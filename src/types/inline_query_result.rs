@@ -52,6 +52,38 @@ pub enum InlineQueryResult {
     Voice(InlineQueryResultVoice),
 }
 
+impl InlineQueryResult {
+    /// Returns the unique identifier of the result.
+    ///
+    /// This is the identifier reported in [`ChosenInlineResult::result_id`].
+    ///
+    /// [`ChosenInlineResult::result_id`]: crate::types::ChosenInlineResult::result_id
+    pub fn id(&self) -> &str {
+        match self {
+            Self::CachedAudio(InlineQueryResultCachedAudio { id, .. })
+            | Self::CachedDocument(InlineQueryResultCachedDocument { id, .. })
+            | Self::CachedGif(InlineQueryResultCachedGif { id, .. })
+            | Self::CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif { id, .. })
+            | Self::CachedPhoto(InlineQueryResultCachedPhoto { id, .. })
+            | Self::CachedSticker(InlineQueryResultCachedSticker { id, .. })
+            | Self::CachedVideo(InlineQueryResultCachedVideo { id, .. })
+            | Self::CachedVoice(InlineQueryResultCachedVoice { id, .. })
+            | Self::Article(InlineQueryResultArticle { id, .. })
+            | Self::Audio(InlineQueryResultAudio { id, .. })
+            | Self::Contact(InlineQueryResultContact { id, .. })
+            | Self::Game(InlineQueryResultGame { id, .. })
+            | Self::Document(InlineQueryResultDocument { id, .. })
+            | Self::Gif(InlineQueryResultGif { id, .. })
+            | Self::Location(InlineQueryResultLocation { id, .. })
+            | Self::Mpeg4Gif(InlineQueryResultMpeg4Gif { id, .. })
+            | Self::Photo(InlineQueryResultPhoto { id, .. })
+            | Self::Venue(InlineQueryResultVenue { id, .. })
+            | Self::Video(InlineQueryResultVideo { id, .. })
+            | Self::Voice(InlineQueryResultVoice { id, .. }) => id,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::{
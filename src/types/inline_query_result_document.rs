@@ -53,13 +53,13 @@ pub struct InlineQueryResultDocument {
     pub input_message_content: Option<InputMessageContent>,
 
     /// URL of the thumbnail (jpeg only) for the file.
-    pub thumb_url: Option<String>,
+    pub thumbnail_url: Option<String>,
 
     /// Thumbnail width.
-    pub thumb_width: Option<i32>,
+    pub thumbnail_width: Option<i32>,
 
     /// Thumbnail height.
-    pub thumb_height: Option<i32>,
+    pub thumbnail_height: Option<i32>,
 }
 
 impl InlineQueryResultDocument {
@@ -131,21 +131,39 @@ impl InlineQueryResultDocument {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = Some(val.into());
+        self.thumbnail_url = Some(val.into());
         self
     }
 
-    pub fn thumb_width(mut self, val: i32) -> Self {
-        self.thumb_width = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
+    pub fn thumbnail_width(mut self, val: i32) -> Self {
+        self.thumbnail_width = Some(val);
         self
     }
 
-    pub fn thumb_height(mut self, val: i32) -> Self {
-        self.thumb_height = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_width`")]
+    pub fn thumb_width(self, val: i32) -> Self {
+        self.thumbnail_width(val)
+    }
+
+    pub fn thumbnail_height(mut self, val: i32) -> Self {
+        self.thumbnail_height = Some(val);
         self
     }
+
+    #[deprecated(note = "renamed to `thumbnail_height`")]
+    pub fn thumb_height(self, val: i32) -> Self {
+        self.thumbnail_height(val)
+    }
 }
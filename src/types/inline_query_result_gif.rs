@@ -28,7 +28,7 @@ pub struct InlineQueryResultGif {
     pub gif_duration: Option<i32>,
 
     /// URL of the static thumbnail for the result (jpeg or gif).
-    pub thumb_url: String,
+    pub thumbnail_url: String,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -58,7 +58,7 @@ pub struct InlineQueryResultGif {
 }
 
 impl InlineQueryResultGif {
-    pub fn new<S1, S2, S3>(id: S1, gif_url: S2, thumb_url: S3) -> Self
+    pub fn new<S1, S2, S3>(id: S1, gif_url: S2, thumbnail_url: S3) -> Self
     where
         S1: Into<String>,
         S2: Into<String>,
@@ -70,7 +70,7 @@ impl InlineQueryResultGif {
             gif_width: None,
             gif_height: None,
             gif_duration: None,
-            thumb_url: thumb_url.into(),
+            thumbnail_url: thumbnail_url.into(),
             title: None,
             caption: None,
             parse_mode: None,
@@ -111,14 +111,22 @@ impl InlineQueryResultGif {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = val.into();
+        self.thumbnail_url = val.into();
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
     pub fn title<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent};
+use crate::types::{Coordinates, InlineKeyboardMarkup, InputMessageContent};
 
 /// Represents a location on a map.
 ///
@@ -49,13 +49,13 @@ pub struct InlineQueryResultLocation {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<String>,
+    pub thumbnail_url: Option<String>,
 
     /// Thumbnail width.
-    pub thumb_width: Option<u32>,
+    pub thumbnail_width: Option<u32>,
 
     /// Thumbnail height.
-    pub thumb_height: Option<u32>,
+    pub thumbnail_height: Option<u32>,
 }
 
 impl InlineQueryResultLocation {
@@ -72,9 +72,9 @@ impl InlineQueryResultLocation {
             live_period: None,
             reply_markup: None,
             input_message_content: None,
-            thumb_url: None,
-            thumb_width: None,
-            thumb_height: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
             horizontal_accuracy: None,
             heading: None,
             proximity_alert_radius: None,
@@ -89,6 +89,20 @@ impl InlineQueryResultLocation {
         self
     }
 
+    /// Creates a result at the `coordinates`.
+    pub fn at<S1, S2>(id: S1, title: S2, coordinates: Coordinates) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self::new(id, title, coordinates.latitude(), coordinates.longitude())
+    }
+
+    /// Returns coordinates of the result or `None` if they are out of range.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        Coordinates::new(self.latitude, self.longitude)
+    }
+
     pub fn latitude(mut self, val: f64) -> Self {
         self.latitude = val;
         self
@@ -137,21 +151,39 @@ impl InlineQueryResultLocation {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = Some(val.into());
+        self.thumbnail_url = Some(val.into());
         self
     }
 
-    pub fn thumb_width(mut self, val: u32) -> Self {
-        self.thumb_width = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
+    pub fn thumbnail_width(mut self, val: u32) -> Self {
+        self.thumbnail_width = Some(val);
         self
     }
 
-    pub fn thumb_height(mut self, val: u32) -> Self {
-        self.thumb_height = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_width`")]
+    pub fn thumb_width(self, val: u32) -> Self {
+        self.thumbnail_width(val)
+    }
+
+    pub fn thumbnail_height(mut self, val: u32) -> Self {
+        self.thumbnail_height = Some(val);
         self
     }
+
+    #[deprecated(note = "renamed to `thumbnail_height`")]
+    pub fn thumb_height(self, val: u32) -> Self {
+        self.thumbnail_height(val)
+    }
 }
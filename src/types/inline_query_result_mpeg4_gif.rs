@@ -29,7 +29,7 @@ pub struct InlineQueryResultMpeg4Gif {
     pub mpeg4_duration: Option<i32>,
 
     /// URL of the static thumbnail (jpeg or gif) for the result.
-    pub thumb_url: String,
+    pub thumbnail_url: String,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -59,7 +59,7 @@ pub struct InlineQueryResultMpeg4Gif {
 }
 
 impl InlineQueryResultMpeg4Gif {
-    pub fn new<S1, S2, S3>(id: S1, mpeg4_url: S2, thumb_url: S3) -> Self
+    pub fn new<S1, S2, S3>(id: S1, mpeg4_url: S2, thumbnail_url: S3) -> Self
     where
         S1: Into<String>,
         S2: Into<String>,
@@ -68,7 +68,7 @@ impl InlineQueryResultMpeg4Gif {
         Self {
             id: id.into(),
             mpeg4_url: mpeg4_url.into(),
-            thumb_url: thumb_url.into(),
+            thumbnail_url: thumbnail_url.into(),
             mpeg4_width: None,
             mpeg4_height: None,
             mpeg4_duration: None,
@@ -112,14 +112,22 @@ impl InlineQueryResultMpeg4Gif {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = val.into();
+        self.thumbnail_url = val.into();
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
     pub fn title<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
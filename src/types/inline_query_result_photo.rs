@@ -20,7 +20,7 @@ pub struct InlineQueryResultPhoto {
     pub photo_url: String,
 
     /// URL of the thumbnail for the photo.
-    pub thumb_url: String,
+    pub thumbnail_url: String,
 
     /// Width of the photo.
     pub photo_width: Option<i32>,
@@ -59,7 +59,7 @@ pub struct InlineQueryResultPhoto {
 }
 
 impl InlineQueryResultPhoto {
-    pub fn new<S1, S2, S3>(id: S1, photo_url: S2, thumb_url: S3) -> Self
+    pub fn new<S1, S2, S3>(id: S1, photo_url: S2, thumbnail_url: S3) -> Self
     where
         S1: Into<String>,
         S2: Into<String>,
@@ -68,7 +68,7 @@ impl InlineQueryResultPhoto {
         Self {
             id: id.into(),
             photo_url: photo_url.into(),
-            thumb_url: thumb_url.into(),
+            thumbnail_url: thumbnail_url.into(),
             photo_width: None,
             photo_height: None,
             title: None,
@@ -97,14 +97,22 @@ impl InlineQueryResultPhoto {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = val.into();
+        self.thumbnail_url = val.into();
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
     pub fn photo_width<S>(mut self, val: i32) -> Self {
         self.photo_width = Some(val);
         self
@@ -52,13 +52,13 @@ pub struct InlineQueryResultVenue {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<String>,
+    pub thumbnail_url: Option<String>,
 
     /// Thumbnail width.
-    pub thumb_width: Option<i32>,
+    pub thumbnail_width: Option<i32>,
 
     /// Thumbnail height.
-    pub thumb_height: Option<i32>,
+    pub thumbnail_height: Option<i32>,
 }
 
 impl InlineQueryResultVenue {
@@ -80,9 +80,9 @@ impl InlineQueryResultVenue {
             google_place_type: None,
             reply_markup: None,
             input_message_content: None,
-            thumb_url: None,
-            thumb_width: None,
-            thumb_height: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
         }
     }
 
@@ -162,21 +162,39 @@ impl InlineQueryResultVenue {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = Some(val.into());
+        self.thumbnail_url = Some(val.into());
         self
     }
 
-    pub fn thumb_width(mut self, val: i32) -> Self {
-        self.thumb_width = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
+    pub fn thumbnail_width(mut self, val: i32) -> Self {
+        self.thumbnail_width = Some(val);
         self
     }
 
-    pub fn thumb_height(mut self, val: i32) -> Self {
-        self.thumb_height = Some(val);
+    #[deprecated(note = "renamed to `thumbnail_width`")]
+    pub fn thumb_width(self, val: i32) -> Self {
+        self.thumbnail_width(val)
+    }
+
+    pub fn thumbnail_height(mut self, val: i32) -> Self {
+        self.thumbnail_height = Some(val);
         self
     }
+
+    #[deprecated(note = "renamed to `thumbnail_height`")]
+    pub fn thumb_height(self, val: i32) -> Self {
+        self.thumbnail_height(val)
+    }
 }
@@ -25,7 +25,7 @@ pub struct InlineQueryResultVideo {
     pub mime_type: Mime,
 
     /// URL of the thumbnail (jpeg only) for the video.
-    pub thumb_url: String,
+    pub thumbnail_url: String,
 
     /// Title for the result.
     pub title: String,
@@ -76,7 +76,7 @@ impl InlineQueryResultVideo {
         id: S1,
         video_url: S2,
         mime_type: Mime,
-        thumb_url: S3,
+        thumbnail_url: S3,
         title: S4,
     ) -> Self
     where
@@ -89,7 +89,7 @@ impl InlineQueryResultVideo {
             id: id.into(),
             video_url: video_url.into(),
             mime_type,
-            thumb_url: thumb_url.into(),
+            thumbnail_url: thumbnail_url.into(),
             title: title.into(),
             caption: None,
             parse_mode: None,
@@ -124,14 +124,22 @@ impl InlineQueryResultVideo {
         self
     }
 
-    pub fn thumb_url<S>(mut self, val: S) -> Self
+    pub fn thumbnail_url<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
     {
-        self.thumb_url = val.into();
+        self.thumbnail_url = val.into();
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail_url`")]
+    pub fn thumb_url<S>(self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.thumbnail_url(val)
+    }
+
     pub fn title<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::WebAppInfo;
+
+/// This object represents a button to be shown above inline query results.
+///
+/// [The official docs](https://core.telegram.org/bots/api#inlinequeryresultsbutton).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct InlineQueryResultsButton {
+    /// Label text on the button.
+    pub text: String,
+
+    /// What happens when the button is pressed.
+    #[serde(flatten)]
+    pub kind: InlineQueryResultsButtonKind,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InlineQueryResultsButtonKind {
+    /// Description of the [Web App] that will be launched when the user
+    /// presses the button. The Web App will be able to switch back to the
+    /// inline mode using the method `switchInlineQuery` inside the Web App.
+    ///
+    /// [Web App]: https://core.telegram.org/bots/webapps
+    WebApp(WebAppInfo),
+
+    /// [Deep-linking] parameter for the `/start` message sent to the bot when
+    /// a user presses the button. 1-64 characters, only `A-Z`, `a-z`, `0-9`,
+    /// `_` and `-` are allowed.
+    ///
+    /// [Deep-linking]: https://core.telegram.org/bots#deep-linking
+    StartParameter(String),
+}
+
+impl InlineQueryResultsButton {
+    /// Creates a button which launches a Web App.
+    pub fn web_app<S>(text: S, web_app: WebAppInfo) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            kind: InlineQueryResultsButtonKind::WebApp(web_app),
+        }
+    }
+
+    /// Creates a button which switches the user to a private chat with the bot
+    /// and sends `/start start_parameter`.
+    pub fn start_parameter<S1, S2>(text: S1, start_parameter: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            text: text.into(),
+            kind: InlineQueryResultsButtonKind::StartParameter(start_parameter.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let button = InlineQueryResultsButton::start_parameter("Connect", "connect");
+        assert_eq!(
+            serde_json::to_string(&button).unwrap(),
+            r#"{"text":"Connect","start_parameter":"connect"}"#
+        );
+
+        let button =
+            InlineQueryResultsButton::web_app("Open", WebAppInfo::new("https://example.com"));
+        assert_eq!(
+            serde_json::to_string(&button).unwrap(),
+            r#"{"text":"Open","web_app":{"url":"https://example.com"}}"#
+        );
+    }
+}
@@ -1,6 +1,33 @@
-use serde::{Deserialize, Serialize};
+use bytes::Bytes;
+use futures::{
+    future::BoxFuture,
+    stream::{BoxStream, Stream, StreamExt},
+    FutureExt, TryFutureExt,
+};
+use mime::Mime;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use std::{borrow::Cow, path::PathBuf};
+use std::{
+    borrow::Cow,
+    collections::{hash_map::RandomState, HashMap},
+    fmt,
+    future::Future,
+    hash::{BuildHasher, Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError, Weak,
+    },
+    task::{Context, Poll},
+};
+
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+
+use crate::ReopenableFileError;
 
 /// This object represents the contents of a file to be uploaded.
 ///
@@ -15,6 +42,12 @@ pub enum InputFile {
     },
     Url(String),
     FileId(String),
+    /// A file which is (re)opened every time it's uploaded, see
+    /// [`InputFile::reopenable`].
+    Reopenable(ReopenableFile),
+    /// A file with an overridden file name or MIME type, see
+    /// [`InputFile::file_name`] and [`InputFile::mime_type`].
+    WithMetadata(FileMetadata),
 }
 
 impl InputFile {
@@ -36,6 +69,40 @@ impl InputFile {
         }
     }
 
+    /// Creates a file which content is produced by the `open` callback.
+    ///
+    /// The callback is called every time the file is uploaded, so unlike a
+    /// plain stream such a file can be cloned and a request with it can be
+    /// sent multiple times (e.g. retried after a network error).
+    ///
+    /// Such files can't be restored from their serialized form in another
+    /// process, see [`ReopenableFile`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use futures::stream;
+    /// use teloxide_core::types::InputFile;
+    ///
+    /// let file = InputFile::reopenable("hello.txt", || async {
+    ///     let chunks = vec![Ok(Bytes::from("hello, ")), Ok(Bytes::from("world"))];
+    ///     Ok(stream::iter(chunks))
+    /// });
+    ///
+    /// // Both uploads call the callback
+    /// let _copy = file.clone();
+    /// ```
+    pub fn reopenable<S, F, Fut, St>(file_name: S, open: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = io::Result<St>> + Send + 'static,
+        St: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        Self::Reopenable(ReopenableFile::new(file_name, open))
+    }
+
     pub fn url<T>(url: T) -> Self
     where
         T: Into<String>,
@@ -50,6 +117,60 @@ impl InputFile {
         Self::FileId(file_id.into())
     }
 
+    /// Sets the name of the file which is sent to Telegram, instead of the name
+    /// of the path or the name passed on creation.
+    ///
+    /// Telegram treats some uploads differently based on the extension of the
+    /// file name, e.g. this allows to send a file under a masked name. The name
+    /// of [URLs] and [file ids] can't be changed, so they are returned as is.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::InputFile;
+    ///
+    /// let voice = InputFile::file("/tmp/recording")
+    ///     .file_name("voice.ogg")
+    ///     .mime_type("audio/ogg".parse().unwrap());
+    /// # let _ = voice;
+    /// ```
+    ///
+    /// [URLs]: InputFile::Url
+    /// [file ids]: InputFile::FileId
+    pub fn file_name<S>(self, file_name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.map_metadata(|meta| meta.file_name = Some(file_name.into()))
+    }
+
+    /// Sets the MIME type (the `Content-Type` of the multipart part) of the
+    /// file, e.g. `audio/ogg` for voice messages encoded with OPUS.
+    ///
+    /// By default no MIME type is sent and Telegram guesses it. The MIME type
+    /// of [URLs] and [file ids] can't be changed, so they are returned as is.
+    ///
+    /// [URLs]: InputFile::Url
+    /// [file ids]: InputFile::FileId
+    pub fn mime_type(self, mime_type: Mime) -> Self {
+        self.map_metadata(|meta| meta.mime_type = Some(mime_type))
+    }
+
+    fn map_metadata(self, f: impl FnOnce(&mut FileMetadata)) -> Self {
+        let mut meta = match self {
+            this @ Self::Url(_) | this @ Self::FileId(_) => return this,
+            Self::WithMetadata(meta) => meta,
+            file => FileMetadata {
+                file: Box::new(file),
+                file_name: None,
+                mime_type: None,
+            },
+        };
+
+        f(&mut meta);
+        Self::WithMetadata(meta)
+    }
+
     pub fn as_file(&self) -> Option<&PathBuf> {
         match self {
             Self::File(path) => Some(path),
@@ -72,6 +193,173 @@ impl InputFile {
     }
 }
 
+/// A file with an overridden file name or MIME type, see
+/// [`InputFile::WithMetadata`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct FileMetadata {
+    /// The file itself.
+    pub file: Box<InputFile>,
+
+    /// The file name to send instead of the file name of the [`file`].
+    ///
+    /// [`file`]: FileMetadata::file
+    pub file_name: Option<String>,
+
+    /// The MIME type of the file.
+    #[serde(with = "crate::types::non_telegram_types::mime::opt_deser")]
+    pub mime_type: Option<Mime>,
+}
+
+type OpenFuture = BoxFuture<'static, io::Result<BoxStream<'static, io::Result<Bytes>>>>;
+
+/// Openers of all alive [`ReopenableFile`]s by id.
+///
+/// Multipart serialization reconstructs files from their serialized form, so
+/// a reopenable file is serialized as its id and looked up here.
+static OPENERS: Lazy<Mutex<HashMap<u64, Weak<Opener>>>> = Lazy::new(Default::default);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Random number which identifies this process, ids are only meaningful in
+/// the process which created them, so ids serialized by other processes (e.g.
+/// persisted and restored after a restart) are rejected.
+static PROCESS: Lazy<u64> = Lazy::new(|| {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    hasher.finish()
+});
+
+/// Locks [`OPENERS`], a panic while it was locked can't leave the map in an
+/// inconsistent state.
+fn openers() -> MutexGuard<'static, HashMap<u64, Weak<Opener>>> {
+    OPENERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+struct Opener {
+    id: u64,
+    open: Box<dyn Fn() -> OpenFuture + Send + Sync>,
+}
+
+impl Drop for Opener {
+    fn drop(&mut self) {
+        openers().remove(&self.id);
+    }
+}
+
+/// A file which content is produced by a callback, see
+/// [`InputFile::reopenable`].
+///
+/// Clones of a `ReopenableFile` share the callback and are equal to each
+/// other.
+///
+/// The callback can't be serialized, so the file is serialized by its id, and
+/// can't be deserialized in another process or after all its clones are
+/// dropped (see [`ReopenableFileError`]). Don't persist requests with such
+/// files.
+#[derive(Clone)]
+pub struct ReopenableFile {
+    file_name: String,
+    opener: Arc<Opener>,
+}
+
+impl ReopenableFile {
+    fn new<S, F, Fut, St>(file_name: S, open: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = io::Result<St>> + Send + 'static,
+        St: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let opener = Arc::new(Opener {
+            id,
+            open: Box::new(move || open().map_ok(StreamExt::boxed).boxed()),
+        });
+
+        openers().insert(id, Arc::downgrade(&opener));
+
+        Self {
+            file_name: file_name.into(),
+            opener,
+        }
+    }
+
+    /// Returns the name of the file.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Calls the callback, returning a fresh stream of the file content.
+    pub fn open(&self) -> impl Future<Output = io::Result<BoxStream<'static, io::Result<Bytes>>>> {
+        (self.opener.open)()
+    }
+}
+
+impl fmt::Debug for ReopenableFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReopenableFile")
+            .field("file_name", &self.file_name)
+            .field("id", &self.opener.id)
+            .finish()
+    }
+}
+
+impl PartialEq for ReopenableFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_name == other.file_name && self.opener.id == other.opener.id
+    }
+}
+
+impl Eq for ReopenableFile {}
+
+impl Hash for ReopenableFile {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.file_name.hash(state);
+        self.opener.id.hash(state);
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ReopenableFile")]
+struct ReopenableFileRepr<'a> {
+    file_name: Cow<'a, str>,
+    id: u64,
+    process: u64,
+}
+
+impl Serialize for ReopenableFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ReopenableFileRepr {
+            file_name: Cow::Borrowed(&self.file_name),
+            id: self.opener.id,
+            process: *PROCESS,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Note that only files which are still alive (i.e. at least one clone of
+/// the file wasn't dropped) and were serialized by this process can be
+/// deserialized, other files fail with a [`ReopenableFileError`].
+impl<'de> Deserialize<'de> for ReopenableFile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = ReopenableFileRepr::deserialize(deserializer)?;
+        if repr.process != *PROCESS {
+            return Err(serde::de::Error::custom(ReopenableFileError::OtherProcess));
+        }
+
+        let opener = openers()
+            .get(&repr.id)
+            .and_then(Weak::upgrade)
+            .ok_or_else(|| serde::de::Error::custom(ReopenableFileError::Dropped(repr.id)))?;
+
+        Ok(Self {
+            file_name: repr.file_name.into_owned(),
+            opener,
+        })
+    }
+}
+
 impl From<InputFile> for Option<PathBuf> {
     fn from(file: InputFile) -> Self {
         match file {
@@ -86,39 +374,221 @@ impl From<InputFile> for Option<PathBuf> {
 use reqwest::multipart::Part;
 
 impl InputFile {
+    /// Reads the file into memory if it's a file on disk which is at most
+    /// `max_size` bytes long, other files are returned as is.
+    pub(crate) async fn buffer(mut self, max_size: u64) -> std::io::Result<Self> {
+        // Metadata is kept, only the file itself is replaced
+        let mut file = &mut self;
+        while let Self::WithMetadata(meta) = file {
+            file = &mut meta.file;
+        }
+
+        if let Self::File(path) = file {
+            if tokio::fs::metadata(&*path).await?.len() <= max_size {
+                let data = tokio::fs::read(&*path).await?;
+                *file = Self::Memory {
+                    file_name: file_name_of(path),
+                    data: Cow::Owned(data),
+                };
+            }
+        }
+
+        Ok(self)
+    }
+
     pub(crate) async fn into_part(self) -> std::io::Result<Part> {
-        use bytes::{Bytes, BytesMut};
         use reqwest::Body;
-        use tokio_util::codec::{Decoder, FramedRead};
 
-        struct FileDecoder;
+        // Outer overrides take precedence over the inner ones
+        let mut file = self;
+        let mut file_name = None;
+        let mut mime_type = None;
+        while let Self::WithMetadata(meta) = file {
+            file_name = file_name.or(meta.file_name);
+            mime_type = mime_type.or(meta.mime_type);
+            file = *meta.file;
+        }
+
+        let mut part = match file {
+            Self::File(path_to_file) => {
+                let file_name = file_name_of(&path_to_file);
 
-        impl Decoder for FileDecoder {
-            type Item = Bytes;
-            type Error = std::io::Error;
+                let file = tokio::fs::File::open(path_to_file).await?;
+                // Knowing the length allows to set `Content-Length` of the request,
+                // the stream fails the upload if the file changes meanwhile
+                let len = file.metadata().await?.len();
+                let file = FileStream::new(file, len);
 
-            fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
-                if src.is_empty() {
-                    return Ok(None);
-                }
-                Ok(Some(src.split().freeze()))
+                Part::stream_with_length(Body::wrap_stream(file), len).file_name(file_name)
+            }
+            Self::Memory { file_name, data } => Part::bytes(data).file_name(file_name),
+            Self::Reopenable(file) => {
+                let stream = file.open().await?;
+                Part::stream(Body::wrap_stream(stream)).file_name(file.file_name)
             }
+            Self::Url(s) | Self::FileId(s) => return Ok(Part::text(s)),
+            Self::WithMetadata(_) => unreachable!("metadata was unwrapped above"),
+        };
+
+        if let Some(file_name) = file_name {
+            part = part.file_name(file_name);
+        }
+        if let Some(mime_type) = mime_type {
+            part = part
+                .mime_str(mime_type.as_ref())
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
         }
 
-        match self {
-            Self::File(path_to_file) => {
-                let file_name = path_to_file
-                    .file_name()
-                    .unwrap()
-                    .to_string_lossy()
-                    .into_owned();
+        Ok(part)
+    }
+}
 
-                let file = FramedRead::new(tokio::fs::File::open(path_to_file).await?, FileDecoder);
+/// Returns the name of the file at the `path` to send to Telegram.
+fn file_name_of(path: &Path) -> String {
+    // Paths like `/` or `..` have no file name, Telegram requires one, but
+    // doesn't care which
+    path.file_name().map_or_else(
+        || "file".to_owned(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
 
-                Ok(Part::stream(Body::wrap_stream(file)).file_name(file_name))
+/// Size of chunks in which files are read from disk while uploading.
+///
+/// Bigger chunks (the default of `tokio` is 8 KiB) mean fewer reads and fewer
+/// chunks copied into the request body.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Stream of a file which must be exactly `remaining` bytes long, since the
+/// length is sent before the file.
+#[pin_project::pin_project]
+struct FileStream<R> {
+    #[pin]
+    inner: ReaderStream<R>,
+    remaining: u64,
+}
+
+impl<R: AsyncRead> FileStream<R> {
+    fn new(reader: R, len: u64) -> Self {
+        Self {
+            inner: ReaderStream::with_capacity(reader, UPLOAD_CHUNK_SIZE),
+            remaining: len,
+        }
+    }
+}
+
+impl<R: AsyncRead> Stream for FileStream<R> {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let res = match futures::ready!(this.inner.poll_next(cx)) {
+            Some(Ok(chunk)) if chunk.len() as u64 > *this.remaining => Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the file has grown while uploading",
+            ))),
+            Some(Ok(chunk)) => {
+                *this.remaining -= chunk.len() as u64;
+                Some(Ok(chunk))
+            }
+            None if *this.remaining != 0 => {
+                *this.remaining = 0;
+                Some(Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "the file has shrunk while uploading",
+                )))
+            }
+            res => res,
+        };
+
+        Poll::Ready(res)
+    }
+}
+
+/// Recursively replaces serialized [`InputFile::Memory`]s in `value` with
+/// their names and lengths, so that contents of the files are not persisted
+/// or logged.
+#[cfg(any(feature = "audit", feature = "outbox", feature = "error_hook"))]
+pub(crate) fn summarize_memory_files(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => {
+            let summary = match map.get("Memory") {
+                Some(Value::Object(file)) if map.len() == 1 => {
+                    match (file.get("file_name"), file.get("data")) {
+                        (Some(Value::String(name)), Some(Value::Array(data))) => {
+                            Some(format!("<file `{}`, {} bytes>", name, data.len()))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            match summary {
+                Some(summary) => *value = Value::String(summary),
+                None => map.values_mut().for_each(summarize_memory_files),
             }
-            Self::Memory { file_name, data } => Ok(Part::bytes(data).file_name(file_name)),
-            Self::Url(s) | Self::FileId(s) => Ok(Part::text(s)),
         }
+        Value::Array(values) => values.iter_mut().for_each(summarize_memory_files),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    #[test]
+    fn file_length_is_checked() {
+        let read = |remaining| {
+            let file = FileStream::new(&b"content"[..], remaining);
+            futures::executor::block_on(file.map_ok(|b| b.to_vec()).try_concat())
+                .map_err(|err| err.kind())
+        };
+
+        assert_eq!(read(7).unwrap(), &b"content"[..]);
+        assert_eq!(read(4).unwrap_err(), io::ErrorKind::InvalidData);
+        assert_eq!(read(10).unwrap_err(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn files_are_read_by_big_chunks() {
+        let data = vec![0; 2 * UPLOAD_CHUNK_SIZE + 1];
+        let file = FileStream::new(&data[..], data.len() as u64);
+        let chunks: Vec<_> =
+            futures::executor::block_on(file.map_ok(|b| b.len()).try_collect()).unwrap();
+
+        assert_eq!(chunks, [UPLOAD_CHUNK_SIZE, UPLOAD_CHUNK_SIZE, 1]);
+    }
+
+    #[test]
+    fn unrestorable_reopenable_files_are_rejected() {
+        let file = ReopenableFile::new("file", || async { Ok(futures::stream::empty()) });
+        let value = serde_json::to_value(&file).unwrap();
+        assert_eq!(
+            serde_json::from_value::<ReopenableFile>(value.clone()).unwrap(),
+            file
+        );
+
+        let mut other = value.clone();
+        other["process"] = (*PROCESS ^ 1).into();
+        let err = serde_json::from_value::<ReopenableFile>(other).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ReopenableFileError::OtherProcess.to_string()
+        );
+
+        let id = file.opener.id;
+        drop(file);
+        let err = serde_json::from_value::<ReopenableFile>(value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ReopenableFileError::Dropped(id).to_string()
+        );
     }
 }
@@ -39,6 +39,13 @@ pub struct InputMediaPhoto {
     /// List of special entities that appear in the caption, which can be
     /// specified instead of `parse_mode`.
     pub caption_entities: Option<Vec<MessageEntity>>,
+
+    /// Pass `true` if the photo needs to be covered with a spoiler
+    /// animation.
+    pub has_spoiler: Option<bool>,
+
+    /// Pass `true`, if the caption must be shown above the message media.
+    pub show_caption_above_media: Option<bool>,
 }
 
 impl InputMediaPhoto {
@@ -48,6 +55,8 @@ impl InputMediaPhoto {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         }
     }
 
@@ -68,6 +77,24 @@ impl InputMediaPhoto {
         self.parse_mode = Some(val);
         self
     }
+
+    pub fn caption_entities<C>(mut self, val: C) -> Self
+    where
+        C: IntoIterator<Item = MessageEntity>,
+    {
+        self.caption_entities = Some(val.into_iter().collect());
+        self
+    }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
+
+    pub const fn show_caption_above_media(mut self, val: bool) -> Self {
+        self.show_caption_above_media = Some(val);
+        self
+    }
 }
 
 /// Represents a video to be sent.
@@ -84,7 +111,7 @@ pub struct InputMediaVideo {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the video to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -112,13 +139,20 @@ pub struct InputMediaVideo {
 
     /// Pass `true`, if the uploaded video is suitable for streaming.
     pub supports_streaming: Option<bool>,
+
+    /// Pass `true` if the video needs to be covered with a spoiler
+    /// animation.
+    pub has_spoiler: Option<bool>,
+
+    /// Pass `true`, if the caption must be shown above the message media.
+    pub show_caption_above_media: Option<bool>,
 }
 
 impl InputMediaVideo {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
@@ -126,6 +160,8 @@ impl InputMediaVideo {
             height: None,
             duration: None,
             supports_streaming: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         }
     }
 
@@ -134,11 +170,16 @@ impl InputMediaVideo {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail`")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -179,6 +220,16 @@ impl InputMediaVideo {
         self.supports_streaming = Some(val);
         self
     }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
+
+    pub const fn show_caption_above_media(mut self, val: bool) -> Self {
+        self.show_caption_above_media = Some(val);
+        self
+    }
 }
 
 /// Represents an animation file (GIF or H.264/MPEG-4 AVC video without
@@ -196,7 +247,7 @@ pub struct InputMediaAnimation {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the animation to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -221,19 +272,28 @@ pub struct InputMediaAnimation {
 
     /// Animation duration.
     pub duration: Option<u16>,
+
+    /// Pass `true` if the animation needs to be covered with a spoiler
+    /// animation.
+    pub has_spoiler: Option<bool>,
+
+    /// Pass `true`, if the caption must be shown above the message media.
+    pub show_caption_above_media: Option<bool>,
 }
 
 impl InputMediaAnimation {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
             height: None,
             duration: None,
             caption_entities: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         }
     }
 
@@ -242,11 +302,16 @@ impl InputMediaAnimation {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail`")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -282,6 +347,16 @@ impl InputMediaAnimation {
         self.duration = Some(val);
         self
     }
+
+    pub const fn has_spoiler(mut self, val: bool) -> Self {
+        self.has_spoiler = Some(val);
+        self
+    }
+
+    pub const fn show_caption_above_media(mut self, val: bool) -> Self {
+        self.show_caption_above_media = Some(val);
+        self
+    }
 }
 
 /// Represents an audio file to be treated as music to be sent.
@@ -298,7 +373,7 @@ pub struct InputMediaAudio {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the audio to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -329,7 +404,7 @@ impl InputMediaAudio {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             performer: None,
@@ -344,11 +419,16 @@ impl InputMediaAudio {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail`")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -406,7 +486,7 @@ pub struct InputMediaDocument {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the document to be sent, 0-1024 charactersю
     pub caption: Option<String>,
@@ -433,7 +513,7 @@ impl InputMediaDocument {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             disable_content_type_detection: None,
@@ -446,11 +526,16 @@ impl InputMediaDocument {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(note = "renamed to `thumbnail`")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -510,6 +595,8 @@ mod tests {
             caption: None,
             parse_mode: None,
             caption_entities: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         });
 
         let actual_json = serde_json::to_string(&photo).unwrap();
@@ -521,7 +608,7 @@ mod tests {
         let expected_json = r#"{"type":"video","media":{"FileId":"123456"}}"#;
         let video = InputMedia::Video(InputMediaVideo {
             media: InputFile::FileId(String::from("123456")),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
@@ -529,6 +616,8 @@ mod tests {
             duration: None,
             supports_streaming: None,
             caption_entities: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         });
 
         let actual_json = serde_json::to_string(&video).unwrap();
@@ -540,13 +629,15 @@ mod tests {
         let expected_json = r#"{"type":"animation","media":{"FileId":"123456"}}"#;
         let video = InputMedia::Animation(InputMediaAnimation {
             media: InputFile::FileId(String::from("123456")),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
             height: None,
             duration: None,
             caption_entities: None,
+            has_spoiler: None,
+            show_caption_above_media: None,
         });
 
         let actual_json = serde_json::to_string(&video).unwrap();
@@ -558,7 +649,7 @@ mod tests {
         let expected_json = r#"{"type":"audio","media":{"FileId":"123456"}}"#;
         let video = InputMedia::Audio(InputMediaAudio {
             media: InputFile::FileId(String::from("123456")),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             duration: None,
@@ -576,7 +667,7 @@ mod tests {
         let expected_json = r#"{"type":"document","media":{"FileId":"123456"}}"#;
         let video = InputMedia::Document(InputMediaDocument {
             media: InputFile::FileId(String::from("123456")),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
@@ -586,4 +677,19 @@ mod tests {
         let actual_json = serde_json::to_string(&video).unwrap();
         assert_eq!(expected_json, actual_json);
     }
+
+    #[test]
+    fn photo_builder() {
+        let expected_json = r#"{"type":"photo","media":{"FileId":"123456"},"caption":"*a*","parse_mode":"MarkdownV2","has_spoiler":true,"show_caption_above_media":true}"#;
+        let photo = InputMedia::Photo(
+            InputMediaPhoto::new(InputFile::FileId(String::from("123456")))
+                .caption("*a*")
+                .parse_mode(ParseMode::MarkdownV2)
+                .has_spoiler(true)
+                .show_caption_above_media(true),
+        );
+
+        let actual_json = serde_json::to_string(&photo).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
 }
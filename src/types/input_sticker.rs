@@ -1,9 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::types::InputFile;
+use crate::types::{InputFile, StickerFormat};
 
 /// Sticker file that may be uploaded to telegram.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum InputSticker {
     /// PNG image with the sticker, must be up to 512 kilobytes in size,
     /// dimensions must not exceed 512px, and either width or height must be
@@ -27,4 +27,44 @@ pub enum InputSticker {
     /// See <https://core.telegram.org/animated_stickers#technical-requirements> for technical requirements
     #[serde(rename = "tgs_sticker")]
     Tgs(InputFile),
+
+    /// WEBM video with the sticker, uploaded using multipart/form-data.
+    ///
+    /// See <https://core.telegram.org/stickers#video-sticker-requirements> for technical requirements
+    #[serde(rename = "webm_sticker")]
+    Webm(InputFile),
+}
+
+impl InputSticker {
+    /// Creates a sticker of the `format` from the `file`.
+    pub fn new(file: InputFile, format: StickerFormat) -> Self {
+        match format {
+            StickerFormat::Static => Self::Png(file),
+            StickerFormat::Animated => Self::Tgs(file),
+            StickerFormat::Video => Self::Webm(file),
+        }
+    }
+
+    /// Returns the format of the sticker.
+    pub fn format(&self) -> StickerFormat {
+        match self {
+            Self::Png(_) => StickerFormat::Static,
+            Self::Tgs(_) => StickerFormat::Animated,
+            Self::Webm(_) => StickerFormat::Video,
+        }
+    }
+
+    /// Returns the sticker file.
+    pub fn file(&self) -> &InputFile {
+        match self {
+            Self::Png(file) | Self::Tgs(file) | Self::Webm(file) => file,
+        }
+    }
+
+    /// Unwraps the sticker file.
+    pub fn into_file(self) -> InputFile {
+        match self {
+            Self::Png(file) | Self::Tgs(file) | Self::Webm(file) => file,
+        }
+    }
 }
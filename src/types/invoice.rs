@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::Currency;
+
 /// This object contains basic information about an invoice.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#invoice).
@@ -15,8 +17,10 @@ pub struct Invoice {
     /// invoice.
     pub start_parameter: String,
 
-    /// Three-letter ISO 4217 currency code.
-    pub currency: String,
+    /// Three-letter ISO 4217 [currency] code.
+    ///
+    /// [currency]: https://core.telegram.org/bots/payments#supported-currencies
+    pub currency: Currency,
 
     /// Total price in the smallest units of the currency (integer, **not**
     /// float/double). For example, for a price of `US$ 1.45` pass `amount =
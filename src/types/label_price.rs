@@ -42,6 +42,73 @@ impl LabeledPrice {
         self.amount = val;
         self
     }
+
+    /// Creates a price from a decimal `amount` in the major units of a
+    /// currency with `exp` digits past the decimal point (see
+    /// [`LabeledPrice::amount`]), e.g. `"1.45"` with `exp = 2` is `145`.
+    ///
+    /// Unlike converting from floats, this is exact. Decimal types (e.g. from
+    /// `rust_decimal`) can be converted via their `to_string`.
+    ///
+    /// Returns `None` if the `amount` is not a decimal number, has more than
+    /// `exp` significant digits past the decimal point or doesn't fit into
+    /// `i32`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::LabeledPrice;
+    ///
+    /// assert_eq!(LabeledPrice::from_decimal("Tea", "1.45", 2).unwrap().amount, 145);
+    /// assert_eq!(LabeledPrice::from_decimal("Tea", "-3", 2).unwrap().amount, -300);
+    /// assert_eq!(LabeledPrice::from_decimal("Tea", "1.455", 2), None);
+    /// ```
+    pub fn from_decimal<S>(label: S, amount: &str, exp: u32) -> Option<Self>
+    where
+        S: Into<String>,
+    {
+        Some(Self::new(label, parse_decimal(amount, exp)?))
+    }
+
+    /// Returns the sum of amounts of the `prices`, or `None` if it overflows.
+    pub fn total(prices: &[LabeledPrice]) -> Option<i32> {
+        prices
+            .iter()
+            .try_fold(0i32, |total, price| total.checked_add(price.amount))
+    }
+}
+
+/// Parses a decimal number into minor units with `exp` digits past the
+/// decimal point.
+fn parse_decimal(amount: &str, exp: u32) -> Option<i32> {
+    let (negative, amount) = match amount.strip_prefix('-') {
+        Some(amount) => (true, amount),
+        None => (false, amount.strip_prefix('+').unwrap_or(amount)),
+    };
+    let (int, frac) = match amount.find('.') {
+        Some(dot) => (&amount[..dot], &amount[dot + 1..]),
+        None => (amount, ""),
+    };
+
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (int.is_empty() && frac.is_empty()) || !digits(int) || !digits(frac) {
+        return None;
+    }
+
+    // Trailing zeros don't change the value, e.g. `1.50` is fine for `exp = 1`
+    let frac = frac.trim_end_matches('0');
+    if frac.len() > exp as usize {
+        return None;
+    }
+
+    let mut res: i64 = 0;
+    for digit in int.bytes().chain(frac.bytes()) {
+        res = res.checked_mul(10)?.checked_add(i64::from(digit - b'0'))?;
+    }
+    res = res.checked_mul(10i64.checked_pow(exp - frac.len() as u32)?)?;
+
+    let res = if negative { -res } else { res };
+    std::convert::TryFrom::try_from(res).ok()
 }
 
 #[cfg(test)]
@@ -58,4 +125,20 @@ mod tests {
         let actual = serde_json::to_string(&labeled_price).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn decimal() {
+        assert_eq!(parse_decimal("1.45", 2), Some(145));
+        assert_eq!(parse_decimal("1.5", 2), Some(150));
+        assert_eq!(parse_decimal("1.50", 1), Some(15));
+        assert_eq!(parse_decimal("+7", 0), Some(7));
+        assert_eq!(parse_decimal(".5", 2), Some(50));
+        assert_eq!(parse_decimal("-21474836.48", 2), Some(i32::MIN));
+        assert_eq!(parse_decimal("21474836.48", 2), None);
+        assert_eq!(parse_decimal("1.452", 2), None);
+        assert_eq!(parse_decimal("1,45", 2), None);
+        assert_eq!(parse_decimal(".", 2), None);
+        assert_eq!(parse_decimal("", 2), None);
+        assert_eq!(parse_decimal("1e3", 2), None);
+    }
 }
@@ -0,0 +1,194 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An [IETF language tag], e.g.: `en`, `en-US` or `zh-Hans-CN`.
+///
+/// Telegram sends language codes as chosen by a user's client, so they are not
+/// guaranteed to be well-formed. Well-formed tags are normalized (the language
+/// is lowercased, the region is uppercased and the script is titlecased),
+/// anything else is kept as is in [`LanguageCode::Other`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::LanguageCode;
+///
+/// let code = LanguageCode::parse("pt-br");
+/// assert_eq!(code.as_str(), "pt-BR");
+/// assert_eq!(code.language(), Some("pt"));
+/// assert_eq!(code.region(), Some("BR"));
+///
+/// assert!(code.matches("pt"));
+/// assert!(code.matches("PT-BR"));
+/// assert!(!code.matches("pt-PT"));
+/// assert!(!code.matches("p"));
+/// ```
+///
+/// [IETF language tag]: https://en.wikipedia.org/wiki/IETF_language_tag
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum LanguageCode {
+    /// A well-formed (and normalized) language tag.
+    Tag(String),
+
+    /// A language code which is not a well-formed language tag.
+    Other(String),
+}
+
+impl LanguageCode {
+    /// Parses a language code, see [`LanguageCode`].
+    pub fn parse(code: &str) -> Self {
+        normalize(code).map_or_else(|| Self::Other(code.to_owned()), Self::Tag)
+    }
+
+    /// Returns the language code as a string.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Tag(s) | Self::Other(s) => s,
+        }
+    }
+
+    /// Returns the primary language subtag, e.g.: `en` for `en-US`.
+    pub fn language(&self) -> Option<&str> {
+        match self {
+            Self::Tag(tag) => tag.split('-').next(),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Returns the region subtag, e.g.: `US` for `en-US` or `419` for `es-419`.
+    pub fn region(&self) -> Option<&str> {
+        match self {
+            Self::Tag(tag) => tag.split('-').skip(1).find(|subtag| is_region(subtag)),
+            Self::Other(_) => None,
+        }
+    }
+
+    /// Returns `true` if the language code starts with the `prefix` (ignoring
+    /// case), which must consist of whole subtags.
+    ///
+    /// E.g. `en` matches `en` and `en-US`, but not `enm` (Middle English).
+    /// [`LanguageCode::Other`] matches only the same string.
+    pub fn matches(&self, prefix: &str) -> bool {
+        let s = self.as_str();
+        match self {
+            Self::Tag(_) => {
+                s.len() >= prefix.len()
+                    && s[..prefix.len()].eq_ignore_ascii_case(prefix)
+                    && matches!(s.as_bytes().get(prefix.len()), None | Some(b'-'))
+                    && !prefix.is_empty()
+            }
+            Self::Other(_) => s.eq_ignore_ascii_case(prefix),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(code: String) -> Self {
+        match normalize(&code) {
+            Some(tag) => Self::Tag(tag),
+            None => Self::Other(code),
+        }
+    }
+}
+
+impl From<&str> for LanguageCode {
+    fn from(code: &str) -> Self {
+        Self::parse(code)
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(code: LanguageCode) -> Self {
+        match code {
+            LanguageCode::Tag(s) | LanguageCode::Other(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns the normalized `code` if it's a well-formed language tag.
+///
+/// This is a simplified check, which accepts `language(-subtag)*` where
+/// `language` is 2-3 letters and subtags are 1-8 alphanumeric characters
+/// (underscores, which some clients use, are treated as hyphens).
+fn normalize(code: &str) -> Option<String> {
+    let mut subtags = code.split(&['-', '_'][..]);
+
+    let language = subtags.next()?;
+    if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut res = language.to_ascii_lowercase();
+    for (i, subtag) in subtags.enumerate() {
+        if !(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        res.push('-');
+        if subtag.len() == 4 && i == 0 && subtag.bytes().all(|b| b.is_ascii_alphabetic()) {
+            // Script, e.g.: `Hans`
+            res.push_str(&subtag[..1].to_ascii_uppercase());
+            res.push_str(&subtag[1..].to_ascii_lowercase());
+        } else if is_region(subtag) {
+            res.push_str(&subtag.to_ascii_uppercase());
+        } else {
+            res.push_str(&subtag.to_ascii_lowercase());
+        }
+    }
+
+    Some(res)
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            LanguageCode::parse("EN"),
+            LanguageCode::Tag("en".to_owned())
+        );
+        assert_eq!(
+            LanguageCode::parse("zh_hans_cn"),
+            LanguageCode::Tag("zh-Hans-CN".to_owned())
+        );
+        assert_eq!(LanguageCode::parse("es-419").region(), Some("419"));
+        assert_eq!(
+            LanguageCode::parse("english"),
+            LanguageCode::Other("english".to_owned())
+        );
+        assert_eq!(LanguageCode::parse("").language(), None);
+    }
+
+    #[test]
+    fn matches() {
+        let code = LanguageCode::parse("en-GB");
+        assert!(code.matches("en"));
+        assert!(code.matches("en-gb"));
+        assert!(!code.matches("e"));
+        assert!(!code.matches(""));
+        assert!(!LanguageCode::parse("enm").matches("en"));
+        assert!(LanguageCode::parse("weird code").matches("WEIRD CODE"));
+    }
+
+    #[test]
+    fn serde() {
+        let code: LanguageCode = serde_json::from_str(r#""ru-ru""#).unwrap();
+        assert_eq!(code, LanguageCode::Tag("ru-RU".to_owned()));
+        assert_eq!(serde_json::to_string(&code).unwrap(), r#""ru-RU""#);
+    }
+}
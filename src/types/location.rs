@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::Coordinates;
+
 /// This object represents a point on the map.
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Location {
@@ -24,3 +26,10 @@ pub struct Location {
     /// member, in meters. For sent live locations only.
     pub proximity_alert_radius: Option<u32>,
 }
+
+impl Location {
+    /// Returns coordinates of the location or `None` if they are out of range.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        Coordinates::new(self.latitude, self.longitude)
+    }
+}
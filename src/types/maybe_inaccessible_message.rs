@@ -0,0 +1,138 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::types::{Chat, InaccessibleMessage, Message};
+
+/// This object describes a message that can be inaccessible to the bot.
+///
+/// [The official docs](https://core.telegram.org/bots/api#maybeinaccessiblemessage).
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum MaybeInaccessibleMessage {
+    /// The message is inaccessible (e.g.: it's too old or was deleted).
+    Inaccessible(InaccessibleMessage),
+    /// A regular, accessible message.
+    Regular(Message),
+}
+
+impl MaybeInaccessibleMessage {
+    /// Returns the identifier of the message.
+    pub fn message_id(&self) -> i32 {
+        match self {
+            Self::Inaccessible(m) => m.id,
+            Self::Regular(m) => m.id,
+        }
+    }
+
+    /// Returns the chat the message belongs to.
+    pub fn chat(&self) -> &Chat {
+        match self {
+            Self::Inaccessible(m) => &m.chat,
+            Self::Regular(m) => &m.chat,
+        }
+    }
+
+    /// Returns the message, if it's accessible.
+    pub fn regular_message(&self) -> Option<&Message> {
+        match self {
+            Self::Regular(m) => Some(m),
+            Self::Inaccessible(_) => None,
+        }
+    }
+
+    /// Returns the message, if it's accessible.
+    pub fn into_regular_message(self) -> Option<Message> {
+        match self {
+            Self::Regular(m) => Some(m),
+            Self::Inaccessible(_) => None,
+        }
+    }
+
+    /// Returns `true` if the message is inaccessible.
+    pub fn is_inaccessible(&self) -> bool {
+        matches!(self, Self::Inaccessible(_))
+    }
+}
+
+impl From<Message> for MaybeInaccessibleMessage {
+    fn from(message: Message) -> Self {
+        Self::Regular(message)
+    }
+}
+
+impl From<InaccessibleMessage> for MaybeInaccessibleMessage {
+    fn from(message: InaccessibleMessage) -> Self {
+        Self::Inaccessible(message)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaybeInaccessibleMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Inaccessible messages are distinguished by `date` being `0`, so we
+        // can't just use `#[serde(untagged)]` (a regular message would
+        // successfully deserialize as an inaccessible one).
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let inaccessible = value.get("date").and_then(serde_json::Value::as_i64) == Some(0);
+
+        let res = if inaccessible {
+            serde_json::from_value(value).map(Self::Inaccessible)
+        } else {
+            serde_json::from_value(value).map(Self::Regular)
+        };
+
+        res.map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inaccessible() {
+        let json = r#"{
+            "message_id": 42,
+            "date": 0,
+            "chat": {
+                "id": 123,
+                "type": "private",
+                "first_name": "Name"
+            }
+        }"#;
+        let message = serde_json::from_str::<MaybeInaccessibleMessage>(json).unwrap();
+
+        assert!(message.is_inaccessible());
+        assert_eq!(message.message_id(), 42);
+        assert_eq!(message.chat().id, 123);
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["date"], 0);
+    }
+
+    #[test]
+    fn regular() {
+        let json = r#"{
+            "message_id": 42,
+            "date": 1568289890,
+            "chat": {
+                "id": 123,
+                "type": "private",
+                "first_name": "Name"
+            },
+            "from": {
+                "id": 123,
+                "is_bot": false,
+                "first_name": "Name"
+            },
+            "text": "text"
+        }"#;
+        let message = serde_json::from_str::<MaybeInaccessibleMessage>(json).unwrap();
+
+        assert!(!message.is_inaccessible());
+        assert_eq!(message.message_id(), 42);
+        assert_eq!(message.regular_message().unwrap().text(), Some("text"));
+    }
+}
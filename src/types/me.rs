@@ -20,3 +20,63 @@ pub struct Me {
     /// `true`, if the bot supports inline queries.
     pub supports_inline_queries: bool,
 }
+
+impl Me {
+    /// Returns the username of the bot.
+    ///
+    /// Bots always have a username, so this never panics for `Me` returned by
+    /// Telegram.
+    pub fn username(&self) -> &str {
+        self.user
+            .username
+            .as_deref()
+            .expect("Bots must have usernames")
+    }
+
+    /// Returns a username mention of the bot, e.g. `@my_bot`.
+    pub fn mention(&self) -> String {
+        format!("@{}", self.username())
+    }
+
+    /// Returns the `https://t.me/<username>` link to the bot.
+    ///
+    /// This link can be used as a base for [deep links], e.g.
+    /// `https://t.me/<username>?start=<parameter>`.
+    ///
+    /// [deep links]: https://core.telegram.org/bots#deep-linking
+    pub fn tme_url(&self) -> reqwest::Url {
+        reqwest::Url::parse(&format!("https://t.me/{}", self.username())).unwrap()
+    }
+
+    /// Returns `true` if the `user` is the bot itself.
+    pub fn is_me(&self, user: &User) -> bool {
+        self.user.id == user.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers() {
+        let me = Me {
+            user: User {
+                id: 42,
+                is_bot: true,
+                first_name: "Bot".to_owned(),
+                last_name: None,
+                username: Some("my_bot".to_owned()),
+                language_code: None,
+            },
+            can_join_groups: false,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+        };
+
+        assert_eq!(me.username(), "my_bot");
+        assert_eq!(me.mention(), "@my_bot");
+        assert_eq!(me.tme_url().as_str(), "https://t.me/my_bot");
+        assert!(me.is_me(&me.user));
+    }
+}
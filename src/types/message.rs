@@ -3,10 +3,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    Animation, Audio, Chat, Contact, Dice, Document, Game, InlineKeyboardMarkup, Invoice, Location,
-    MessageAutoDeleteTimerChanged, MessageEntity, PassportData, PhotoSize, Poll,
-    ProximityAlertTriggered, Sticker, SuccessfulPayment, True, User, Venue, Video, VideoNote,
-    Voice, VoiceChatEnded, VoiceChatParticipantsInvited, VoiceChatScheduled, VoiceChatStarted,
+    Animation, Audio, Chat, ChatBackground, Contact, Dice, Document, Game, InlineKeyboardMarkup,
+    Invoice, Location, MessageAutoDeleteTimerChanged, MessageEntity, MessageEntityKind,
+    MessageEntityRef, PassportData, PhotoSize, Poll, ProximityAlertTriggered, Sticker, Story,
+    SuccessfulPayment, ThreadId, True, User, Venue, Video, VideoChatEnded,
+    VideoChatParticipantsInvited, VideoChatScheduled, VideoChatStarted, VideoNote, Voice,
 };
 
 /// This object represents a message.
@@ -24,15 +25,32 @@ pub struct Message {
     /// Conversation the message belongs to.
     pub chat: Chat,
 
+    /// Unique identifier of a message thread to which the message belongs;
+    /// for supergroups only.
+    pub message_thread_id: Option<ThreadId>,
+
+    /// `true`, if the message is sent to a forum topic.
+    #[serde(default)]
+    pub is_topic_message: bool,
+
     /// Bot through which the message was sent.
     pub via_bot: Option<User>,
 
+    /// Unique identifier of the business connection from which the message
+    /// was received, for messages of business accounts.
+    pub business_connection_id: Option<String>,
+
     #[serde(flatten)]
     pub kind: MessageKind,
 }
 
+/// Kind of a message.
+///
+/// The enum is non-exhaustive, since the `partial_api` feature adds the
+/// `Unknown` variant.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
+#[non_exhaustive]
 pub enum MessageKind {
     Common(MessageCommon),
     NewChatMembers(MessageNewChatMembers),
@@ -52,10 +70,22 @@ pub enum MessageKind {
     PassportData(MessagePassportData),
     Dice(MessageDice),
     ProximityAlertTriggered(MessageProximityAlertTriggered),
-    VoiceChatScheduled(MessageVoiceChatScheduled),
-    VoiceChatStarted(MessageVoiceChatStarted),
-    VoiceChatEnded(MessageVoiceChatEnded),
-    VoiceChatParticipantsInvited(MessageVoiceChatParticipantsInvited),
+    VideoChatScheduled(MessageVideoChatScheduled),
+    VideoChatStarted(MessageVideoChatStarted),
+    VideoChatEnded(MessageVideoChatEnded),
+    VideoChatParticipantsInvited(MessageVideoChatParticipantsInvited),
+    ChatBackgroundSet(MessageChatBackgroundSet),
+
+    /// A message which content is not supported by this library yet, e.g.
+    /// because it was introduced in a newer version of the Bot API.
+    ///
+    /// Note that a message is parsed as `Unknown` only if it doesn't match
+    /// any of the other variants, so once typed support for the content is
+    /// added, such messages are parsed into the corresponding variant instead.
+    #[cfg(feature = "partial_api")]
+    #[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "partial_api")))]
+    #[non_exhaustive]
+    Unknown(MessageUnknown),
 }
 
 #[serde_with_macros::skip_serializing_none]
@@ -74,9 +104,17 @@ pub struct MessageCommon {
     /// title of an anonymous group administrator.
     pub author_signature: Option<String>,
 
+    /// `true`, if the message is a channel post that was automatically
+    /// forwarded to the connected discussion group.
+    #[serde(default)]
+    pub is_automatic_forward: bool,
+
     #[serde(flatten)]
     pub forward_kind: ForwardKind,
 
+    /// For replies to a story, the original story.
+    pub reply_to_story: Option<Story>,
+
     /// Date the message was last edited in Unix time.
     pub edit_date: Option<i32>,
 
@@ -272,6 +310,7 @@ pub enum MediaKind {
     Photo(MediaPhoto),
     Poll(MediaPoll),
     Sticker(MediaSticker),
+    Story(MediaStory),
     Text(MediaText),
     Video(MediaVideo),
     VideoNote(MediaVideoNote),
@@ -382,6 +421,12 @@ pub struct MediaSticker {
     pub sticker: Sticker,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MediaStory {
+    /// Message is a forwarded story.
+    pub story: Story,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MediaText {
     /// For text messages, the actual UTF-8 text of the message, 0-4096
@@ -456,27 +501,59 @@ pub struct MessageProximityAlertTriggered {
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct MessageVoiceChatScheduled {
-    /// Service message: voice chat scheduled
-    pub voice_chat_scheduled: VoiceChatScheduled,
+pub struct MessageVideoChatScheduled {
+    /// Service message: video chat scheduled
+    #[serde(alias = "voice_chat_scheduled")]
+    pub video_chat_scheduled: VideoChatScheduled,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct MessageVoiceChatStarted {
-    /// Service message: voice chat started.
-    pub voice_chat_started: VoiceChatStarted,
+pub struct MessageVideoChatStarted {
+    /// Service message: video chat started.
+    #[serde(alias = "voice_chat_started")]
+    pub video_chat_started: VideoChatStarted,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct MessageVoiceChatEnded {
-    /// Service message: voice chat ended.
-    pub voice_chat_ended: VoiceChatEnded,
+pub struct MessageVideoChatEnded {
+    /// Service message: video chat ended.
+    #[serde(alias = "voice_chat_ended")]
+    pub video_chat_ended: VideoChatEnded,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct MessageVoiceChatParticipantsInvited {
-    /// Service message: new participants invited to a voice chat.
-    pub voice_chat_participants_invited: VoiceChatParticipantsInvited,
+pub struct MessageVideoChatParticipantsInvited {
+    /// Service message: new participants invited to a video chat.
+    #[serde(alias = "voice_chat_participants_invited")]
+    pub video_chat_participants_invited: VideoChatParticipantsInvited,
+}
+
+/// A service message about a change of the chat background.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageChatBackgroundSet {
+    /// Service message: chat background set.
+    pub chat_background_set: ChatBackground,
+}
+
+/// Content of a message which is not supported by this library yet, see
+/// [`MessageKind::Unknown`].
+#[cfg(feature = "partial_api")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "partial_api")))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MessageUnknown {
+    /// All fields of the message except the ones common for all messages
+    /// (i.e. the fields of [`Message`]), as they were sent by Telegram.
+    #[serde(flatten)]
+    pub raw: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "partial_api")]
+impl MessageUnknown {
+    /// Returns the raw value of the `field`, e.g. `"checklist"`.
+    pub fn get(&self, field: &str) -> Option<&serde_json::Value> {
+        self.raw.get(field)
+    }
 }
 
 mod getters {
@@ -487,13 +564,15 @@ mod getters {
         message::{ForwardKind::NonChannel, MessageKind::*},
         Chat, ForwardChannel, ForwardKind, ForwardNonChannel, ForwardOrigin, ForwardedFrom,
         MediaAnimation, MediaAudio, MediaContact, MediaDocument, MediaGame, MediaKind,
-        MediaLocation, MediaPhoto, MediaPoll, MediaSticker, MediaText, MediaVenue, MediaVideo,
-        MediaVideoNote, MediaVoice, Message, MessageChannelChatCreated, MessageCommon,
-        MessageConnectedWebsite, MessageDeleteChatPhoto, MessageDice, MessageEntity,
-        MessageGroupChatCreated, MessageInvoice, MessageLeftChatMember, MessageMigrate,
-        MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle, MessagePassportData,
-        MessagePinned, MessageProximityAlertTriggered, MessageSuccessfulPayment,
-        MessageSupergroupChatCreated, PhotoSize, True, User,
+        MediaLocation, MediaPhoto, MediaPoll, MediaSticker, MediaStory, MediaText, MediaVenue,
+        MediaVideo, MediaVideoNote, MediaVoice, Message, MessageChannelChatCreated,
+        MessageChatBackgroundSet, MessageCommon, MessageConnectedWebsite, MessageDeleteChatPhoto,
+        MessageDice, MessageEntity, MessageGroupChatCreated, MessageInvoice, MessageLeftChatMember,
+        MessageMessageAutoDeleteTimerChanged, MessageMigrate, MessageNewChatMembers,
+        MessageNewChatPhoto, MessageNewChatTitle, MessagePassportData, MessagePinned,
+        MessageProximityAlertTriggered, MessageSuccessfulPayment, MessageSupergroupChatCreated,
+        MessageVideoChatEnded, MessageVideoChatParticipantsInvited, MessageVideoChatScheduled,
+        MessageVideoChatStarted, PhotoSize, Story, ThreadId, True, User,
     };
 
     /// Getters for [Message] fields from [telegram docs].
@@ -524,10 +603,33 @@ mod getters {
             }
         }
 
+        pub fn is_automatic_forward(&self) -> bool {
+            match &self.kind {
+                Common(MessageCommon {
+                    is_automatic_forward,
+                    ..
+                }) => *is_automatic_forward,
+                _ => false,
+            }
+        }
+
         pub fn chat_id(&self) -> i64 {
             self.chat.id
         }
 
+        /// Returns the thread the message belongs to, for supergroups only.
+        pub fn thread_id(&self) -> Option<ThreadId> {
+            self.message_thread_id
+        }
+
+        /// Returns the forum topic the message was sent to, or `None` if the
+        /// message is not a [topic message].
+        ///
+        /// [topic message]: Message::is_topic_message
+        pub fn topic_id(&self) -> Option<ThreadId> {
+            self.message_thread_id.filter(|_| self.is_topic_message)
+        }
+
         /// NOTE: this is getter for both `forward_from` and
         /// `forward_sender_name`
         pub fn forward_from(&self) -> Option<&ForwardedFrom> {
@@ -597,6 +699,13 @@ mod getters {
             }
         }
 
+        pub fn reply_to_story(&self) -> Option<&Story> {
+            match &self.kind {
+                Common(MessageCommon { reply_to_story, .. }) => reply_to_story.as_ref(),
+                _ => None,
+            }
+        }
+
         pub fn edit_date(&self) -> Option<&i32> {
             match &self.kind {
                 Common(MessageCommon { edit_date, .. }) => edit_date.as_ref(),
@@ -746,6 +855,16 @@ mod getters {
             }
         }
 
+        pub fn story(&self) -> Option<&types::Story> {
+            match &self.kind {
+                Common(MessageCommon {
+                    media_kind: MediaKind::Story(MediaStory { story, .. }),
+                    ..
+                }) => Some(story),
+                _ => None,
+            }
+        }
+
         pub fn video(&self) -> Option<&types::Video> {
             match &self.kind {
                 Common(MessageCommon {
@@ -977,6 +1096,64 @@ mod getters {
             }
         }
 
+        pub fn video_chat_scheduled(&self) -> Option<&types::VideoChatScheduled> {
+            match &self.kind {
+                VideoChatScheduled(MessageVideoChatScheduled {
+                    video_chat_scheduled,
+                }) => Some(video_chat_scheduled),
+                _ => None,
+            }
+        }
+
+        pub fn video_chat_started(&self) -> Option<&types::VideoChatStarted> {
+            match &self.kind {
+                VideoChatStarted(MessageVideoChatStarted { video_chat_started }) => {
+                    Some(video_chat_started)
+                }
+                _ => None,
+            }
+        }
+
+        pub fn video_chat_ended(&self) -> Option<&types::VideoChatEnded> {
+            match &self.kind {
+                VideoChatEnded(MessageVideoChatEnded { video_chat_ended }) => {
+                    Some(video_chat_ended)
+                }
+                _ => None,
+            }
+        }
+
+        pub fn video_chat_participants_invited(
+            &self,
+        ) -> Option<&types::VideoChatParticipantsInvited> {
+            match &self.kind {
+                VideoChatParticipantsInvited(MessageVideoChatParticipantsInvited {
+                    video_chat_participants_invited,
+                }) => Some(video_chat_participants_invited),
+                _ => None,
+            }
+        }
+
+        pub fn message_auto_delete_timer_changed(
+            &self,
+        ) -> Option<&types::MessageAutoDeleteTimerChanged> {
+            match &self.kind {
+                MessageAutoDeleteTimerChanged(MessageMessageAutoDeleteTimerChanged {
+                    message_auto_delete_timer_changed,
+                }) => Some(message_auto_delete_timer_changed),
+                _ => None,
+            }
+        }
+
+        pub fn chat_background_set(&self) -> Option<&types::ChatBackground> {
+            match &self.kind {
+                ChatBackgroundSet(MessageChatBackgroundSet {
+                    chat_background_set,
+                }) => Some(chat_background_set),
+                _ => None,
+            }
+        }
+
         pub fn reply_markup(&self) -> Option<&types::InlineKeyboardMarkup> {
             match &self.kind {
                 Common(MessageCommon { reply_markup, .. }) => reply_markup.as_ref(),
@@ -987,6 +1164,87 @@ mod getters {
 }
 
 impl Message {
+    /// Returns identifiers of all custom emoji used in the text or the caption
+    /// of the message, without duplicates (in order of appearance).
+    ///
+    /// See also [`RequesterExt::custom_emoji_stickers`].
+    ///
+    /// [`RequesterExt::custom_emoji_stickers`]: crate::requests::RequesterExt::custom_emoji_stickers
+    pub fn custom_emoji_ids(&self) -> Vec<&str> {
+        let entities = self.entities().or_else(|| self.caption_entities());
+
+        let mut ids: Vec<&str> = Vec::new();
+        for entity in entities.unwrap_or(&[]) {
+            if let MessageEntityKind::CustomEmoji { custom_emoji_id } = &entity.kind {
+                if !ids.contains(&&**custom_emoji_id) {
+                    ids.push(custom_emoji_id);
+                }
+            }
+        }
+
+        ids
+    }
+
+    /// Returns entities of the text or the caption of the message together
+    /// with the text they refer to.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::Message;
+    ///
+    /// # let message: Message = serde_json::from_str(r#"{"message_id":1,"date":0,"chat":{"id":1,"type":"private","first_name":"f"},"text":"/start #tag","entities":[{"type":"bot_command","offset":0,"length":6},{"type":"hashtag","offset":7,"length":4}]}"#).unwrap();
+    /// let entities = message.parse_entities();
+    /// assert_eq!(entities[0].text(), "/start");
+    /// assert_eq!(message.commands(), ["/start"]);
+    /// assert_eq!(message.hashtags(), ["#tag"]);
+    /// ```
+    pub fn parse_entities(&self) -> Vec<MessageEntityRef<'_>> {
+        match (self.text(), self.entities()) {
+            (Some(text), Some(entities)) => MessageEntityRef::parse(text, entities),
+            _ => match (self.caption(), self.caption_entities()) {
+                (Some(caption), Some(entities)) => MessageEntityRef::parse(caption, entities),
+                _ => Vec::new(),
+            },
+        }
+    }
+
+    /// Returns URLs in the text or the caption of the message, both plain
+    /// ones and those of text links.
+    pub fn urls(&self) -> Vec<&str> {
+        self.parse_entities()
+            .iter()
+            .filter_map(MessageEntityRef::url)
+            .collect()
+    }
+
+    /// Returns mentions (e.g. `@username`) in the text or the caption of the
+    /// message.
+    pub fn mentions(&self) -> Vec<&str> {
+        self.parse_entities()
+            .iter()
+            .filter_map(MessageEntityRef::mention)
+            .collect()
+    }
+
+    /// Returns bot commands (e.g. `/start@bot`) in the text or the caption
+    /// of the message.
+    pub fn commands(&self) -> Vec<&str> {
+        self.parse_entities()
+            .iter()
+            .filter_map(MessageEntityRef::command)
+            .collect()
+    }
+
+    /// Returns hashtags (e.g. `#rust`) in the text or the caption of the
+    /// message.
+    pub fn hashtags(&self) -> Vec<&str> {
+        self.parse_entities()
+            .iter()
+            .filter_map(MessageEntityRef::hashtag)
+            .collect()
+    }
+
     /// Produces a direct link to the message.
     ///
     /// Note that for private groups the link will only be accesible for group
@@ -1225,4 +1483,210 @@ mod tests {
         let message = from_str::<Message>(json);
         assert!(message.is_ok());
     }
+
+    #[test]
+    fn de_story() {
+        let json = r#"{
+          "message_id": 199787,
+          "from": {
+           "id": 250918540,
+           "is_bot": false,
+           "first_name": "Андрей"
+          },
+          "chat": {
+           "id": 250918540,
+           "first_name": "Андрей",
+           "type": "private"
+          },
+          "date": 1568290622,
+          "story": {
+           "chat": {
+            "id": -1001234567890,
+            "title": "Channel",
+            "type": "channel"
+           },
+           "id": 17
+          },
+          "reply_to_story": {
+           "chat": {
+            "id": -1001234567890,
+            "title": "Channel",
+            "type": "channel"
+           },
+           "id": 16
+          }
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(message.story().map(|s| s.id), Some(17));
+        assert_eq!(message.reply_to_story().map(|s| s.id), Some(16));
+        assert_eq!(message.story().unwrap().chat.title(), Some("Channel"));
+    }
+
+    #[test]
+    fn de_chat_background_set() {
+        let json = r#"{
+          "message_id": 200,
+          "chat": {
+           "id": -1001234567890,
+           "title": "Group",
+           "type": "supergroup"
+          },
+          "date": 1568290622,
+          "chat_background_set": {
+           "type": {
+            "type": "fill",
+            "fill": {
+             "type": "gradient",
+             "top_color": 16777215,
+             "bottom_color": 0,
+             "rotation_angle": 45
+            },
+            "dark_theme_dimming": 30
+           }
+          }
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(
+            message.chat_background_set(),
+            Some(&ChatBackground {
+                kind: BackgroundType::Fill {
+                    fill: BackgroundFill::Gradient {
+                        top_color: 0xFFFFFF,
+                        bottom_color: 0,
+                        rotation_angle: 45,
+                    },
+                    dark_theme_dimming: 30,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn custom_emoji_ids() {
+        let json = r#"{
+          "message_id": 1,
+          "chat": {
+           "id": 1,
+           "first_name": "A",
+           "type": "private"
+          },
+          "date": 1568290622,
+          "text": "x y x",
+          "entities": [
+           {"type": "custom_emoji", "offset": 0, "length": 1, "custom_emoji_id": "1"},
+           {"type": "bold", "offset": 1, "length": 1},
+           {"type": "custom_emoji", "offset": 2, "length": 1, "custom_emoji_id": "2"},
+           {"type": "custom_emoji", "offset": 4, "length": 1, "custom_emoji_id": "1"}
+          ]
+         }"#;
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(message.custom_emoji_ids(), ["1", "2"]);
+    }
+
+    #[test]
+    fn video_chat() {
+        let message = |service: &str| {
+            let json = format!(
+                r#"{{
+                  "message_id": 1,
+                  "chat": {{"id": -1001, "title": "Chat", "type": "supergroup"}},
+                  "date": 1568290622,
+                  {}
+                }}"#,
+                service
+            );
+            from_str::<Message>(&json).unwrap()
+        };
+
+        let ended = message(r#""video_chat_ended": {"duration": 3600}"#);
+        assert_eq!(
+            ended.video_chat_ended().unwrap().duration,
+            std::time::Duration::from_secs(3600)
+        );
+
+        // The old name is still accepted
+        let started = message(r#""voice_chat_started": {}"#);
+        assert!(started.video_chat_started().is_some());
+
+        let invited = message(
+            r#""video_chat_participants_invited": {"users": [{"id": 1, "is_bot": false, "first_name": "A"}]}"#,
+        );
+        assert_eq!(
+            invited
+                .video_chat_participants_invited()
+                .unwrap()
+                .users
+                .len(),
+            1
+        );
+
+        let invited = message(r#""video_chat_participants_invited": {}"#);
+        assert!(invited
+            .video_chat_participants_invited()
+            .unwrap()
+            .users
+            .is_empty());
+    }
+
+    #[test]
+    fn topic_message() {
+        let json = r#"{
+          "message_id": 10,
+          "message_thread_id": 3,
+          "is_topic_message": true,
+          "chat": {"id": -1001, "title": "Forum", "type": "supergroup"},
+          "date": 1568290622,
+          "text": "hi"
+        }"#;
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(message.thread_id(), Some(ThreadId(3)));
+        assert_eq!(message.topic_id(), Some(ThreadId(3)));
+
+        // A reply thread in a non-forum supergroup
+        let json = r#"{
+          "message_id": 10,
+          "message_thread_id": 3,
+          "chat": {"id": -1001, "title": "Group", "type": "supergroup"},
+          "date": 1568290622,
+          "text": "hi"
+        }"#;
+        let message = from_str::<Message>(json).unwrap();
+        assert_eq!(message.thread_id(), Some(ThreadId(3)));
+        assert_eq!(message.topic_id(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "partial_api")]
+    fn unknown_content() {
+        let json = r#"{
+          "message_id": 10,
+          "chat": {"id": -1001, "title": "Group", "type": "supergroup"},
+          "date": 1568290622,
+          "checklist": {"title": "Todo", "tasks": [{"id": 1, "text": "a"}]}
+        }"#;
+        let message = from_str::<Message>(json).unwrap();
+        match &message.kind {
+            MessageKind::Unknown(unknown) => {
+                assert_eq!(unknown.get("checklist").unwrap()["title"], "Todo");
+                assert!(unknown.get("message_id").is_none());
+            }
+            kind => panic!("unexpected kind: {:?}", kind),
+        }
+
+        // Round-trips
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(serde_json::from_value::<Message>(value).unwrap(), message);
+
+        // Known content is still parsed as usual
+        let json = r#"{
+          "message_id": 10,
+          "chat": {"id": -1001, "title": "Group", "type": "supergroup"},
+          "date": 1568290622,
+          "text": "hi"
+        }"#;
+        assert!(matches!(
+            from_str::<Message>(json).unwrap().kind,
+            MessageKind::Common(_)
+        ));
+    }
 }
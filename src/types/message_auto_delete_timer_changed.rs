@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 /// This object represents a service message about a change in auto-delete timer
@@ -7,3 +9,30 @@ pub struct MessageAutoDeleteTimerChanged {
     /// New auto-delete time for messages in the chat
     pub message_auto_delete_time: u32,
 }
+
+impl MessageAutoDeleteTimerChanged {
+    /// Returns the new auto-delete time or `None` if the timer was disabled.
+    pub fn auto_delete_time(&self) -> Option<Duration> {
+        match self.message_auto_delete_time {
+            0 => None,
+            secs => Some(Duration::from_secs(secs.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_delete_time() {
+        let changed: MessageAutoDeleteTimerChanged =
+            serde_json::from_str(r#"{"message_auto_delete_time":86400}"#).unwrap();
+        assert_eq!(changed.auto_delete_time(), Some(Duration::from_secs(24 * 60 * 60)));
+
+        let disabled = MessageAutoDeleteTimerChanged {
+            message_auto_delete_time: 0,
+        };
+        assert_eq!(disabled.auto_delete_time(), None);
+    }
+}
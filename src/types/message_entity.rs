@@ -63,6 +63,68 @@ pub enum MessageEntityKind {
     TextMention { user: User },
     Underline,
     Strikethrough,
+    CustomEmoji { custom_emoji_id: String },
+
+    /// An entity of a type which is unknown to this library (e.g. one added
+    /// in a newer version of the Bot API), with the name of the type.
+    #[serde(untagged, with = "other_kind")]
+    Other(String),
+}
+
+/// (De)serializes the type name of [`MessageEntityKind::Other`] as the `type`
+/// field.
+mod other_kind {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Types of the other variants. Since untagged variants are tried when
+    /// the other ones fail, these are rejected to keep the errors of e.g.
+    /// `text_link`s without an `url`.
+    const KNOWN: &[&str] = &[
+        "mention",
+        "hashtag",
+        "cashtag",
+        "bot_command",
+        "url",
+        "email",
+        "phone_number",
+        "bold",
+        "italic",
+        "code",
+        "pre",
+        "text_link",
+        "text_mention",
+        "underline",
+        "strikethrough",
+        "custom_emoji",
+    ];
+
+    #[derive(Serialize, Deserialize)]
+    struct Tag<T> {
+        #[serde(rename = "type")]
+        kind: T,
+    }
+
+    pub(super) fn serialize<S>(kind: &str, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Tag { kind }.serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Tag { kind } = Tag::<String>::deserialize(deserializer)?;
+        if KNOWN.contains(&&*kind) {
+            return Err(D::Error::custom(format!(
+                "invalid message entity of type `{}`",
+                kind
+            )));
+        }
+
+        Ok(kind)
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +150,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unknown_kind() {
+        let entity: MessageEntity =
+            serde_json::from_str(r#"{"type":"spoiler","offset":1,"length":2}"#).unwrap();
+        assert_eq!(
+            entity,
+            MessageEntity::new(MessageEntityKind::Other("spoiler".to_owned()), 1, 2)
+        );
+        assert_eq!(
+            serde_json::to_value(&entity).unwrap(),
+            serde_json::json!({"type": "spoiler", "offset": 1, "length": 2})
+        );
+
+        // Known types are still checked
+        assert!(serde_json::from_str::<MessageEntity>(
+            r#"{"type":"text_link","offset":1,"length":2}"#
+        )
+        .is_err());
+    }
+
     #[test]
     fn pre() {
         use serde_json::from_str;
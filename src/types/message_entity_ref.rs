@@ -0,0 +1,159 @@
+use std::ops::Range;
+
+use crate::types::{MessageEntity, MessageEntityKind};
+
+/// A [`MessageEntity`] together with the text it refers to.
+///
+/// Offsets of entities are in UTF-16 code units, this resolves them into the
+/// corresponding part of a Rust string, see [`Message::parse_entities`].
+///
+/// [`Message::parse_entities`]: crate::types::Message::parse_entities
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MessageEntityRef<'a> {
+    message: &'a str,
+    range: Range<usize>,
+    kind: &'a MessageEntityKind,
+}
+
+impl<'a> MessageEntityRef<'a> {
+    /// Resolves `entities` of the `text`.
+    ///
+    /// Entities with offsets which don't correspond to the `text` (e.g.
+    /// because they are out of bounds) are skipped.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{MessageEntity, MessageEntityKind, MessageEntityRef};
+    ///
+    /// // 🦀 is 2 UTF-16 code units long
+    /// let text = "🦀 /start";
+    /// let entities = [MessageEntity::new(MessageEntityKind::BotCommand, 3, 6)];
+    ///
+    /// let parsed = MessageEntityRef::parse(text, &entities);
+    /// assert_eq!(parsed[0].text(), "/start");
+    /// assert_eq!(parsed[0].range(), 5..11);
+    /// ```
+    pub fn parse(text: &'a str, entities: &'a [MessageEntity]) -> Vec<Self> {
+        // Byte offsets of UTF-16 offsets of all char boundaries, including
+        // the end of the text
+        let mut boundaries = Vec::with_capacity(text.len() + 1);
+        let mut utf16 = 0;
+        for (byte, c) in text.char_indices() {
+            boundaries.push((utf16, byte));
+            utf16 += c.len_utf16();
+        }
+        boundaries.push((utf16, text.len()));
+
+        let byte_offset = |utf16: usize| {
+            boundaries
+                .binary_search_by_key(&utf16, |&(u, _)| u)
+                .ok()
+                .map(|idx| boundaries[idx].1)
+        };
+
+        entities
+            .iter()
+            .filter_map(|entity| {
+                let start = byte_offset(entity.offset)?;
+                let end = byte_offset(entity.offset.checked_add(entity.length)?)?;
+
+                Some(Self {
+                    message: text,
+                    range: start..end,
+                    kind: &entity.kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the kind of the entity.
+    pub fn kind(&self) -> &'a MessageEntityKind {
+        self.kind
+    }
+
+    /// Returns the text of the entity, e.g. `/start` for a bot command.
+    pub fn text(&self) -> &'a str {
+        &self.message[self.range.clone()]
+    }
+
+    /// Returns the range of the entity in bytes (unlike
+    /// [`MessageEntity::offset`] which is in UTF-16 code units).
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the whole text of the message.
+    pub fn message_text(&self) -> &'a str {
+        self.message
+    }
+
+    /// Returns the URL if this is a [`Url`] (the text itself) or a
+    /// [`TextLink`] (its `url`).
+    ///
+    /// [`Url`]: MessageEntityKind::Url
+    /// [`TextLink`]: MessageEntityKind::TextLink
+    pub fn url(&self) -> Option<&'a str> {
+        match self.kind {
+            MessageEntityKind::Url => Some(self.text()),
+            MessageEntityKind::TextLink { url } => Some(url),
+            _ => None,
+        }
+    }
+
+    /// Returns the mention, e.g. `@username`, if this is a
+    /// [`Mention`](MessageEntityKind::Mention).
+    pub fn mention(&self) -> Option<&'a str> {
+        self.text_if(matches!(self.kind, MessageEntityKind::Mention))
+    }
+
+    /// Returns the command, e.g. `/start@bot`, if this is a
+    /// [`BotCommand`](MessageEntityKind::BotCommand).
+    pub fn command(&self) -> Option<&'a str> {
+        self.text_if(matches!(self.kind, MessageEntityKind::BotCommand))
+    }
+
+    /// Returns the hashtag, e.g. `#rust`, if this is a
+    /// [`Hashtag`](MessageEntityKind::Hashtag).
+    pub fn hashtag(&self) -> Option<&'a str> {
+        self.text_if(matches!(self.kind, MessageEntityKind::Hashtag))
+    }
+
+    fn text_if(&self, cond: bool) -> Option<&'a str> {
+        if cond {
+            Some(self.text())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let text = "Привет #rust, see ya.ru";
+        let entities = [
+            MessageEntity::new(MessageEntityKind::Hashtag, 7, 5),
+            MessageEntity::new(MessageEntityKind::Url, 18, 5),
+            // Out of bounds
+            MessageEntity::new(MessageEntityKind::Bold, 20, 10),
+        ];
+
+        let parsed = MessageEntityRef::parse(text, &entities);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].hashtag(), Some("#rust"));
+        assert_eq!(parsed[0].url(), None);
+        assert_eq!(parsed[1].url(), Some("ya.ru"));
+        assert_eq!(parsed[1].range(), 24..29);
+    }
+
+    #[test]
+    fn surrogate_pairs() {
+        // An entity can't start in the middle of 🦀
+        let entities = [MessageEntity::new(MessageEntityKind::Bold, 1, 1)];
+        assert!(MessageEntityRef::parse("🦀", &entities).is_empty());
+    }
+}
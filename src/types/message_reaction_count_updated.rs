@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Chat, ReactionCount};
+
+/// This object represents reaction changes on a message with anonymous
+/// reactions.
+///
+/// [The official docs](https://core.telegram.org/bots/api#messagereactioncountupdated).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageReactionCountUpdated {
+    /// The chat containing the message.
+    pub chat: Chat,
+
+    /// Unique message identifier inside the chat.
+    pub message_id: i32,
+
+    /// Date of the change in Unix time.
+    pub date: i64,
+
+    /// List of reactions that are present on the message.
+    pub reactions: Vec<ReactionCount>,
+}
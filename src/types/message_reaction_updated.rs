@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Chat, ReactionType, User};
+
+/// This object represents a change of a reaction on a message performed by a
+/// user.
+///
+/// [The official docs](https://core.telegram.org/bots/api#messagereactionupdated).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageReactionUpdated {
+    /// The chat containing the message the user reacted to.
+    pub chat: Chat,
+
+    /// Unique identifier of the message inside the chat.
+    pub message_id: i32,
+
+    /// The user that changed the reaction, if the user isn't anonymous.
+    pub user: Option<User>,
+
+    /// The chat on behalf of which the reaction was changed, if the user is
+    /// anonymous.
+    pub actor_chat: Option<Chat>,
+
+    /// Date of the change in Unix time.
+    pub date: i64,
+
+    /// Previous list of reaction types that were set by the user.
+    pub old_reaction: Vec<ReactionType>,
+
+    /// New list of reaction types that have been set by the user.
+    pub new_reaction: Vec<ReactionType>,
+}
+
+impl MessageReactionUpdated {
+    /// Returns reactions which were added by this change.
+    pub fn added(&self) -> impl Iterator<Item = &ReactionType> {
+        let old = &self.old_reaction;
+        self.new_reaction.iter().filter(move |r| !old.contains(r))
+    }
+
+    /// Returns reactions which were removed by this change.
+    pub fn removed(&self) -> impl Iterator<Item = &ReactionType> {
+        let new = &self.new_reaction;
+        self.old_reaction.iter().filter(move |r| !new.contains(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "chat": {"id": -1, "type": "supergroup", "title": "G"},
+            "message_id": 7,
+            "user": {"id": 1, "is_bot": false, "first_name": "A"},
+            "date": 100,
+            "old_reaction": [{"type": "emoji", "emoji": "👍"}, {"type": "emoji", "emoji": "🔥"}],
+            "new_reaction": [{"type": "emoji", "emoji": "🔥"}, {"type": "custom_emoji", "custom_emoji_id": "1"}]
+        }"#;
+        let updated = serde_json::from_str::<MessageReactionUpdated>(json).unwrap();
+
+        assert_eq!(updated.user.as_ref().map(|u| u.id), Some(1));
+        assert_eq!(updated.actor_chat, None);
+        assert_eq!(
+            updated.added().collect::<Vec<_>>(),
+            [&ReactionType::custom_emoji("1")]
+        );
+        assert_eq!(
+            updated.removed().collect::<Vec<_>>(),
+            [&ReactionType::emoji("👍")]
+        );
+    }
+}
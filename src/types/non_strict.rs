@@ -0,0 +1,317 @@
+//! Non-strict deserialization of Telegram types.
+//!
+//! Some Bot API servers (most notably local [Bot API server] builds) may
+//! deviate from the official schema, e.g. by sending integers or booleans as
+//! strings. Strict deserialization fails on such objects and e.g. drops the
+//! whole update. Functions in this module first try to deserialize strictly
+//! and, if that fails, [`normalize`] the JSON and try again.
+//!
+//! [`Update::try_parse`] and [`SemiparsedVec`] (and thus
+//! [`GetUpdatesFaultTolerant`]) use this mode.
+//!
+//! [Bot API server]: https://github.com/tdlib/telegram-bot-api
+//! [`Update::try_parse`]: crate::types::Update::try_parse
+//! [`SemiparsedVec`]: crate::types::SemiparsedVec
+//! [`GetUpdatesFaultTolerant`]: crate::payloads::GetUpdatesFaultTolerant
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+
+/// Fields which always contain integers.
+const INTEGER_FIELDS: &[&str] = &[
+    "update_id",
+    "message_id",
+    "date",
+    "edit_date",
+    "forward_date",
+    "forward_from_message_id",
+    "migrate_to_chat_id",
+    "migrate_from_chat_id",
+    "user_id",
+    "chat_id",
+    "file_size",
+    "width",
+    "height",
+    "duration",
+    "until_date",
+    "total_count",
+    "voter_count",
+    "total_voter_count",
+    "pending_update_count",
+];
+
+/// Fields which contain integers in message entities (but not e.g. in inline
+/// queries, where `offset` is a string).
+const ENTITY_FIELDS: &[&str] = &["offset", "length"];
+
+/// Chat types, used to tell apart chat objects (which have integer ids) from
+/// other objects with a `type` field.
+const CHAT_TYPES: &[&str] = &["private", "group", "supergroup", "channel"];
+
+/// Deserializes `T` from `value`, normalizing the value if the strict
+/// deserialization fails.
+///
+/// The value is only cloned if the strict deserialization fails. If both
+/// attempts fail, the error of the strict attempt is returned.
+pub fn from_value<T>(value: &Value) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    match T::deserialize(value) {
+        Ok(res) => Ok(res),
+        Err(err) => {
+            let mut value = value.clone();
+            if !normalize(&mut value) {
+                return Err(err);
+            }
+
+            serde_json::from_value(value).map_err(|_| err)
+        }
+    }
+}
+
+/// Deserializes `T` from a JSON string, see [`from_value`].
+pub fn from_str<T>(s: &str) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    from_value(&serde_json::from_str(s)?)
+}
+
+/// Fixes known deviations from the schema in `value`, returning `true` if
+/// anything was changed.
+///
+/// Namely, this converts
+/// - strings in integer fields (e.g. `message_id`, `date`, ids of users and
+///   chats or offsets of message entities) into integers,
+/// - `"true"`/`"false"` strings in boolean fields (`is_*`, `can_*`, `has_*`)
+///   into booleans.
+pub fn normalize(value: &mut Value) -> bool {
+    match value {
+        Value::Object(map) => {
+            let object = Object {
+                has_int_id: map.contains_key("is_bot")
+                    || matches!(map.get("type"), Some(Value::String(ty)) if CHAT_TYPES.contains(&&**ty)),
+                is_entity: ENTITY_FIELDS.iter().all(|f| map.contains_key(*f))
+                    && map.contains_key("type"),
+            };
+
+            let mut changed = false;
+            for (key, value) in map.iter_mut() {
+                changed |= normalize_field(key, object, value);
+            }
+
+            changed | fix_map(map)
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .fold(false, |changed, v| normalize(v) | changed),
+        _ => false,
+    }
+}
+
+/// What is known about an object, to tell which of its fields are integers.
+#[derive(Clone, Copy)]
+struct Object {
+    /// The object is a user or a chat.
+    has_int_id: bool,
+    /// The object is a message entity.
+    is_entity: bool,
+}
+
+fn normalize_field(key: &str, object: Object, value: &mut Value) -> bool {
+    let is_int = INTEGER_FIELDS.contains(&key)
+        || (key == "id" && object.has_int_id)
+        || (ENTITY_FIELDS.contains(&key) && object.is_entity);
+    let is_bool = key.starts_with("is_") || key.starts_with("can_") || key.starts_with("has_");
+
+    match value {
+        Value::String(s) if is_int => match s.parse::<i64>() {
+            Ok(n) => {
+                *value = Value::Number(Number::from(n));
+                true
+            }
+            Err(_) => false,
+        },
+        Value::String(s) if is_bool => match &**s {
+            "true" => {
+                *value = Value::Bool(true);
+                true
+            }
+            "false" => {
+                *value = Value::Bool(false);
+                true
+            }
+            _ => false,
+        },
+        _ => normalize(value),
+    }
+}
+
+/// Drops `null` fields, which some servers send instead of omitting the
+/// field.
+fn fix_map(map: &mut Map<String, Value>) -> bool {
+    let len = map.len();
+    map.retain(|_, v| !v.is_null());
+    len != map.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CallbackQuery, Update, UpdateKind};
+
+    #[test]
+    fn stringified_numbers() {
+        let json = r#"{
+            "update_id": "1",
+            "message": {
+                "message_id": "2",
+                "from": { "id": "42", "is_bot": "false", "first_name": "Anon" },
+                "chat": { "id": "42", "type": "private", "first_name": "Anon" },
+                "date": "1600000000",
+                "reply_markup": null,
+                "text": "123"
+            }
+        }"#;
+
+        assert!(serde_json::from_str::<Update>(json).is_err());
+
+        let update = from_str::<Update>(json).unwrap();
+        assert_eq!(update.id, 1);
+        assert_eq!(update.chat_id(), Some(42));
+        assert_eq!(update.user().map(|u| u.id), Some(42));
+    }
+
+    #[test]
+    fn string_ids_are_kept() {
+        let json = r#"{
+            "id": "123",
+            "from": { "id": "42", "is_bot": false, "first_name": "Anon" },
+            "chat_instance": "456",
+            "data": "1"
+        }"#;
+
+        let query = from_str::<CallbackQuery>(json).unwrap();
+        assert_eq!(query.from.id, 42);
+        assert_eq!(query.id, "123");
+        assert_eq!(query.data.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn string_offsets_of_inline_queries_are_kept() {
+        let json = r#"{
+            "update_id": 1,
+            "inline_query": {
+                "id": "1",
+                "from": { "id": "42", "is_bot": false, "first_name": "Anon" },
+                "query": "cats",
+                "offset": "10"
+            }
+        }"#;
+
+        match from_str::<Update>(json).unwrap().kind {
+            UpdateKind::InlineQuery(query) => {
+                assert_eq!(query.from.id, 42);
+                assert_eq!(query.offset, "10");
+            }
+            kind => panic!("unexpected update: {:?}", kind),
+        }
+    }
+
+    /// Updates which are used by [`random_stringification`].
+    const CORPUS: &[&str] = &[
+        r#"{
+            "update_id": 1,
+            "message": {
+                "message_id": 2,
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "chat": { "id": -100, "type": "supergroup", "title": "Group" },
+                "date": 1600000000,
+                "edit_date": 1600000001,
+                "text": "/start @bot",
+                "entities": [
+                    { "type": "bot_command", "offset": 0, "length": 6 },
+                    { "type": "mention", "offset": 7, "length": 4 }
+                ]
+            }
+        }"#,
+        r#"{
+            "update_id": 3,
+            "callback_query": {
+                "id": "4",
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "chat_instance": "5",
+                "data": "6"
+            }
+        }"#,
+        r#"{
+            "update_id": 7,
+            "inline_query": {
+                "id": "8",
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "query": "9",
+                "offset": "10"
+            }
+        }"#,
+        r#"{
+            "update_id": 11,
+            "my_chat_member": {
+                "chat": { "id": 42, "type": "private", "first_name": "Anon" },
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "date": 1600000000,
+                "old_chat_member": {
+                    "user": { "id": 1, "is_bot": true, "first_name": "Bot" },
+                    "status": "member"
+                },
+                "new_chat_member": {
+                    "user": { "id": 1, "is_bot": true, "first_name": "Bot" },
+                    "status": "kicked",
+                    "until_date": 0
+                }
+            }
+        }"#,
+    ];
+
+    /// Turns random integers and booleans in `value` into strings (the way
+    /// some servers send them), returning the number of changed fields.
+    fn stringify(value: &mut Value, rng: &mut impl FnMut() -> u64) -> usize {
+        match value {
+            Value::Number(_) | Value::Bool(_) if rng() & 1 == 0 => {
+                *value = Value::String(value.to_string());
+                1
+            }
+            Value::Object(map) => map.values_mut().map(|v| stringify(v, rng)).sum(),
+            Value::Array(values) => values.iter_mut().map(|v| stringify(v, rng)).sum(),
+            _ => 0,
+        }
+    }
+
+    /// A small property test: whatever fields of the corpus are stringified,
+    /// non-strict deserialization returns the same update.
+    #[test]
+    fn random_stringification() {
+        // xorshift, to keep the test deterministic and dependency-free
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut rng = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for json in CORPUS {
+            let value: Value = serde_json::from_str(json).unwrap();
+            let expected = serde_json::from_value::<Update>(value.clone()).unwrap();
+
+            for _ in 0..64 {
+                let mut mutated = value.clone();
+                let changed = stringify(&mut mutated, &mut rng);
+
+                let actual = from_value::<Update>(&mutated)
+                    .unwrap_or_else(|err| panic!("{} ({} changes): {}", err, changed, mutated));
+                assert_eq!(actual, expected, "{}", mutated);
+            }
+        }
+    }
+}
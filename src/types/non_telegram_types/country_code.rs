@@ -1,9 +1,39 @@
+use std::{fmt, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
-/// ISO 3166-1 alpha-2 language code.
-#[allow(clippy::upper_case_acronyms)]
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-pub enum CountryCode {
+macro_rules! country_codes {
+    ($($(#[$meta:meta])* $code:ident,)*) => {
+        /// ISO 3166-1 alpha-2 country code.
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+        pub enum CountryCode {
+            $($(#[$meta])* $code,)*
+        }
+
+        impl CountryCode {
+            /// Returns the two-letter code of the country.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Self::$code => stringify!($code),)*
+                }
+            }
+        }
+
+        impl FromStr for CountryCode {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($code) => Ok(Self::$code),)*
+                    _ => Err(()),
+                }
+            }
+        }
+    };
+}
+
+country_codes! {
     /// Andorra
     AD,
     /// United Arab Emirates
@@ -503,3 +533,25 @@ pub enum CountryCode {
     /// Zimbabwe
     ZW,
 }
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes() {
+        assert_eq!("DE".parse(), Ok(CountryCode::DE));
+        assert_eq!("de".parse::<CountryCode>(), Err(()));
+        assert_eq!(CountryCode::GB.to_string(), "GB");
+        assert_eq!(
+            serde_json::to_string(&CountryCode::GB).unwrap(),
+            format!("\"{}\"", CountryCode::GB)
+        );
+    }
+}
@@ -1,365 +1,598 @@
+use std::{fmt, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
-/// ISO 4217 currency.
-#[allow(clippy::upper_case_acronyms)]
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-pub enum Currency {
+macro_rules! currencies {
+    ($($(#[$meta:meta])* $code:ident = $exp:literal,)*) => {
+        /// ISO 4217 currency.
+        ///
+        /// Also includes [`Currency::XTR`], the currency of Telegram Stars.
+        /// Telegram doesn't support all of these currencies, see
+        /// [`Currency::is_supported`].
+        #[allow(clippy::upper_case_acronyms)]
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+        pub enum Currency {
+            $($(#[$meta])* $code,)*
+        }
+
+        impl Currency {
+            /// Returns the three-letter code of the currency.
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Self::$code => stringify!($code),)*
+                }
+            }
+
+            /// Returns the number of digits past the decimal point, i.e. the
+            /// exponent of the smallest units (which are used for amounts,
+            /// see [`LabeledPrice::amount`]) of the currency.
+            ///
+            /// This is `0` for codes which are not currencies (e.g.
+            /// [`Currency::XAU`]).
+            ///
+            /// ## Examples
+            ///
+            /// ```
+            /// use teloxide_core::types::Currency;
+            ///
+            /// assert_eq!(Currency::USD.exp(), 2);
+            /// assert_eq!(Currency::JPY.exp(), 0);
+            /// assert_eq!(Currency::XTR.exp(), 0);
+            /// ```
+            ///
+            /// [`LabeledPrice::amount`]: crate::types::LabeledPrice::amount
+            pub fn exp(self) -> u32 {
+                match self {
+                    $(Self::$code => $exp,)*
+                }
+            }
+        }
+
+        impl FromStr for Currency {
+            type Err = ();
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($code) => Ok(Self::$code),)*
+                    _ => Err(()),
+                }
+            }
+        }
+    };
+}
+
+currencies! {
     /// United Arab Emirates dirham
-    AED,
+    AED = 2,
     /// Afghan afghani
-    AFN,
+    AFN = 2,
     /// Albanian lek
-    ALL,
+    ALL = 2,
     /// Armenian dram
-    AMD,
+    AMD = 2,
     /// Netherlands Antillean guilder
-    ANG,
+    ANG = 2,
     /// Angolan kwanza
-    AOA,
+    AOA = 2,
     /// Argentine peso
-    ARS,
+    ARS = 2,
     /// Australian dollar
-    AUD,
+    AUD = 2,
     /// Aruban florin
-    AWG,
+    AWG = 2,
     /// Azerbaijani manat
-    AZN,
+    AZN = 2,
     /// Bosnia and Herzegovina convertible mark
-    BAM,
+    BAM = 2,
     /// Barbados dollar
-    BBD,
+    BBD = 2,
     /// Bangladeshi taka
-    BDT,
+    BDT = 2,
     /// Bulgarian lev
-    BGN,
+    BGN = 2,
     /// Bahraini dinar
-    BHD,
+    BHD = 3,
     /// Burundian franc
-    BIF,
+    BIF = 0,
     /// Bermudian dollar
-    BMD,
+    BMD = 2,
     /// Brunei dollar
-    BND,
+    BND = 2,
     /// Boliviano
-    BOB,
+    BOB = 2,
     /// Bolivian Mvdol (funds code)
-    BOV,
+    BOV = 2,
     /// Brazilian real
-    BRL,
+    BRL = 2,
     /// Bahamian dollar
-    BSD,
+    BSD = 2,
     /// Bhutanese ngultrum
-    BTN,
+    BTN = 2,
     /// Botswana pula
-    BWP,
+    BWP = 2,
     /// Belarusian ruble
-    BYN,
+    BYN = 2,
     /// Belize dollar
-    BZD,
+    BZD = 2,
     /// Canadian dollar
-    CAD,
+    CAD = 2,
     /// Congolese franc
-    CDF,
+    CDF = 2,
     /// WIR euro (complementary currency)
-    CHE,
+    CHE = 2,
     /// Swiss franc
-    CHF,
+    CHF = 2,
     /// WIR franc (complementary currency)
-    CHW,
+    CHW = 2,
     /// Unidad de Fomento (funds code)
-    CLF,
+    CLF = 4,
     /// Chilean peso
-    CLP,
+    CLP = 0,
     /// Chinese yuan
-    CNY,
+    CNY = 2,
     /// Colombian peso
-    COP,
+    COP = 2,
     /// Unidad de Valor Real (UVR) (funds code)
-    COU,
+    COU = 2,
     /// Costa Rican colon
-    CRC,
+    CRC = 2,
     /// Cuban convertible peso
-    CUC,
+    CUC = 2,
     /// Cuban peso
-    CUP,
+    CUP = 2,
     /// Cape Verdean escudo
-    CVE,
+    CVE = 2,
     /// Czech koruna
-    CZK,
+    CZK = 2,
     /// Djiboutian franc
-    DJF,
+    DJF = 0,
     /// Danish krone
-    DKK,
+    DKK = 2,
     /// Dominican peso
-    DOP,
+    DOP = 2,
     /// Algerian dinar
-    DZD,
+    DZD = 2,
     /// Egyptian pound
-    EGP,
+    EGP = 2,
     /// Eritrean nakfa
-    ERN,
+    ERN = 2,
     /// Ethiopian birr
-    ETB,
+    ETB = 2,
     /// Euro
-    EUR,
+    EUR = 2,
     /// Fiji dollar
-    FJD,
+    FJD = 2,
     /// Falkland Islands pound
-    FKP,
+    FKP = 2,
     /// Pound sterling
-    GBP,
+    GBP = 2,
     /// Georgian lari
-    GEL,
+    GEL = 2,
     /// Ghanaian cedi
-    GHS,
+    GHS = 2,
     /// Gibraltar pound
-    GIP,
+    GIP = 2,
     /// Gambian dalasi
-    GMD,
+    GMD = 2,
     /// Guinean franc
-    GNF,
+    GNF = 0,
     /// Guatemalan quetzal
-    GTQ,
+    GTQ = 2,
     /// Guyanese dollar
-    GYD,
+    GYD = 2,
     /// Hong Kong dollar
-    HKD,
+    HKD = 2,
     /// Honduran lempira
-    HNL,
+    HNL = 2,
     /// Croatian kuna
-    HRK,
+    HRK = 2,
     /// Haitian gourde
-    HTG,
+    HTG = 2,
     /// Hungarian forint
-    HUF,
+    HUF = 2,
     /// Indonesian rupiah
-    IDR,
+    IDR = 2,
     /// Israeli new shekel
-    ILS,
+    ILS = 2,
     /// Indian rupee
-    INR,
+    INR = 2,
     /// Iraqi dinar
-    IQD,
+    IQD = 3,
     /// Iranian rial
-    IRR,
+    IRR = 2,
     /// Icelandic króna
-    ISK,
+    ISK = 0,
     /// Jamaican dollar
-    JMD,
+    JMD = 2,
     /// Jordanian dinar
-    JOD,
+    JOD = 3,
     /// Japanese yen
-    JPY,
+    JPY = 0,
     /// Kenyan shilling
-    KES,
+    KES = 2,
     /// Kyrgyzstani som
-    KGS,
+    KGS = 2,
     /// Cambodian riel
-    KHR,
+    KHR = 2,
     /// Comoro franc
-    KMF,
+    KMF = 0,
     /// North Korean won
-    KPW,
+    KPW = 2,
     /// South Korean won
-    KRW,
+    KRW = 0,
     /// Kuwaiti dinar
-    KWD,
+    KWD = 3,
     /// Cayman Islands dollar
-    KYD,
+    KYD = 2,
     /// Kazakhstani tenge
-    KZT,
+    KZT = 2,
     /// Lao kip
-    LAK,
+    LAK = 2,
     /// Lebanese pound
-    LBP,
+    LBP = 2,
     /// Sri Lankan rupee
-    LKR,
+    LKR = 2,
     /// Liberian dollar
-    LRD,
+    LRD = 2,
     /// Lesotho loti
-    LSL,
+    LSL = 2,
     /// Libyan dinar
-    LYD,
+    LYD = 3,
     /// Moroccan dirham
-    MAD,
+    MAD = 2,
     /// Moldovan leu
-    MDL,
+    MDL = 2,
     /// Malagasy ariary
-    MGA,
+    MGA = 2,
     /// Macedonian denar
-    MKD,
+    MKD = 2,
     /// Myanmar kyat
-    MMK,
+    MMK = 2,
     /// Mongolian tögrög
-    MNT,
+    MNT = 2,
     /// Macanese pataca
-    MOP,
+    MOP = 2,
     /// Mauritanian ouguiya
-    MRU,
+    MRU = 2,
     /// Mauritian rupee
-    MUR,
+    MUR = 2,
     /// Maldivian rufiyaa
-    MVR,
+    MVR = 2,
     /// Malawian kwacha
-    MWK,
+    MWK = 2,
     /// Mexican peso
-    MXN,
+    MXN = 2,
     /// Mexican Unidad de Inversion (UDI) (funds code)
-    MXV,
+    MXV = 2,
     /// Malaysian ringgit
-    MYR,
+    MYR = 2,
     /// Mozambican metical
-    MZN,
+    MZN = 2,
     /// Namibian dollar
-    NAD,
+    NAD = 2,
     /// Nigerian naira
-    NGN,
+    NGN = 2,
     /// Nicaraguan córdoba
-    NIO,
+    NIO = 2,
     /// Norwegian krone
-    NOK,
+    NOK = 2,
     /// Nepalese rupee
-    NPR,
+    NPR = 2,
     /// New Zealand dollar
-    NZD,
+    NZD = 2,
     /// Omani rial
-    OMR,
+    OMR = 3,
     /// Panamanian balboa
-    PAB,
+    PAB = 2,
     /// Peruvian sol
-    PEN,
+    PEN = 2,
     /// Papua New Guinean kina
-    PGK,
+    PGK = 2,
     /// Philippine peso
-    PHP,
+    PHP = 2,
     /// Pakistani rupee
-    PKR,
+    PKR = 2,
     /// Polish złoty
-    PLN,
+    PLN = 2,
     /// Paraguayan guaraní
-    PYG,
+    PYG = 0,
     /// Qatari riyal
-    QAR,
+    QAR = 2,
     /// Romanian leu
-    RON,
+    RON = 2,
     /// Serbian dinar
-    RSD,
+    RSD = 2,
     /// Russian ruble
-    RUB,
+    RUB = 2,
     /// Rwandan franc
-    RWF,
+    RWF = 0,
     /// Saudi riyal
-    SAR,
+    SAR = 2,
     /// Solomon Islands dollar
-    SBD,
+    SBD = 2,
     /// Seychelles rupee
-    SCR,
+    SCR = 2,
     /// Sudanese pound
-    SDG,
+    SDG = 2,
     /// Swedish krona/kronor
-    SEK,
+    SEK = 2,
     /// Singapore dollar
-    SGD,
+    SGD = 2,
     /// Saint Helena pound
-    SHP,
+    SHP = 2,
     /// Sierra Leonean leone
-    SLL,
+    SLL = 2,
     /// Somali shilling
-    SOS,
+    SOS = 2,
     /// Surinamese dollar
-    SRD,
+    SRD = 2,
     /// South Sudanese pound
-    SSP,
+    SSP = 2,
     /// São Tomé and Príncipe dobra
-    STN,
+    STN = 2,
     /// Salvadoran colón
-    SVC,
+    SVC = 2,
     /// Syrian pound
-    SYP,
+    SYP = 2,
     /// Swazi lilangeni
-    SZL,
+    SZL = 2,
     /// Thai baht
-    THB,
+    THB = 2,
     /// Tajikistani somoni
-    TJS,
+    TJS = 2,
     /// Turkmenistan manat
-    TMT,
+    TMT = 2,
     /// Tunisian dinar
-    TND,
+    TND = 3,
     /// Tongan paʻanga
-    TOP,
+    TOP = 2,
     /// Turkish lira
-    TRY,
+    TRY = 2,
     /// Trinidad and Tobago dollar
-    TTD,
+    TTD = 2,
     /// New Taiwan dollar
-    TWD,
+    TWD = 2,
     /// Tanzanian shilling
-    TZS,
+    TZS = 2,
     /// Ukrainian hryvnia
-    UAH,
+    UAH = 2,
     /// Ugandan shilling
-    UGX,
+    UGX = 0,
     /// United States dollar
-    USD,
+    USD = 2,
     /// United States dollar (next day) (funds code)
-    USN,
+    USN = 2,
     /// Uruguay Peso en Unidades Indexadas (URUIURUI) (funds code)
-    UYI,
+    UYI = 0,
     /// Uruguayan peso
-    UYU,
+    UYU = 2,
     /// Unidad previsional
-    UYW,
+    UYW = 4,
     /// Uzbekistan som
-    UZS,
+    UZS = 2,
     /// Venezuelan bolívar soberano
-    VES,
+    VES = 2,
     /// Vietnamese đồng
-    VND,
+    VND = 0,
     /// Vanuatu vatu
-    VUV,
+    VUV = 0,
     /// Samoan tala
-    WST,
+    WST = 2,
     /// CFA franc BEAC
-    XAF,
+    XAF = 0,
     /// Silver (one troy ounce)
-    XAG,
+    XAG = 0,
     /// Gold (one troy ounce)
-    XAU,
+    XAU = 0,
     /// European Composite Unit (EURCO) (bond market unit)
-    XBA,
+    XBA = 0,
     /// European Monetary Unit (E.M.U.-6) (bond market unit)
-    XBB,
+    XBB = 0,
     /// European Unit of Account 9 (E.U.A.-9) (bond market unit)
-    XBC,
+    XBC = 0,
     /// European Unit of Account 17 (E.U.A.-17) (bond market unit)
-    XBD,
+    XBD = 0,
     /// East Caribbean dollar
-    XCD,
+    XCD = 2,
     /// Special drawing rights
-    XDR,
+    XDR = 0,
     /// CFA franc BCEAO
-    XOF,
+    XOF = 0,
     /// Palladium (one troy ounce)
-    XPD,
+    XPD = 0,
     /// CFP franc (franc Pacifique)
-    XPF,
+    XPF = 0,
     /// Platinum (one troy ounce)
-    XPT,
+    XPT = 0,
     /// SUCRE
-    XSU,
+    XSU = 0,
+    /// Telegram Stars, used for payments in digital goods
+    XTR = 0,
     /// Code reserved for testing
-    XTS,
+    XTS = 0,
     /// ADB Unit of Account
-    XUA,
+    XUA = 0,
     /// No currency
-    XXX,
+    XXX = 0,
     /// Yemeni rial
-    YER,
+    YER = 2,
     /// South African rand
-    ZAR,
+    ZAR = 2,
     /// Zambian kwacha
-    ZMW,
+    ZMW = 2,
     /// Zimbabwean dollar
-    ZWL,
+    ZWL = 2,
+}
+
+impl Currency {
+    /// Returns `true` if the currency is in the list of [currencies supported
+    /// by Telegram] for payments.
+    ///
+    /// [currencies supported by Telegram]: https://core.telegram.org/bots/payments#supported-currencies
+    pub fn is_supported(self) -> bool {
+        matches!(
+            self,
+            Self::AED
+                | Self::AFN
+                | Self::ALL
+                | Self::AMD
+                | Self::ARS
+                | Self::AUD
+                | Self::AZN
+                | Self::BAM
+                | Self::BDT
+                | Self::BGN
+                | Self::BND
+                | Self::BOB
+                | Self::BRL
+                | Self::BYN
+                | Self::CAD
+                | Self::CHF
+                | Self::CLP
+                | Self::CNY
+                | Self::COP
+                | Self::CRC
+                | Self::CZK
+                | Self::DKK
+                | Self::DOP
+                | Self::DZD
+                | Self::EGP
+                | Self::ETB
+                | Self::EUR
+                | Self::GBP
+                | Self::GEL
+                | Self::GTQ
+                | Self::HKD
+                | Self::HNL
+                | Self::HRK
+                | Self::HUF
+                | Self::IDR
+                | Self::ILS
+                | Self::INR
+                | Self::ISK
+                | Self::JMD
+                | Self::JPY
+                | Self::KES
+                | Self::KGS
+                | Self::KRW
+                | Self::KZT
+                | Self::LBP
+                | Self::LKR
+                | Self::MAD
+                | Self::MDL
+                | Self::MNT
+                | Self::MUR
+                | Self::MVR
+                | Self::MXN
+                | Self::MYR
+                | Self::MZN
+                | Self::NGN
+                | Self::NIO
+                | Self::NOK
+                | Self::NPR
+                | Self::NZD
+                | Self::PAB
+                | Self::PEN
+                | Self::PHP
+                | Self::PKR
+                | Self::PLN
+                | Self::PYG
+                | Self::QAR
+                | Self::RON
+                | Self::RSD
+                | Self::RUB
+                | Self::SAR
+                | Self::SEK
+                | Self::SGD
+                | Self::THB
+                | Self::TJS
+                | Self::TRY
+                | Self::TTD
+                | Self::TWD
+                | Self::TZS
+                | Self::UAH
+                | Self::UGX
+                | Self::USD
+                | Self::UYU
+                | Self::UZS
+                | Self::VND
+                | Self::YER
+                | Self::ZAR
+                | Self::XTR
+        )
+    }
+
+    /// Formats an `amount` in the smallest units of the currency, e.g. `145`
+    /// of [`Currency::USD`] is `"1.45 USD"`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::Currency;
+    ///
+    /// assert_eq!(Currency::USD.format_amount(145), "1.45 USD");
+    /// assert_eq!(Currency::USD.format_amount(-5), "-0.05 USD");
+    /// assert_eq!(Currency::BHD.format_amount(1500), "1.500 BHD");
+    /// assert_eq!(Currency::XTR.format_amount(50), "50 XTR");
+    /// ```
+    pub fn format_amount(self, amount: i64) -> String {
+        let exp = self.exp() as usize;
+        let sign = if amount < 0 { "-" } else { "" };
+        let digits = i128::from(amount).abs().to_string();
+
+        if exp == 0 {
+            return format!("{}{} {}", sign, digits, self);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = exp + 1);
+        let (int, frac) = digits.split_at(digits.len() - exp);
+        format!("{}{}.{} {}", sign, int, frac, self)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes() {
+        assert_eq!("EUR".parse(), Ok(Currency::EUR));
+        assert_eq!("XTR".parse(), Ok(Currency::XTR));
+        assert_eq!("eur".parse::<Currency>(), Err(()));
+        assert_eq!("EURO".parse::<Currency>(), Err(()));
+        assert_eq!(Currency::EUR.to_string(), "EUR");
+
+        // Consistent with serde
+        assert_eq!(serde_json::to_string(&Currency::XTR).unwrap(), r#""XTR""#);
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""KWD""#).unwrap(),
+            Currency::KWD
+        );
+    }
+
+    #[test]
+    fn exp_and_support() {
+        assert_eq!(Currency::KWD.exp(), 3);
+        assert_eq!(Currency::CLF.exp(), 4);
+        assert!(Currency::USD.is_supported());
+        assert!(Currency::XTR.is_supported());
+        assert!(!Currency::XAU.is_supported());
+    }
+
+    #[test]
+    fn format_amount() {
+        assert_eq!(Currency::EUR.format_amount(0), "0.00 EUR");
+        assert_eq!(Currency::EUR.format_amount(100_000), "1000.00 EUR");
+        assert_eq!(Currency::JPY.format_amount(-500), "-500 JPY");
+        assert_eq!(
+            Currency::USD.format_amount(i64::MIN),
+            "-92233720368547758.08 USD"
+        );
+    }
 }
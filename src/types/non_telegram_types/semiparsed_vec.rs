@@ -1,10 +1,15 @@
 use serde::de::DeserializeOwned;
-use serde_json::{from_value, Value};
+use serde_json::Value;
+
+use crate::types::non_strict::from_value;
 
 /// A vector of possibly unparsed JSON objects.
 ///
 /// Similar to `Vec<T>` but if it fails to deserialize element, it just saves
-/// `Err((serde_json::Value, serde_json::Error))`.
+/// `Err((serde_json::Value, serde_json::Error))`. Elements are deserialized
+/// in the [non-strict] mode.
+///
+/// [non-strict]: crate::types::non_strict
 #[derive(Debug, serde::Deserialize)]
 #[serde(from = "Vec<serde_json::Value>")]
 #[serde(bound = "T: DeserializeOwned")]
@@ -14,7 +19,7 @@ impl<T: DeserializeOwned> From<Vec<serde_json::Value>> for SemiparsedVec<T> {
     fn from(vec: Vec<Value>) -> Self {
         Self(
             vec.into_iter()
-                .map(|val| from_value(val.clone()).map_err(|e| (val, e)))
+                .map(|val| from_value(&val).map_err(|e| (val, e)))
                 .collect(),
         )
     }
@@ -19,3 +19,18 @@ pub struct OrderInfo {
     /// User's shipping address.
     pub shipping_address: ShippingAddress,
 }
+
+impl OrderInfo {
+    /// Trims whitespace around the name, the phone number and the email and
+    /// normalizes the shipping address, see [`ShippingAddress::normalize`].
+    pub fn normalize(&mut self) {
+        for field in [&mut self.name, &mut self.phone_number, &mut self.email].iter_mut() {
+            let trimmed = field.trim();
+            if trimmed.len() != field.len() {
+                **field = trimmed.to_owned();
+            }
+        }
+
+        self.shipping_address.normalize();
+    }
+}
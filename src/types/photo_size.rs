@@ -25,6 +25,78 @@ pub struct PhotoSize {
     pub file_size: Option<u32>,
 }
 
+impl PhotoSize {
+    /// Returns the number of pixels in the photo, i.e. `width * height`.
+    pub fn area(&self) -> i64 {
+        i64::from(self.width) * i64::from(self.height)
+    }
+}
+
+/// Selectors for the sizes of a photo, e.g. from [`Message::photo`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::{PhotoSize, PhotoSizesExt};
+///
+/// let size = |width, height, file_size| PhotoSize {
+///     file_id: format!("{}x{}", width, height),
+///     file_unique_id: String::new(),
+///     width,
+///     height,
+///     file_size: Some(file_size),
+/// };
+/// let sizes = [size(90, 60, 1_000), size(320, 213, 15_000), size(1280, 853, 120_000)];
+///
+/// assert_eq!(sizes.largest().unwrap().file_id, "1280x853");
+/// assert_eq!(sizes.smallest().unwrap().file_id, "90x60");
+/// assert_eq!(sizes.best_under_bytes(100_000).unwrap().file_id, "320x213");
+/// assert_eq!(sizes.closest_to(400, 300).unwrap().file_id, "320x213");
+/// ```
+///
+/// [`Message::photo`]: crate::types::Message::photo
+pub trait PhotoSizesExt {
+    /// Returns the size with the most pixels.
+    fn largest(&self) -> Option<&PhotoSize>;
+
+    /// Returns the size with the least pixels.
+    fn smallest(&self) -> Option<&PhotoSize>;
+
+    /// Returns the largest size which is known to be at most `bytes` bytes.
+    ///
+    /// Sizes without [`file_size`] are skipped.
+    ///
+    /// [`file_size`]: PhotoSize::file_size
+    fn best_under_bytes(&self, bytes: u64) -> Option<&PhotoSize>;
+
+    /// Returns the size with dimensions closest to `width`x`height`, i.e. the
+    /// one with the smallest sum of differences of widths and heights.
+    fn closest_to(&self, width: i32, height: i32) -> Option<&PhotoSize>;
+}
+
+impl PhotoSizesExt for [PhotoSize] {
+    fn largest(&self) -> Option<&PhotoSize> {
+        self.iter().max_by_key(|size| size.area())
+    }
+
+    fn smallest(&self) -> Option<&PhotoSize> {
+        self.iter().min_by_key(|size| size.area())
+    }
+
+    fn best_under_bytes(&self, bytes: u64) -> Option<&PhotoSize> {
+        self.iter()
+            .filter(|size| matches!(size.file_size, Some(s) if u64::from(s) <= bytes))
+            .max_by_key(|size| size.area())
+    }
+
+    fn closest_to(&self, width: i32, height: i32) -> Option<&PhotoSize> {
+        self.iter().min_by_key(|size| {
+            (i64::from(size.width) - i64::from(width)).abs()
+                + (i64::from(size.height) - i64::from(height)).abs()
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +115,27 @@ mod tests {
         let actual = serde_json::from_str::<PhotoSize>(json).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn selectors() {
+        let size = |width, height, file_size| PhotoSize {
+            file_id: format!("{}x{}", width, height),
+            file_unique_id: String::new(),
+            width,
+            height,
+            file_size,
+        };
+        let sizes = [
+            size(800, 800, None),
+            size(90, 90, Some(10)),
+            size(320, 320, Some(100)),
+        ];
+
+        assert_eq!(sizes.largest().unwrap().file_id, "800x800");
+        assert_eq!(sizes.best_under_bytes(1000).unwrap().file_id, "320x320");
+        assert_eq!(sizes.best_under_bytes(10).unwrap().file_id, "90x90");
+        assert_eq!(sizes.best_under_bytes(9), None);
+        assert_eq!(sizes.closest_to(100, 50).unwrap().file_id, "90x90");
+        assert_eq!([].smallest(), None);
+    }
 }
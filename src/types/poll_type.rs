@@ -5,4 +5,25 @@ use serde::{Deserialize, Serialize};
 pub enum PollType {
     Quiz,
     Regular,
+
+    /// A type which is unknown to this library (e.g. one added in a newer
+    /// version of the Bot API).
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_type() {
+        let ty: PollType = serde_json::from_str(r#""survey""#).unwrap();
+        assert_eq!(ty, PollType::Other("survey".to_owned()));
+        assert_eq!(serde_json::to_string(&ty).unwrap(), r#""survey""#);
+        assert_eq!(
+            serde_json::from_str::<PollType>(r#""quiz""#).unwrap(),
+            PollType::Quiz
+        );
+    }
 }
@@ -0,0 +1,165 @@
+use crate::{
+    payloads::{AnswerPreCheckoutQuery, AnswerShippingQuery},
+    types::ShippingOption,
+};
+
+/// An answer to a [`ShippingQuery`], see [`RequesterExt::answer_shipping`].
+///
+/// Unlike [`AnswerShippingQuery`], where `ok` and the fields required by it
+/// are set separately, this can't be inconsistent.
+///
+/// [`ShippingQuery`]: crate::types::ShippingQuery
+/// [`RequesterExt::answer_shipping`]: crate::requests::RequesterExt::answer_shipping
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShippingAnswer {
+    /// Delivery to the address is possible with the shipping options.
+    Ok(Vec<ShippingOption>),
+
+    /// Delivery is impossible, the message explaining why is displayed to
+    /// the user.
+    Error(String),
+}
+
+impl ShippingAnswer {
+    /// Creates [`ShippingAnswer::Ok`].
+    pub fn ok<I>(options: I) -> Self
+    where
+        I: IntoIterator<Item = ShippingOption>,
+    {
+        Self::Ok(options.into_iter().collect())
+    }
+
+    /// Creates [`ShippingAnswer::Error`].
+    pub fn error<S>(reason: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Error(reason.into())
+    }
+
+    /// Returns the answer set in the `payload`, or `None` if the payload is
+    /// inconsistent (e.g. `ok` is `true`, but there are no shipping options).
+    pub fn from_payload(payload: &AnswerShippingQuery) -> Option<Self> {
+        match (
+            payload.ok,
+            &payload.shipping_options,
+            &payload.error_message,
+        ) {
+            (true, Some(options), None) => Some(Self::Ok(options.clone())),
+            (false, None, Some(reason)) => Some(Self::Error(reason.clone())),
+            _ => None,
+        }
+    }
+
+    /// Sets the answer to the `payload`, replacing the previous one.
+    pub fn apply(self, payload: &mut AnswerShippingQuery) {
+        match self {
+            Self::Ok(options) => {
+                payload.ok = true;
+                payload.shipping_options = Some(options);
+                payload.error_message = None;
+            }
+            Self::Error(reason) => {
+                payload.ok = false;
+                payload.shipping_options = None;
+                payload.error_message = Some(reason);
+            }
+        }
+    }
+}
+
+/// An answer to a [`PreCheckoutQuery`], see
+/// [`RequesterExt::answer_pre_checkout`].
+///
+/// [`PreCheckoutQuery`]: crate::types::PreCheckoutQuery
+/// [`RequesterExt::answer_pre_checkout`]: crate::requests::RequesterExt::answer_pre_checkout
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PreCheckoutAnswer {
+    /// The bot is ready to proceed with the order.
+    Ok,
+
+    /// The order can't be completed, the message explaining why is displayed
+    /// to the user.
+    Error(String),
+}
+
+impl PreCheckoutAnswer {
+    /// Creates [`PreCheckoutAnswer::Ok`].
+    pub fn ok() -> Self {
+        Self::Ok
+    }
+
+    /// Creates [`PreCheckoutAnswer::Error`].
+    pub fn error<S>(reason: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Error(reason.into())
+    }
+
+    /// Returns the answer set in the `payload`, or `None` if the payload is
+    /// inconsistent (e.g. `ok` is `false`, but there is no error message).
+    pub fn from_payload(payload: &AnswerPreCheckoutQuery) -> Option<Self> {
+        match (payload.ok, &payload.error_message) {
+            (true, None) => Some(Self::Ok),
+            (false, Some(reason)) => Some(Self::Error(reason.clone())),
+            _ => None,
+        }
+    }
+
+    /// Sets the answer to the `payload`, replacing the previous one.
+    pub fn apply(self, payload: &mut AnswerPreCheckoutQuery) {
+        match self {
+            Self::Ok => {
+                payload.ok = true;
+                payload.error_message = None;
+            }
+            Self::Error(reason) => {
+                payload.ok = false;
+                payload.error_message = Some(reason);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payloads::AnswerShippingQuerySetters;
+
+    #[test]
+    fn shipping_answer() {
+        let mut payload = AnswerShippingQuery::new("id", true);
+        assert_eq!(ShippingAnswer::from_payload(&payload), None);
+
+        let answer = ShippingAnswer::ok(vec![ShippingOption::new("0", "Post", vec![])]);
+        answer.clone().apply(&mut payload);
+        assert_eq!(ShippingAnswer::from_payload(&payload), Some(answer));
+
+        ShippingAnswer::error("Sorry").apply(&mut payload);
+        assert!(!payload.ok);
+        assert_eq!(payload.shipping_options, None);
+        assert_eq!(payload.error_message.as_deref(), Some("Sorry"));
+
+        let payload = payload.shipping_options(vec![]);
+        assert_eq!(ShippingAnswer::from_payload(&payload), None);
+    }
+
+    #[test]
+    fn pre_checkout_answer() {
+        let mut payload = AnswerPreCheckoutQuery::new("id", false);
+        assert_eq!(PreCheckoutAnswer::from_payload(&payload), None);
+
+        PreCheckoutAnswer::error("Sold out").apply(&mut payload);
+        assert_eq!(
+            PreCheckoutAnswer::from_payload(&payload),
+            Some(PreCheckoutAnswer::error("Sold out"))
+        );
+
+        PreCheckoutAnswer::ok().apply(&mut payload);
+        assert_eq!(
+            PreCheckoutAnswer::from_payload(&payload),
+            Some(PreCheckoutAnswer::Ok)
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::ReactionType;
+
+/// Represents a reaction added to a message along with the number of times it
+/// was added.
+///
+/// [The official docs](https://core.telegram.org/bots/api#reactioncount).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReactionCount {
+    /// Type of the reaction.
+    #[serde(rename = "type")]
+    pub kind: ReactionType,
+
+    /// Number of times the reaction was added.
+    pub total_count: u32,
+}
+
+impl ReactionCount {
+    /// Compares reaction counts so that the most popular reactions go first.
+    ///
+    /// Reactions with the same count are ordered by [`ReactionType`], so the
+    /// order is stable between updates.
+    pub fn cmp_popularity(&self, other: &Self) -> Ordering {
+        other
+            .total_count
+            .cmp(&self.total_count)
+            .then_with(|| self.kind.cmp(&other.kind))
+    }
+
+    /// Sorts reaction counts so that the most popular reactions go first, see
+    /// [`cmp_popularity`].
+    ///
+    /// [`cmp_popularity`]: ReactionCount::cmp_popularity
+    pub fn sort_by_popularity(counts: &mut [Self]) {
+        counts.sort_by(Self::cmp_popularity);
+    }
+
+    /// Returns the `page`-th (starting from `0`) page of `counts` with at most
+    /// `per_page` reactions, the page is empty if it's out of range.
+    ///
+    /// `counts` are expected to be sorted, e.g. with
+    /// [`sort_by_popularity`].
+    ///
+    /// [`sort_by_popularity`]: ReactionCount::sort_by_popularity
+    pub fn page(counts: &[Self], page: usize, per_page: usize) -> &[Self] {
+        let start = page.saturating_mul(per_page).min(counts.len());
+        let end = start.saturating_add(per_page).min(counts.len());
+        &counts[start..end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(emoji: &str, total_count: u32) -> ReactionCount {
+        ReactionCount {
+            kind: ReactionType::emoji(emoji),
+            total_count,
+        }
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{"type":{"type":"emoji","emoji":"👍"},"total_count":3}"#;
+        assert_eq!(
+            serde_json::from_str::<ReactionCount>(json).unwrap(),
+            count("👍", 3)
+        );
+    }
+
+    #[test]
+    fn popularity_pages() {
+        let mut counts = vec![count("a", 1), count("c", 5), count("b", 5), count("d", 2)];
+        ReactionCount::sort_by_popularity(&mut counts);
+
+        assert_eq!(
+            counts,
+            [count("b", 5), count("c", 5), count("d", 2), count("a", 1)]
+        );
+        assert_eq!(ReactionCount::page(&counts, 1, 3), [count("a", 1)]);
+        assert!(ReactionCount::page(&counts, 2, 3).is_empty());
+    }
+}
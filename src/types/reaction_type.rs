@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of a reaction.
+///
+/// [The official docs](https://core.telegram.org/bots/api#reactiontype).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+pub enum ReactionType {
+    /// The reaction is based on an emoji.
+    Emoji {
+        /// Reaction emoji, e.g.: “👍”. See the [official docs] for the list of
+        /// allowed emoji.
+        ///
+        /// [official docs]: https://core.telegram.org/bots/api#reactiontypeemoji
+        emoji: String,
+    },
+    /// The reaction is based on a custom emoji.
+    CustomEmoji {
+        /// Custom emoji identifier.
+        custom_emoji_id: String,
+    },
+}
+
+impl ReactionType {
+    /// Creates an emoji reaction.
+    pub fn emoji<S>(emoji: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::Emoji {
+            emoji: emoji.into(),
+        }
+    }
+
+    /// Creates a custom emoji reaction.
+    pub fn custom_emoji<S>(custom_emoji_id: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::CustomEmoji {
+            custom_emoji_id: custom_emoji_id.into(),
+        }
+    }
+}
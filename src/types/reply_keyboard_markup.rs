@@ -42,6 +42,15 @@ pub struct KeyboardMarkup {
     ///
     /// [`Message`]: crate::types::Message
     pub selective: Option<bool>,
+
+    /// Requests clients to always show the keyboard when the regular keyboard
+    /// is hidden. Defaults to `false`, in which case the custom keyboard can
+    /// be hidden and opened with a keyboard icon.
+    pub is_persistent: Option<bool>,
+
+    /// The placeholder to be shown in the input field when the keyboard is
+    /// active; 1-64 characters.
+    pub input_field_placeholder: Option<String>,
 }
 
 impl KeyboardMarkup {
@@ -59,6 +68,8 @@ impl KeyboardMarkup {
             resize_keyboard: None,
             one_time_keyboard: None,
             selective: None,
+            is_persistent: None,
+            input_field_placeholder: None,
         }
     }
 
@@ -98,4 +109,63 @@ impl KeyboardMarkup {
         self.selective = val.into();
         self
     }
+
+    pub fn is_persistent<T>(mut self, val: T) -> Self
+    where
+        T: Into<Option<bool>>,
+    {
+        self.is_persistent = val.into();
+        self
+    }
+
+    pub fn input_field_placeholder<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.input_field_placeholder = Some(val.into());
+        self
+    }
+
+    /// Requests clients to resize the keyboard, a shortcut for
+    /// `.resize_keyboard(true)`.
+    pub fn resize(self) -> Self {
+        self.resize_keyboard(true)
+    }
+
+    /// Requests clients to hide the keyboard after it's used, a shortcut for
+    /// `.one_time_keyboard(true)`.
+    pub fn one_time(self) -> Self {
+        self.one_time_keyboard(true)
+    }
+
+    /// Requests clients to always show the keyboard, a shortcut for
+    /// `.is_persistent(true)`.
+    pub fn persistent(self) -> Self {
+        self.is_persistent(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let markup = KeyboardMarkup::new(vec![vec![KeyboardButton::new("Yes")]]);
+        assert_eq!(
+            serde_json::to_string(&markup).unwrap(),
+            r#"{"keyboard":[[{"text":"Yes"}]]}"#
+        );
+
+        let markup = markup
+            .resize()
+            .one_time()
+            .persistent()
+            .selective(true)
+            .input_field_placeholder("Are you sure?");
+        assert_eq!(
+            serde_json::to_string(&markup).unwrap(),
+            r#"{"keyboard":[[{"text":"Yes"}]],"resize_keyboard":true,"one_time_keyboard":true,"selective":true,"is_persistent":true,"input_field_placeholder":"Are you sure?"}"#
+        );
+    }
 }
@@ -24,3 +24,206 @@ pub struct ShippingAddress {
     /// Address post code.
     pub post_code: String,
 }
+
+impl ShippingAddress {
+    /// Normalizes the address as entered by the user.
+    ///
+    /// Leading and trailing whitespace is removed from all the fields and
+    /// inner whitespace is collapsed into single spaces. The post code is
+    /// uppercased and, if it matches the format used in the country (see
+    /// [`ShippingAddress::post_code_is_valid`]), reformatted with the usual
+    /// separators, e.g. `"sw1a1aa"` becomes `"SW1A 1AA"` in
+    /// [`CountryCode::GB`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{CountryCode, ShippingAddress};
+    ///
+    /// let mut address = ShippingAddress {
+    ///     country_code: CountryCode::CA,
+    ///     state: "ON".to_owned(),
+    ///     city: " Ottawa ".to_owned(),
+    ///     street_line1: "111  Wellington  St".to_owned(),
+    ///     street_line2: String::new(),
+    ///     post_code: "k1a0a9".to_owned(),
+    /// };
+    ///
+    /// address.normalize();
+    /// assert_eq!(address.city, "Ottawa");
+    /// assert_eq!(address.street_line1, "111 Wellington St");
+    /// assert_eq!(address.post_code, "K1A 0A9");
+    /// ```
+    pub fn normalize(&mut self) {
+        for field in [
+            &mut self.state,
+            &mut self.city,
+            &mut self.street_line1,
+            &mut self.street_line2,
+        ]
+        .iter_mut()
+        {
+            **field = collapse_whitespace(field);
+        }
+
+        let post_code = collapse_whitespace(&self.post_code).to_uppercase();
+        self.post_code = post_code_formats(self.country_code)
+            .iter()
+            .find_map(|format| reformat(format, &post_code))
+            .unwrap_or(post_code);
+    }
+
+    /// Checks the post code against the formats used in the country.
+    ///
+    /// Letters are compared case-insensitively and separators (spaces and
+    /// `-`) are ignored, so e.g. both `"12345-6789"` and `"123456789"` are
+    /// valid in [`CountryCode::US`].
+    ///
+    /// Returns `None` if the format of post codes in the country is not known
+    /// to this library (or the country doesn't use post codes), in which case
+    /// you may want to use your own validation.
+    pub fn post_code_is_valid(&self) -> Option<bool> {
+        let formats = post_code_formats(self.country_code);
+        if formats.is_empty() {
+            return None;
+        }
+
+        Some(
+            formats
+                .iter()
+                .any(|format| reformat(format, &self.post_code).is_some()),
+        )
+    }
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Formats of post codes: `9` is a digit, `A` is a letter, ` ` and `-` are
+/// separators.
+fn post_code_formats(country: CountryCode) -> &'static [&'static str] {
+    use CountryCode::*;
+
+    match country {
+        AT | AU | BE | BG | CH | DK | HU | NO | NZ | ZA => &["9999"],
+        DE | EE | ES | FI | FR | HR | IT | KR | MX | TR | UA => &["99999"],
+        BY | CN | IN | RO | RU | SG | UZ => &["999999"],
+        CZ | GR | SE | SK => &["999 99"],
+        BR => &["99999-999"],
+        CA => &["A9A 9A9"],
+        GB => &[
+            "A9 9AA", "A99 9AA", "A9A 9AA", "AA9 9AA", "AA99 9AA", "AA9A 9AA",
+        ],
+        IL => &["9999999"],
+        JP => &["999-9999"],
+        NL => &["9999 AA"],
+        PL => &["99-999"],
+        PT => &["9999-999"],
+        US => &["99999", "99999-9999"],
+        _ => &[],
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c == ' ' || c == '-'
+}
+
+/// Formats the `post_code` according to the `format`, returns `None` if it
+/// doesn't match the format.
+fn reformat(format: &str, post_code: &str) -> Option<String> {
+    let mut chars = post_code.chars().filter(|&c| !is_separator(c));
+    let mut formatted = String::with_capacity(format.len());
+
+    for f in format.chars() {
+        if is_separator(f) {
+            formatted.push(f);
+            continue;
+        }
+
+        let c = chars.next()?.to_ascii_uppercase();
+        let matches = match f {
+            '9' => c.is_ascii_digit(),
+            _ => c.is_ascii_uppercase(),
+        };
+        if !matches {
+            return None;
+        }
+        formatted.push(c);
+    }
+
+    match chars.next() {
+        Some(_) => None,
+        None => Some(formatted),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(country_code: CountryCode, post_code: &str) -> ShippingAddress {
+        ShippingAddress {
+            country_code,
+            state: String::new(),
+            city: "City".to_owned(),
+            street_line1: "Street 1".to_owned(),
+            street_line2: String::new(),
+            post_code: post_code.to_owned(),
+        }
+    }
+
+    #[test]
+    fn post_code_validation() {
+        assert_eq!(
+            address(CountryCode::US, "12345").post_code_is_valid(),
+            Some(true)
+        );
+        assert_eq!(
+            address(CountryCode::US, "12345-6789").post_code_is_valid(),
+            Some(true)
+        );
+        assert_eq!(
+            address(CountryCode::US, "1234").post_code_is_valid(),
+            Some(false)
+        );
+        assert_eq!(
+            address(CountryCode::US, "").post_code_is_valid(),
+            Some(false)
+        );
+        assert_eq!(
+            address(CountryCode::NL, "1234 ab").post_code_is_valid(),
+            Some(true)
+        );
+        assert_eq!(
+            address(CountryCode::NL, "ab 1234").post_code_is_valid(),
+            Some(false)
+        );
+        assert_eq!(
+            address(CountryCode::AQ, "whatever").post_code_is_valid(),
+            None
+        );
+    }
+
+    #[test]
+    fn normalization() {
+        let mut gb = address(CountryCode::GB, " ec1a  1bb ");
+        gb.city = " London\t".to_owned();
+        gb.normalize();
+        assert_eq!(gb.post_code, "EC1A 1BB");
+        assert_eq!(gb.city, "London");
+
+        let mut gb = address(CountryCode::GB, "m11ae");
+        gb.normalize();
+        assert_eq!(gb.post_code, "M1 1AE");
+
+        let mut pl = address(CountryCode::PL, "00950");
+        pl.normalize();
+        assert_eq!(pl.post_code, "00-950");
+
+        // Unknown formats are only cleaned up
+        let mut invalid = address(CountryCode::DE, "abc  12");
+        invalid.normalize();
+        assert_eq!(invalid.post_code, "ABC 12");
+    }
+}
@@ -2,6 +2,23 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::LabeledPrice;
 
+/// An error returned by [`ShippingOptionBuilder::build`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum InvalidPrices {
+    /// There are no prices.
+    #[error("the list of prices is empty")]
+    Empty,
+
+    /// The total doesn't fit into `i32`.
+    #[error("the total price overflows")]
+    Overflow,
+
+    /// The total is zero or negative (only separate prices, e.g. discounts,
+    /// may be negative).
+    #[error("the total price must be positive")]
+    NonPositiveTotal,
+}
+
 /// This object represents one shipping option.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#shippingoption).
@@ -18,6 +35,38 @@ pub struct ShippingOption {
 }
 
 impl ShippingOption {
+    /// Creates a builder of a shipping option which validates the prices.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{InvalidPrices, ShippingOption};
+    ///
+    /// let option = ShippingOption::builder("express", "Express")
+    ///     .price("Delivery", 1000)
+    ///     .price("Discount", -200)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(option.total(), Some(800));
+    ///
+    /// let err = ShippingOption::builder("free", "Free").build();
+    /// assert_eq!(err, Err(InvalidPrices::Empty));
+    /// ```
+    pub fn builder<S1, S2>(id: S1, title: S2) -> ShippingOptionBuilder
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        ShippingOptionBuilder {
+            option: Self::new(id, title, None),
+        }
+    }
+
+    /// Returns the sum of the prices, or `None` if it overflows.
+    pub fn total(&self) -> Option<i32> {
+        LabeledPrice::total(&self.prices)
+    }
+
     pub fn new<S1, S2, P>(id: S1, title: S2, prices: P) -> Self
     where
         S1: Into<String>,
@@ -56,6 +105,47 @@ impl ShippingOption {
     }
 }
 
+/// A builder of [`ShippingOption`], see [`ShippingOption::builder`].
+#[derive(Clone, Debug)]
+#[must_use = "`ShippingOptionBuilder` does nothing unless built"]
+pub struct ShippingOptionBuilder {
+    option: ShippingOption,
+}
+
+impl ShippingOptionBuilder {
+    /// Adds a price portion.
+    pub fn price<S>(mut self, label: S, amount: i32) -> Self
+    where
+        S: Into<String>,
+    {
+        self.option.prices.push(LabeledPrice::new(label, amount));
+        self
+    }
+
+    /// Adds price portions.
+    pub fn prices<P>(mut self, prices: P) -> Self
+    where
+        P: IntoIterator<Item = LabeledPrice>,
+    {
+        self.option.prices.extend(prices);
+        self
+    }
+
+    /// Builds the shipping option, checking that there is at least one price
+    /// and that the total is positive.
+    pub fn build(self) -> Result<ShippingOption, InvalidPrices> {
+        if self.option.prices.is_empty() {
+            return Err(InvalidPrices::Empty);
+        }
+
+        match self.option.total() {
+            None => Err(InvalidPrices::Overflow),
+            Some(total) if total <= 0 => Err(InvalidPrices::NonPositiveTotal),
+            Some(_) => Ok(self.option),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +164,30 @@ mod tests {
         let actual = serde_json::to_string(&shipping_option).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn builder() {
+        let option = ShippingOption::builder("0", "Option")
+            .price("Label", 60)
+            .build()
+            .unwrap();
+        assert_eq!(
+            option,
+            ShippingOption::new("0", "Option", vec![LabeledPrice::new("Label", 60)])
+        );
+
+        let overflow = ShippingOption::builder("0", "Option")
+            .prices(vec![
+                LabeledPrice::new("a", i32::MAX),
+                LabeledPrice::new("b", 1),
+            ])
+            .build();
+        assert_eq!(overflow, Err(InvalidPrices::Overflow));
+
+        let free = ShippingOption::builder("0", "Option")
+            .price("Delivery", 100)
+            .price("Discount", -100)
+            .build();
+        assert_eq!(free, Err(InvalidPrices::NonPositiveTotal));
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{MaskPosition, PhotoSize};
+use crate::types::{File, MaskPosition, PhotoSize, StickerFormat};
 
 /// This object represents a sticker.
 ///
@@ -27,8 +27,15 @@ pub struct Sticker {
     /// [animated]: https://telegram.org/blog/animated-stickers
     pub is_animated: bool,
 
+    /// `true`, if the sticker is a [video sticker].
+    ///
+    /// [video sticker]: https://telegram.org/blog/video-stickers-better-reactions
+    #[serde(default)]
+    pub is_video: bool,
+
     /// Sticker thumbnail in the .webp or .jpg format.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 
     /// Emoji associated with the sticker.
     pub emoji: Option<String>,
@@ -36,9 +43,78 @@ pub struct Sticker {
     /// Name of the sticker set to which the sticker belongs.
     pub set_name: Option<String>,
 
+    /// Premium animation for the sticker, if the sticker is premium.
+    pub premium_animation: Option<File>,
+
     /// For mask stickers, the position where the mask should be placed.
     pub mask_position: Option<MaskPosition>,
 
+    /// For custom emoji stickers, unique identifier of the custom emoji.
+    pub custom_emoji_id: Option<String>,
+
+    /// `true`, if the sticker must be repainted to a text color in messages,
+    /// the color of the Telegram Premium badge in emoji status, white color on
+    /// chat photos, or another appropriate color in other places. For custom
+    /// emoji stickers only.
+    #[serde(default)]
+    pub needs_repainting: bool,
+
     /// File size.
     pub file_size: Option<u32>,
 }
+
+impl Sticker {
+    /// Returns the format of the sticker file.
+    pub fn format(&self) -> StickerFormat {
+        StickerFormat::from_flags(self.is_animated, self.is_video)
+    }
+
+    /// Returns `true` if the sticker is a static (`.webp`) sticker.
+    pub fn is_static(&self) -> bool {
+        self.format() == StickerFormat::Static
+    }
+
+    /// Returns `true` if the sticker is a custom emoji.
+    pub fn is_custom_emoji(&self) -> bool {
+        self.custom_emoji_id.is_some()
+    }
+
+    /// Returns `true` if the sticker is premium, i.e. has a
+    /// [`premium_animation`].
+    ///
+    /// [`premium_animation`]: Sticker::premium_animation
+    pub fn is_premium(&self) -> bool {
+        self.premium_animation.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize() {
+        let json = r#"{
+            "file_id": "id",
+            "file_unique_id": "unique",
+            "width": 100,
+            "height": 100,
+            "is_animated": false,
+            "is_video": true,
+            "custom_emoji_id": "123",
+            "needs_repainting": true,
+            "premium_animation": {
+                "file_id": "anim",
+                "file_unique_id": "anim_unique",
+                "file_size": 42
+            }
+        }"#;
+        let sticker = serde_json::from_str::<Sticker>(json).unwrap();
+
+        assert_eq!(sticker.format(), StickerFormat::Video);
+        assert!(sticker.is_custom_emoji());
+        assert!(sticker.is_premium());
+        assert!(sticker.needs_repainting);
+        assert_eq!(sticker.premium_animation.unwrap().file_path, None);
+    }
+}
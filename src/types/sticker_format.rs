@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// Format of a sticker file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerFormat {
+    /// `.webp` (or `.png` when uploading) image.
+    Static,
+
+    /// `.tgs` animation, see [technical requirements].
+    ///
+    /// [technical requirements]: https://core.telegram.org/stickers#animated-sticker-requirements
+    Animated,
+
+    /// `.webm` video, see [technical requirements].
+    ///
+    /// [technical requirements]: https://core.telegram.org/stickers#video-sticker-requirements
+    Video,
+}
+
+impl StickerFormat {
+    /// Returns the format of a sticker from its `is_animated` and `is_video`
+    /// flags (e.g. [`Sticker::is_animated`] and [`Sticker::is_video`]).
+    ///
+    /// [`Sticker::is_animated`]: crate::types::Sticker::is_animated
+    /// [`Sticker::is_video`]: crate::types::Sticker::is_video
+    pub fn from_flags(is_animated: bool, is_video: bool) -> Self {
+        match (is_animated, is_video) {
+            (true, _) => Self::Animated,
+            (false, true) => Self::Video,
+            (false, false) => Self::Static,
+        }
+    }
+
+    /// Returns the extension of files in this format (of stickers sent by
+    /// Telegram).
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Static => "webp",
+            Self::Animated => "tgs",
+            Self::Video => "webm",
+        }
+    }
+}
@@ -25,5 +25,6 @@ pub struct StickerSet {
     pub stickers: Vec<Sticker>,
 
     /// Sticker set thumbnail in the .WEBP or .TGS format.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Chat;
+
+/// This object represents a story.
+///
+/// [The official docs](https://core.telegram.org/bots/api#story).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Story {
+    /// Chat that posted the story.
+    pub chat: Chat,
+
+    /// Unique identifier for the story in the chat.
+    pub id: i32,
+}
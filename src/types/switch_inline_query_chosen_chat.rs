@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// This object represents an inline button that switches the current user to
+/// inline mode in a chosen chat, with an optional default inline query.
+///
+/// Note that at least one of the `allow_*` fields should be `true`, otherwise
+/// the user won't be able to choose any chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#switchinlinequerychosenchat).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SwitchInlineQueryChosenChat {
+    /// The default inline query to be inserted in the input field. If left
+    /// empty, only the bot's username will be inserted.
+    #[serde(default)]
+    pub query: String,
+
+    /// `true`, if private chats with users can be chosen.
+    #[serde(default)]
+    pub allow_user_chats: bool,
+
+    /// `true`, if private chats with bots can be chosen.
+    #[serde(default)]
+    pub allow_bot_chats: bool,
+
+    /// `true`, if group and supergroup chats can be chosen.
+    #[serde(default)]
+    pub allow_group_chats: bool,
+
+    /// `true`, if channel chats can be chosen.
+    #[serde(default)]
+    pub allow_channel_chats: bool,
+}
+
+impl SwitchInlineQueryChosenChat {
+    /// Creates a new `SwitchInlineQueryChosenChat` with the `query`, which
+    /// doesn't allow any chats yet.
+    pub fn new<S>(query: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn query<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.query = val.into();
+        self
+    }
+
+    pub fn allow_user_chats(mut self, val: bool) -> Self {
+        self.allow_user_chats = val;
+        self
+    }
+
+    pub fn allow_bot_chats(mut self, val: bool) -> Self {
+        self.allow_bot_chats = val;
+        self
+    }
+
+    pub fn allow_group_chats(mut self, val: bool) -> Self {
+        self.allow_group_chats = val;
+        self
+    }
+
+    pub fn allow_channel_chats(mut self, val: bool) -> Self {
+        self.allow_channel_chats = val;
+        self
+    }
+
+    /// Allows all kinds of chats to be chosen.
+    pub fn allow_all(self) -> Self {
+        self.allow_user_chats(true)
+            .allow_bot_chats(true)
+            .allow_group_chats(true)
+            .allow_channel_chats(true)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::ChatId;
+use crate::types::{ChatId, Message};
 
 use serde::{Deserialize, Serialize};
 
@@ -15,3 +15,61 @@ impl From<String> for TargetMessage {
         Self::Inline { inline_message_id }
     }
 }
+
+impl From<&Message> for TargetMessage {
+    fn from(message: &Message) -> Self {
+        Self::Common {
+            chat_id: ChatId::Id(message.chat.id),
+            message_id: message.id,
+        }
+    }
+}
+
+impl<C> From<(C, i32)> for TargetMessage
+where
+    C: Into<ChatId>,
+{
+    fn from((chat_id, message_id): (C, i32)) -> Self {
+        Self::Common {
+            chat_id: chat_id.into(),
+            message_id,
+        }
+    }
+}
+
+/// A game message in chat or inline message.
+///
+/// Unlike [`TargetMessage`], the chat can only be specified by its numeric
+/// identifier, since that's the only thing [`SetGameScore`] accepts.
+///
+/// [`SetGameScore`]: crate::payloads::SetGameScore
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GameTarget {
+    Common { chat_id: i64, message_id: i32 },
+    Inline { inline_message_id: String },
+}
+
+impl From<String> for GameTarget {
+    fn from(inline_message_id: String) -> Self {
+        Self::Inline { inline_message_id }
+    }
+}
+
+impl From<&Message> for GameTarget {
+    fn from(message: &Message) -> Self {
+        Self::Common {
+            chat_id: message.chat.id,
+            message_id: message.id,
+        }
+    }
+}
+
+impl From<(i64, i32)> for GameTarget {
+    fn from((chat_id, message_id): (i64, i32)) -> Self {
+        Self::Common {
+            chat_id,
+            message_id,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Unique identifier of a message thread, i.e. of a forum topic in a forum
+/// supergroup.
+///
+/// Threads are identified by the id of the message which started them, so a
+/// `ThreadId` can be obtained from [`Message::thread_id`] or created from a
+/// message id.
+///
+/// [`Message::thread_id`]: crate::types::Message::thread_id
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ThreadId(pub i32);
+
+impl From<i32> for ThreadId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ThreadId> for i32 {
+    fn from(ThreadId(id): ThreadId) -> Self {
+        id
+    }
+}
+
+impl fmt::Display for ThreadId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -3,8 +3,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    CallbackQuery, Chat, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message, Poll,
-    PollAnswer, PreCheckoutQuery, ShippingQuery, User,
+    non_strict, AllowedUpdate, BusinessConnection, BusinessMessagesDeleted, CallbackQuery, Chat,
+    ChatJoinRequest, ChatMemberUpdated, ChosenInlineResult, InlineQuery, Message,
+    MessageReactionCountUpdated, MessageReactionUpdated, Poll, PollAnswer, PreCheckoutQuery,
+    ShippingQuery, ThreadId, User,
 };
 use serde_json::Value;
 
@@ -32,9 +34,12 @@ pub struct Update {
 impl Update {
     /// Tries to parse `value` into `Update`, logging an error on failure.
     ///
-    /// It is used to implement update listeners.
+    /// The value is parsed in the [non-strict] mode. It is used to implement
+    /// update listeners.
+    ///
+    /// [non-strict]: crate::types::non_strict
     pub fn try_parse(value: &Value) -> Result<Self, serde_json::Error> {
-        match serde_json::from_value(value.clone()) {
+        match non_strict::from_value(value) {
             Ok(update) => Ok(update),
             Err(error) => {
                 log::error!(
@@ -108,30 +113,216 @@ pub enum UpdateKind {
     ///
     /// [`AllowedUpdate::ChatMember`]: crate::types::AllowedUpdate::ChatMember
     ChatMember(ChatMemberUpdated),
+
+    /// A request to join the chat has been sent. The bot must have the
+    /// _can_invite_users_ administrator right in the chat to receive these
+    /// updates.
+    ChatJoinRequest(ChatJoinRequest),
+
+    /// A reaction to a message was changed by a user. The bot must be an
+    /// administrator in the chat and must explicitly specify
+    /// [`AllowedUpdate::MessageReaction`] in the list of `allowed_updates` to
+    /// receive these updates.
+    MessageReaction(MessageReactionUpdated),
+
+    /// Reactions to a message with anonymous reactions were changed. The bot
+    /// must be an administrator in the chat and must explicitly specify
+    /// [`AllowedUpdate::MessageReactionCount`] in the list of
+    /// `allowed_updates` to receive these updates.
+    MessageReactionCount(MessageReactionCountUpdated),
+
+    /// The bot was connected to or disconnected from a business account, or a
+    /// user edited an existing connection with the bot.
+    BusinessConnection(BusinessConnection),
+
+    /// New message from a connected business account.
+    BusinessMessage(Message),
+
+    /// New version of a message from a connected business account.
+    EditedBusinessMessage(Message),
+
+    /// Messages were deleted from a connected business account.
+    DeletedBusinessMessages(BusinessMessagesDeleted),
+}
+
+impl UpdateKind {
+    /// Returns the [`AllowedUpdate`] which corresponds to this kind of
+    /// updates.
+    pub fn allowed_update(&self) -> AllowedUpdate {
+        match self {
+            UpdateKind::Message(_) => AllowedUpdate::Message,
+            UpdateKind::EditedMessage(_) => AllowedUpdate::EditedMessage,
+            UpdateKind::ChannelPost(_) => AllowedUpdate::ChannelPost,
+            UpdateKind::EditedChannelPost(_) => AllowedUpdate::EditedChannelPost,
+            UpdateKind::InlineQuery(_) => AllowedUpdate::InlineQuery,
+            UpdateKind::ChosenInlineResult(_) => AllowedUpdate::ChosenInlineResult,
+            UpdateKind::CallbackQuery(_) => AllowedUpdate::CallbackQuery,
+            UpdateKind::ShippingQuery(_) => AllowedUpdate::ShippingQuery,
+            UpdateKind::PreCheckoutQuery(_) => AllowedUpdate::PreCheckoutQuery,
+            UpdateKind::Poll(_) => AllowedUpdate::Poll,
+            UpdateKind::PollAnswer(_) => AllowedUpdate::PollAnswer,
+            UpdateKind::MyChatMember(_) => AllowedUpdate::MyChatMember,
+            UpdateKind::ChatMember(_) => AllowedUpdate::ChatMember,
+            UpdateKind::ChatJoinRequest(_) => AllowedUpdate::ChatJoinRequest,
+            UpdateKind::MessageReaction(_) => AllowedUpdate::MessageReaction,
+            UpdateKind::MessageReactionCount(_) => AllowedUpdate::MessageReactionCount,
+            UpdateKind::BusinessConnection(_) => AllowedUpdate::BusinessConnection,
+            UpdateKind::BusinessMessage(_) => AllowedUpdate::BusinessMessage,
+            UpdateKind::EditedBusinessMessage(_) => AllowedUpdate::EditedBusinessMessage,
+            UpdateKind::DeletedBusinessMessages(_) => AllowedUpdate::DeletedBusinessMessages,
+        }
+    }
 }
 
 impl Update {
+    /// Returns the user who caused the update, if any.
+    ///
+    /// That is the sender of a message (or of an edited message), the user
+    /// who sent a query or answered a poll, the user who changed a chat
+    /// member status or a reaction, the user who sent a join request, or the
+    /// owner of a business account.
+    ///
+    /// Returns `None` for updates which aren't caused by a particular user,
+    /// i.e. for [`UpdateKind::Poll`], [`UpdateKind::MessageReactionCount`],
+    /// [`UpdateKind::DeletedBusinessMessages`] and for channel posts,
+    /// messages or reactions sent on behalf of a chat.
     pub fn user(&self) -> Option<&User> {
         match &self.kind {
-            UpdateKind::Message(m) => m.from(),
-            UpdateKind::EditedMessage(m) => m.from(),
+            UpdateKind::Message(m)
+            | UpdateKind::EditedMessage(m)
+            | UpdateKind::ChannelPost(m)
+            | UpdateKind::EditedChannelPost(m)
+            | UpdateKind::BusinessMessage(m)
+            | UpdateKind::EditedBusinessMessage(m) => m.from(),
             UpdateKind::CallbackQuery(query) => Some(&query.from),
             UpdateKind::ChosenInlineResult(chosen) => Some(&chosen.from),
             UpdateKind::InlineQuery(query) => Some(&query.from),
             UpdateKind::ShippingQuery(query) => Some(&query.from),
             UpdateKind::PreCheckoutQuery(query) => Some(&query.from),
             UpdateKind::PollAnswer(answer) => Some(&answer.user),
-            _ => None,
+            UpdateKind::ChatJoinRequest(request) => Some(&request.from),
+            UpdateKind::MyChatMember(updated) | UpdateKind::ChatMember(updated) => {
+                Some(&updated.from)
+            }
+            UpdateKind::MessageReaction(reaction) => reaction.user.as_ref(),
+            UpdateKind::BusinessConnection(connection) => Some(&connection.user),
+            UpdateKind::Poll(_)
+            | UpdateKind::MessageReactionCount(_)
+            | UpdateKind::DeletedBusinessMessages(_) => None,
         }
     }
 
+    /// Returns the chat in which the update happened, if any.
+    ///
+    /// Returns `None` for updates which aren't bound to a chat, i.e. inline
+    /// queries, chosen inline results, shipping and pre-checkout queries,
+    /// polls and poll answers, business connections and callback queries from
+    /// inline messages.
     pub fn chat(&self) -> Option<&Chat> {
         match &self.kind {
-            UpdateKind::Message(m) => Some(&m.chat),
-            UpdateKind::EditedMessage(m) => Some(&m.chat),
-            UpdateKind::ChannelPost(p) => Some(&p.chat),
-            UpdateKind::EditedChannelPost(p) => Some(&p.chat),
-            UpdateKind::CallbackQuery(q) => Some(&q.message.as_ref()?.chat),
+            UpdateKind::Message(m)
+            | UpdateKind::EditedMessage(m)
+            | UpdateKind::ChannelPost(m)
+            | UpdateKind::EditedChannelPost(m)
+            | UpdateKind::BusinessMessage(m)
+            | UpdateKind::EditedBusinessMessage(m) => Some(&m.chat),
+            UpdateKind::CallbackQuery(q) => Some(q.message.as_ref()?.chat()),
+            UpdateKind::MyChatMember(updated) | UpdateKind::ChatMember(updated) => {
+                Some(&updated.chat)
+            }
+            UpdateKind::ChatJoinRequest(request) => Some(&request.chat),
+            UpdateKind::MessageReaction(reaction) => Some(&reaction.chat),
+            UpdateKind::MessageReactionCount(reaction) => Some(&reaction.chat),
+            UpdateKind::DeletedBusinessMessages(deleted) => Some(&deleted.chat),
+            UpdateKind::InlineQuery(_)
+            | UpdateKind::ChosenInlineResult(_)
+            | UpdateKind::ShippingQuery(_)
+            | UpdateKind::PreCheckoutQuery(_)
+            | UpdateKind::Poll(_)
+            | UpdateKind::PollAnswer(_)
+            | UpdateKind::BusinessConnection(_) => None,
+        }
+    }
+
+    /// Returns the id of the chat in which the update happened, if any.
+    ///
+    /// See [`Update::chat`] for more.
+    pub fn chat_id(&self) -> Option<i64> {
+        self.chat().map(|chat| chat.id)
+    }
+
+    /// Returns the thread in which the update happened, if any.
+    ///
+    /// Only messages and callback queries from accessible messages can belong
+    /// to a thread, see [`Message::thread_id`].
+    pub fn thread_id(&self) -> Option<ThreadId> {
+        match &self.kind {
+            UpdateKind::Message(m)
+            | UpdateKind::EditedMessage(m)
+            | UpdateKind::ChannelPost(m)
+            | UpdateKind::EditedChannelPost(m)
+            | UpdateKind::BusinessMessage(m)
+            | UpdateKind::EditedBusinessMessage(m) => m.thread_id(),
+            UpdateKind::CallbackQuery(q) => q.message.as_ref()?.regular_message()?.thread_id(),
+            UpdateKind::InlineQuery(_)
+            | UpdateKind::ChosenInlineResult(_)
+            | UpdateKind::ShippingQuery(_)
+            | UpdateKind::PreCheckoutQuery(_)
+            | UpdateKind::Poll(_)
+            | UpdateKind::PollAnswer(_)
+            | UpdateKind::MyChatMember(_)
+            | UpdateKind::ChatMember(_)
+            | UpdateKind::ChatJoinRequest(_)
+            | UpdateKind::MessageReaction(_)
+            | UpdateKind::MessageReactionCount(_)
+            | UpdateKind::BusinessConnection(_)
+            | UpdateKind::DeletedBusinessMessages(_) => None,
+        }
+    }
+
+    /// Returns the id of the business connection through which the update
+    /// was received, if any.
+    ///
+    /// That is the connection of business messages (and edited business
+    /// messages), of deleted business messages and the business connection
+    /// itself.
+    pub fn business_connection_id(&self) -> Option<&str> {
+        match &self.kind {
+            UpdateKind::BusinessMessage(m) | UpdateKind::EditedBusinessMessage(m) => {
+                m.business_connection_id.as_deref()
+            }
+            UpdateKind::DeletedBusinessMessages(deleted) => Some(&deleted.business_connection_id),
+            UpdateKind::BusinessConnection(connection) => Some(&connection.id),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the message which the update is about, together with
+    /// the chat of the message, for message reaction updates.
+    pub fn reaction_message(&self) -> Option<(&Chat, i32)> {
+        match &self.kind {
+            UpdateKind::MessageReaction(reaction) => Some((&reaction.chat, reaction.message_id)),
+            UpdateKind::MessageReactionCount(reaction) => {
+                Some((&reaction.chat, reaction.message_id))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the change of reactions of a user, for
+    /// [`UpdateKind::MessageReaction`] updates.
+    pub fn reaction(&self) -> Option<&MessageReactionUpdated> {
+        match &self.kind {
+            UpdateKind::MessageReaction(reaction) => Some(reaction),
+            _ => None,
+        }
+    }
+
+    /// Returns the new counts of anonymous reactions, for
+    /// [`UpdateKind::MessageReactionCount`] updates.
+    pub fn reaction_count(&self) -> Option<&MessageReactionCountUpdated> {
+        match &self.kind {
+            UpdateKind::MessageReactionCount(reaction) => Some(reaction),
             _ => None,
         }
     }
@@ -141,7 +332,7 @@ impl Update {
 mod test {
     use crate::types::{
         Chat, ChatKind, ChatPrivate, ForwardKind, ForwardOrigin, MediaKind, MediaText, Message,
-        MessageCommon, MessageKind, Update, UpdateKind, User,
+        MessageCommon, MessageKind, ThreadId, Update, UpdateKind, User,
     };
 
     // TODO: more tests for deserialization
@@ -173,6 +364,9 @@ mod test {
             id: 892_252_934,
             kind: UpdateKind::Message(Message {
                 via_bot: None,
+                business_connection_id: None,
+                message_thread_id: None,
+                is_topic_message: false,
                 id: 6557,
                 date: 1_569_518_342,
                 chat: Chat {
@@ -184,9 +378,6 @@ mod test {
                         last_name: None,
                         bio: None,
                     }),
-                    photo: None,
-                    pinned_message: None,
-                    message_auto_delete_time: None,
                 },
                 kind: MessageKind::Common(MessageCommon {
                     from: Some(User {
@@ -200,6 +391,7 @@ mod test {
                     forward_kind: ForwardKind::Origin(ForwardOrigin {
                         reply_to_message: None,
                     }),
+                    reply_to_story: None,
                     edit_date: None,
                     media_kind: MediaKind::Text(MediaText {
                         text: String::from("hello there"),
@@ -208,6 +400,7 @@ mod test {
                     reply_markup: None,
                     sender_chat: None,
                     author_signature: None,
+                    is_automatic_forward: false,
                 }),
             }),
         };
@@ -318,4 +511,138 @@ mod test {
 
         serde_json::from_str::<Update>(json).unwrap();
     }
+
+    #[test]
+    fn my_chat_member_routing() {
+        let json = r#"{
+            "update_id": 1,
+            "my_chat_member": {
+                "chat": { "id": -1001, "title": "Group", "type": "supergroup" },
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "date": 1600000000,
+                "old_chat_member": {
+                    "user": { "id": 1, "is_bot": true, "first_name": "Bot" },
+                    "status": "left"
+                },
+                "new_chat_member": {
+                    "user": { "id": 1, "is_bot": true, "first_name": "Bot" },
+                    "status": "member"
+                }
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.user().map(|u| u.id), Some(42));
+        assert_eq!(update.chat_id(), Some(-1001));
+        assert_eq!(update.thread_id(), None);
+    }
+
+    #[test]
+    fn thread_id() {
+        let json = r#"{
+            "update_id": 1,
+            "message": {
+                "message_id": 2,
+                "message_thread_id": 7,
+                "is_topic_message": true,
+                "date": 1600000000,
+                "chat": { "id": -1001, "title": "Forum", "type": "supergroup", "is_forum": true },
+                "from": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "text": "hi"
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.thread_id(), Some(ThreadId(7)));
+    }
+
+    #[test]
+    fn reaction_routing() {
+        let json = r#"{
+            "update_id": 1,
+            "message_reaction": {
+                "chat": { "id": -1001, "title": "Group", "type": "supergroup" },
+                "message_id": 5,
+                "user": { "id": 42, "is_bot": false, "first_name": "Anon" },
+                "date": 1600000000,
+                "old_reaction": [],
+                "new_reaction": [{ "type": "emoji", "emoji": "👍" }]
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.user().map(|u| u.id), Some(42));
+        assert_eq!(update.chat_id(), Some(-1001));
+        assert_eq!(
+            update.reaction_message().map(|(chat, id)| (chat.id, id)),
+            Some((-1001, 5))
+        );
+        assert_eq!(update.reaction().unwrap().new_reaction.len(), 1);
+        assert!(update.reaction_count().is_none());
+
+        let json = r#"{
+            "update_id": 2,
+            "message_reaction_count": {
+                "chat": { "id": -1002, "title": "Channel", "type": "channel" },
+                "message_id": 6,
+                "date": 1600000000,
+                "reactions": [{ "type": { "type": "emoji", "emoji": "👍" }, "total_count": 3 }]
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.user(), None);
+        assert_eq!(update.chat_id(), Some(-1002));
+        assert_eq!(update.reaction_count().unwrap().reactions[0].total_count, 3);
+    }
+
+    #[test]
+    fn business_connection_id() {
+        let json = r#"{
+            "update_id": 1,
+            "business_message": {
+                "message_id": 2,
+                "business_connection_id": "conn",
+                "date": 1600000000,
+                "chat": { "id": 7, "type": "private", "first_name": "Client" },
+                "from": { "id": 7, "is_bot": false, "first_name": "Client" },
+                "text": "hi"
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.business_connection_id(), Some("conn"));
+        assert_eq!(update.user().map(|u| u.id), Some(7));
+        assert_eq!(update.chat_id(), Some(7));
+
+        let json = r#"{
+            "update_id": 2,
+            "deleted_business_messages": {
+                "business_connection_id": "conn",
+                "chat": { "id": 7, "type": "private", "first_name": "Client" },
+                "message_ids": [2, 3]
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.business_connection_id(), Some("conn"));
+        assert_eq!(update.chat_id(), Some(7));
+
+        let json = r#"{
+            "update_id": 3,
+            "business_connection": {
+                "id": "conn",
+                "user": { "id": 1, "is_bot": false, "first_name": "Owner" },
+                "user_chat_id": 1,
+                "date": 1600000000,
+                "can_reply": true,
+                "is_enabled": true
+            }
+        }"#;
+        let update = serde_json::from_str::<Update>(json).unwrap();
+
+        assert_eq!(update.business_connection_id(), Some("conn"));
+        assert_eq!(update.user().map(|u| u.id), Some(1));
+        assert_eq!(update.chat(), None);
+    }
 }
@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    types::LanguageCode,
+    utils::{html, markdown},
+};
+
 /// This object represents a Telegram user or bot.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#user).
@@ -28,6 +33,8 @@ pub struct User {
 }
 
 impl User {
+    /// Returns the first and the last (if any) names of the user joined with a
+    /// space.
     pub fn full_name(&self) -> String {
         match &self.last_name {
             Some(last_name) => (format!("{0} {1}", self.first_name, last_name)),
@@ -35,6 +42,61 @@ impl User {
         }
     }
 
+    /// Returns the parsed [`language_code`] of the user.
+    ///
+    /// [`language_code`]: User::language_code
+    pub fn language(&self) -> Option<LanguageCode> {
+        self.language_code.as_deref().map(LanguageCode::parse)
+    }
+
+    /// Returns a name to display, i.e. the [full name], or `@username` if the
+    /// name is blank (which may happen with names consisting of invisible
+    /// characters).
+    ///
+    /// [full name]: User::full_name
+    pub fn preferred_name(&self) -> String {
+        let full_name = self.full_name();
+        match (&self.username, full_name.trim().is_empty()) {
+            (Some(username), true) => format!("@{}", username),
+            _ => full_name,
+        }
+    }
+
+    /// Returns an inline mention of the user for [`ParseMode::Html`], which is
+    /// displayed as the [preferred name].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::User;
+    ///
+    /// let user = User {
+    ///     id: 42,
+    ///     is_bot: false,
+    ///     first_name: "Tom & Jerry".to_owned(),
+    ///     last_name: None,
+    ///     username: None,
+    ///     language_code: None,
+    /// };
+    ///
+    /// assert_eq!(user.mention_html(), r#"<a href="tg://user?id=42">Tom &amp; Jerry</a>"#);
+    /// ```
+    ///
+    /// [`ParseMode::Html`]: crate::types::ParseMode::Html
+    /// [preferred name]: User::preferred_name
+    pub fn mention_html(&self) -> String {
+        html::user_mention(self.id, &self.preferred_name())
+    }
+
+    /// Returns an inline mention of the user for [`ParseMode::MarkdownV2`],
+    /// which is displayed as the [preferred name].
+    ///
+    /// [`ParseMode::MarkdownV2`]: crate::types::ParseMode::MarkdownV2
+    /// [preferred name]: User::preferred_name
+    pub fn mention_markdown(&self) -> String {
+        markdown::user_mention(self.id, &self.preferred_name())
+    }
+
     pub fn mention(&self) -> Option<String> {
         Some(format!("@{}", self.username.as_ref()?))
     }
@@ -48,6 +110,24 @@ impl User {
 mod tests {
     use super::*;
 
+    #[test]
+    fn names() {
+        let mut user = User {
+            id: 1,
+            is_bot: false,
+            first_name: "First".to_owned(),
+            last_name: Some("Last".to_owned()),
+            username: Some("user".to_owned()),
+            language_code: None,
+        };
+        assert_eq!(user.preferred_name(), "First Last");
+        assert_eq!(user.mention_markdown(), "[First Last](tg://user?id=1)");
+
+        user.first_name = " ".to_owned();
+        user.last_name = None;
+        assert_eq!(user.preferred_name(), "@user");
+    }
+
     #[test]
     fn deserialize() {
         let json = r#"{
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::Location;
+use crate::types::{Coordinates, Location};
 
 /// This object represents a venue.
 #[serde_with_macros::skip_serializing_none]
@@ -31,3 +31,10 @@ pub struct Venue {
     /// [supported types]: https://developers.google.com/places/web-service/supported_types
     pub google_place_type: Option<String>,
 }
+
+impl Venue {
+    /// Returns coordinates of the venue or `None` if they are out of range.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        self.location.coordinates()
+    }
+}
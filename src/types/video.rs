@@ -27,7 +27,8 @@ pub struct Video {
     pub duration: u32,
 
     /// Video thumbnail.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 
     /// Original filename as defined by sender
     pub file_name: Option<String>,
@@ -38,4 +39,11 @@ pub struct Video {
 
     /// File size.
     pub file_size: Option<u32>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
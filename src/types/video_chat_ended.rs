@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// This object represents a service message about a video chat ended in the
+/// chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#videochatended).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct VideoChatEnded {
+    /// Video chat duration.
+    #[serde(with = "crate::types::non_telegram_types::duration::secs")]
+    pub duration: Duration,
+}
+
+/// Old name of [`VideoChatEnded`].
+#[deprecated(note = "renamed to `VideoChatEnded`")]
+pub type VoiceChatEnded = VideoChatEnded;
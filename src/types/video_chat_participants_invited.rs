@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::User;
+
+/// This object represents a service message about new members invited to a
+/// video chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#videochatparticipantsinvited).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct VideoChatParticipantsInvited {
+    /// New members that were invited to the video chat
+    #[serde(default)]
+    pub users: Vec<User>,
+}
+
+/// Old name of [`VideoChatParticipantsInvited`].
+#[deprecated(note = "renamed to `VideoChatParticipantsInvited`")]
+pub type VoiceChatParticipantsInvited = VideoChatParticipantsInvited;
@@ -0,0 +1,27 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// This object represents a service message about a video chat scheduled in the
+/// chat.
+///
+/// [The official docs](https://core.telegram.org/bots/api#videochatscheduled).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct VideoChatScheduled {
+    /// Point in time (Unix timestamp) when the video chat is supposed to be
+    /// started by a chat administrator.
+    pub start_date: u64,
+}
+
+impl VideoChatScheduled {
+    /// Returns [`start_date`] as a [`SystemTime`].
+    ///
+    /// [`start_date`]: VideoChatScheduled::start_date
+    pub fn start_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.start_date)
+    }
+}
+
+/// Old name of [`VideoChatScheduled`].
+#[deprecated(note = "renamed to `VideoChatScheduled`")]
+pub type VoiceChatScheduled = VideoChatScheduled;
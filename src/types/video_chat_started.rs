@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// This object represents a service message about a video chat started in the
+/// chat. Currently holds no information.
+///
+/// [The official docs](https://core.telegram.org/bots/api#videochatstarted).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct VideoChatStarted {}
+
+/// Old name of [`VideoChatStarted`].
+#[deprecated(note = "renamed to `VideoChatStarted`")]
+pub type VoiceChatStarted = VideoChatStarted;
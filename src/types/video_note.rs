@@ -28,8 +28,22 @@ pub struct VideoNote {
     pub duration: u32,
 
     /// Video thumbnail.
-    pub thumb: Option<PhotoSize>,
+    #[serde(alias = "thumb")]
+    pub thumbnail: Option<PhotoSize>,
 
     /// File size.
     pub file_size: Option<u32>,
+
+    /// Transcription of the video message, sent only by [local Bot API
+    /// servers] with speech recognition enabled.
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    pub transcription: Option<String>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
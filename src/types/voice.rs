@@ -24,4 +24,41 @@ pub struct Voice {
 
     /// File size.
     pub file_size: Option<u64>,
+
+    /// Transcription of the voice message, sent only by [local Bot API
+    /// servers] with speech recognition enabled.
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    pub transcription: Option<String>,
+
+    /// Fields which are not known to this library, e.g. sent by patched
+    /// [local Bot API servers].
+    ///
+    /// [local Bot API servers]: https://github.com/tdlib/telegram-bot-api
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn extra_fields() {
+        use super::*;
+
+        let json = r#"{
+            "file_id": "id",
+            "file_unique_id": "unique",
+            "duration": 3,
+            "mime_type": "audio/ogg",
+            "transcription": "hello",
+            "waveform": "AAEC"
+        }"#;
+        let voice = serde_json::from_str::<Voice>(json).unwrap();
+
+        assert_eq!(voice.duration, 3);
+        assert_eq!(voice.transcription.as_deref(), Some("hello"));
+        assert_eq!(voice.extra.len(), 1);
+        assert_eq!(voice.extra["waveform"], "AAEC");
+        assert_eq!(serde_json::to_value(&voice).unwrap()["waveform"], "AAEC");
+    }
 }
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes a [Web App].
+///
+/// [The official docs](https://core.telegram.org/bots/api#webappinfo).
+///
+/// [Web App]: https://core.telegram.org/bots/webapps
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened with additional data as
+    /// specified in [Initializing Web Apps].
+    ///
+    /// [Initializing Web Apps]: https://core.telegram.org/bots/webapps#initializing-web-apps
+    pub url: String,
+}
+
+impl WebAppInfo {
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self { url: url.into() }
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::payloads::SetWebhook;
+
 /// Contains information about the current status of a webhook.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#webhookinfo).
@@ -35,3 +37,102 @@ pub struct WebhookInfo {
     /// types.
     pub allowed_updates: Option<Vec<String>>,
 }
+
+impl WebhookInfo {
+    /// Returns `true` if calling [`SetWebhook`] with the `params` wouldn't
+    /// change anything, see [`RequesterExt::ensure_webhook`].
+    ///
+    /// Fields which aren't set in the `params` are not compared, since
+    /// Telegram keeps the previous settings for them. Note that uploaded
+    /// certificates can't be compared, so only the presence of a certificate
+    /// is checked. The secret token isn't returned by Telegram either, so it's
+    /// not compared at all, see [`RequesterExt::ensure_webhook_with`].
+    ///
+    /// [`RequesterExt::ensure_webhook`]: crate::requests::RequesterExt::ensure_webhook
+    /// [`RequesterExt::ensure_webhook_with`]: crate::requests::RequesterExt::ensure_webhook_with
+    pub fn is_up_to_date(&self, params: &SetWebhook) -> bool {
+        if self.url != params.url {
+            return false;
+        }
+
+        if params.certificate.is_some() && !self.has_custom_certificate {
+            return false;
+        }
+
+        if params.ip_address.is_some() && params.ip_address != self.ip_address {
+            return false;
+        }
+
+        if let Some(max) = params.max_connections {
+            if self.max_connections != Some(u32::from(max)) {
+                return false;
+            }
+        }
+
+        if params.drop_pending_updates == Some(true) && self.pending_update_count > 0 {
+            return false;
+        }
+
+        if let Some(allowed) = &params.allowed_updates {
+            let mut desired = allowed
+                .iter()
+                .filter_map(|update| match serde_json::to_value(update) {
+                    Ok(serde_json::Value::String(name)) => Some(name),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let mut current = self.allowed_updates.clone().unwrap_or_default();
+
+            desired.sort();
+            desired.dedup();
+            current.sort();
+            current.dedup();
+
+            if desired != current {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{payloads::SetWebhookSetters, types::AllowedUpdate};
+
+    #[test]
+    fn up_to_date() {
+        let info = WebhookInfo {
+            url: "https://example.com/hook".to_owned(),
+            has_custom_certificate: false,
+            pending_update_count: 0,
+            ip_address: None,
+            last_error_date: None,
+            last_error_message: None,
+            max_connections: Some(40),
+            allowed_updates: Some(vec!["message".to_owned(), "callback_query".to_owned()]),
+        };
+
+        let params = SetWebhook::new("https://example.com/hook");
+        assert!(info.is_up_to_date(&params));
+
+        let params = SetWebhook {
+            allowed_updates: Some(vec![AllowedUpdate::CallbackQuery, AllowedUpdate::Message]),
+            max_connections: Some(40),
+            ..params
+        };
+        assert!(info.is_up_to_date(&params));
+
+        let params = SetWebhook {
+            allowed_updates: Some(vec![AllowedUpdate::Message]),
+            ..params
+        };
+        assert!(!info.is_up_to_date(&params));
+        assert!(!info.is_up_to_date(&SetWebhook::new("https://example.com/other")));
+
+        // The secret token isn't reported by Telegram
+        assert!(info.is_up_to_date(&SetWebhook::new("https://example.com/hook").secret_token("s")));
+    }
+}
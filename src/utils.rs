@@ -0,0 +1,22 @@
+//! Some useful utilities.
+
+#[cfg(feature = "audio_metadata")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "audio_metadata")))]
+pub mod audio_metadata;
+pub mod broadcast;
+pub mod custom_emoji;
+pub mod html;
+pub mod inline_paginator;
+pub mod inline_results;
+pub mod markdown;
+#[cfg(any(feature = "audio_metadata", feature = "video_metadata"))]
+mod mp4;
+pub mod render;
+pub mod text;
+pub mod thumbnail;
+pub mod update_stats;
+pub mod update_tracker;
+#[cfg(feature = "video_metadata")]
+#[cfg_attr(all(docsrs, feature = "nightly"), doc(cfg(feature = "video_metadata")))]
+pub mod video_metadata;
+pub mod webhook;
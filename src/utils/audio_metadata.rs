@@ -0,0 +1,554 @@
+//! Reading metadata of audio files.
+//!
+//! Telegram clients show the duration, the performer and the title of an
+//! audio in the music player, but they are only known to Telegram if they are
+//! passed to [`SendAudio`]. [`AudioMetadata`] reads them (and the cover art)
+//! from `.mp3` files (ID3 tags and MPEG frames) and `.m4a` files (iTunes
+//! metadata), so that they can be filled automatically.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! # async {
+//! use teloxide_core::{
+//!     prelude::*, requests::HasPayload, types::InputFile, utils::audio_metadata,
+//! };
+//!
+//! let bot = Bot::new("TOKEN");
+//! let mut request = bot.send_audio(0, InputFile::file("song.mp3"));
+//! audio_metadata::fill_send_audio(request.payload_mut()).await?;
+//! request.send().await?;
+//! # Ok::<_, Box<dyn std::error::Error>>(()) };
+//! ```
+
+use std::{borrow::Cow, io};
+
+use crate::{
+    payloads::SendAudio,
+    types::InputFile,
+    utils::{
+        mp4::{self, read_u32},
+        thumbnail::Thumbnail,
+    },
+};
+
+/// Metadata of an audio file, see the [module-level docs].
+///
+/// [module-level docs]: self
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AudioMetadata {
+    /// Duration of the audio in seconds.
+    pub duration: Option<u32>,
+
+    /// Performer (artist) of the audio.
+    pub performer: Option<String>,
+
+    /// Title of the audio.
+    pub title: Option<String>,
+
+    /// Embedded cover art, in its original format (usually JPEG or PNG).
+    pub cover: Option<Vec<u8>>,
+}
+
+impl AudioMetadata {
+    /// Parses metadata of an `.mp3` or `.m4a` file.
+    ///
+    /// Returns `None` if the format is not recognized.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if mp4::is_mp4(data) {
+            Some(parse_m4a(data))
+        } else {
+            parse_mp3(data)
+        }
+    }
+
+    /// Reads metadata of the `file`.
+    ///
+    /// The whole file is read into memory. Returns `Ok(None)` for files
+    /// which can't be read locally (i.e. URLs, file ids and [reopenable
+    /// files]) and for unrecognized formats.
+    ///
+    /// [reopenable files]: InputFile::Reopenable
+    pub async fn read(file: &InputFile) -> io::Result<Option<Self>> {
+        let mut file = file;
+        while let InputFile::WithMetadata(meta) = file {
+            file = &meta.file;
+        }
+
+        let data = match file {
+            InputFile::File(path) => Cow::Owned(tokio::fs::read(path).await?),
+            InputFile::Memory { data, .. } => Cow::Borrowed(&**data),
+            _ => return Ok(None),
+        };
+
+        Ok(Self::parse(&data))
+    }
+
+    /// Returns the cover art if it satisfies the [requirements] for
+    /// thumbnails.
+    ///
+    /// [requirements]: crate::utils::thumbnail
+    pub fn thumbnail(&self) -> Option<InputFile> {
+        let cover = Thumbnail::new(self.cover.clone()?).ok()?;
+        Some(cover.into())
+    }
+
+    /// Sets `duration`, `performer`, `title` and `thumbnail` of the
+    /// `payload`, which are not set yet and are known.
+    pub fn fill(&self, payload: &mut SendAudio) {
+        if payload.duration.is_none() {
+            payload.duration = self.duration;
+        }
+        if payload.performer.is_none() {
+            payload.performer = self.performer.clone();
+        }
+        if payload.title.is_none() {
+            payload.title = self.title.clone();
+        }
+        if payload.thumbnail.is_none() {
+            payload.thumbnail = self.thumbnail();
+        }
+    }
+}
+
+/// Reads metadata of the audio of the `payload` and [fills] the payload with
+/// it.
+///
+/// [fills]: AudioMetadata::fill
+pub async fn fill_send_audio(payload: &mut SendAudio) -> io::Result<()> {
+    if let Some(meta) = AudioMetadata::read(&payload.audio).await? {
+        meta.fill(payload);
+    }
+
+    Ok(())
+}
+
+fn parse_m4a(data: &[u8]) -> AudioMetadata {
+    let ilst = mp4::find(data, &[b"moov", b"udta", b"meta", b"ilst"]).unwrap_or(&[]);
+    // Every item contains a `data` box with a type indicator and a locale
+    let item = |ty: &[u8; 4]| mp4::find(ilst, &[ty, b"data"]).and_then(|data| data.get(8..));
+    let text = |ty: &[u8; 4]| {
+        item(ty)
+            .map(|text| String::from_utf8_lossy(text).trim().to_owned())
+            .filter(|text| !text.is_empty())
+    };
+
+    AudioMetadata {
+        duration: mp4::find(data, &[b"moov"]).and_then(mp4::duration),
+        performer: text(b"\xA9ART"),
+        title: text(b"\xA9nam"),
+        cover: item(b"covr").map(<[u8]>::to_vec),
+    }
+}
+
+fn parse_mp3(data: &[u8]) -> Option<AudioMetadata> {
+    let mut meta = AudioMetadata::default();
+    let mut audio = data;
+
+    if data.starts_with(b"ID3") {
+        let (tag, rest) = split_id3v2(data)?;
+        parse_id3v2(tag, &mut meta);
+        audio = rest;
+    }
+
+    let mut audio_len = audio.len();
+    if let Some(tag) = audio.len().checked_sub(128).map(|at| &audio[at..]) {
+        if tag.starts_with(b"TAG") {
+            audio_len -= 128;
+            // ID3v1 is only used if there is no ID3v2
+            if meta.title.is_none() {
+                meta.title = latin1(&tag[3..33]);
+            }
+            if meta.performer.is_none() {
+                meta.performer = latin1(&tag[33..63]);
+            }
+        }
+    }
+
+    match mpeg_duration(&audio[..audio_len]) {
+        // The duration of the stream is more accurate than `TLEN`
+        Some(duration) => meta.duration = Some(duration),
+        None if meta.duration.is_some() => {}
+        // Neither a tag, nor MPEG frames
+        None if meta == AudioMetadata::default() => return None,
+        None => {}
+    }
+
+    Some(meta)
+}
+
+/// Splits an ID3v2 tag (without the header) from the rest of the file.
+fn split_id3v2(data: &[u8]) -> Option<(Id3v2<'_>, &[u8])> {
+    let header = data.get(..10)?;
+    let version = header[3];
+    let flags = header[5];
+    let size = syncsafe(&header[6..10])? as usize;
+    // Tags with a footer are 10 bytes longer
+    let footer = if flags & 0x10 != 0 { 10 } else { 0 };
+
+    let end = (10 + size + footer).min(data.len());
+    let mut frames = data.get(10..(10 + size).min(data.len()))?;
+
+    // Skip the extended header
+    if flags & 0x40 != 0 {
+        let len = match version {
+            3 => read_u32(frames, 0)? as usize + 4,
+            _ => syncsafe(frames.get(..4)?)? as usize,
+        };
+        frames = frames.get(len..)?;
+    }
+
+    Some((Id3v2 { version, frames }, &data[end..]))
+}
+
+struct Id3v2<'a> {
+    version: u8,
+    frames: &'a [u8],
+}
+
+fn parse_id3v2(tag: Id3v2<'_>, meta: &mut AudioMetadata) {
+    // ID3v2.2 has a different frame format, which is rare nowadays
+    if tag.version != 3 && tag.version != 4 {
+        return;
+    }
+
+    let mut frames = tag.frames;
+    while frames.len() >= 10 && frames[0] != 0 {
+        let id = &frames[..4];
+        let size = match tag.version {
+            3 => read_u32(frames, 4),
+            _ => syncsafe(&frames[4..8]),
+        };
+        let content = match size.and_then(|size| frames.get(10..10 + size as usize)) {
+            Some(content) => content,
+            None => break,
+        };
+        frames = &frames[10 + content.len()..];
+
+        match id {
+            b"TIT2" => meta.title = text(content),
+            b"TPE1" => meta.performer = text(content),
+            b"TLEN" => {
+                meta.duration = text(content)
+                    .and_then(|ms| ms.parse::<u64>().ok())
+                    .and_then(|ms| mp4::seconds(ms, 1000))
+            }
+            b"APIC" if meta.cover.is_none() => meta.cover = picture(content),
+            _ => {}
+        }
+    }
+}
+
+/// Decodes a syncsafe integer, i.e. one with 7 bits per byte.
+fn syncsafe(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() != 4 || bytes.iter().any(|b| b & 0x80 != 0) {
+        return None;
+    }
+
+    Some(bytes.iter().fold(0, |acc, &b| acc << 7 | u32::from(b)))
+}
+
+/// Decodes the content of a text frame, i.e. an encoding byte and a text.
+fn text(content: &[u8]) -> Option<String> {
+    let (&encoding, text) = content.split_first()?;
+    let text = decode(encoding, text);
+    // ID3v2.4 separates multiple values with nulls, the first one is used
+    let text = text.split('\0').next().unwrap_or("").trim();
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_owned())
+    }
+}
+
+/// Returns the picture of an `APIC` frame.
+fn picture(content: &[u8]) -> Option<Vec<u8>> {
+    let (&encoding, rest) = content.split_first()?;
+    // MIME type (always latin1), terminated by null
+    let mime_end = rest.iter().position(|&b| b == 0)?;
+    // Skip the picture type
+    let rest = rest.get(mime_end + 2..)?;
+
+    // Description, terminated by null in the encoding of the frame
+    let data_start = if encoding == 1 || encoding == 2 {
+        rest.chunks(2).position(|c| c == [0, 0])? * 2 + 2
+    } else {
+        rest.iter().position(|&b| b == 0)? + 1
+    };
+
+    rest.get(data_start..)
+        .filter(|data| !data.is_empty())
+        .map(<[u8]>::to_vec)
+}
+
+fn decode(encoding: u8, text: &[u8]) -> String {
+    match encoding {
+        0 => text.iter().map(|&b| char::from(b)).collect(),
+        1 | 2 => {
+            let (little_endian, text) = match text {
+                [0xFF, 0xFE, rest @ ..] => (true, rest),
+                [0xFE, 0xFF, rest @ ..] => (false, rest),
+                _ => (false, text),
+            };
+            let units: Vec<u16> = text
+                .chunks_exact(2)
+                .map(|c| {
+                    if little_endian {
+                        u16::from_le_bytes([c[0], c[1]])
+                    } else {
+                        u16::from_be_bytes([c[0], c[1]])
+                    }
+                })
+                .collect();
+
+            String::from_utf16_lossy(&units)
+        }
+        _ => String::from_utf8_lossy(text).into_owned(),
+    }
+}
+
+fn latin1(field: &[u8]) -> Option<String> {
+    let text = decode(0, field);
+    let text = text.trim_end_matches(&['\0', ' '][..]);
+
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_owned())
+    }
+}
+
+/// A header of an MPEG audio layer III frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FrameHeader {
+    mpeg1: bool,
+    mono: bool,
+    bitrate: u32,
+    sample_rate: u32,
+    len: usize,
+}
+
+impl FrameHeader {
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        const BITRATES_V1: [u32; 15] = [
+            0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+        ];
+        const BITRATES_V2: [u32; 15] =
+            [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+        const SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+        let bytes = bytes.get(..4)?;
+        if bytes[0] != 0xFF || bytes[1] & 0xE0 != 0xE0 {
+            return None;
+        }
+
+        // 0 is MPEG 2.5, 1 is reserved, 2 is MPEG 2, 3 is MPEG 1
+        let version = (bytes[1] >> 3) & 0b11;
+        let layer = (bytes[1] >> 1) & 0b11;
+        let bitrate_index = usize::from(bytes[2] >> 4);
+        let sample_rate_index = usize::from((bytes[2] >> 2) & 0b11);
+        if version == 1 || layer != 1 || bitrate_index == 0 || bitrate_index == 15 {
+            return None;
+        }
+
+        let mpeg1 = version == 3;
+        let bitrate = if mpeg1 {
+            BITRATES_V1[bitrate_index]
+        } else {
+            BITRATES_V2[bitrate_index]
+        };
+        let sample_rate = SAMPLE_RATES.get(sample_rate_index)? >> (3 - version).min(2);
+        let padding = usize::from((bytes[2] >> 1) & 1);
+        let coefficient = if mpeg1 { 144 } else { 72 };
+
+        Some(Self {
+            mpeg1,
+            mono: bytes[3] >> 6 == 0b11,
+            bitrate,
+            sample_rate,
+            len: (coefficient * bitrate * 1000 / sample_rate) as usize + padding,
+        })
+    }
+
+    fn samples(&self) -> u64 {
+        if self.mpeg1 {
+            1152
+        } else {
+            576
+        }
+    }
+
+    /// Returns the offset of a Xing header from the start of the frame.
+    fn xing_offset(&self) -> usize {
+        4 + match (self.mpeg1, self.mono) {
+            (true, false) => 32,
+            (true, true) | (false, false) => 17,
+            (false, true) => 9,
+        }
+    }
+}
+
+/// Returns the duration of MPEG audio in seconds.
+///
+/// VBR files are expected to have a Xing or a VBRI header with the number of
+/// frames, otherwise the file is assumed to have a constant bitrate.
+fn mpeg_duration(audio: &[u8]) -> Option<u32> {
+    // Junk before the first frame is only searched for in a reasonable range
+    let (start, header) = (0..audio.len().min(64 * 1024)).find_map(|at| {
+        let header = FrameHeader::parse(&audio[at..])?;
+        // The next frame must have a header too, unless the file ends
+        match audio.get(at + header.len..) {
+            Some(next) if next.len() >= 4 && FrameHeader::parse(next).is_none() => None,
+            _ => Some((at, header)),
+        }
+    })?;
+    let frame = &audio[start..];
+
+    let xing = header.xing_offset();
+    let frames = match frame.get(xing..xing + 4) {
+        Some(b"Xing") | Some(b"Info") if read_u32(frame, xing + 4)? & 1 != 0 => {
+            read_u32(frame, xing + 8)
+        }
+        _ if frame.get(36..40) == Some(b"VBRI") => read_u32(frame, 36 + 14),
+        _ => None,
+    };
+
+    let (duration, timescale) = match frames {
+        Some(frames) => (u64::from(frames) * header.samples(), header.sample_rate),
+        None => ((audio.len() - start) as u64 * 8, header.bitrate * 1000),
+    };
+
+    mp4::seconds(duration, timescale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        payloads::SendAudioSetters,
+        utils::{
+            mp4::build::{mp4_box, mvhd},
+            thumbnail::jpeg,
+        },
+    };
+
+    fn frame(id: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        [
+            id,
+            &(content.len() as u32).to_be_bytes()[..],
+            &[0, 0],
+            content,
+        ]
+        .concat()
+    }
+
+    fn id3v2(frames: &[Vec<u8>]) -> Vec<u8> {
+        let frames = frames.concat();
+        let len = frames.len() as u32;
+        let size = [
+            (len >> 21) as u8 & 0x7F,
+            (len >> 14) as u8 & 0x7F,
+            (len >> 7) as u8 & 0x7F,
+            len as u8 & 0x7F,
+        ];
+        [&b"ID3\x03\x00\x00"[..], &size, &frames].concat()
+    }
+
+    /// MPEG 1 layer III, 128 kbit/s, 44100 Hz, stereo, `count` frames.
+    fn frames(count: usize) -> Vec<u8> {
+        let mut frame = vec![0; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+        frame.repeat(count)
+    }
+
+    #[test]
+    fn mp3_id3v2() {
+        let tag = id3v2(&[
+            frame(b"TIT2", b"\x03Song\0"),
+            frame(b"TPE1", b"\x01\xFF\xFEA\0r\0t\0"),
+            frame(b"TLEN", b"\x0099000"),
+            frame(
+                b"APIC",
+                &[&b"\x00image/jpeg\0\x03desc\0"[..], &jpeg(300, 300)].concat(),
+            ),
+        ]);
+        // ~10 seconds
+        let file = [tag, frames(383)].concat();
+
+        let meta = AudioMetadata::parse(&file).unwrap();
+        assert_eq!(
+            meta,
+            AudioMetadata {
+                duration: Some(10),
+                performer: Some("Art".to_owned()),
+                title: Some("Song".to_owned()),
+                cover: Some(jpeg(300, 300)),
+            }
+        );
+        assert_eq!(
+            meta.thumbnail(),
+            Some(InputFile::memory("thumbnail.jpg", jpeg(300, 300)))
+        );
+    }
+
+    #[test]
+    fn mp3_xing_and_id3v1() {
+        let mut audio = frames(3);
+        // Info header of a stereo MPEG 1 frame, with 11484 frames (300 s)
+        audio[36..48].copy_from_slice(&[b'I', b'n', b'f', b'o', 0, 0, 0, 1, 0, 0, 0x2C, 0xDC]);
+
+        let mut id3v1 = vec![0; 128];
+        id3v1[..3].copy_from_slice(b"TAG");
+        id3v1[3..7].copy_from_slice(b"Song");
+        id3v1[33..36].copy_from_slice(b"Art");
+
+        let meta = AudioMetadata::parse(&[audio, id3v1].concat()).unwrap();
+        assert_eq!(meta.duration, Some(300));
+        assert_eq!(meta.title.as_deref(), Some("Song"));
+        assert_eq!(meta.performer.as_deref(), Some("Art"));
+        assert_eq!(meta.cover, None);
+
+        assert_eq!(AudioMetadata::parse(b"not an audio"), None);
+    }
+
+    #[test]
+    fn m4a() {
+        let item = |ty: &[u8; 4], indicator: u8, content: &[u8]| {
+            mp4_box(
+                ty,
+                &[&mp4_box(
+                    b"data",
+                    &[&[0, 0, 0, indicator, 0, 0, 0, 0], content],
+                )],
+            )
+        };
+        let ilst = mp4_box(
+            b"ilst",
+            &[
+                &item(b"\xA9nam", 1, b"Song"),
+                &item(b"\xA9ART", 1, b"Art"),
+                &item(b"covr", 13, &jpeg(300, 300)),
+            ],
+        );
+        let meta = mp4_box(b"meta", &[&[0; 4], &mp4_box(b"hdlr", &[&[0; 25]]), &ilst]);
+        let file = [
+            mp4_box(b"ftyp", &[b"M4A "]),
+            mp4_box(
+                b"moov",
+                &[&mvhd(44100, 44100 * 42), &mp4_box(b"udta", &[&meta])],
+            ),
+        ]
+        .concat();
+
+        let mut payload = SendAudio::new(0, InputFile::file_id("id")).title("Custom");
+        AudioMetadata::parse(&file).unwrap().fill(&mut payload);
+        assert_eq!(payload.duration, Some(42));
+        assert_eq!(payload.performer.as_deref(), Some("Art"));
+        assert_eq!(payload.title.as_deref(), Some("Custom"));
+        assert_eq!(
+            payload.thumbnail,
+            Some(InputFile::memory("thumbnail.jpg", jpeg(300, 300)))
+        );
+    }
+}
@@ -0,0 +1,517 @@
+//! Sending a request to many chats, e.g. an announcement to all users.
+//!
+//! [`Broadcast`] sends requests with bounded concurrency, waits and retries on
+//! flood control errors and collects failures classified by their
+//! [`FailureKind`] (e.g. so that users who blocked the bot can be removed from
+//! the mailing list) into a [`BroadcastReport`].
+//!
+//! A broadcast can be resumed (e.g. after a restart) from a [`Checkpoint`],
+//! which is reported while the broadcast progresses.
+
+use std::{
+    collections::BTreeSet,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::{stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+use crate::{
+    errors::{AsApiError, AsResponseParameters},
+    requests::Request,
+    ApiError,
+};
+
+/// Default number of requests sent at the same time, see
+/// [`Broadcast::concurrency`].
+pub const DEFAULT_CONCURRENCY: usize = 10;
+
+/// Default number of retries after flood control errors, see
+/// [`Broadcast::flood_retries`].
+pub const DEFAULT_FLOOD_RETRIES: u32 = 3;
+
+/// Sends requests to many chats with [the default settings], see
+/// [`Broadcast`].
+///
+/// [the default settings]: Broadcast::new
+pub async fn broadcast<I, R>(requests: I) -> BroadcastReport
+where
+    I: IntoIterator<Item = R>,
+    R: Request,
+    R::Err: AsApiError + AsResponseParameters,
+{
+    Broadcast::new().send(requests).await
+}
+
+/// A reason of a failed send.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum FailureKind {
+    /// The user blocked the bot or the bot was kicked from the chat.
+    Blocked,
+
+    /// The user's account was deactivated.
+    Deactivated,
+
+    /// The chat doesn't exist (or the bot never had access to it).
+    ChatNotFound,
+
+    /// The request still hit flood control after all retries.
+    Flood,
+
+    /// Any other error.
+    Other,
+}
+
+impl FailureKind {
+    /// Classifies an error.
+    pub fn of<E>(err: &E) -> Self
+    where
+        E: AsApiError + AsResponseParameters,
+    {
+        if err.retry_after().is_some() {
+            return Self::Flood;
+        }
+
+        match err.api_error() {
+            Some(ApiError::BotBlocked)
+            | Some(ApiError::BotKicked)
+            | Some(ApiError::BotKickedFromSupergroup) => Self::Blocked,
+            Some(ApiError::UserDeactivated) => Self::Deactivated,
+            Some(ApiError::ChatNotFound) => Self::ChatNotFound,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns `true` if sending to the chat again is pointless, i.e. the chat
+    /// should be removed from the list of recipients.
+    pub fn is_permanent(&self) -> bool {
+        matches!(self, Self::Blocked | Self::Deactivated | Self::ChatNotFound)
+    }
+}
+
+/// A failed send, see [`BroadcastReport::failures`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct BroadcastFailure {
+    /// Index of the request in the broadcasted iterator (including the
+    /// requests skipped by [resuming]).
+    ///
+    /// [resuming]: Broadcast::resume
+    pub index: usize,
+
+    /// The reason of the failure.
+    pub kind: FailureKind,
+
+    /// Description of the error.
+    pub error: String,
+}
+
+/// A point from which a broadcast can be resumed.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Number of requests from the start of the iterator which were
+    /// completed (either sent or failed).
+    ///
+    /// Since requests are sent concurrently, some requests after this one may
+    /// be completed too, those will be sent again on resume.
+    pub completed: usize,
+}
+
+/// The result of a [`Broadcast`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BroadcastReport {
+    /// Number of successfully sent requests.
+    pub sent: usize,
+
+    /// Failed requests, ordered by index.
+    pub failures: Vec<BroadcastFailure>,
+
+    /// Number of flood control errors which were waited out.
+    pub flood_waits: u32,
+
+    /// The checkpoint after the whole broadcast.
+    pub checkpoint: Checkpoint,
+}
+
+impl BroadcastReport {
+    /// Returns failures of the given kind.
+    pub fn failures_of(&self, kind: FailureKind) -> impl Iterator<Item = &BroadcastFailure> {
+        self.failures.iter().filter(move |f| f.kind == kind)
+    }
+
+    /// Returns indices of requests which failed permanently (see
+    /// [`FailureKind::is_permanent`]).
+    pub fn permanent_failures(&self) -> impl Iterator<Item = usize> + '_ {
+        self.failures
+            .iter()
+            .filter(|f| f.kind.is_permanent())
+            .map(|f| f.index)
+    }
+}
+
+type OnCheckpoint = Arc<dyn Fn(Checkpoint) + Send + Sync>;
+
+/// Sends a request to many chats.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide_core::{
+///     prelude::*,
+///     utils::broadcast::{Broadcast, Checkpoint},
+/// };
+///
+/// # async {
+/// let bot = Bot::new("TOKEN");
+/// let users: Vec<i64> = vec![/* ... */];
+/// # let saved: Option<Checkpoint> = None;
+///
+/// let report = Broadcast::new()
+///     .concurrency(5)
+///     .resume(saved.unwrap_or_default())
+///     .on_checkpoint(|checkpoint| {
+///         // save `checkpoint` somewhere to resume after a restart
+///     })
+///     .send(users.iter().map(|&id| bot.send_message(id, "We've moved to @newbot!")))
+///     .await;
+///
+/// for index in report.permanent_failures() {
+///     // remove users[index] from the mailing list
+/// }
+/// # };
+/// ```
+#[derive(Clone)]
+pub struct Broadcast {
+    concurrency: usize,
+    flood_retries: u32,
+    resume: Checkpoint,
+    on_checkpoint: Option<OnCheckpoint>,
+}
+
+impl Broadcast {
+    /// Creates new `Broadcast` which sends up to [`DEFAULT_CONCURRENCY`]
+    /// requests at the same time and retries up to [`DEFAULT_FLOOD_RETRIES`]
+    /// times after flood control errors.
+    pub fn new() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            flood_retries: DEFAULT_FLOOD_RETRIES,
+            resume: Checkpoint::default(),
+            on_checkpoint: None,
+        }
+    }
+
+    /// Sets the maximum number of requests sent at the same time.
+    ///
+    /// Note that Telegram allows to send about 30 messages per second, consider
+    /// using [`Throttle`] to stay within the limits.
+    ///
+    /// ## Panics
+    ///
+    /// If `concurrency` is `0`.
+    ///
+    /// [`Throttle`]: crate::adaptors::Throttle
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        assert!(concurrency > 0, "concurrency must be positive");
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the maximum number of retries of a request after flood control
+    /// errors. Before each retry the time requested by Telegram is waited.
+    ///
+    /// Flood control applies to the whole bot, so after such an error no
+    /// requests are sent until the requested time passes.
+    pub fn flood_retries(mut self, retries: u32) -> Self {
+        self.flood_retries = retries;
+        self
+    }
+
+    /// Resumes the broadcast from the `checkpoint`, i.e. skips the requests
+    /// which were already completed.
+    pub fn resume(mut self, checkpoint: Checkpoint) -> Self {
+        self.resume = checkpoint;
+        self
+    }
+
+    /// Sets a function which is called every time the [`Checkpoint`] moves.
+    pub fn on_checkpoint<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Checkpoint) + Send + Sync + 'static,
+    {
+        self.on_checkpoint = Some(Arc::new(f));
+        self
+    }
+
+    /// Sends all the `requests`.
+    pub async fn send<I, R>(self, requests: I) -> BroadcastReport
+    where
+        I: IntoIterator<Item = R>,
+        R: Request,
+        R::Err: AsApiError + AsResponseParameters,
+    {
+        let Self {
+            concurrency,
+            flood_retries,
+            resume,
+            on_checkpoint,
+        } = self;
+
+        let mut report = BroadcastReport {
+            checkpoint: resume,
+            ..<_>::default()
+        };
+        let mut progress = Progress::new(resume.completed);
+
+        // The time until which all sends wait after a flood control error
+        let pause = Mutex::new(None::<Instant>);
+        let pause = &pause;
+
+        let sends = requests.into_iter().enumerate().skip(resume.completed).map(
+            |(index, request)| async move {
+                let mut flood_waits = 0;
+                let res = loop {
+                    // Other sends may have hit flood control while this one was waiting
+                    loop {
+                        let until = *pause.lock().unwrap();
+                        match until {
+                            Some(until) if until > Instant::now() => {
+                                tokio::time::sleep_until(until).await
+                            }
+                            _ => break,
+                        }
+                    }
+
+                    match request.send_ref().await {
+                        Err(err) => match err.retry_after() {
+                            Some(secs) if flood_waits < flood_retries => {
+                                flood_waits += 1;
+                                let until =
+                                    Instant::now() + Duration::from_secs(secs.max(0) as u64);
+                                let mut pause = pause.lock().unwrap();
+                                *pause = Some(pause.map_or(until, |p| p.max(until)));
+                            }
+                            _ => break Err(err),
+                        },
+                        Ok(_) => break Ok(()),
+                    }
+                };
+
+                (index, res, flood_waits)
+            },
+        );
+        let mut sends = stream::iter(sends).buffer_unordered(concurrency);
+
+        while let Some((index, res, flood_waits)) = sends.next().await {
+            report.flood_waits += flood_waits;
+            match res {
+                Ok(()) => report.sent += 1,
+                Err(err) => report.failures.push(BroadcastFailure {
+                    index,
+                    kind: FailureKind::of(&err),
+                    error: err.to_string(),
+                }),
+            }
+
+            if let Some(completed) = progress.complete(index) {
+                report.checkpoint = Checkpoint { completed };
+                if let Some(f) = &on_checkpoint {
+                    f(report.checkpoint);
+                }
+            }
+        }
+
+        report.failures.sort_by_key(|f| f.index);
+        report
+    }
+}
+
+impl Default for Broadcast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Broadcast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Broadcast")
+            .field("concurrency", &self.concurrency)
+            .field("flood_retries", &self.flood_retries)
+            .field("resume", &self.resume)
+            .finish()
+    }
+}
+
+/// Tracks the number of leading completed requests.
+struct Progress {
+    completed: usize,
+    /// Completed indices after `completed`.
+    ahead: BTreeSet<usize>,
+}
+
+impl Progress {
+    fn new(completed: usize) -> Self {
+        Self {
+            completed,
+            ahead: BTreeSet::new(),
+        }
+    }
+
+    /// Marks the request as completed, returns the new number of leading
+    /// completed requests, if it changed.
+    fn complete(&mut self, index: usize) -> Option<usize> {
+        if index != self.completed {
+            self.ahead.insert(index);
+            return None;
+        }
+
+        self.completed += 1;
+        while self.ahead.remove(&self.completed) {
+            self.completed += 1;
+        }
+
+        Some(self.completed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::future::ready;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::{payloads::DeleteMessage, test_utils::MockRequest, types::True, RequestError};
+
+    /// Returns a request which fails with the given errors (one per send) and
+    /// then succeeds.
+    fn scripted(errors: Vec<RequestError>) -> MockRequest<DeleteMessage> {
+        let errors = Mutex::new(errors);
+        MockRequest::new(DeleteMessage::new(1, 1), move |_| {
+            ready(errors.lock().unwrap().pop().map_or(Ok(True), Err))
+        })
+    }
+
+    fn api(kind: ApiError) -> RequestError {
+        RequestError::ApiError {
+            kind,
+            status_code: StatusCode::FORBIDDEN,
+        }
+    }
+
+    #[test]
+    fn classification() {
+        assert_eq!(
+            FailureKind::of(&api(ApiError::BotBlocked)),
+            FailureKind::Blocked
+        );
+        assert_eq!(
+            FailureKind::of(&api(ApiError::UserDeactivated)),
+            FailureKind::Deactivated
+        );
+        assert_eq!(
+            FailureKind::of(&RequestError::RetryAfter(1)),
+            FailureKind::Flood
+        );
+        assert_eq!(
+            FailureKind::of(&api(ApiError::MessageIsTooLong)),
+            FailureKind::Other
+        );
+        assert!(FailureKind::ChatNotFound.is_permanent());
+        assert!(!FailureKind::Flood.is_permanent());
+    }
+
+    #[test]
+    fn flood_control_pauses_all_sends() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let request = |index: usize, errors: Vec<RequestError>| {
+            let errors = Mutex::new(errors);
+            let sent = Arc::clone(&sent);
+            MockRequest::new(DeleteMessage::new(1, 1), move |_| {
+                let res = errors.lock().unwrap().pop().map_or(Ok(True), Err);
+                sent.lock()
+                    .unwrap()
+                    .push((index, Instant::now(), res.is_ok()));
+                ready(res)
+            })
+        };
+        let requests = vec![
+            request(0, vec![RequestError::RetryAfter(5)]),
+            request(1, vec![]),
+            request(2, vec![]),
+        ];
+
+        let start = crate::test_utils::rt().block_on(async {
+            tokio::time::pause();
+            let start = Instant::now();
+            let report = Broadcast::new().concurrency(2).send(requests).await;
+            assert_eq!(report.sent, 3);
+            start
+        });
+
+        // Nothing is sent after the flood control error until the wait ends,
+        // including requests to other chats
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[0], (0, start, false));
+        assert_eq!(sent.len(), 4);
+        for &(_, time, ok) in &sent[1..] {
+            assert!(ok);
+            assert!(time >= start + Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn progress() {
+        let mut progress = Progress::new(0);
+        assert_eq!(progress.complete(1), None);
+        assert_eq!(progress.complete(2), None);
+        assert_eq!(progress.complete(0), Some(3));
+        assert_eq!(progress.complete(3), Some(4));
+    }
+
+    #[test]
+    fn report() {
+        let requests = vec![
+            scripted(vec![]),
+            scripted(vec![api(ApiError::BotBlocked)]),
+            scripted(vec![RequestError::RetryAfter(0)]),
+            scripted(vec![
+                RequestError::RetryAfter(0),
+                RequestError::RetryAfter(0),
+            ]),
+            scripted(vec![api(ApiError::ChatNotFound)]),
+        ];
+
+        let checkpoints = Arc::new(Mutex::new(Vec::new()));
+        let broadcast = Broadcast::new()
+            .flood_retries(1)
+            .resume(Checkpoint { completed: 1 })
+            .on_checkpoint({
+                let checkpoints = Arc::clone(&checkpoints);
+                move |c| checkpoints.lock().unwrap().push(c.completed)
+            });
+
+        let report = crate::test_utils::rt().block_on(broadcast.send(requests));
+
+        assert_eq!(report.sent, 1);
+        assert_eq!(report.flood_waits, 2);
+        assert_eq!(report.checkpoint, Checkpoint { completed: 5 });
+        assert_eq!(
+            report
+                .failures
+                .iter()
+                .map(|f| (f.index, f.kind))
+                .collect::<Vec<_>>(),
+            [
+                (1, FailureKind::Blocked),
+                (3, FailureKind::Flood),
+                (4, FailureKind::ChatNotFound)
+            ]
+        );
+        assert_eq!(report.permanent_failures().collect::<Vec<_>>(), [1, 4]);
+        assert_eq!(checkpoints.lock().unwrap().last(), Some(&5));
+    }
+}
@@ -0,0 +1,166 @@
+//! Utilities for rendering custom emoji on platforms which don't support
+//! them, e.g. when mirroring messages to other chat services.
+//!
+//! Telegram sends a usual emoji as the text of every [custom emoji entity],
+//! so the text is readable as is. These functions allow to replace the text
+//! with something else, e.g. the emoji associated with the sticker of the
+//! custom emoji (see [`RequesterExt::render_custom_emoji`]) or a textual
+//! description.
+//!
+//! [custom emoji entity]: crate::types::MessageEntityKind::CustomEmoji
+//! [`RequesterExt::render_custom_emoji`]: crate::requests::RequesterExt::render_custom_emoji
+
+use std::collections::HashMap;
+
+use crate::types::{MessageEntity, MessageEntityKind, MessageEntityRef, Sticker};
+
+/// Replaces the text of custom emoji entities of the `text` with the result of
+/// `fallback`.
+///
+/// `fallback` is called with the identifier of the custom emoji and the text
+/// of the entity, if it returns `None` the text is left as is. Entities which
+/// don't correspond to the `text` or which overlap preceding custom emoji are
+/// ignored.
+///
+/// Note that offsets of `entities` are not valid for the returned text.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     types::{MessageEntity, MessageEntityKind},
+///     utils::custom_emoji::replace_custom_emoji,
+/// };
+///
+/// let text = "Hi 👋 there ⭐";
+/// let entities = [
+///     MessageEntity::new(MessageEntityKind::CustomEmoji { custom_emoji_id: "1".into() }, 3, 2),
+///     MessageEntity::new(MessageEntityKind::CustomEmoji { custom_emoji_id: "2".into() }, 12, 1),
+/// ];
+///
+/// let rendered = replace_custom_emoji(text, &entities, |id, _| match id {
+///     "1" => Some(":wave:".to_owned()),
+///     _ => None,
+/// });
+/// assert_eq!(rendered, "Hi :wave: there ⭐");
+/// ```
+pub fn replace_custom_emoji<F>(text: &str, entities: &[MessageEntity], mut fallback: F) -> String
+where
+    F: FnMut(&str, &str) -> Option<String>,
+{
+    let mut emoji: Vec<_> = MessageEntityRef::parse(text, entities)
+        .into_iter()
+        .filter_map(|entity| match entity.kind() {
+            MessageEntityKind::CustomEmoji { custom_emoji_id } => Some((entity, custom_emoji_id)),
+            _ => None,
+        })
+        .collect();
+    emoji.sort_by_key(|(entity, _)| entity.range().start);
+
+    let mut rendered = String::with_capacity(text.len());
+    let mut pos = 0;
+    for (entity, id) in emoji {
+        let range = entity.range();
+        if range.start < pos {
+            continue;
+        }
+
+        if let Some(replacement) = fallback(id, entity.text()) {
+            rendered.push_str(&text[pos..range.start]);
+            rendered.push_str(&replacement);
+            pos = range.end;
+        }
+    }
+    rendered.push_str(&text[pos..]);
+
+    rendered
+}
+
+/// Replaces the text of custom emoji entities of the `text` with the emoji
+/// associated with their stickers.
+///
+/// `stickers` is a map from a custom emoji identifier to its sticker, as
+/// returned by [`RequesterExt::custom_emoji_stickers`]. Custom emoji without
+/// a sticker (or without an associated emoji) are left as is.
+///
+/// [`RequesterExt::custom_emoji_stickers`]: crate::requests::RequesterExt::custom_emoji_stickers
+pub fn replace_with_sticker_emoji(
+    text: &str,
+    entities: &[MessageEntity],
+    stickers: &HashMap<String, Sticker>,
+) -> String {
+    replace_custom_emoji(text, entities, |id, _| {
+        stickers.get(id).and_then(|sticker| sticker.emoji.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_emoji(id: &str, offset: usize, length: usize) -> MessageEntity {
+        MessageEntity::new(
+            MessageEntityKind::CustomEmoji {
+                custom_emoji_id: id.to_owned(),
+            },
+            offset,
+            length,
+        )
+    }
+
+    #[test]
+    fn replaces_in_order() {
+        // Entities are not required to be sorted
+        let entities = [
+            custom_emoji("2", 4, 2),
+            MessageEntity::new(MessageEntityKind::Bold, 0, 6),
+            custom_emoji("1", 0, 2),
+        ];
+
+        let rendered = replace_custom_emoji("🦀, 🦀!", &entities, |id, text| {
+            Some(format!("[{}:{}]", id, text))
+        });
+        assert_eq!(rendered, "[1:🦀], [2:🦀]!");
+    }
+
+    #[test]
+    fn skips_invalid() {
+        let entities = [
+            custom_emoji("1", 0, 2),
+            custom_emoji("2", 1, 2),
+            custom_emoji("3", 10, 2),
+        ];
+
+        let rendered = replace_custom_emoji("ab x", &entities, |id, _| Some(id.to_owned()));
+        assert_eq!(rendered, "1 x");
+
+        // Middle of a surrogate pair
+        let entities = [custom_emoji("1", 1, 2)];
+        let rendered = replace_custom_emoji("🦀 x", &entities, |id, _| Some(id.to_owned()));
+        assert_eq!(rendered, "🦀 x");
+    }
+
+    #[test]
+    fn sticker_emoji() {
+        let sticker: Sticker = serde_json::from_str(
+            r#"{
+                "file_id": "id",
+                "file_unique_id": "unique",
+                "width": 100,
+                "height": 100,
+                "is_animated": false,
+                "is_video": false,
+                "emoji": "🦞",
+                "custom_emoji_id": "1"
+            }"#,
+        )
+        .unwrap();
+        let stickers = vec![("1".to_owned(), sticker)].into_iter().collect();
+        let entities = [custom_emoji("1", 0, 2), custom_emoji("2", 3, 2)];
+
+        assert_eq!(
+            replace_with_sticker_emoji("🦀 🦀", &entities, &stickers),
+            "🦞 🦀"
+        );
+    }
+}
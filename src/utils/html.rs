@@ -0,0 +1,57 @@
+//! Utilities for composing texts with [`ParseMode::Html`].
+//!
+//! [`ParseMode::Html`]: crate::types::ParseMode::Html
+
+/// Escapes `<`, `>` and `&`, so that `s` is displayed as is.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::html;
+///
+/// assert_eq!(html::escape("1 < 2 & 3 > 2"), "1 &lt; 2 &amp; 3 &gt; 2");
+/// ```
+pub fn escape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '<' => res.push_str("&lt;"),
+            '>' => res.push_str("&gt;"),
+            '&' => res.push_str("&amp;"),
+            c => res.push(c),
+        }
+    }
+    res
+}
+
+/// Returns a link with the `text` (which is escaped) to the `url`.
+pub fn link(url: &str, text: &str) -> String {
+    format!(
+        "<a href=\"{}\">{}</a>",
+        escape(url).replace('"', "&quot;"),
+        escape(text)
+    )
+}
+
+/// Returns an inline mention of the user with the `user_id`, displayed as
+/// `text` (which is escaped).
+pub fn user_mention(user_id: i64, text: &str) -> String {
+    link(&format!("tg://user?id={}", user_id), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mention() {
+        assert_eq!(
+            user_mention(42, "<Admin> & co"),
+            "<a href=\"tg://user?id=42\">&lt;Admin&gt; &amp; co</a>"
+        );
+        assert_eq!(
+            link("https://example.com/?a=\"b\"&c", "x"),
+            "<a href=\"https://example.com/?a=&quot;b&quot;&amp;c\">x</a>"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Pagination of inline query results.
+//!
+//! Telegram allows at most 50 results per [`AnswerInlineQuery`] and asks for
+//! more by sending a new [`InlineQuery`] with the [`offset`] set to the
+//! [`next_offset`] of the previous answer. [`InlinePaginator`] keeps track of
+//! that.
+//!
+//! [`offset`]: InlineQuery::offset
+//! [`next_offset`]: AnswerInlineQuery::next_offset
+
+use crate::{
+    payloads::{AnswerInlineQuery, AnswerInlineQuerySetters},
+    types::{InlineQuery, InlineQueryResult},
+};
+
+/// The maximum number of results in one [`AnswerInlineQuery`].
+pub const MAX_INLINE_RESULTS: usize = 50;
+
+/// Slices results of inline queries into pages and manages the `next_offset`.
+///
+/// The offset is the (decimal) number of results which were already shown, so
+/// the paginator doesn't have any state and the same results must be produced
+/// for the same query (e.g. by running the same search).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     types::{InlineQuery, InlineQueryResult, InlineQueryResultArticle, InputMessageContent, InputMessageContentText, User},
+///     utils::inline_paginator::InlinePaginator,
+/// };
+///
+/// # let user = User { id: 0, is_bot: false, first_name: "".into(), last_name: None, username: None, language_code: None };
+/// let results = (0..120).map(|i| {
+///     let content = InputMessageContent::Text(InputMessageContentText::new(i.to_string()));
+///     InlineQueryResult::Article(InlineQueryResultArticle::new(i.to_string(), "title", content))
+/// });
+///
+/// let query = InlineQuery::new("id", user, "", "");
+/// let payload = InlinePaginator::new().answer(&query, results.clone());
+/// assert_eq!(payload.results.len(), 50);
+/// assert_eq!(payload.next_offset.as_deref(), Some("50"));
+///
+/// // ... and after the second page the client asks for the third one
+/// let query = query.offset("100");
+/// let payload = InlinePaginator::new().answer(&query, results);
+/// assert_eq!(payload.results.len(), 20);
+/// assert_eq!(payload.next_offset.as_deref(), Some(""));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InlinePaginator {
+    page_size: usize,
+}
+
+/// A page of inline query results, see [`InlinePaginator::page`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlinePage {
+    /// Results on this page.
+    pub results: Vec<InlineQueryResult>,
+
+    /// Offset of the next page or an empty string if this is the last page.
+    pub next_offset: String,
+}
+
+impl InlinePaginator {
+    /// Creates a paginator with pages of [`MAX_INLINE_RESULTS`] results.
+    pub fn new() -> Self {
+        Self {
+            page_size: MAX_INLINE_RESULTS,
+        }
+    }
+
+    /// Sets the number of results per page, clamped to
+    /// `1..=`[`MAX_INLINE_RESULTS`].
+    pub fn page_size(self, page_size: usize) -> Self {
+        Self {
+            page_size: match page_size {
+                0 => 1,
+                _ => page_size.min(MAX_INLINE_RESULTS),
+            },
+        }
+    }
+
+    /// Returns the number of results per page.
+    pub fn get_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Decodes an offset sent by a client, i.e. the number of results to skip.
+    ///
+    /// Empty (the first query) and malformed offsets are decoded as `0`.
+    pub fn decode_offset(offset: &str) -> usize {
+        offset.parse().unwrap_or(0)
+    }
+
+    /// Encodes the number of results to skip as an offset.
+    pub fn encode_offset(skip: usize) -> String {
+        skip.to_string()
+    }
+
+    /// Returns the page of `results` which starts at the `offset`.
+    ///
+    /// Only the results up to the end of the page (and one more, to check if
+    /// there is a next page) are taken from the iterator.
+    pub fn page<I, R>(&self, offset: &str, results: I) -> InlinePage
+    where
+        I: IntoIterator<Item = R>,
+        R: Into<InlineQueryResult>,
+    {
+        let skip = Self::decode_offset(offset);
+        let mut results: Vec<_> = results
+            .into_iter()
+            .skip(skip)
+            .take(self.page_size + 1)
+            .map(Into::into)
+            .collect();
+
+        let next_offset = if results.len() > self.page_size {
+            results.truncate(self.page_size);
+            Self::encode_offset(skip + self.page_size)
+        } else {
+            String::new()
+        };
+
+        InlinePage {
+            results,
+            next_offset,
+        }
+    }
+
+    /// Builds an answer to the `query` with the page of `results` requested by
+    /// it.
+    pub fn answer<I, R>(&self, query: &InlineQuery, results: I) -> AnswerInlineQuery
+    where
+        I: IntoIterator<Item = R>,
+        R: Into<InlineQueryResult>,
+    {
+        self.page(&query.offset, results)
+            .into_payload(query.id.clone())
+    }
+}
+
+impl Default for InlinePaginator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InlinePage {
+    /// Builds an answer to the inline query with the `inline_query_id`.
+    pub fn into_payload<S>(self, inline_query_id: S) -> AnswerInlineQuery
+    where
+        S: Into<String>,
+    {
+        AnswerInlineQuery::new(inline_query_id, self.results).next_offset(self.next_offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{InlineQueryResultArticle, InputMessageContent, InputMessageContentText};
+
+    fn results(n: usize) -> impl Iterator<Item = InlineQueryResult> + Clone {
+        (0..n).map(|i| {
+            let content = InputMessageContent::Text(InputMessageContentText::new("text"));
+            InlineQueryResult::Article(InlineQueryResultArticle::new(i.to_string(), "", content))
+        })
+    }
+
+    fn id(result: &InlineQueryResult) -> &str {
+        match result {
+            InlineQueryResult::Article(article) => &article.id,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn pages() {
+        let paginator = InlinePaginator::new().page_size(2);
+
+        let page = paginator.page("", results(5));
+        assert_eq!(page.results.iter().map(id).collect::<Vec<_>>(), ["0", "1"]);
+        assert_eq!(page.next_offset, "2");
+
+        let page = paginator.page("4", results(5));
+        assert_eq!(page.results.iter().map(id).collect::<Vec<_>>(), ["4"]);
+        assert_eq!(page.next_offset, "");
+
+        // Exactly at the end of the results
+        assert_eq!(paginator.page("2", results(4)).next_offset, "");
+        assert!(paginator.page("9", results(4)).results.is_empty());
+        assert_eq!(paginator.page("garbage", results(4)).next_offset, "2");
+    }
+
+    #[test]
+    fn page_size() {
+        assert_eq!(InlinePaginator::new().page_size(0).get_page_size(), 1);
+        assert_eq!(
+            InlinePaginator::new().page_size(100).get_page_size(),
+            MAX_INLINE_RESULTS
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Correlating [`ChosenInlineResult`]s with answered inline query results.
+//!
+//! When a user chooses a result of an inline query, Telegram only sends the
+//! [`result_id`] back (and only if [inline feedback] is enabled). To act on
+//! the choice, bots usually need to remember what was behind each result when
+//! answering the query, [`InlineResultStore`] abstracts this storage.
+//!
+//! Result ids are only unique within one query, so values are associated with
+//! the id of the user who sent the query and the id of the result.
+//!
+//! [`ChosenInlineResult`]: crate::types::ChosenInlineResult
+//! [`result_id`]: crate::types::ChosenInlineResult::result_id
+//! [inline feedback]: https://core.telegram.org/bots/inline#collecting-feedback
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use crate::types::InlineQueryResult;
+
+/// Storage of values associated with ids of answered inline query results
+/// (and ids of users who sent the queries).
+///
+/// Implement this trait to keep the values in a database or cache,
+/// [`MemoryInlineResultStore`] is a bounded in-memory implementation.
+pub trait InlineResultStore {
+    /// Value associated with a result.
+    type Value;
+
+    /// Associates the `value` with the `result_id` of a result sent to the
+    /// user with the `user_id`.
+    fn remember(&self, user_id: i64, result_id: &str, value: Self::Value);
+
+    /// Returns the value associated with the `result_id` of a result sent to
+    /// the user with the `user_id`, if any.
+    fn lookup(&self, user_id: i64, result_id: &str) -> Option<Self::Value>;
+
+    /// Associates values with all the `results` of a query of the user with
+    /// the `user_id` (e.g. right before answering the query with them).
+    fn remember_results<'a, I, F>(&self, user_id: i64, results: I, mut value: F)
+    where
+        Self: Sized,
+        I: IntoIterator<Item = &'a InlineQueryResult>,
+        F: FnMut(&InlineQueryResult) -> Self::Value,
+    {
+        for result in results {
+            self.remember(user_id, result.id(), value(result));
+        }
+    }
+}
+
+/// In-memory [`InlineResultStore`] which keeps at most `capacity` values,
+/// evicting the oldest ones.
+#[derive(Debug)]
+pub struct MemoryInlineResultStore<V> {
+    capacity: usize,
+    state: Mutex<State<V>>,
+}
+
+#[derive(Debug)]
+struct State<V> {
+    values: HashMap<(i64, String), V>,
+    /// Keys in the order of insertion.
+    order: VecDeque<(i64, String)>,
+}
+
+impl<V> MemoryInlineResultStore<V> {
+    /// Creates a store which keeps at most `capacity` values.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(State {
+                values: HashMap::new(),
+                order: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().values.len()
+    }
+
+    /// Returns `true` if the store is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<V: Clone> InlineResultStore for MemoryInlineResultStore<V> {
+    type Value = V;
+
+    fn remember(&self, user_id: i64, result_id: &str, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let key = (user_id, result_id.to_owned());
+        let mut state = self.state.lock().unwrap();
+        if state.values.insert(key.clone(), value).is_none() {
+            state.order.push_back(key);
+        }
+
+        while state.values.len() > self.capacity {
+            match state.order.pop_front() {
+                Some(oldest) => state.values.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+
+    fn lookup(&self, user_id: i64, result_id: &str) -> Option<V> {
+        let key = (user_id, result_id.to_owned());
+        self.state.lock().unwrap().values.get(&key).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        ChosenInlineResult, InlineQueryResultArticle, InputMessageContent, InputMessageContentText,
+        User,
+    };
+
+    fn article(id: &str) -> InlineQueryResult {
+        let content = InputMessageContent::Text(InputMessageContentText::new("text"));
+        InlineQueryResult::Article(InlineQueryResultArticle::new(id, "title", content))
+    }
+
+    #[test]
+    fn remember_and_lookup() {
+        let store = MemoryInlineResultStore::new(2);
+        let results = [article("a"), article("b"), article("c")];
+        store.remember_results(1, &results, |r| r.id().to_uppercase());
+
+        // "a" is evicted
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.lookup(1, "a"), None);
+        assert_eq!(store.lookup(1, "c").as_deref(), Some("C"));
+        assert_eq!(store.lookup(2, "c"), None);
+
+        let chosen = ChosenInlineResult {
+            result_id: "b".to_owned(),
+            from: User {
+                id: 1,
+                is_bot: false,
+                first_name: "".to_owned(),
+                last_name: None,
+                username: None,
+                language_code: None,
+            },
+            location: None,
+            inline_message_id: None,
+            query: "".to_owned(),
+        };
+        assert_eq!(chosen.lookup(&store).as_deref(), Some("B"));
+    }
+
+    #[test]
+    fn overwrite_keeps_order() {
+        let store = MemoryInlineResultStore::new(2);
+        store.remember(1, "a", 1);
+        store.remember(1, "b", 2);
+        store.remember(1, "a", 3);
+        store.remember(1, "c", 4);
+
+        assert_eq!(store.lookup(1, "a"), None);
+        assert_eq!(store.lookup(1, "b"), Some(2));
+        assert_eq!(store.lookup(1, "c"), Some(4));
+    }
+
+    #[test]
+    fn results_of_users_are_separate() {
+        let store = MemoryInlineResultStore::new(4);
+        store.remember(1, "0", "first");
+        store.remember(2, "0", "second");
+
+        assert_eq!(store.lookup(1, "0"), Some("first"));
+        assert_eq!(store.lookup(2, "0"), Some("second"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Utilities for composing texts with [`ParseMode::MarkdownV2`].
+//!
+//! [`ParseMode::MarkdownV2`]: crate::types::ParseMode::MarkdownV2
+
+/// Escapes all characters which have special meaning in MarkdownV2, so that
+/// `s` is displayed as is.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::markdown;
+///
+/// assert_eq!(markdown::escape("*not bold* (1.5)"), r"\*not bold\* \(1\.5\)");
+/// ```
+pub fn escape(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(
+            c,
+            '_' | '*'
+                | '['
+                | ']'
+                | '('
+                | ')'
+                | '~'
+                | '`'
+                | '>'
+                | '#'
+                | '+'
+                | '-'
+                | '='
+                | '|'
+                | '{'
+                | '}'
+                | '.'
+                | '!'
+                | '\\'
+        ) {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
+
+/// Escapes `)` and `\` in a link url, as required by MarkdownV2.
+pub fn escape_link_url(url: &str) -> String {
+    let mut res = String::with_capacity(url.len());
+    for c in url.chars() {
+        if c == ')' || c == '\\' {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
+
+/// Escapes `` ` `` and `\` in the text of code and pre entities, as required
+/// by MarkdownV2.
+pub fn escape_code(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c == '`' || c == '\\' {
+            res.push('\\');
+        }
+        res.push(c);
+    }
+    res
+}
+
+/// Returns a link with the `text` (which is escaped) to the `url`.
+pub fn link(url: &str, text: &str) -> String {
+    format!("[{}]({})", escape(text), escape_link_url(url))
+}
+
+/// Returns an inline mention of the user with the `user_id`, displayed as
+/// `text` (which is escaped).
+pub fn user_mention(user_id: i64, text: &str) -> String {
+    link(&format!("tg://user?id={}", user_id), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mention() {
+        assert_eq!(
+            user_mention(42, "John [admin]"),
+            r"[John \[admin\]](tg://user?id=42)"
+        );
+        assert_eq!(
+            link("https://e.com/(a)", "a.b"),
+            r"[a\.b](https://e.com/(a\))"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! A minimal reader of ISO base media files (`.mp4`, `.m4a`, ...), only
+//! sufficient for reading metadata.
+
+/// Returns `true` if `data` looks like an ISO base media file.
+pub(crate) fn is_mp4(data: &[u8]) -> bool {
+    data.get(4..8) == Some(b"ftyp")
+}
+
+/// Iterator over boxes (`(type, content)`) of a box or of a file.
+///
+/// Iteration stops at the first malformed box.
+pub(crate) struct Boxes<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Boxes<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> Iterator for Boxes<'a> {
+    type Item = ([u8; 4], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = u64::from(read_u32(self.data, 0)?);
+        let ty = [
+            *self.data.get(4)?,
+            *self.data.get(5)?,
+            *self.data.get(6)?,
+            *self.data.get(7)?,
+        ];
+
+        let (header, size) = match size {
+            // The box extends to the end of the file
+            0 => (8, self.data.len() as u64),
+            1 => (16, read_u64(self.data, 8)?),
+            size => (8, size),
+        };
+
+        if size < header || size > self.data.len() as u64 {
+            self.data = &[];
+            return None;
+        }
+
+        let (this, rest) = self.data.split_at(size as usize);
+        self.data = rest;
+        Some((ty, &this[header as usize..]))
+    }
+}
+
+/// Returns the content of the first box at the `path`, e.g.
+/// `[b"moov", b"mvhd"]`.
+pub(crate) fn find<'a>(data: &'a [u8], path: &[&[u8; 4]]) -> Option<&'a [u8]> {
+    let (first, rest) = path.split_first()?;
+    let content = Boxes::new(data)
+        .find(|(ty, _)| ty == *first)
+        .map(|(_, content)| content)?;
+
+    if rest.is_empty() {
+        Some(content)
+    } else {
+        find(children(first, content), rest)
+    }
+}
+
+/// Returns the part of the `content` of a box which contains its children.
+pub(crate) fn children<'a>(ty: &[u8; 4], content: &'a [u8]) -> &'a [u8] {
+    match ty {
+        // `meta` is a full box in MP4, but not in QuickTime files, which have
+        // `hdlr` right away
+        b"meta" if content.get(4..8) != Some(b"hdlr") => content.get(4..).unwrap_or(&[]),
+        b"stsd" => content.get(8..).unwrap_or(&[]),
+        _ => content,
+    }
+}
+
+/// Returns the duration of the movie in seconds, rounded, from the `mvhd` box
+/// of the `moov` box.
+pub(crate) fn duration(moov: &[u8]) -> Option<u32> {
+    let mvhd = find(moov, &[b"mvhd"])?;
+    let (timescale, duration) = match mvhd.first()? {
+        0 => (read_u32(mvhd, 12)?, u64::from(read_u32(mvhd, 16)?)),
+        1 => (read_u32(mvhd, 20)?, read_u64(mvhd, 24)?),
+        _ => return None,
+    };
+
+    seconds(duration, timescale)
+}
+
+/// Converts the `duration` in units of the `timescale` to rounded seconds.
+pub(crate) fn seconds(duration: u64, timescale: u32) -> Option<u32> {
+    // Unknown durations are stored as all ones
+    if timescale == 0 || duration == u64::MAX || duration == u64::from(u32::MAX) {
+        return None;
+    }
+
+    let timescale = u64::from(timescale);
+    let seconds = duration.saturating_add(timescale / 2) / timescale;
+    Some(seconds.min(u64::from(u32::MAX)) as u32)
+}
+
+/// Iterator over `trak` boxes of the `moov` box with the handler `handler`
+/// (e.g. `vide` or `soun`).
+#[cfg(feature = "video_metadata")]
+pub(crate) fn tracks<'a>(
+    moov: &'a [u8],
+    handler: &'a [u8; 4],
+) -> impl Iterator<Item = &'a [u8]> + 'a {
+    Boxes::new(moov)
+        .filter(|(ty, _)| ty == b"trak")
+        .map(|(_, trak)| trak)
+        .filter(move |trak| {
+            find(trak, &[b"mdia", b"hdlr"]).and_then(|hdlr| hdlr.get(8..12)) == Some(handler)
+        })
+}
+
+pub(crate) fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    let bytes = data.get(at..at + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub(crate) fn read_u64(data: &[u8], at: usize) -> Option<u64> {
+    let high = read_u32(data, at)?;
+    let low = read_u32(data, at + 4)?;
+    Some(u64::from(high) << 32 | u64::from(low))
+}
+
+/// Helpers for building files in tests.
+#[cfg(test)]
+pub(crate) mod build {
+    /// Returns a box of type `ty` with the `content`.
+    pub(crate) fn mp4_box(ty: &[u8; 4], content: &[&[u8]]) -> Vec<u8> {
+        let len: usize = content.iter().map(|c| c.len()).sum();
+        let mut res = Vec::with_capacity(8 + len);
+        res.extend_from_slice(&(8 + len as u32).to_be_bytes());
+        res.extend_from_slice(ty);
+        for c in content {
+            res.extend_from_slice(c);
+        }
+        res
+    }
+
+    /// Returns a `trak` box (with `tkhd` version 0) of the `handler` type.
+    #[cfg(feature = "video_metadata")]
+    pub(crate) fn trak(handler: &[u8; 4], width: u32, height: u32, rotated: bool) -> Vec<u8> {
+        // 16.16 fixed point numbers of the matrix, rotated by 90 degrees or not
+        let (a, b, c): (u32, u32, u32) = if rotated { (0, 1, 0xFFFF) } else { (1, 0, 0) };
+        let mut tkhd = vec![0; 40];
+        for value in [a, b, 0, c, a, 0, 0, 0, 0x4000].iter() {
+            tkhd.extend_from_slice(&(value << 16).to_be_bytes());
+        }
+        tkhd.extend_from_slice(&(width << 16).to_be_bytes());
+        tkhd.extend_from_slice(&(height << 16).to_be_bytes());
+
+        let hdlr = mp4_box(b"hdlr", &[&[0; 8], handler, &[0; 13]]);
+        mp4_box(
+            b"trak",
+            &[&mp4_box(b"tkhd", &[&tkhd]), &mp4_box(b"mdia", &[&hdlr])],
+        )
+    }
+
+    /// Returns an `mvhd` box (version 0).
+    pub(crate) fn mvhd(timescale: u32, duration: u32) -> Vec<u8> {
+        let mut content = vec![0; 12];
+        content.extend_from_slice(&timescale.to_be_bytes());
+        content.extend_from_slice(&duration.to_be_bytes());
+        content.extend_from_slice(&[0; 80]);
+        mp4_box(b"mvhd", &[&content])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build::*, *};
+
+    #[test]
+    fn boxes() {
+        let file = [
+            mp4_box(b"ftyp", &[b"M4A "]),
+            mp4_box(b"moov", &[&mvhd(1000, 12_600), &mp4_box(b"udta", &[])]),
+        ]
+        .concat();
+
+        assert!(is_mp4(&file));
+        let types: Vec<_> = Boxes::new(&file).map(|(ty, _)| ty).collect();
+        assert_eq!(types, [*b"ftyp", *b"moov"]);
+        assert_eq!(find(&file, &[b"moov", b"udta"]), Some(&[][..]));
+        assert_eq!(find(&file, &[b"moov", b"trak"]), None);
+        assert_eq!(find(&file, &[b"moov"]).and_then(duration), Some(13));
+
+        // Truncated box
+        assert_eq!(Boxes::new(&file[..file.len() - 1]).count(), 1);
+    }
+
+    #[test]
+    fn seconds_saturate() {
+        assert_eq!(seconds(u64::MAX - 1, 1000), Some(u32::MAX));
+        assert_eq!(seconds(u64::MAX, 1000), None);
+    }
+}
@@ -0,0 +1,383 @@
+//! Reconstruction of formatted texts from entities.
+//!
+//! Telegram sends texts of messages without markup, the formatting is
+//! described by [entities] instead. Functions of this module turn such a text
+//! back into markup, e.g. to edit and resend a message or to post it to
+//! another chat.
+//!
+//! Entities which are detected by Telegram automatically (mentions, hashtags,
+//! urls, etc.) don't need markup and are rendered as plain text. Partially
+//! overlapping entities are split so that the markup is properly nested.
+//! Entities inside of code and pre entities are ignored, as Telegram doesn't
+//! support them.
+//!
+//! [entities]: crate::types::MessageEntity
+
+use std::cmp::Reverse;
+
+use crate::{
+    types::{MessageEntity, MessageEntityKind, MessageEntityRef},
+    utils::{html, markdown},
+};
+
+/// Renders the `text` with the `entities` as [`ParseMode::Html`] markup.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     types::{MessageEntity, MessageEntityKind},
+///     utils::render::to_html,
+/// };
+///
+/// let entities = [
+///     MessageEntity::new(MessageEntityKind::Bold, 0, 10),
+///     MessageEntity::new(MessageEntityKind::Italic, 4, 1),
+/// ];
+/// assert_eq!(to_html("1 < 2 true", &entities), "<b>1 &lt; <i>2</i> true</b>");
+/// ```
+///
+/// [`ParseMode::Html`]: crate::types::ParseMode::Html
+pub fn to_html(text: &str, entities: &[MessageEntity]) -> String {
+    render(text, entities, Markup::Html)
+}
+
+/// Renders the `text` with the `entities` as [`ParseMode::MarkdownV2`]
+/// markup.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     types::{MessageEntity, MessageEntityKind},
+///     utils::render::to_markdown_v2,
+/// };
+///
+/// let entities = [
+///     MessageEntity::new(MessageEntityKind::Bold, 0, 10),
+///     MessageEntity::new(MessageEntityKind::Italic, 4, 1),
+/// ];
+/// assert_eq!(to_markdown_v2("1 + 2 true", &entities), r"*1 \+ _2_ true*");
+/// ```
+///
+/// [`ParseMode::MarkdownV2`]: crate::types::ParseMode::MarkdownV2
+pub fn to_markdown_v2(text: &str, entities: &[MessageEntity]) -> String {
+    render(text, entities, Markup::MarkdownV2)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Markup {
+    Html,
+    MarkdownV2,
+}
+
+impl Markup {
+    fn open(self, kind: &MessageEntityKind) -> String {
+        use MessageEntityKind::*;
+
+        match (self, kind) {
+            (Markup::Html, Bold) => "<b>".to_owned(),
+            (Markup::Html, Italic) => "<i>".to_owned(),
+            (Markup::Html, Underline) => "<u>".to_owned(),
+            (Markup::Html, Strikethrough) => "<s>".to_owned(),
+            (Markup::Html, Code) => "<code>".to_owned(),
+            (Markup::Html, Pre { language: None }) => "<pre>".to_owned(),
+            (
+                Markup::Html,
+                Pre {
+                    language: Some(lang),
+                },
+            ) => {
+                format!("<pre><code class=\"language-{}\">", html_attr(lang))
+            }
+            (Markup::Html, TextLink { url }) => format!("<a href=\"{}\">", html_attr(url)),
+            (Markup::Html, TextMention { user }) => {
+                format!("<a href=\"tg://user?id={}\">", user.id)
+            }
+            (Markup::Html, CustomEmoji { custom_emoji_id }) => {
+                format!("<tg-emoji emoji-id=\"{}\">", html_attr(custom_emoji_id))
+            }
+
+            (Markup::MarkdownV2, Bold) => "*".to_owned(),
+            (Markup::MarkdownV2, Italic) => "_".to_owned(),
+            (Markup::MarkdownV2, Underline) => "__".to_owned(),
+            (Markup::MarkdownV2, Strikethrough) => "~".to_owned(),
+            (Markup::MarkdownV2, Code) => "`".to_owned(),
+            (Markup::MarkdownV2, Pre { language }) => {
+                format!("```{}\n", language.as_deref().unwrap_or(""))
+            }
+            (Markup::MarkdownV2, TextLink { .. }) | (Markup::MarkdownV2, TextMention { .. }) => {
+                "[".to_owned()
+            }
+            (Markup::MarkdownV2, CustomEmoji { .. }) => "![".to_owned(),
+
+            (_, Mention)
+            | (_, Hashtag)
+            | (_, Cashtag)
+            | (_, BotCommand)
+            | (_, Url)
+            | (_, Email)
+            | (_, PhoneNumber)
+            // Unknown entities are rendered as plain text
+            | (_, Other(_)) => String::new(),
+        }
+    }
+
+    fn close(self, kind: &MessageEntityKind) -> String {
+        use MessageEntityKind::*;
+
+        match (self, kind) {
+            (Markup::Html, Bold) => "</b>".to_owned(),
+            (Markup::Html, Italic) => "</i>".to_owned(),
+            (Markup::Html, Underline) => "</u>".to_owned(),
+            (Markup::Html, Strikethrough) => "</s>".to_owned(),
+            (Markup::Html, Code) => "</code>".to_owned(),
+            (Markup::Html, Pre { language: None }) => "</pre>".to_owned(),
+            (Markup::Html, Pre { language: Some(_) }) => "</code></pre>".to_owned(),
+            (Markup::Html, TextLink { .. }) | (Markup::Html, TextMention { .. }) => {
+                "</a>".to_owned()
+            }
+            (Markup::Html, CustomEmoji { .. }) => "</tg-emoji>".to_owned(),
+
+            (Markup::MarkdownV2, Bold) => "*".to_owned(),
+            (Markup::MarkdownV2, Italic) => "_".to_owned(),
+            (Markup::MarkdownV2, Underline) => "__".to_owned(),
+            (Markup::MarkdownV2, Strikethrough) => "~".to_owned(),
+            (Markup::MarkdownV2, Code) => "`".to_owned(),
+            (Markup::MarkdownV2, Pre { .. }) => "```".to_owned(),
+            (Markup::MarkdownV2, TextLink { url }) => {
+                format!("]({})", markdown::escape_link_url(url))
+            }
+            (Markup::MarkdownV2, TextMention { user }) => format!("](tg://user?id={})", user.id),
+            (Markup::MarkdownV2, CustomEmoji { custom_emoji_id }) => format!(
+                "](tg://emoji?id={})",
+                markdown::escape_link_url(custom_emoji_id)
+            ),
+
+            (_, Mention)
+            | (_, Hashtag)
+            | (_, Cashtag)
+            | (_, BotCommand)
+            | (_, Url)
+            | (_, Email)
+            | (_, PhoneNumber)
+            // Unknown entities are rendered as plain text
+            | (_, Other(_)) => String::new(),
+        }
+    }
+
+    fn escape(self, text: &str, code: bool) -> String {
+        match self {
+            Markup::Html => html::escape(text),
+            Markup::MarkdownV2 if code => markdown::escape_code(text),
+            Markup::MarkdownV2 => markdown::escape(text),
+        }
+    }
+
+    /// Appends a tag (or a markdown delimiter) to the `res`.
+    fn push_tag(self, res: &mut String, tag: &str) {
+        // `___` is ambiguous, Telegram ignores `\r` which can be used to
+        // separate italic and underline delimiters
+        if self == Markup::MarkdownV2 && res.ends_with('_') && tag.starts_with('_') {
+            res.push('\r');
+        }
+        res.push_str(tag);
+    }
+}
+
+fn html_attr(s: &str) -> String {
+    html::escape(s).replace('"', "&quot;")
+}
+
+/// Returns `true` if the entity of the `kind` requires markup.
+fn needs_markup(kind: &MessageEntityKind) -> bool {
+    use MessageEntityKind::*;
+
+    match kind {
+        Bold
+        | Italic
+        | Underline
+        | Strikethrough
+        | Code
+        | Pre { .. }
+        | TextLink { .. }
+        | TextMention { .. }
+        | CustomEmoji { .. } => true,
+        Mention | Hashtag | Cashtag | BotCommand | Url | Email | PhoneNumber | Other(_) => false,
+    }
+}
+
+fn is_code(entity: &MessageEntityRef<'_>) -> bool {
+    matches!(
+        entity.kind(),
+        MessageEntityKind::Code | MessageEntityKind::Pre { .. }
+    )
+}
+
+fn render(text: &str, entities: &[MessageEntity], markup: Markup) -> String {
+    let mut entities: Vec<_> = MessageEntityRef::parse(text, entities)
+        .into_iter()
+        .filter(|entity| needs_markup(entity.kind()) && !entity.range().is_empty())
+        .collect();
+    // Outer entities are opened first
+    entities.sort_by_key(|entity| (entity.range().start, Reverse(entity.range().end)));
+
+    let mut boundaries: Vec<usize> = entities
+        .iter()
+        .flat_map(|entity| vec![entity.range().start, entity.range().end])
+        .chain(Some(text.len()))
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut res = String::with_capacity(text.len());
+    let mut open: Vec<&MessageEntityRef<'_>> = Vec::new();
+    let mut entities = entities.iter().peekable();
+    let mut pos = 0;
+
+    for boundary in boundaries {
+        if boundary > pos {
+            let code = open.iter().any(|entity| is_code(entity));
+            res.push_str(&markup.escape(&text[pos..boundary], code));
+            pos = boundary;
+        }
+
+        // Entities which end here are closed together with all entities
+        // opened after them, the latter are then reopened
+        if let Some(idx) = open.iter().position(|entity| entity.range().end <= pos) {
+            let closed: Vec<_> = open.drain(idx..).collect();
+            for entity in closed.iter().rev() {
+                markup.push_tag(&mut res, &markup.close(entity.kind()));
+            }
+            for entity in closed {
+                if entity.range().end > pos {
+                    markup.push_tag(&mut res, &markup.open(entity.kind()));
+                    open.push(entity);
+                }
+            }
+        }
+
+        while let Some(&entity) = entities.peek() {
+            if entity.range().start != pos {
+                break;
+            }
+
+            entities.next();
+            if open.iter().any(|entity| is_code(entity)) {
+                continue;
+            }
+
+            markup.push_tag(&mut res, &markup.open(entity.kind()));
+            open.push(entity);
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::User;
+
+    fn entity(kind: MessageEntityKind, offset: usize, length: usize) -> MessageEntity {
+        MessageEntity::new(kind, offset, length)
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(to_html("a < b", &[]), "a &lt; b");
+        assert_eq!(to_markdown_v2("a.b", &[]), r"a\.b");
+
+        let entities = [entity(MessageEntityKind::Mention, 0, 5)];
+        assert_eq!(to_markdown_v2("@user_", &entities), r"@user\_");
+    }
+
+    #[test]
+    fn nested() {
+        let entities = [
+            entity(MessageEntityKind::Italic, 2, 3),
+            entity(MessageEntityKind::Bold, 0, 6),
+            entity(MessageEntityKind::Underline, 2, 3),
+        ];
+
+        assert_eq!(
+            to_html("ab cde f", &entities),
+            "<b>ab<i><u> cd</u></i>e</b> f"
+        );
+        assert_eq!(
+            to_markdown_v2("ab cde f", &entities),
+            "*ab_\r__ cd__\r_e* f"
+        );
+    }
+
+    #[test]
+    fn overlapping() {
+        let entities = [
+            entity(MessageEntityKind::Bold, 0, 4),
+            entity(MessageEntityKind::Strikethrough, 2, 4),
+        ];
+
+        assert_eq!(to_html("abcdef", &entities), "<b>ab<s>cd</s></b><s>ef</s>");
+        assert_eq!(to_markdown_v2("abcdef", &entities), "*ab~cd~*~ef~");
+    }
+
+    #[test]
+    fn code() {
+        let entities = [
+            entity(MessageEntityKind::Code, 0, 5),
+            entity(MessageEntityKind::Bold, 1, 2),
+            entity(
+                MessageEntityKind::Pre {
+                    language: Some("rust".to_owned()),
+                },
+                6,
+                7,
+            ),
+        ];
+        let text = r"a`\.b fn a<T>";
+
+        assert_eq!(
+            to_html(text, &entities),
+            "<code>a`\\.b</code> <pre><code class=\"language-rust\">fn a&lt;T&gt;</code></pre>"
+        );
+        assert_eq!(
+            to_markdown_v2(text, &entities),
+            "`a\\`\\\\.b` ```rust\nfn a<T>```"
+        );
+    }
+
+    #[test]
+    fn links() {
+        let user: User =
+            serde_json::from_str(r#"{"id":42,"is_bot":false,"first_name":"A"}"#).unwrap();
+        let entities = [
+            entity(
+                MessageEntityKind::TextLink {
+                    url: "https://e.com/(\"a\")".to_owned(),
+                },
+                0,
+                4,
+            ),
+            entity(MessageEntityKind::TextMention { user }, 5, 2),
+            entity(
+                MessageEntityKind::CustomEmoji {
+                    custom_emoji_id: "1".to_owned(),
+                },
+                8,
+                2,
+            ),
+        ];
+        let text = "link me 👍";
+
+        assert_eq!(
+            to_html(text, &entities),
+            "<a href=\"https://e.com/(&quot;a&quot;)\">link</a> <a \
+             href=\"tg://user?id=42\">me</a> <tg-emoji emoji-id=\"1\">👍</tg-emoji>"
+        );
+        assert_eq!(
+            to_markdown_v2(text, &entities),
+            "[link](https://e.com/(\"a\"\\)) [me](tg://user?id=42) ![👍](tg://emoji?id=1)"
+        );
+    }
+}
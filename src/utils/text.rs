@@ -0,0 +1,270 @@
+//! Utilities for text of messages and captions.
+//!
+//! Telegram limits the length of texts after parsing of entities (i.e. without
+//! markup) and counts it in UTF-16 code units, so neither [`str::len`] nor
+//! `.chars().count()` can be used to check the limits.
+
+use std::{borrow::Cow, iter::Peekable, str::Chars};
+
+use crate::types::ParseMode;
+
+/// The maximum length of a message text, see [`visible_len`].
+pub const MAX_MESSAGE_TEXT_LEN: usize = 4096;
+
+/// The maximum length of a media caption, see [`visible_len`].
+pub const MAX_CAPTION_LEN: usize = 1024;
+
+/// Returns the length of the `text` as counted by Telegram, i.e. the number of
+/// UTF-16 code units in the text after parsing of entities with the
+/// `parse_mode`.
+///
+/// The markup is assumed to be valid, for invalid markup the result is
+/// unspecified (Telegram would reject such text anyway).
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{types::ParseMode, utils::text::visible_len};
+///
+/// assert_eq!(visible_len("*bold* and _italic_", Some(ParseMode::MarkdownV2)), 15);
+/// assert_eq!(visible_len("<b>1 &lt; 2</b>", Some(ParseMode::Html)), 5);
+/// // Emojis outside of the BMP take 2 code units
+/// assert_eq!(visible_len("🧇", None), 2);
+/// ```
+pub fn visible_len(text: &str, parse_mode: Option<ParseMode>) -> usize {
+    match parse_mode {
+        None => utf16_len(text),
+        Some(ParseMode::Html) => html_len(text),
+        Some(ParseMode::MarkdownV2) => markdown_len(text, true),
+        #[allow(deprecated)]
+        Some(ParseMode::Markdown) => markdown_len(text, false),
+    }
+}
+
+/// Truncates the plain `text` to at most `max_len` UTF-16 code units (as
+/// counted by Telegram), replacing the end with `…` if the text was truncated.
+///
+/// Markup can't be truncated without breaking it, so this should be applied
+/// to the text before formatting (and escaping) it.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::text::{truncate_with_ellipsis, MAX_CAPTION_LEN};
+///
+/// assert_eq!(truncate_with_ellipsis("Hello, world!", 6), "Hello…");
+/// assert_eq!(truncate_with_ellipsis("Hello", MAX_CAPTION_LEN), "Hello");
+/// ```
+pub fn truncate_with_ellipsis(text: &str, max_len: usize) -> Cow<'_, str> {
+    if utf16_len(text) <= max_len {
+        return Cow::Borrowed(text);
+    }
+
+    if max_len == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // `…` is a single UTF-16 code unit
+    let mut len = 1;
+    let end = text
+        .char_indices()
+        .find(|&(_, c)| {
+            len += c.len_utf16();
+            len > max_len
+        })
+        .map_or(text.len(), |(i, _)| i);
+
+    Cow::Owned(format!("{}…", text[..end].trim_end()))
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+fn html_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                // Skip the tag
+                for c in &mut chars {
+                    if c == '>' {
+                        break;
+                    }
+                }
+            }
+            '&' => len += html_entity(&mut chars).map_or(1, char::len_utf16),
+            c => len += c.len_utf16(),
+        }
+    }
+
+    len
+}
+
+/// Parses an HTML entity (after the `&`), consuming it only if it's valid.
+fn html_entity(chars: &mut Peekable<Chars<'_>>) -> Option<char> {
+    let rest = chars.clone();
+    let name = rest
+        .clone()
+        .take(10)
+        .take_while(|&c| c != ';')
+        .collect::<String>();
+    if rest.clone().nth(name.chars().count()) != Some(';') {
+        return None;
+    }
+
+    let c = match &*name {
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        "quot" => '"',
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            std::char::from_u32(code)?
+        }
+    };
+
+    // Consume the name and `;`
+    for _ in 0..=name.chars().count() {
+        chars.next();
+    }
+
+    Some(c)
+}
+
+/// Length of MarkdownV2 (`v2 = true`) or legacy Markdown text.
+fn markdown_len(text: &str, v2: bool) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+    let mut line_start = true;
+
+    while let Some(c) = chars.next() {
+        let at_line_start = line_start;
+        line_start = c == '\n';
+
+        match c {
+            '\\' if v2 => len += chars.next().map_or(0, char::len_utf16),
+            '\\' if matches!(chars.peek(), Some('_') | Some('*') | Some('`') | Some('[')) => {
+                len += chars.next().map_or(0, char::len_utf16)
+            }
+            '*' | '_' | '[' => {}
+            '~' | '|' if v2 => {}
+            // Block quotations
+            '>' if v2 && at_line_start => {}
+            // Custom emoji
+            '!' if v2 && chars.peek() == Some(&'[') => {}
+            ']' if chars.peek() == Some(&'(') => {
+                // Skip the url
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' if v2 => {
+                            chars.next();
+                        }
+                        ')' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '`' => {
+                let mut rest = chars.clone();
+                let pre = rest.next() == Some('`') && rest.next() == Some('`');
+                if pre {
+                    chars.next();
+                    chars.next();
+                }
+
+                len += code_len(&mut chars, pre, v2);
+            }
+            c => len += c.len_utf16(),
+        }
+    }
+
+    len
+}
+
+/// Length of a code (or a pre if `pre = true`) block after the opening
+/// backtick(s), consumes the closing backtick(s).
+fn code_len(chars: &mut Peekable<Chars<'_>>, pre: bool, escapes: bool) -> usize {
+    let mut content = String::new();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if escapes => content.extend(chars.next()),
+            '`' if !pre => break,
+            '`' => {
+                let mut rest = chars.clone();
+                if rest.next() == Some('`') && rest.next() == Some('`') {
+                    chars.next();
+                    chars.next();
+                    break;
+                }
+                content.push(c);
+            }
+            c => content.push(c),
+        }
+    }
+
+    // The first line of a pre block is its language
+    let content = match content.find('\n') {
+        Some(i) if pre => &content[i + 1..],
+        _ => &content[..],
+    };
+
+    utf16_len(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_v2() {
+        let len = |text| visible_len(text, Some(ParseMode::MarkdownV2));
+
+        assert_eq!(len("plain"), 5);
+        assert_eq!(len("*bold* __underline__ ||spoiler|| ~strike~"), 29);
+        assert_eq!(len(r"1\.5 \*"), 5);
+        assert_eq!(len("[link](https://example.com/\\))"), 4);
+        assert_eq!(len("![👍](tg://emoji?id=5368324170671202286)"), 2);
+        assert_eq!(len("`co*de`"), 5);
+        assert_eq!(len("```rust\nfn main() {}```"), 12);
+        assert_eq!(len(">quote"), 5);
+    }
+
+    #[test]
+    fn html() {
+        let len = |text| visible_len(text, Some(ParseMode::Html));
+
+        assert_eq!(
+            len("<b>bold</b> <a href=\"https://example.com\">link</a>"),
+            9
+        );
+        assert_eq!(len("&lt;&gt;&amp;&quot;"), 4);
+        assert_eq!(len("&#128512; &#x1F600;"), 5);
+        assert_eq!(len("a & b"), 5);
+        assert_eq!(len("&unknown;"), 9);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn markdown() {
+        let len = |text| visible_len(text, Some(ParseMode::Markdown));
+
+        assert_eq!(len("*bold* _italic_ [link](https://example.com)"), 16);
+        assert_eq!(len(r"\_ 1.5"), 5);
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!(truncate_with_ellipsis("abc", 3), "abc");
+        assert_eq!(truncate_with_ellipsis("abcd", 3), "ab…");
+        assert_eq!(truncate_with_ellipsis("ab cd", 4), "ab…");
+        assert_eq!(truncate_with_ellipsis("a🧇b", 3), "a…");
+        assert_eq!(truncate_with_ellipsis("abcd", 0), "");
+    }
+}
@@ -0,0 +1,188 @@
+//! Checking requirements of Telegram for thumbnails.
+//!
+//! Thumbnails passed to methods like [`SendAudio`] or [`SendDocument`] must
+//! be JPEGs of at most 200 kB, with both the width and the height of at most
+//! 320 pixels. Telegram silently ignores thumbnails which don't satisfy the
+//! requirements, so [`Thumbnail`] checks them beforehand.
+//!
+//! [`SendAudio`]: crate::payloads::SendAudio
+//! [`SendDocument`]: crate::payloads::SendDocument
+
+use crate::{errors::ThumbnailError, types::InputFile};
+
+/// The maximum size of a thumbnail, in bytes.
+pub const MAX_THUMBNAIL_SIZE: usize = 200 * 1024;
+
+/// The maximum width and height of a thumbnail, in pixels.
+pub const MAX_THUMBNAIL_DIMENSION: u32 = 320;
+
+/// A JPEG which satisfies the requirements for thumbnails.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{utils::thumbnail::Thumbnail, ThumbnailError};
+///
+/// assert_eq!(Thumbnail::new(b"GIF89a".to_vec()), Err(ThumbnailError::NotJpeg));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Thumbnail {
+    data: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Thumbnail {
+    /// Checks the JPEG `data`, see [`validate`].
+    pub fn new<D>(data: D) -> Result<Self, ThumbnailError>
+    where
+        D: Into<Vec<u8>>,
+    {
+        let data = data.into();
+        let (width, height) = validate(&data)?;
+
+        Ok(Self {
+            data,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the width of the image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the JPEG.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Turns the thumbnail into a memory file named `thumbnail.jpg`.
+    pub fn into_input_file(self) -> InputFile {
+        InputFile::memory("thumbnail.jpg", self.data)
+    }
+}
+
+impl From<Thumbnail> for InputFile {
+    fn from(thumbnail: Thumbnail) -> Self {
+        thumbnail.into_input_file()
+    }
+}
+
+/// Checks that the `data` is a JPEG of at most [`MAX_THUMBNAIL_SIZE`] bytes,
+/// which is at most [`MAX_THUMBNAIL_DIMENSION`] pixels wide and high.
+///
+/// Returns the width and the height of the image.
+pub fn validate(data: &[u8]) -> Result<(u32, u32), ThumbnailError> {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return Err(ThumbnailError::NotJpeg);
+    }
+
+    if data.len() > MAX_THUMBNAIL_SIZE {
+        return Err(ThumbnailError::TooLarge {
+            size: data.len(),
+            limit: MAX_THUMBNAIL_SIZE,
+        });
+    }
+
+    let (width, height) = jpeg_dimensions(data).ok_or(ThumbnailError::Malformed)?;
+    if width > MAX_THUMBNAIL_DIMENSION || height > MAX_THUMBNAIL_DIMENSION {
+        return Err(ThumbnailError::DimensionsTooLarge {
+            width,
+            height,
+            limit: MAX_THUMBNAIL_DIMENSION,
+        });
+    }
+
+    Ok((width, height))
+}
+
+/// Returns the dimensions of a JPEG from its "start of frame" segment.
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let read_u16 = |at: usize| -> Option<u32> {
+        let bytes = data.get(at..at + 2)?;
+        Some(u32::from(bytes[0]) << 8 | u32::from(bytes[1]))
+    };
+
+    // Skip the "start of image" marker
+    let mut pos = 2;
+    loop {
+        if *data.get(pos)? != 0xFF {
+            return None;
+        }
+
+        let marker = *data.get(pos + 1)?;
+        match marker {
+            // Fill bytes
+            0xFF => pos += 1,
+            // Markers without a segment
+            0x01 | 0xD0..=0xD7 => pos += 2,
+            // End of image or start of scan before a frame
+            0xD9 | 0xDA => return None,
+            // Start of frame (except for DHT, JPG and DAC, which share the range)
+            0xC0..=0xCF if marker != 0xC4 && marker != 0xC8 && marker != 0xCC => {
+                let height = read_u16(pos + 5)?;
+                let width = read_u16(pos + 7)?;
+                return Some((width, height));
+            }
+            _ => pos += 2 + read_u16(pos + 2)? as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn jpeg(width: u16, height: u16) -> Vec<u8> {
+    let [w0, w1] = width.to_be_bytes();
+    let [h0, h1] = height.to_be_bytes();
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0, 16];
+    jpeg.extend_from_slice(b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0");
+    jpeg.extend_from_slice(&[0xFF, 0xC0, 0, 17, 8, h0, h1, w0, w1, 3]);
+    jpeg.extend_from_slice(&[1, 0x22, 0, 2, 0x11, 1, 3, 0x11, 1]);
+    jpeg.extend_from_slice(&[0xFF, 0xD9]);
+    jpeg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation() {
+        let thumbnail = Thumbnail::new(jpeg(320, 180)).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (320, 180));
+        assert_eq!(
+            InputFile::from(thumbnail),
+            InputFile::memory("thumbnail.jpg", jpeg(320, 180))
+        );
+
+        assert_eq!(validate(b"\x89PNG"), Err(ThumbnailError::NotJpeg));
+        assert_eq!(
+            validate(&jpeg(320, 180)[..20]),
+            Err(ThumbnailError::Malformed)
+        );
+        assert_eq!(
+            validate(&jpeg(180, 640)),
+            Err(ThumbnailError::DimensionsTooLarge {
+                width: 180,
+                height: 640,
+                limit: 320
+            })
+        );
+
+        let mut large = jpeg(100, 100);
+        large.resize(MAX_THUMBNAIL_SIZE + 1, 0);
+        assert_eq!(
+            validate(&large),
+            Err(ThumbnailError::TooLarge {
+                size: MAX_THUMBNAIL_SIZE + 1,
+                limit: MAX_THUMBNAIL_SIZE
+            })
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Counting received updates.
+//!
+//! [`UpdateStatsCollector`] counts updates by their kind and by the type of
+//! their chat, as well as errors, e.g. updates which couldn't be parsed. This
+//! is useful for capacity planning and for noticing updates which are
+//! silently dropped (e.g. because of Bot API changes).
+//!
+//! Unlike [`UpdateTracker`], the collector is shareable, so snapshots can be
+//! taken from another task while updates are counted.
+//!
+//! [`UpdateTracker`]: crate::utils::update_tracker::UpdateTracker
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+
+use crate::types::{AllowedUpdate, Chat, Update};
+
+/// A type of a chat, see [`UpdateStats::by_chat_type`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ChatType {
+    /// A private chat with a user.
+    Private,
+
+    /// A group.
+    Group,
+
+    /// A supergroup.
+    Supergroup,
+
+    /// A channel.
+    Channel,
+}
+
+impl ChatType {
+    /// Returns the type of the `chat`.
+    pub fn of(chat: &Chat) -> Self {
+        if chat.is_private() {
+            Self::Private
+        } else if chat.is_group() {
+            Self::Group
+        } else if chat.is_supergroup() {
+            Self::Supergroup
+        } else {
+            Self::Channel
+        }
+    }
+}
+
+/// Counters of an [`UpdateStatsCollector`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UpdateStats {
+    /// Number of received updates.
+    pub updates: u64,
+
+    /// Number of updates of every kind.
+    pub by_kind: HashMap<AllowedUpdate, u64>,
+
+    /// Number of updates in chats of every type. Updates without a chat
+    /// (e.g. inline queries) are not counted.
+    pub by_chat_type: HashMap<ChatType, u64>,
+
+    /// Number of errors, including parse failures.
+    pub errors: u64,
+
+    /// Number of errors caused by responses (or updates) which couldn't be
+    /// parsed.
+    pub parse_failures: u64,
+}
+
+impl UpdateStats {
+    /// Returns the number of updates of the `kind`.
+    pub fn kind(&self, kind: AllowedUpdate) -> u64 {
+        self.by_kind.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of updates in chats of the type `chat_type`.
+    pub fn chat_type(&self, chat_type: ChatType) -> u64 {
+        self.by_chat_type.get(&chat_type).copied().unwrap_or(0)
+    }
+}
+
+/// Shared counters of updates, see the [module-level docs].
+///
+/// Clones of the collector share the counters.
+///
+/// ## Examples
+///
+/// ```no_run
+/// # async {
+/// use futures::StreamExt;
+/// use teloxide_core::{
+///     prelude::*,
+///     types::AllowedUpdate,
+///     utils::update_stats::{UpdateStatsCollector, UpdateStreamExt},
+/// };
+///
+/// let stats = UpdateStatsCollector::new();
+/// let mut updates = Bot::new("TOKEN")
+///     .polling()
+///     .into_stream()
+///     .with_stats(stats.clone())
+///     .boxed();
+///
+/// while let Some(update) = updates.next().await {
+///     // ...
+///     # let _ = update;
+/// }
+///
+/// println!("{} messages", stats.snapshot().kind(AllowedUpdate::Message));
+/// # };
+/// ```
+///
+/// [module-level docs]: self
+#[derive(Clone, Debug, Default)]
+pub struct UpdateStatsCollector {
+    stats: Arc<Mutex<UpdateStats>>,
+}
+
+impl UpdateStatsCollector {
+    /// Creates a collector with zeroed counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts the `update`.
+    pub fn record(&self, update: &Update) {
+        let mut stats = self.stats.lock().unwrap();
+        stats.updates += 1;
+        *stats
+            .by_kind
+            .entry(update.kind.allowed_update())
+            .or_insert(0) += 1;
+        if let Some(chat) = update.chat() {
+            *stats.by_chat_type.entry(ChatType::of(chat)).or_insert(0) += 1;
+        }
+    }
+
+    /// Counts an error which is not a parse failure.
+    pub fn record_error(&self) {
+        self.stats.lock().unwrap().errors += 1;
+    }
+
+    /// Counts a parse failure, e.g. an error of
+    /// [`GetUpdatesFaultTolerant`].
+    ///
+    /// [`GetUpdatesFaultTolerant`]: crate::payloads::GetUpdatesFaultTolerant
+    pub fn record_parse_failure(&self) {
+        let mut stats = self.stats.lock().unwrap();
+        stats.errors += 1;
+        stats.parse_failures += 1;
+    }
+
+    /// Counts the update or the error.
+    ///
+    /// Errors caused by a [`serde_json::Error`] (e.g.
+    /// [`RequestError::InvalidJson`]) are counted as parse failures.
+    ///
+    /// [`RequestError::InvalidJson`]: crate::RequestError::InvalidJson
+    pub fn record_result<E>(&self, res: &Result<Update, E>)
+    where
+        E: Error + 'static,
+    {
+        match res {
+            Ok(update) => self.record(update),
+            Err(err) if is_parse_error(err) => self.record_parse_failure(),
+            Err(_) => self.record_error(),
+        }
+    }
+
+    /// Returns the current counters.
+    pub fn snapshot(&self) -> UpdateStats {
+        self.stats.lock().unwrap().clone()
+    }
+
+    /// Returns the current counters and zeroes them.
+    pub fn reset(&self) -> UpdateStats {
+        std::mem::take(&mut *self.stats.lock().unwrap())
+    }
+}
+
+fn is_parse_error(err: &(dyn Error + 'static)) -> bool {
+    let mut err = Some(err);
+    while let Some(e) = err {
+        if e.is::<serde_json::Error>() {
+            return true;
+        }
+        err = e.source();
+    }
+
+    false
+}
+
+/// Extensions of streams of updates.
+pub trait UpdateStreamExt<E>: Stream<Item = Result<Update, E>> + Sized {
+    /// Counts updates and errors of this stream with the `collector`.
+    fn with_stats(self, collector: UpdateStatsCollector) -> WithStats<Self> {
+        WithStats {
+            stream: self,
+            collector,
+        }
+    }
+}
+
+impl<S, E> UpdateStreamExt<E> for S where S: Stream<Item = Result<Update, E>> {}
+
+/// Stream returned by [`UpdateStreamExt::with_stats`].
+#[pin_project::pin_project]
+#[derive(Debug)]
+#[must_use = "Streams do nothing unless polled"]
+pub struct WithStats<S> {
+    #[pin]
+    stream: S,
+    collector: UpdateStatsCollector,
+}
+
+impl<S> WithStats<S> {
+    /// Returns the collector used by this stream.
+    pub fn collector(&self) -> &UpdateStatsCollector {
+        &self.collector
+    }
+}
+
+impl<S, E> Stream for WithStats<S>
+where
+    S: Stream<Item = Result<Update, E>>,
+    E: Error + 'static,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = futures::ready!(this.stream.poll_next(cx));
+        if let Some(res) = &item {
+            this.collector.record_result(res);
+        }
+
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use super::*;
+    use crate::RequestError;
+
+    fn update(id: i32, chat_type: &str) -> Update {
+        serde_json::from_value(serde_json::json!({
+            "update_id": id,
+            "message": {
+                "message_id": 1,
+                "date": 0,
+                "chat": { "id": 1, "type": chat_type, "title": "t", "first_name": "u" },
+                "text": "hi"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn counts() {
+        let poll: Update = serde_json::from_value(serde_json::json!({
+            "update_id": 4,
+            "poll": {
+                "id": "1",
+                "question": "?",
+                "options": [],
+                "is_closed": true,
+                "total_voter_count": 0,
+                "is_anonymous": true,
+                "type": "regular",
+                "allows_multiple_answers": false
+            }
+        }))
+        .unwrap();
+        let parse_error = serde_json::from_str::<Update>("{}").unwrap_err();
+        let updates = vec![
+            Ok(update(1, "private")),
+            Ok(update(2, "private")),
+            Ok(update(3, "supergroup")),
+            Ok(poll),
+            Err(RequestError::InvalidJson(parse_error)),
+            Err(RequestError::RetryAfter(1)),
+        ];
+
+        let collector = UpdateStatsCollector::new();
+        let received: Vec<_> = block_on(
+            stream::iter(updates)
+                .with_stats(collector.clone())
+                .collect(),
+        );
+        assert_eq!(received.len(), 6);
+
+        let stats = collector.snapshot();
+        assert_eq!(stats.updates, 4);
+        assert_eq!(stats.kind(AllowedUpdate::Message), 3);
+        assert_eq!(stats.kind(AllowedUpdate::Poll), 1);
+        assert_eq!(stats.kind(AllowedUpdate::InlineQuery), 0);
+        assert_eq!(stats.chat_type(ChatType::Private), 2);
+        assert_eq!(stats.chat_type(ChatType::Supergroup), 1);
+        assert_eq!(stats.chat_type(ChatType::Channel), 0);
+        assert_eq!(stats.errors, 2);
+        assert_eq!(stats.parse_failures, 1);
+
+        assert_eq!(collector.reset(), stats);
+        assert_eq!(collector.snapshot(), UpdateStats::default());
+    }
+}
@@ -0,0 +1,364 @@
+//! Tracking continuity of [`Update::id`]s.
+//!
+//! Telegram assigns sequential ids to updates, so a gap between two consecutive
+//! ids means that some updates were lost (or not delivered yet) and a repeated
+//! id means that an update was delivered twice (e.g. a webhook redelivery
+//! after a timeout, or an update received both by a webhook and polling).
+//! [`UpdateTracker`] detects both, which is useful as a correctness net for
+//! layers which want to process every update exactly once.
+//!
+//! Note that if a bot doesn't receive updates for at least a week, the next
+//! update id is chosen randomly, which is reported as a gap or, if the new id
+//! is much smaller than the previous one, as a [reset].
+//!
+//! [reset]: UpdateStatus::Reset
+
+use std::{collections::BTreeSet, convert::TryFrom};
+
+use crate::types::Update;
+
+/// Default limit of remembered missing update ids, see
+/// [`UpdateTracker::max_missing`].
+pub const DEFAULT_MAX_MISSING: usize = 100;
+
+/// Default distance of a backwards jump of ids which is treated as a reset,
+/// see [`UpdateTracker::reset_distance`].
+pub const DEFAULT_RESET_DISTANCE: u32 = 100_000;
+
+/// What [`UpdateTracker::track`] found out about an update.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum UpdateStatus {
+    /// The first tracked update.
+    First,
+
+    /// The update directly follows the previous one.
+    InOrder,
+
+    /// Some updates between the previous and this one are missing.
+    Gap {
+        /// Number of missing updates.
+        missed: u32,
+    },
+
+    /// The update was previously reported missing (i.e. it arrived out of
+    /// order).
+    Late,
+
+    /// The update was already tracked.
+    Duplicate,
+
+    /// The update is older than the tracker remembers (e.g. its id was
+    /// forgotten because of [`UpdateTracker::max_missing`]), so it's unknown
+    /// whether it was already tracked.
+    Unknown,
+
+    /// The id is much smaller than the previous one, which means that
+    /// Telegram started assigning ids anew. The tracker starts over from this
+    /// update.
+    Reset,
+}
+
+impl UpdateStatus {
+    /// Returns `true` if the update wasn't seen before, i.e. should be
+    /// processed.
+    ///
+    /// [`UpdateStatus::Unknown`] updates are considered new, so that no
+    /// update is lost.
+    pub fn is_new(&self) -> bool {
+        !matches!(self, Self::Duplicate)
+    }
+}
+
+/// Counters of an [`UpdateTracker`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct UpdateTrackerStats {
+    /// Number of tracked updates, including duplicates.
+    pub tracked: u64,
+
+    /// Number of gaps.
+    pub gaps: u64,
+
+    /// Total number of updates missing in gaps, including the ones which
+    /// arrived late.
+    pub missed: u64,
+
+    /// Number of updates which arrived out of order.
+    pub late: u64,
+
+    /// Number of duplicated updates.
+    pub duplicates: u64,
+
+    /// Number of updates which were older than the tracker remembers.
+    pub unknown: u64,
+
+    /// Number of resets of ids.
+    pub resets: u64,
+}
+
+/// Tracks [`Update::id`] continuity, detecting gaps and duplicates.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::utils::update_tracker::{UpdateStatus, UpdateTracker};
+///
+/// let mut tracker = UpdateTracker::new();
+///
+/// assert_eq!(tracker.track(10), UpdateStatus::First);
+/// assert_eq!(tracker.track(11), UpdateStatus::InOrder);
+/// assert_eq!(tracker.track(14), UpdateStatus::Gap { missed: 2 });
+/// assert_eq!(tracker.track(12), UpdateStatus::Late);
+/// assert_eq!(tracker.track(11), UpdateStatus::Duplicate);
+///
+/// assert_eq!(tracker.missing().collect::<Vec<_>>(), [13]);
+/// assert_eq!(tracker.stats().duplicates, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateTracker {
+    last: Option<i32>,
+    /// The smallest id the tracker knows about, every id between it and
+    /// `last` was either tracked or is `missing`.
+    horizon: i32,
+    /// Ids which are less than `last` but weren't tracked yet.
+    missing: BTreeSet<i32>,
+    max_missing: usize,
+    reset_distance: u32,
+    stats: UpdateTrackerStats,
+}
+
+impl UpdateTracker {
+    /// Creates new `UpdateTracker` which remembers at most
+    /// [`DEFAULT_MAX_MISSING`] missing ids.
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            horizon: 0,
+            missing: BTreeSet::new(),
+            max_missing: DEFAULT_MAX_MISSING,
+            reset_distance: DEFAULT_RESET_DISTANCE,
+            stats: UpdateTrackerStats::default(),
+        }
+    }
+
+    /// Sets the maximum number of remembered missing ids.
+    ///
+    /// When the limit is exceeded, the smallest missing ids are forgotten,
+    /// so if they (or updates with even smaller ids) arrive later, they are
+    /// reported as [`UpdateStatus::Unknown`] instead of
+    /// [`UpdateStatus::Late`] or [`UpdateStatus::Duplicate`].
+    pub fn max_missing(mut self, max_missing: usize) -> Self {
+        self.max_missing = max_missing;
+        self.forget_excess();
+        self
+    }
+
+    /// Sets the distance of a backwards jump of ids (from the largest tracked
+    /// id) which is reported as [`UpdateStatus::Reset`].
+    ///
+    /// Smaller jumps to ids which the tracker doesn't remember are reported
+    /// as [`UpdateStatus::Unknown`].
+    pub fn reset_distance(mut self, reset_distance: u32) -> Self {
+        self.reset_distance = reset_distance;
+        self
+    }
+
+    /// Tracks an update with the id `id`.
+    pub fn track(&mut self, id: i32) -> UpdateStatus {
+        self.stats.tracked += 1;
+
+        let last = match self.last {
+            Some(last) => last,
+            None => {
+                self.start(id);
+                return UpdateStatus::First;
+            }
+        };
+
+        if id < self.horizon {
+            return if i64::from(last) - i64::from(id) >= i64::from(self.reset_distance) {
+                self.stats.resets += 1;
+                self.start(id);
+                UpdateStatus::Reset
+            } else {
+                self.stats.unknown += 1;
+                UpdateStatus::Unknown
+            };
+        }
+
+        if id <= last {
+            return if self.missing.remove(&id) {
+                self.stats.late += 1;
+                UpdateStatus::Late
+            } else {
+                self.stats.duplicates += 1;
+                UpdateStatus::Duplicate
+            };
+        }
+
+        self.last = Some(id);
+        let missed = (i64::from(id) - i64::from(last) - 1) as u32;
+        if missed == 0 {
+            return UpdateStatus::InOrder;
+        }
+
+        self.stats.gaps += 1;
+        self.stats.missed += u64::from(missed);
+
+        // Only the largest ids can be remembered anyway
+        let max_missing = i64::try_from(self.max_missing).unwrap_or(i64::MAX);
+        let from = i64::from(id)
+            .saturating_sub(max_missing)
+            .max(i64::from(last) + 1) as i32;
+        if i64::from(from) > i64::from(last) + 1 {
+            self.horizon = from;
+        }
+        self.missing.extend(from..id);
+        self.forget_excess();
+
+        UpdateStatus::Gap { missed }
+    }
+
+    /// Tracks the `update`, see [`track`].
+    ///
+    /// [`track`]: UpdateTracker::track
+    pub fn track_update(&mut self, update: &Update) -> UpdateStatus {
+        self.track(update.id)
+    }
+
+    /// Returns the largest tracked update id (since the last reset).
+    pub fn last_id(&self) -> Option<i32> {
+        self.last
+    }
+
+    /// Returns ids of the missing updates (at most [`max_missing`] of them)
+    /// in ascending order.
+    ///
+    /// [`max_missing`]: UpdateTracker::max_missing
+    pub fn missing(&self) -> impl Iterator<Item = i32> + '_ {
+        self.missing.iter().copied()
+    }
+
+    /// Returns counters of tracked updates.
+    pub fn stats(&self) -> UpdateTrackerStats {
+        self.stats
+    }
+
+    /// Forgets everything and starts tracking from the `id`.
+    fn start(&mut self, id: i32) {
+        self.last = Some(id);
+        self.horizon = id;
+        self.missing.clear();
+    }
+
+    fn forget_excess(&mut self) {
+        while self.missing.len() > self.max_missing {
+            let first = *self.missing.iter().next().unwrap();
+            self.missing.remove(&first);
+            // All the smaller ids are forgotten too
+            self.horizon = first + 1;
+        }
+    }
+}
+
+impl Default for UpdateTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gaps_and_duplicates() {
+        let mut tracker = UpdateTracker::new();
+
+        assert_eq!(tracker.track(1), UpdateStatus::First);
+        assert_eq!(tracker.track(1), UpdateStatus::Duplicate);
+        assert_eq!(tracker.track(2), UpdateStatus::InOrder);
+        assert_eq!(tracker.track(5), UpdateStatus::Gap { missed: 2 });
+        assert_eq!(tracker.track(4), UpdateStatus::Late);
+        assert_eq!(tracker.track(4), UpdateStatus::Duplicate);
+        assert_eq!(tracker.last_id(), Some(5));
+        assert_eq!(tracker.missing().collect::<Vec<_>>(), [3]);
+
+        assert_eq!(
+            tracker.stats(),
+            UpdateTrackerStats {
+                tracked: 6,
+                gaps: 1,
+                missed: 2,
+                late: 1,
+                duplicates: 2,
+                unknown: 0,
+                resets: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_is_bounded() {
+        let mut tracker = UpdateTracker::new().max_missing(2);
+
+        tracker.track(0);
+        assert_eq!(
+            tracker.track(1_000_000),
+            UpdateStatus::Gap { missed: 999_999 }
+        );
+        assert_eq!(tracker.missing().collect::<Vec<_>>(), [999_998, 999_999]);
+
+        assert_eq!(tracker.track(1_000_003), UpdateStatus::Gap { missed: 2 });
+        assert_eq!(
+            tracker.missing().collect::<Vec<_>>(),
+            [1_000_001, 1_000_002]
+        );
+
+        // Forgotten ids are unknown
+        assert_eq!(tracker.track(999_999), UpdateStatus::Unknown);
+        assert_eq!(tracker.track(1_000_001), UpdateStatus::Late);
+        assert_eq!(tracker.track(1_000_001), UpdateStatus::Duplicate);
+        assert_eq!(tracker.stats().unknown, 1);
+    }
+
+    #[test]
+    fn unbounded_missing() {
+        let mut tracker = UpdateTracker::new().max_missing(usize::MAX);
+
+        tracker.track(0);
+        assert_eq!(tracker.track(10), UpdateStatus::Gap { missed: 9 });
+        assert_eq!(tracker.missing().count(), 9);
+        assert_eq!(tracker.track(1), UpdateStatus::Late);
+    }
+
+    #[test]
+    fn reset() {
+        let mut tracker = UpdateTracker::new().reset_distance(1000);
+
+        tracker.track(5000);
+        tracker.track(5002);
+        // A small jump backwards, before the first tracked update
+        assert_eq!(tracker.track(4999), UpdateStatus::Unknown);
+        assert_eq!(tracker.track(3000), UpdateStatus::Reset);
+        assert_eq!(tracker.last_id(), Some(3000));
+        assert_eq!(tracker.missing().count(), 0);
+
+        assert_eq!(tracker.track(3001), UpdateStatus::InOrder);
+        assert_eq!(tracker.track(3000), UpdateStatus::Duplicate);
+        assert_eq!(tracker.stats().resets, 1);
+    }
+
+    #[test]
+    fn extreme_ids() {
+        let mut tracker = UpdateTracker::new();
+
+        tracker.track(i32::MIN);
+        assert_eq!(
+            tracker.track(i32::MAX),
+            UpdateStatus::Gap {
+                missed: u32::MAX - 1
+            }
+        );
+        assert_eq!(tracker.missing().count(), DEFAULT_MAX_MISSING);
+    }
+}
@@ -0,0 +1,319 @@
+//! Reading metadata of video files.
+//!
+//! Without the dimensions passed to [`SendVideo`], Telegram clients may show
+//! a stretched preview of the video until it's loaded. [`VideoMetadata`]
+//! reads the dimensions and the duration of `.mp4` files (and other ISO base
+//! media files, e.g. `.mov`), so that they can be filled automatically.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! # async {
+//! use teloxide_core::{
+//!     prelude::*, requests::HasPayload, types::InputFile, utils::video_metadata,
+//! };
+//!
+//! let bot = Bot::new("TOKEN");
+//! let mut request = bot.send_video(0, InputFile::file("video.mp4"));
+//! video_metadata::fill_send_video(request.payload_mut()).await?;
+//! request.send().await?;
+//! # Ok::<_, Box<dyn std::error::Error>>(()) };
+//! ```
+
+use std::{io, io::SeekFrom, path::Path};
+
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::{
+    payloads::SendVideo,
+    types::InputFile,
+    utils::mp4::{self, read_u32, read_u64, Boxes},
+};
+
+/// The maximum size of a `moov` box which is read from a file, in bytes.
+const MAX_MOOV_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Metadata of a video file, see the [module-level docs].
+///
+/// [module-level docs]: self
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct VideoMetadata {
+    /// Duration of the video in seconds.
+    pub duration: Option<u32>,
+
+    /// Width of the video, as it should be displayed (i.e. after rotation).
+    pub width: Option<u32>,
+
+    /// Height of the video, as it should be displayed (i.e. after rotation).
+    pub height: Option<u32>,
+
+    /// `true` if the metadata is placed before the media data (a.k.a. "fast
+    /// start"), so the video can be played while it's being downloaded.
+    pub supports_streaming: bool,
+}
+
+impl VideoMetadata {
+    /// Parses metadata of an `.mp4` file.
+    ///
+    /// Returns `None` if the format is not recognized.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if !mp4::is_mp4(data) {
+            return None;
+        }
+
+        let mut boxes = Boxes::new(data);
+        let mut supports_streaming = true;
+        let moov = boxes.find_map(|(ty, content)| match &ty {
+            b"moov" => Some(content),
+            b"mdat" => {
+                supports_streaming = false;
+                None
+            }
+            _ => None,
+        })?;
+
+        Some(Self::from_moov(moov, supports_streaming))
+    }
+
+    /// Reads metadata of the `file`.
+    ///
+    /// Only the metadata is read from files on disk, memory files are parsed
+    /// with [`parse`]. Returns `Ok(None)` for files which can't be read
+    /// locally (i.e. URLs, file ids and [reopenable files]) and for
+    /// unrecognized formats.
+    ///
+    /// [`parse`]: VideoMetadata::parse
+    /// [reopenable files]: InputFile::Reopenable
+    pub async fn read(file: &InputFile) -> io::Result<Option<Self>> {
+        let mut file = file;
+        while let InputFile::WithMetadata(meta) = file {
+            file = &meta.file;
+        }
+
+        match file {
+            InputFile::File(path) => Ok(read_moov(path)
+                .await?
+                .map(|(moov, supports_streaming)| Self::from_moov(&moov, supports_streaming))),
+            InputFile::Memory { data, .. } => Ok(Self::parse(data)),
+            _ => Ok(None),
+        }
+    }
+
+    fn from_moov(moov: &[u8], supports_streaming: bool) -> Self {
+        let dimensions = mp4::tracks(moov, b"vide").find_map(dimensions);
+
+        Self {
+            duration: mp4::duration(moov),
+            width: dimensions.map(|(width, _)| width),
+            height: dimensions.map(|(_, height)| height),
+            supports_streaming,
+        }
+    }
+
+    /// Sets `duration`, `width`, `height` and `supports_streaming` of the
+    /// `payload`, which are not set yet and are known.
+    ///
+    /// `supports_streaming` is only set if the video supports streaming.
+    pub fn fill(&self, payload: &mut SendVideo) {
+        if payload.duration.is_none() {
+            payload.duration = self.duration;
+        }
+        if payload.width.is_none() {
+            payload.width = self.width;
+        }
+        if payload.height.is_none() {
+            payload.height = self.height;
+        }
+        if payload.supports_streaming.is_none() && self.supports_streaming {
+            payload.supports_streaming = Some(true);
+        }
+    }
+}
+
+/// Reads metadata of the video of the `payload` and [fills] the payload with
+/// it.
+///
+/// [fills]: VideoMetadata::fill
+pub async fn fill_send_video(payload: &mut SendVideo) -> io::Result<()> {
+    if let Some(meta) = VideoMetadata::read(&payload.video).await? {
+        meta.fill(payload);
+    }
+
+    Ok(())
+}
+
+/// Returns the display dimensions of a video track, from its `tkhd` box.
+fn dimensions(trak: &[u8]) -> Option<(u32, u32)> {
+    let tkhd = mp4::find(trak, &[b"tkhd"])?;
+    // Creation and modification times and the duration are 64-bit in the
+    // version 1
+    let (matrix, size) = match tkhd.first()? {
+        0 => (40, 76),
+        1 => (52, 88),
+        _ => return None,
+    };
+
+    // Both are 16.16 fixed point numbers
+    let width = read_u32(tkhd, size)? >> 16;
+    let height = read_u32(tkhd, size + 4)? >> 16;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    // The transformation matrix rotates the video by 90 or 270 degrees
+    let a = read_u32(tkhd, matrix)?;
+    let b = read_u32(tkhd, matrix + 4)?;
+    if a == 0 && b != 0 {
+        Some((height, width))
+    } else {
+        Some((width, height))
+    }
+}
+
+/// Reads the content of the `moov` box of the file at the `path`, skipping
+/// other boxes, and whether it's placed before the `mdat` box.
+async fn read_moov(path: &Path) -> io::Result<Option<(Vec<u8>, bool)>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let len = file.metadata().await?.len();
+
+    let mut pos = 0;
+    let mut supports_streaming = true;
+    while pos + 8 <= len {
+        let mut header = [0; 16];
+        file.seek(SeekFrom::Start(pos)).await?;
+        file.read_exact(&mut header[..8]).await?;
+
+        if pos == 0 && &header[4..8] != b"ftyp" {
+            return Ok(None);
+        }
+
+        let (header_len, size) = match read_u32(&header, 0) {
+            Some(0) => (8, len - pos),
+            Some(1) => {
+                // The file is truncated within the 64-bit size
+                match file.read_exact(&mut header[8..]).await {
+                    Ok(_) => {}
+                    Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+                    Err(err) => return Err(err),
+                }
+                (16, read_u64(&header, 8).unwrap_or(0))
+            }
+            size => (8, u64::from(size.unwrap_or(0))),
+        };
+        if size < header_len || size > len - pos {
+            return Ok(None);
+        }
+
+        match &header[4..8] {
+            b"moov" if size - header_len <= MAX_MOOV_SIZE => {
+                let mut moov = vec![0; (size - header_len) as usize];
+                file.read_exact(&mut moov).await?;
+                return Ok(Some((moov, supports_streaming)));
+            }
+            b"moov" => return Ok(None),
+            b"mdat" => supports_streaming = false,
+            _ => {}
+        }
+
+        pos += size;
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        payloads::SendVideoSetters,
+        utils::mp4::build::{mp4_box, mvhd, trak},
+    };
+
+    fn video(rotated: bool, fast_start: bool) -> Vec<u8> {
+        let moov = mp4_box(
+            b"moov",
+            &[
+                &mvhd(600, 600 * 15),
+                &trak(b"soun", 0, 0, false),
+                &trak(b"vide", 1920, 1080, rotated),
+            ],
+        );
+        let mdat = mp4_box(b"mdat", &[&[0; 16]]);
+        let ftyp = mp4_box(b"ftyp", &[b"isom"]);
+
+        if fast_start {
+            [ftyp, moov, mdat].concat()
+        } else {
+            [ftyp, mdat, moov].concat()
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            VideoMetadata::parse(&video(false, true)),
+            Some(VideoMetadata {
+                duration: Some(15),
+                width: Some(1920),
+                height: Some(1080),
+                supports_streaming: true,
+            })
+        );
+        assert_eq!(
+            VideoMetadata::parse(&video(true, false)),
+            Some(VideoMetadata {
+                duration: Some(15),
+                width: Some(1080),
+                height: Some(1920),
+                supports_streaming: false,
+            })
+        );
+        assert_eq!(VideoMetadata::parse(b"not a video"), None);
+    }
+
+    #[test]
+    fn read_file() {
+        let path = std::env::temp_dir().join(format!(
+            "teloxide-core-video-metadata-{}.mp4",
+            std::process::id()
+        ));
+        std::fs::write(&path, video(true, false)).unwrap();
+
+        let rt = crate::test_utils::rt();
+        let meta = rt.block_on(VideoMetadata::read(&InputFile::file(&path)));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(meta.unwrap(), VideoMetadata::parse(&video(true, false)));
+    }
+
+    #[test]
+    fn read_truncated_file() {
+        let path = std::env::temp_dir().join(format!(
+            "teloxide-core-video-metadata-truncated-{}.mp4",
+            std::process::id()
+        ));
+        // A box with a 64-bit size, cut off within the size
+        let file = [
+            mp4_box(b"ftyp", &[b"isom"]),
+            vec![0, 0, 0, 1, b'm', b'd', b'a', b't', 0],
+        ]
+        .concat();
+        std::fs::write(&path, file).unwrap();
+
+        let rt = crate::test_utils::rt();
+        let meta = rt.block_on(VideoMetadata::read(&InputFile::file(&path)));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(meta.unwrap(), None);
+    }
+
+    #[test]
+    fn fill() {
+        let meta = VideoMetadata::parse(&video(false, true)).unwrap();
+        let mut payload = SendVideo::new(0, InputFile::file_id("id")).width(640);
+        meta.fill(&mut payload);
+
+        assert_eq!(payload.duration, Some(15));
+        assert_eq!(payload.width, Some(640));
+        assert_eq!(payload.height, Some(1080));
+        assert_eq!(payload.supports_streaming, Some(true));
+    }
+}
@@ -0,0 +1,51 @@
+//! Receiving updates by a webhook.
+//!
+//! If a webhook is set with a [`secret_token`], Telegram sends the token in
+//! the [`SECRET_TOKEN_HEADER`] of every webhook request, so requests which
+//! don't come from Telegram can be rejected with [`check_secret_token`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use teloxide_core::utils::webhook::check_secret_token;
+//!
+//! // The value of the `X-Telegram-Bot-Api-Secret-Token` header, if any
+//! let header: Option<&[u8]> = Some(b"my-secret");
+//!
+//! assert!(check_secret_token("my-secret", header));
+//! assert!(!check_secret_token("my-secret", None));
+//! ```
+//!
+//! [`secret_token`]: crate::payloads::SetWebhook::secret_token
+
+use subtle::ConstantTimeEq;
+
+/// Name of the header in which Telegram sends the secret token of the
+/// webhook.
+pub const SECRET_TOKEN_HEADER: &str = "X-Telegram-Bot-Api-Secret-Token";
+
+/// Returns `true` if the value of the [`SECRET_TOKEN_HEADER`] of a webhook
+/// request is the `secret_token` the webhook was set with.
+///
+/// The comparison is done in constant time, so the token can't be guessed by
+/// measuring response times. A missing header never matches.
+pub fn check_secret_token(secret_token: &str, header: Option<&[u8]>) -> bool {
+    match header {
+        Some(header) => bool::from(secret_token.as_bytes().ct_eq(header)),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_token() {
+        assert!(check_secret_token("abc", Some(b"abc")));
+        assert!(!check_secret_token("abc", Some(b"abd")));
+        assert!(!check_secret_token("abc", Some(b"ab")));
+        assert!(!check_secret_token("abc", Some(b"")));
+        assert!(!check_secret_token("abc", None));
+    }
+}